@@ -118,6 +118,9 @@ pub(crate) fn handle_step2_action(
             component_id,
             component_key,
         } => open_compat_for_component(state, game_tab, tp_file, component_id, component_key),
+        Step2Action::JumpToComponent { tp2, component_id } => {
+            jump_to_component(state, &tp2, component_id);
+        }
         Step2Action::SelectBgeeViaLog | Step2Action::SelectBg2eeViaLog => {}
     }
 }
@@ -148,6 +151,7 @@ fn accept_latest_for_exact_version_misses(
         .map(
             |request| super::app_step2_update_check::Step2UpdateCheckRequest {
                 game_tab: request.game_tab.clone(),
+                modlist_id: String::new(),
                 tp_file: request.tp_file.clone(),
                 label: request.label.clone(),
                 source_id: request.source_id.clone(),
@@ -387,6 +391,17 @@ fn open_compat_for_component(
     state.step2.compat_popup_open = true;
 }
 
+fn jump_to_component(state: &mut WizardState, tp2: &str, component_id: u32) {
+    let game_tab = state.step2.active_game_tab.clone();
+    let previous = state.step2.selected.clone();
+    crate::app::selection_jump::step2_jump_to_target(state, &game_tab, tp2, Some(component_id));
+    if state.step2.selected == previous {
+        state.step2.scan_status = format!("{tp2} #{component_id} is not in the scanned mod list");
+    } else {
+        state.step2.jump_to_selected_requested = true;
+    }
+}
+
 fn set_selected_mod_update_locked(state: &mut WizardState, locked: bool) {
     let Some(Step2Selection::Mod { game_tab, tp_file }) = state.step2.selected.clone() else {
         return;
@@ -548,6 +563,7 @@ fn invalidate_update_selected_results(state: &mut WizardState) {
     state.step2.update_selected_download_failed_sources.clear();
     state.step2.update_selected_extracted_sources.clear();
     state.step2.update_selected_extract_failed_sources.clear();
+    state.step2.update_selected_lock_drift_sources.clear();
     state.step2.update_selected_known_sources.clear();
     state.step2.update_selected_manual_sources.clear();
     state.step2.update_selected_unknown_sources.clear();
@@ -197,6 +197,9 @@ fn auto_build_blocker_before_install(state: &WizardState) -> Option<String> {
     if let Some(source) = state.step2.update_selected_extract_failed_sources.first() {
         return Some(format!("failed extraction/config restore: {source}"));
     }
+    if let Some(drift) = state.step2.update_selected_lock_drift_sources.first() {
+        return Some(format!("modlist lock drift: {drift}"));
+    }
     if !state.step2.update_selected_update_assets.is_empty()
         || !state.step2.update_selected_missing_sources.is_empty()
         || !state.step2.update_selected_update_sources.is_empty()
@@ -233,6 +236,9 @@ pub(crate) fn unresolved_required_mods_blocker(state: &WizardState) -> Option<St
     if let Some(source) = state.step2.update_selected_extract_failed_sources.first() {
         return Some(format!("failed extraction/config restore: {source}"));
     }
+    if let Some(drift) = state.step2.update_selected_lock_drift_sources.first() {
+        return Some(format!("modlist lock drift: {drift}"));
+    }
     if state.step2.update_selected_update_assets.is_empty()
         && (!state.step2.update_selected_missing_sources.is_empty()
             || !state.step2.update_selected_update_sources.is_empty())
@@ -243,7 +249,8 @@ pub(crate) fn unresolved_required_mods_blocker(state: &WizardState) -> Option<St
 }
 
 fn auto_build_preflight_blocker(state: &WizardState) -> Option<String> {
-    let (ok, message) = crate::app::state_validation::run_path_check(&state.step1);
+    let (ok, message) =
+        crate::app::state_validation::run_path_check(&state.step1, state.step5.resume_available);
     if ok {
         None
     } else {
@@ -6,10 +6,10 @@ use std::fs;
 use std::sync::{Mutex, OnceLock};
 use std::time::SystemTime;
 
+use crate::app::tra_resolver::tra_table;
 use crate::parser::collect_tp2_component_blocks;
 
 use super::compat_rule_runtime::normalize_mod_key;
-use super::compat_setup_tra::load_tp2_setup_tra_map;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct ComponentConflict {
@@ -19,7 +19,10 @@ pub(crate) struct ComponentConflict {
     pub(crate) message: Option<String>,
 }
 
-pub(crate) fn load_component_conflicts(tp2_path: &str) -> HashMap<String, Vec<ComponentConflict>> {
+pub(crate) fn load_component_conflicts(
+    tp2_path: &str,
+    language: Option<usize>,
+) -> HashMap<String, Vec<ComponentConflict>> {
     if tp2_path.trim().is_empty() {
         return HashMap::new();
     }
@@ -29,30 +32,36 @@ pub(crate) fn load_component_conflicts(tp2_path: &str) -> HashMap<String, Vec<Co
 
     if let Some(entry) = cache.get(tp2_path)
         && entry.stamp == stamp
+        && entry.language == language
     {
         return entry.conflicts.clone();
     }
 
-    let conflicts = load_component_conflicts_uncached(tp2_path);
+    let conflicts = load_component_conflicts_uncached(tp2_path, language);
     cache.insert(
         tp2_path.to_string(),
         CachedConflicts {
             stamp,
+            language,
             conflicts: conflicts.clone(),
         },
     );
     conflicts
 }
 
-fn load_component_conflicts_uncached(tp2_path: &str) -> HashMap<String, Vec<ComponentConflict>> {
+fn load_component_conflicts_uncached(
+    tp2_path: &str,
+    language: Option<usize>,
+) -> HashMap<String, Vec<ComponentConflict>> {
     let Ok(tp2_text) = fs::read_to_string(tp2_path) else {
         return HashMap::new();
     };
-    let tra_map = load_tp2_setup_tra_map(std::path::Path::new(tp2_path));
+    let tra = tra_table(std::path::Path::new(tp2_path), language);
+    let tra_map = tra.entries();
 
     let mut out = HashMap::<String, Vec<ComponentConflict>>::new();
     for (component_id, block) in collect_tp2_component_blocks(&tp2_text) {
-        let conflicts = collect_component_conflicts(&block, &tra_map);
+        let conflicts = collect_component_conflicts(&block, tra_map);
         if !conflicts.is_empty() {
             out.insert(component_id, conflicts);
         }
@@ -70,6 +79,7 @@ struct FileCacheStamp {
 #[derive(Debug, Clone)]
 struct CachedConflicts {
     stamp: FileCacheStamp,
+    language: Option<usize>,
     conflicts: HashMap<String, Vec<ComponentConflict>>,
 }
 
@@ -4,6 +4,8 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
 
+use crate::app::tra_resolver::ModLanguages;
+
 use super::compat_conflict_parse::{ComponentConflict, load_component_conflicts};
 use super::compat_rule_runtime::{CompatActiveItem, normalize_mod_key};
 
@@ -36,11 +38,12 @@ pub(crate) struct ConflictScanContext {
 
 pub(in crate::app) fn build_conflict_scan_context(
     active_items: &[CompatActiveItem],
+    languages: &ModLanguages,
     conflict_cache: &mut ComponentConflictCache,
 ) -> ConflictScanContext {
     ConflictScanContext {
         active_orders: active_order_map(active_items),
-        adjacency: build_selected_conflict_graph(active_items, conflict_cache),
+        adjacency: build_selected_conflict_graph(active_items, languages, conflict_cache),
     }
 }
 
@@ -154,6 +157,7 @@ fn path_reaches(
 
 fn build_selected_conflict_graph(
     active_items: &[CompatActiveItem],
+    languages: &ModLanguages,
     conflict_cache: &mut ComponentConflictCache,
 ) -> HashMap<String, Vec<ConflictEdge>> {
     let active_keys = active_key_set(active_items);
@@ -163,9 +167,12 @@ fn build_selected_conflict_graph(
         if item.tp2_path.trim().is_empty() {
             continue;
         }
-        let conflicts_by_component = conflict_cache
-            .entry(item.tp2_path.clone())
-            .or_insert_with(|| load_component_conflicts(&item.tp2_path));
+        let conflicts_by_component =
+            conflict_cache
+                .entry(item.tp2_path.clone())
+                .or_insert_with(|| {
+                    load_component_conflicts(&item.tp2_path, languages.get(&item.tp2_path))
+                });
         let Some(conflicts) = conflicts_by_component.get(item.component_id.trim()) else {
             continue;
         };
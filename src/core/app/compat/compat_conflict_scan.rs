@@ -2,6 +2,7 @@
 // Copyright (c) 2026 Born2BSalty
 
 use crate::app::state::{Step2ComponentState, Step2ModState};
+use crate::app::tra_resolver::ModLanguages;
 
 use super::compat_conflict_runtime::{
     ComponentConflictCache, ConflictCompatHit, build_conflict_scan_context,
@@ -14,7 +15,11 @@ use super::compat_rule_runtime::{
 pub(crate) fn apply_step2_scan_conflict(mods: &mut [Step2ModState]) {
     let active_items = collect_step2_active_items(mods);
     let mut conflict_cache = ComponentConflictCache::new();
-    let conflict_context = build_conflict_scan_context(&active_items, &mut conflict_cache);
+    let conflict_context = build_conflict_scan_context(
+        &active_items,
+        &ModLanguages::from_mods(mods),
+        &mut conflict_cache,
+    );
 
     for mod_state in mods {
         let current_mod_key = normalize_mod_key(&mod_state.tp_file);
@@ -7,6 +7,7 @@ use std::sync::{Mutex, OnceLock};
 use std::time::SystemTime;
 
 use crate::app::state::{Step2ComponentState, Step2ModState};
+use crate::app::tra_resolver::{TraTable, mod_language, tra_table};
 
 use super::compat_rule_runtime::normalize_mod_key;
 
@@ -15,9 +16,10 @@ pub(crate) fn apply_step2_scan_deprecated(mods: &mut [Step2ModState]) {
 
     for mod_state in mods {
         let current_mod_key = normalize_mod_key(&mod_state.tp_file);
+        let language = mod_language(mod_state);
         let deprecated_hits = deprecated_cache
             .entry(mod_state.tp2_path.clone())
-            .or_insert_with(|| load_component_deprecated_hits(&mod_state.tp2_path));
+            .or_insert_with(|| load_component_deprecated_hits(&mod_state.tp2_path, language));
 
         for component in &mut mod_state.components {
             if component
@@ -63,7 +65,10 @@ fn apply_deprecated(
     component.disabled_reason = Some(hit.message.clone());
 }
 
-fn load_component_deprecated_hits(tp2_path: &str) -> HashMap<String, DeprecatedHit> {
+fn load_component_deprecated_hits(
+    tp2_path: &str,
+    language: Option<usize>,
+) -> HashMap<String, DeprecatedHit> {
     if tp2_path.trim().is_empty() {
         return HashMap::new();
     }
@@ -72,25 +77,31 @@ fn load_component_deprecated_hits(tp2_path: &str) -> HashMap<String, DeprecatedH
     let stamp = cache_stamp(tp2_path);
     if let Some(entry) = cache.get(tp2_path)
         && entry.stamp == stamp
+        && entry.language == language
     {
         return entry.hits.clone();
     }
-    let hits = load_component_deprecated_hits_uncached(tp2_path);
+    let hits = load_component_deprecated_hits_uncached(tp2_path, language);
     cache.insert(
         tp2_path.to_string(),
         CachedDeprecatedHits {
             stamp,
+            language,
             hits: hits.clone(),
         },
     );
     hits
 }
 
-fn load_component_deprecated_hits_uncached(tp2_path: &str) -> HashMap<String, DeprecatedHit> {
+fn load_component_deprecated_hits_uncached(
+    tp2_path: &str,
+    language: Option<usize>,
+) -> HashMap<String, DeprecatedHit> {
     let Ok(tp2_text) = fs::read_to_string(tp2_path) else {
         return HashMap::new();
     };
 
+    let tra = tra_table(std::path::Path::new(tp2_path), language);
     let mut out = HashMap::<String, DeprecatedHit>::new();
     let lines: Vec<&str> = tp2_text.lines().collect();
     let mut index = 0usize;
@@ -127,7 +138,7 @@ fn load_component_deprecated_hits_uncached(tp2_path: &str) -> HashMap<String, De
             component_id,
             DeprecatedHit {
                 source: deprecated_source(tp2_path),
-                message: deprecated_message(&raw_evidence),
+                message: deprecated_message(&raw_evidence, &tra),
                 raw_evidence,
             },
         );
@@ -145,6 +156,7 @@ struct FileCacheStamp {
 #[derive(Debug, Clone)]
 struct CachedDeprecatedHits {
     stamp: FileCacheStamp,
+    language: Option<usize>,
     hits: HashMap<String, DeprecatedHit>,
 }
 
@@ -203,11 +215,19 @@ fn inline_begin_deprecated(line: &str) -> Option<&str> {
     Some(search_region[dep_idx..].trim_start())
 }
 
-fn deprecated_message(raw_evidence: &str) -> String {
+fn deprecated_message(raw_evidence: &str, tra: &TraTable) -> String {
     let Some(dep_idx) = raw_evidence.to_ascii_uppercase().find("DEPRECATED") else {
         return "TP2 marks this component as deprecated.".to_string();
     };
     let tail = raw_evidence[dep_idx + "DEPRECATED".len()..].trim_start();
+    if tail.starts_with('@')
+        && let Some(message) = tail
+            .split_whitespace()
+            .next()
+            .and_then(|reference| tra.get(reference))
+    {
+        return message.to_string();
+    }
     if let Some(start) = tail.find('~')
         && let Some(end_rel) = tail[start + 1..].find('~')
     {
@@ -215,7 +215,7 @@ fn target_matches_one(
     })
 }
 
-fn related_targets(rule: &CompatRule) -> Vec<(String, Option<String>)> {
+pub(in crate::app) fn related_targets(rule: &CompatRule) -> Vec<(String, Option<String>)> {
     let related_mods = string_or_many_items(rule.related_mod.as_ref());
     if related_mods.is_empty() {
         return Vec::new();
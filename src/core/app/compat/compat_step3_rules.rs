@@ -8,6 +8,7 @@ use std::sync::{Mutex, OnceLock};
 
 use crate::app::compat_issue::CompatIssue;
 use crate::app::state::{Step1State, Step2ModState, Step3ItemState};
+use crate::app::tra_resolver::ModLanguages;
 
 use super::compat_conflict_runtime::{
     ComponentConflictCache, ConflictCompatHit, ConflictScanContext, build_conflict_scan_context,
@@ -83,7 +84,11 @@ fn collect_step3_compat_markers_uncached(
     let mut conflict_cache = ComponentConflictCache::new();
     let mut path_guard_cache = ComponentPathGuardCache::new();
     let path_context = PathRequirementContext::for_tab(step1, tab);
-    let conflict_context = build_conflict_scan_context(&active_items, &mut conflict_cache);
+    let conflict_context = build_conflict_scan_context(
+        &active_items,
+        &ModLanguages::from_mods(mods),
+        &mut conflict_cache,
+    );
     let predicate_context =
         build_mismatch_context(step1, tab, collect_checked_components(&active_items));
 
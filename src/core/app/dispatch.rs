@@ -1,11 +1,15 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Copyright (c) 2026 Born2BSalty
 
-use crate::config::options::AppCommandConfig;
+use crate::config::options::{AppCommandConfig, CoreOptions, EetConfig, NormalConfig};
+use crate::settings::layers::{self, LayerInputs};
+use crate::settings::profiles::ProfilesStore;
 use anyhow::Result;
-use tracing::info;
+use tracing::{info, warn};
 
-use super::{eet, normal, scan_components, scan_languages};
+use super::{
+    eet, modlist_lock_command, normal, registry_migrate_command, scan_components, scan_languages,
+};
 
 pub fn run(command: &AppCommandConfig) -> Result<()> {
     info!("BIO started");
@@ -16,12 +20,49 @@ pub fn run(command: &AppCommandConfig) -> Result<()> {
                 "GUI dispatch must be handled from main before calling app::dispatch::run"
             ));
         }
-        AppCommandConfig::Normal(config) => normal::run(config)?,
-        AppCommandConfig::Eet(config) => eet::run(config)?,
+        AppCommandConfig::Normal(config) => normal::run(&NormalConfig {
+            options: layered_options(&config.options),
+            ..config.clone()
+        })?,
+        AppCommandConfig::Eet(config) => eet::run(&EetConfig {
+            options: layered_options(&config.options),
+            ..config.clone()
+        })?,
         AppCommandConfig::Scan(scan) => match scan {
             crate::config::options::ScanConfig::Components { .. } => scan_components::run(scan)?,
             crate::config::options::ScanConfig::Languages { .. } => scan_languages::run(scan)?,
         },
+        AppCommandConfig::Lock(config) => modlist_lock_command::run(config)?,
+        AppCommandConfig::Migrate { dry_run } => registry_migrate_command::run(*dry_run)?,
     }
     Ok(())
 }
+
+fn layered_options(options: &CoreOptions) -> CoreOptions {
+    let launch = layers::launch_overrides();
+    let env = layers::env_overrides();
+    let profiles = if launch.profile.is_some() {
+        ProfilesStore::new_default().load().unwrap_or_else(|err| {
+            warn!("settings profiles load failed: {err}");
+            crate::settings::profiles::SettingsProfiles::default()
+        })
+    } else {
+        crate::settings::profiles::SettingsProfiles::default()
+    };
+    let (layered, errors) = layers::layer_core_options(
+        options,
+        &launch.explicit_args,
+        &profiles,
+        LayerInputs {
+            profile_name: launch.profile.as_deref(),
+            modlist: None,
+            env: Some(&env),
+            cli: Some(&launch.settings),
+            explicit: &[],
+        },
+    );
+    for err in errors {
+        warn!("settings layer skipped: {err}");
+    }
+    layered
+}
@@ -37,10 +37,14 @@ pub fn run(config: &EetConfig) -> Result<()> {
     )?;
 
     info!("running pre-eet plan");
-    runner::run_plan(&bg1_plan, &config.options, &config.bg1_game_directory)?;
+    let mut failures = runner::run_plan(&bg1_plan, &config.options, &config.bg1_game_directory)?;
     info!("running eet plan");
-    runner::run_plan(&bg2_plan, &config.options, &config.bg2_game_directory)?;
-    Ok(())
+    failures.extend(runner::run_plan(
+        &bg2_plan,
+        &config.options,
+        &config.bg2_game_directory,
+    )?);
+    runner::ensure_no_failures(&failures)
 }
 
 fn build_plan(
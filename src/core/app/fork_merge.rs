@@ -0,0 +1,396 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::{BTreeSet, HashMap};
+
+use super::compat_rule_runtime::normalize_mod_key;
+use super::layer_merge::order_with_edges;
+use crate::app::modlist_share::ModlistSharePreview;
+use crate::mods::component::Component;
+use crate::registry::workspace_model::{ComponentRef, ModlistWorkspaceState};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum ForkSide {
+    #[default]
+    Local,
+    Upstream,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ForkConflictKind {
+    Selection,
+    Prompt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ForkConflict {
+    pub(crate) kind: ForkConflictKind,
+    pub(crate) local: Option<ComponentRef>,
+    pub(crate) upstream: Option<ComponentRef>,
+    pub(crate) choice: ForkSide,
+}
+
+impl ForkConflict {
+    #[must_use]
+    pub(crate) fn label(&self) -> String {
+        self.either_side()
+            .map(|component| format!("{} #{}", component.tp2, component.id))
+            .unwrap_or_default()
+    }
+
+    fn either_side(&self) -> Option<&ComponentRef> {
+        self.local.as_ref().or(self.upstream.as_ref())
+    }
+
+    #[must_use]
+    pub(crate) fn describe(&self, side: ForkSide) -> String {
+        let pick = match side {
+            ForkSide::Local => self.local.as_ref(),
+            ForkSide::Upstream => self.upstream.as_ref(),
+        };
+        match (self.kind, pick) {
+            (_, None) => "not installed".to_string(),
+            (ForkConflictKind::Selection, Some(_)) => {
+                "installed (prompt answers changed)".to_string()
+            }
+            (ForkConflictKind::Prompt, Some(component)) => format!(
+                "answers: {}",
+                component
+                    .wlb_inputs
+                    .as_deref()
+                    .unwrap_or("(asked at install)")
+            ),
+        }
+    }
+
+    fn resolved(&self) -> Option<&ComponentRef> {
+        match self.choice {
+            ForkSide::Local => self.local.as_ref(),
+            ForkSide::Upstream => self.upstream.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ForkTabMerge {
+    pub(crate) tab: String,
+    pub(crate) candidates: Vec<ComponentRef>,
+    pub(crate) conflicts: Vec<ForkConflict>,
+    pub(crate) adopted: usize,
+    pub(crate) order_conflicts: Vec<String>,
+}
+
+impl ForkTabMerge {
+    #[must_use]
+    pub(crate) fn resolved_order(&self) -> Vec<ComponentRef> {
+        let by_key: HashMap<ComponentKey, &ForkConflict> = self
+            .conflicts
+            .iter()
+            .filter_map(|conflict| Some((component_key(conflict.either_side()?), conflict)))
+            .collect();
+        self.candidates
+            .iter()
+            .filter_map(|component| match by_key.get(&component_key(component)) {
+                Some(conflict) => conflict.resolved().cloned(),
+                None => Some(component.clone()),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct ForkMerge {
+    pub(crate) tabs: Vec<ForkTabMerge>,
+}
+
+impl ForkMerge {
+    #[must_use]
+    pub(crate) fn conflict_count(&self) -> usize {
+        self.tabs.iter().map(|tab| tab.conflicts.len()).sum()
+    }
+
+    #[must_use]
+    pub(crate) fn adopted_count(&self) -> usize {
+        self.tabs.iter().map(|tab| tab.adopted).sum()
+    }
+
+    pub(crate) fn apply_to(&self, workspace: &mut ModlistWorkspaceState, iwdee: bool) {
+        for tab in &self.tabs {
+            let order = tab.resolved_order();
+            match (tab.tab.as_str(), iwdee) {
+                ("BGEE", true) => workspace.order_iwdee = order,
+                ("BGEE", false) => workspace.order_bgee = order,
+                _ => workspace.order_bg2ee = order,
+            }
+        }
+    }
+}
+
+type ComponentKey = (String, i64);
+
+fn component_key(component: &ComponentRef) -> ComponentKey {
+    (normalize_mod_key(&component.tp2), component.id)
+}
+
+#[must_use]
+pub(crate) fn components_from_log(text: &str) -> Vec<ComponentRef> {
+    text.lines()
+        .filter(|line| line.trim_start().starts_with('~'))
+        .filter_map(|line| Component::parse_weidu_line(line).ok())
+        .filter_map(|component| {
+            Some(ComponentRef {
+                tp2: component.tp_file,
+                id: component.component.trim().parse().ok()?,
+                language: component.lang.trim().parse().unwrap_or(0),
+                wlb_inputs: component.wlb_inputs,
+            })
+        })
+        .collect()
+}
+
+#[must_use]
+pub(crate) fn merge_share_codes(
+    base: &ModlistSharePreview,
+    upstream: &ModlistSharePreview,
+    local: &ModlistWorkspaceState,
+    iwdee: bool,
+) -> ForkMerge {
+    let first_local = if iwdee {
+        &local.order_iwdee
+    } else {
+        &local.order_bgee
+    };
+    let tabs = [
+        (
+            "BGEE",
+            &base.bgee_log_text,
+            &upstream.bgee_log_text,
+            first_local,
+        ),
+        (
+            "BG2EE",
+            &base.bg2ee_log_text,
+            &upstream.bg2ee_log_text,
+            &local.order_bg2ee,
+        ),
+    ];
+    ForkMerge {
+        tabs: tabs
+            .into_iter()
+            .filter_map(|(tab, base_log, upstream_log, local_order)| {
+                let base = components_from_log(base_log);
+                let upstream = components_from_log(upstream_log);
+                if base.is_empty() && upstream.is_empty() && local_order.is_empty() {
+                    return None;
+                }
+                // A fork that was never saved past the import still mirrors its ancestor.
+                let local = if local_order.is_empty() {
+                    &base
+                } else {
+                    local_order
+                };
+                Some(three_way_merge(tab, &base, &upstream, local))
+            })
+            .collect(),
+    }
+}
+
+#[must_use]
+pub(crate) fn three_way_merge(
+    tab: &str,
+    base: &[ComponentRef],
+    upstream: &[ComponentRef],
+    local: &[ComponentRef],
+) -> ForkTabMerge {
+    let index = |order: &[ComponentRef]| -> HashMap<ComponentKey, usize> {
+        order
+            .iter()
+            .enumerate()
+            .map(|(pos, component)| (component_key(component), pos))
+            .collect()
+    };
+    let (base_pos, upstream_pos, local_pos) = (index(base), index(upstream), index(local));
+
+    let mut merge = ForkTabMerge {
+        tab: tab.to_string(),
+        ..ForkTabMerge::default()
+    };
+    let mut nodes: HashMap<ComponentKey, usize> = HashMap::new();
+    for component in local.iter().chain(upstream) {
+        let key = component_key(component);
+        if nodes.contains_key(&key) {
+            continue;
+        }
+        let base_c = base_pos.get(&key).map(|&pos| &base[pos]);
+        let upstream_c = upstream_pos.get(&key).map(|&pos| &upstream[pos]);
+        let local_c = local_pos.get(&key).map(|&pos| &local[pos]);
+        let base_inputs = base_c.map(|c| &c.wlb_inputs);
+        let candidate = match (local_c, upstream_c) {
+            (Some(mine), Some(theirs)) => {
+                if mine.wlb_inputs == theirs.wlb_inputs || Some(&theirs.wlb_inputs) == base_inputs {
+                    Some(mine.clone())
+                } else if Some(&mine.wlb_inputs) == base_inputs {
+                    merge.adopted += 1;
+                    Some(theirs.clone())
+                } else {
+                    merge.conflicts.push(ForkConflict {
+                        kind: ForkConflictKind::Prompt,
+                        local: Some(mine.clone()),
+                        upstream: Some(theirs.clone()),
+                        choice: ForkSide::Local,
+                    });
+                    Some(mine.clone())
+                }
+            }
+            (Some(mine), None) => match base_c {
+                None => Some(mine.clone()),
+                Some(ancestor) if ancestor.wlb_inputs == mine.wlb_inputs => {
+                    merge.adopted += 1;
+                    None
+                }
+                Some(_) => {
+                    merge.conflicts.push(ForkConflict {
+                        kind: ForkConflictKind::Selection,
+                        local: Some(mine.clone()),
+                        upstream: None,
+                        choice: ForkSide::Local,
+                    });
+                    Some(mine.clone())
+                }
+            },
+            (None, Some(theirs)) => match base_c {
+                None => {
+                    merge.adopted += 1;
+                    Some(theirs.clone())
+                }
+                Some(ancestor) if ancestor.wlb_inputs == theirs.wlb_inputs => None,
+                Some(_) => {
+                    merge.conflicts.push(ForkConflict {
+                        kind: ForkConflictKind::Selection,
+                        local: None,
+                        upstream: Some(theirs.clone()),
+                        choice: ForkSide::Local,
+                    });
+                    Some(theirs.clone())
+                }
+            },
+            (None, None) => None,
+        };
+        if let Some(candidate) = candidate {
+            nodes.insert(key, merge.candidates.len());
+            merge.candidates.push(candidate);
+        }
+    }
+
+    let before = |positions: &HashMap<ComponentKey, usize>, a: &ComponentKey, b: &ComponentKey| {
+        positions.get(a).zip(positions.get(b)).map(|(x, y)| x < y)
+    };
+    let mut edges = BTreeSet::new();
+    for (sequence, other_pos, from_local) in
+        [(local, &upstream_pos, true), (upstream, &local_pos, false)]
+    {
+        let kept: Vec<ComponentKey> = sequence
+            .iter()
+            .map(component_key)
+            .filter(|key| nodes.contains_key(key))
+            .collect();
+        for pair in kept.windows(2) {
+            let (a, b) = (&pair[0], &pair[1]);
+            let other_flips = before(other_pos, a, b) == Some(false);
+            let base_agrees = before(&base_pos, a, b) == Some(true);
+            let edge = match (from_local, other_flips && base_agrees) {
+                // The other side moved this pair away from the ancestor while this side did not.
+                (true, true) => (nodes[b], nodes[a]),
+                (false, true) => continue,
+                _ => (nodes[a], nodes[b]),
+            };
+            edges.insert(edge);
+        }
+    }
+    match order_with_edges(&merge.candidates, &edges) {
+        Ok(order) => merge.candidates = order,
+        Err(cycle) => {
+            merge.candidates = cycle.fallback;
+            merge.order_conflicts = cycle.components;
+        }
+    }
+    merge
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(tp2: &str, id: i64, inputs: Option<&str>) -> ComponentRef {
+        ComponentRef {
+            tp2: tp2.to_string(),
+            id,
+            language: 0,
+            wlb_inputs: inputs.map(str::to_string),
+        }
+    }
+
+    fn labels(order: &[ComponentRef]) -> Vec<String> {
+        order
+            .iter()
+            .map(|c| format!("{}#{}", c.tp2, c.id))
+            .collect()
+    }
+
+    #[test]
+    fn three_way_merge_adopts_upstream_changes_and_flags_conflicts() {
+        let base = [
+            component("a", 0, None),
+            component("b", 0, None),
+            component("c", 0, Some("1")),
+            component("d", 0, None),
+        ];
+        // Upstream moved d to the front, retuned c, added e.
+        let upstream = [
+            component("d", 0, None),
+            component("a", 0, None),
+            component("b", 0, None),
+            component("c", 0, Some("2")),
+            component("e", 0, None),
+        ];
+        // Locally b was dropped, c retuned differently, and mine was added after a.
+        let local = [
+            component("a", 0, None),
+            component("mine", 5, None),
+            component("c", 0, Some("3")),
+            component("d", 0, None),
+        ];
+        let mut merge = three_way_merge("BG2EE", &base, &upstream, &local);
+        assert_eq!(merge.adopted, 1, "only the new upstream component e");
+        assert_eq!(merge.conflicts.len(), 1);
+        assert_eq!(merge.conflicts[0].kind, ForkConflictKind::Prompt);
+        assert!(merge.order_conflicts.is_empty());
+        assert_eq!(
+            labels(&merge.resolved_order()),
+            ["d#0", "a#0", "mine#5", "c#0", "e#0"]
+        );
+        assert_eq!(merge.resolved_order()[3].wlb_inputs.as_deref(), Some("3"));
+
+        merge.conflicts[0].choice = ForkSide::Upstream;
+        assert_eq!(merge.resolved_order()[3].wlb_inputs.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn removal_against_edit_is_a_selection_conflict() {
+        let base = [component("a", 0, Some("x")), component("b", 0, None)];
+        let upstream = [component("b", 0, None)];
+        let local = [component("a", 0, Some("y")), component("b", 0, None)];
+        let mut merge = three_way_merge("BGEE", &base, &upstream, &local);
+        assert_eq!(merge.conflicts.len(), 1);
+        assert_eq!(merge.conflicts[0].kind, ForkConflictKind::Selection);
+        assert_eq!(labels(&merge.resolved_order()), ["a#0", "b#0"]);
+        merge.conflicts[0].choice = ForkSide::Upstream;
+        assert_eq!(labels(&merge.resolved_order()), ["b#0"]);
+
+        let log = "~A/SETUP-A.TP2~ #0 #3 // Main: v1 // @wlb-inputs: 1,2\n// comment\n";
+        let parsed = components_from_log(log);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].id, 3);
+        assert_eq!(parsed[0].wlb_inputs.as_deref(), Some("1,2"));
+    }
+}
@@ -0,0 +1,295 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+
+use super::compat_rule_runtime::{
+    compat_component_matches, compat_mod_matches, non_empty, normalize_kind, normalize_mod_key,
+    related_targets, tab_matches,
+};
+use crate::app::compat_rules::CompatRule;
+use crate::registry::model::LayerProvenance;
+use crate::registry::modlist_layers::ModlistLayer;
+use crate::registry::workspace_model::{ComponentRef, ModlistWorkspaceState};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OrderCycle {
+    pub(crate) components: Vec<String>,
+    pub(crate) fallback: Vec<ComponentRef>,
+}
+
+impl fmt::Display for OrderCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "order constraints form a cycle around {}",
+            self.components.join(", ")
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LayerCompose {
+    pub(crate) workspace: ModlistWorkspaceState,
+    pub(crate) provenance: Vec<LayerProvenance>,
+}
+
+impl LayerCompose {
+    #[must_use]
+    pub(crate) fn component_count(&self) -> usize {
+        self.workspace.order_bgee.len()
+            + self.workspace.order_bg2ee.len()
+            + self.workspace.order_iwdee.len()
+    }
+}
+
+type ComponentKey = (String, i64);
+
+fn component_key(component: &ComponentRef) -> ComponentKey {
+    (
+        component.tp2.replace('\\', "/").to_ascii_uppercase(),
+        component.id,
+    )
+}
+
+pub(crate) fn compose_from_layers(
+    layers: &[&ModlistLayer],
+    rules: &[CompatRule],
+    base: Option<(&ModlistWorkspaceState, &[LayerProvenance])>,
+) -> Result<LayerCompose, OrderCycle> {
+    let mut workspace = base.map(|(current, _)| current.clone()).unwrap_or_default();
+    let live_ids: HashSet<&str> = layers.iter().map(|layer| layer.id.as_str()).collect();
+    let current_keys: HashSet<ComponentKey> = layers
+        .iter()
+        .flat_map(|layer| layer.components())
+        .map(component_key)
+        .collect();
+    let dropped: HashSet<ComponentKey> = base
+        .map(|(_, applied)| applied)
+        .unwrap_or_default()
+        .iter()
+        .filter(|applied| live_ids.contains(applied.layer_id.as_str()))
+        .flat_map(|applied| applied.components.iter().map(component_key))
+        .filter(|key| !current_keys.contains(key))
+        .collect();
+
+    let merge_tab =
+        |current: &[ComponentRef], pick: fn(&ModlistLayer) -> &[ComponentRef], tab: &str| {
+            let kept: Vec<ComponentRef> = current
+                .iter()
+                .filter(|component| !dropped.contains(&component_key(component)))
+                .cloned()
+                .collect();
+            let mut sequences: Vec<&[ComponentRef]> = vec![&kept];
+            sequences.extend(layers.iter().map(|layer| pick(layer)));
+            merge_orders(&sequences, rules, tab)
+        };
+    workspace.order_bgee = merge_tab(&workspace.order_bgee, |l| l.order_bgee.as_slice(), "BGEE")?;
+    workspace.order_bg2ee = merge_tab(
+        &workspace.order_bg2ee,
+        |l| l.order_bg2ee.as_slice(),
+        "BG2EE",
+    )?;
+    workspace.order_iwdee =
+        merge_tab(&workspace.order_iwdee, |l| l.order_iwdee.as_slice(), "BGEE")?;
+
+    let mut provenance: Vec<LayerProvenance> = base
+        .map(|(_, applied)| applied)
+        .unwrap_or_default()
+        .iter()
+        .filter(|applied| !live_ids.contains(applied.layer_id.as_str()))
+        .cloned()
+        .collect();
+    provenance.extend(layers.iter().map(|layer| layer.provenance()));
+
+    Ok(LayerCompose {
+        workspace,
+        provenance,
+    })
+}
+
+pub(crate) fn merge_orders(
+    sequences: &[&[ComponentRef]],
+    rules: &[CompatRule],
+    tab: &str,
+) -> Result<Vec<ComponentRef>, OrderCycle> {
+    let mut nodes: Vec<ComponentRef> = Vec::new();
+    let mut index: HashMap<ComponentKey, usize> = HashMap::new();
+    let mut edges: BTreeSet<(usize, usize)> = BTreeSet::new();
+    for sequence in sequences {
+        let mut previous: Option<usize> = None;
+        for component in *sequence {
+            let node = *index.entry(component_key(component)).or_insert_with(|| {
+                nodes.push(component.clone());
+                nodes.len() - 1
+            });
+            if let Some(previous) = previous.filter(|previous| *previous != node) {
+                edges.insert((previous, node));
+            }
+            previous = Some(node);
+        }
+    }
+    add_compat_edges(&nodes, rules, tab, &mut edges);
+    order_with_edges(&nodes, &edges)
+}
+
+pub(crate) fn order_with_edges(
+    nodes: &[ComponentRef],
+    edges: &BTreeSet<(usize, usize)>,
+) -> Result<Vec<ComponentRef>, OrderCycle> {
+    let count = nodes.len();
+    let mut indegree = vec![0usize; count];
+    let mut outgoing = vec![Vec::new(); count];
+    for &(from, to) in edges {
+        outgoing[from].push(to);
+        indegree[to] += 1;
+    }
+    let mut ready: BTreeSet<usize> = (0..count).filter(|&node| indegree[node] == 0).collect();
+    let mut emitted = vec![false; count];
+    let mut order = Vec::with_capacity(count);
+    let mut forced = Vec::new();
+    loop {
+        let next = if let Some(node) = ready.pop_first() {
+            node
+        } else {
+            // Constraints form a cycle: the earliest-seen component wins its spot.
+            let Some(node) = (0..count).find(|&node| !emitted[node]) else {
+                break;
+            };
+            forced.push(format!("{} #{}", nodes[node].tp2, nodes[node].id));
+            node
+        };
+        emitted[next] = true;
+        order.push(nodes[next].clone());
+        for &to in &outgoing[next] {
+            if !emitted[to] {
+                indegree[to] -= 1;
+                if indegree[to] == 0 {
+                    ready.insert(to);
+                }
+            }
+        }
+    }
+    if forced.is_empty() {
+        Ok(order)
+    } else {
+        Err(OrderCycle {
+            components: forced,
+            fallback: order,
+        })
+    }
+}
+
+fn add_compat_edges(
+    nodes: &[ComponentRef],
+    rules: &[CompatRule],
+    tab: &str,
+    edges: &mut BTreeSet<(usize, usize)>,
+) {
+    for rule in rules.iter().filter(|rule| {
+        rule.enabled && normalize_kind(&rule.kind) == "order_block" && tab_matches(rule, tab)
+    }) {
+        let before = match non_empty(rule.position.as_deref())
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "before" => true,
+            "after" => false,
+            _ => continue,
+        };
+        let targets = related_targets(rule);
+        for (from, component) in nodes.iter().enumerate() {
+            let id = component.id.to_string();
+            if !compat_mod_matches(rule, &component.tp2, &component.tp2)
+                || !compat_component_matches(rule, &id, "", "")
+            {
+                continue;
+            }
+            for (to, other) in nodes.iter().enumerate() {
+                let hit = from != to
+                    && targets.iter().any(|(related_mod, related_component)| {
+                        normalize_mod_key(&other.tp2) == normalize_mod_key(related_mod)
+                            && related_component
+                                .as_deref()
+                                .is_none_or(|wanted| wanted.trim() == other.id.to_string())
+                    });
+                if hit {
+                    edges.insert(if before { (from, to) } else { (to, from) });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::model::Game;
+
+    fn component(tp2: &str, id: i64) -> ComponentRef {
+        ComponentRef {
+            tp2: tp2.to_string(),
+            id,
+            ..ComponentRef::default()
+        }
+    }
+
+    fn labels(order: &[ComponentRef]) -> Vec<String> {
+        order
+            .iter()
+            .map(|c| format!("{}#{}", c.tp2, c.id))
+            .collect()
+    }
+
+    #[test]
+    fn merge_interleaves_layers_and_honours_compat_order_rules() {
+        let fixes = [component("fix", 0), component("ui", 0), component("npc", 0)];
+        let tactics = [
+            component("ui", 0),
+            component("scs", 10),
+            component("npc", 0),
+        ];
+        let merged = merge_orders(&[&fixes, &tactics], &[], "BG2EE").expect("no cycle");
+        assert_eq!(labels(&merged), ["fix#0", "ui#0", "scs#10", "npc#0"]);
+
+        let rule: CompatRule = serde_json::from_str(
+            r#"{"mod":"scs","kind":"order_block","position":"after","related_mod":"npc"}"#,
+        )
+        .expect("rule");
+        let cycle = merge_orders(&[&fixes, &tactics], &[rule], "BG2EE")
+            .expect_err("the layer order and rule disagree");
+        assert_eq!(cycle.components, ["npc #0"]);
+        assert_eq!(
+            labels(&cycle.fallback),
+            ["fix#0", "ui#0", "npc#0", "scs#10"]
+        );
+    }
+
+    #[test]
+    fn reapply_keeps_user_extras_and_drops_removed_layer_components() {
+        let mut layer = ModlistLayer {
+            id: "L1".to_string(),
+            name: "Fixpacks".to_string(),
+            game: Game::BG2EE,
+            order_bg2ee: vec![component("fix", 0), component("old", 0)],
+            ..ModlistLayer::default()
+        };
+        let first = compose_from_layers(&[&layer], &[], None).expect("compose");
+        assert_eq!(first.component_count(), 2);
+
+        let mut workspace = first.workspace.clone();
+        workspace.order_bg2ee.push(component("mine", 3));
+        layer.order_bg2ee = vec![component("fix", 0), component("new", 0)];
+        layer.revision = 2;
+        let again = compose_from_layers(&[&layer], &[], Some((&workspace, &first.provenance)))
+            .expect("recompose");
+        assert_eq!(
+            labels(&again.workspace.order_bg2ee),
+            ["fix#0", "mine#3", "new#0"]
+        );
+        assert_eq!(again.provenance.len(), 1);
+        assert_eq!(again.provenance[0].revision, 2);
+    }
+}
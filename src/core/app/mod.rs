@@ -88,8 +88,6 @@ pub mod compat_rule_runtime;
 pub mod compat_rules;
 #[path = "compat/compat_rules_model.rs"]
 pub mod compat_rules_model;
-#[path = "compat/compat_setup_tra.rs"]
-pub mod compat_setup_tra;
 #[path = "compat/compat_step3_rules.rs"]
 pub mod compat_step3_rules;
 pub mod component_block_preview;
@@ -97,14 +95,20 @@ pub mod component_details;
 pub mod controller;
 pub mod dispatch;
 pub mod eet;
+pub mod fork_merge;
+pub mod layer_merge;
 pub mod mod_downloads;
 #[path = "step2/update/mod_update_locks.rs"]
 pub mod mod_update_locks;
 pub mod modlist_config_files;
+pub mod modlist_lock_command;
 pub mod modlist_share;
+#[path = "step2/update/modlist_update_schedule.rs"]
+pub mod modlist_update_schedule;
 pub mod normal;
 #[path = "step2/update/platform_asset_target.rs"]
 pub mod platform_asset_target;
+pub mod prompt_dry_run;
 pub mod prompt_eval_context;
 pub mod prompt_eval_summary;
 pub mod prompt_eval_summary_step3;
@@ -112,6 +116,7 @@ pub mod prompt_eval_vars;
 pub mod prompt_jump_targets;
 pub mod prompt_popup_nav;
 pub mod prompt_popup_text;
+pub mod registry_migrate_command;
 #[path = "step2/scan/mod.rs"]
 pub mod scan;
 #[path = "step2/scan/scan_components.rs"]
@@ -138,6 +143,7 @@ pub mod state_validation_modes;
 pub mod step2_action;
 #[path = "step2/scan/step2_worker.rs"]
 pub mod step2_worker;
+pub mod step3_families;
 pub mod step3_history;
 pub mod step3_prompt_edit;
 pub mod step3_toolbar;
@@ -147,3 +153,5 @@ pub mod step4_weidu_log_export;
 pub mod step5;
 pub mod step5_runtime_status;
 pub mod terminal;
+pub mod tp2_browser;
+pub mod tra_resolver;
@@ -386,10 +386,19 @@ pub(crate) fn load_two_tier_sources() -> ModDownloadsLoad {
 /// Loads sources applying all three tiers: app-default → global-user → per-modlist override.
 /// When no modlist is active the result equals `load_two_tier_sources()`.
 pub(crate) fn load_mod_download_sources() -> ModDownloadsLoad {
+    load_mod_download_sources_with_overlay(active_modlist_downloads_path())
+}
+
+/// Same three-tier load as `load_mod_download_sources`, for a modlist that may not be active.
+pub(crate) fn load_mod_download_sources_for_modlist(modlist_dir: &Path) -> ModDownloadsLoad {
+    load_mod_download_sources_with_overlay(Some(modlist_dir.join(MOD_DOWNLOADS_USER_FILE_NAME)))
+}
+
+fn load_mod_download_sources_with_overlay(per_modlist_path: Option<PathBuf>) -> ModDownloadsLoad {
     let mut result = load_two_tier_sources();
 
     // Per-modlist overlay (additive; skipped when ambient is unset or file absent).
-    if let Some(per_modlist_path) = active_modlist_downloads_path().filter(|p| p.exists()) {
+    if let Some(per_modlist_path) = per_modlist_path.filter(|p| p.exists()) {
         {
             let per_load = load_source_overlays_from_path(&per_modlist_path);
             // Rebuild by_source map with the same key format used in the two-tier passes.
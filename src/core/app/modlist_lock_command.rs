@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use anyhow::{Result, anyhow};
+use tracing::info;
+
+use crate::config::options::LockConfig;
+use crate::install_runtime::modlist_lock::{
+    ModlistLock, apply_pending_update, diff_locks, lock_path_for_modlist,
+    pending_lock_path_for_modlist, render_lock_diff,
+};
+
+pub fn run(config: &LockConfig) -> Result<()> {
+    info!("command=lock {:?}", config);
+    match config {
+        LockConfig::Diff { modlist_id } => {
+            print!("{}", render_lock_diff(&pending_changes(modlist_id)?));
+            println!();
+        }
+        LockConfig::Update {
+            modlist_id,
+            confirmed,
+        } => {
+            let changes = pending_changes(modlist_id)?;
+            print!("{}", render_lock_diff(&changes));
+            println!();
+            if changes.is_empty() {
+                return Ok(());
+            }
+            if !confirmed {
+                println!("re-run with --yes to rewrite the lock with these changes");
+                return Ok(());
+            }
+            apply_pending_update(modlist_id).map_err(|err| anyhow!(err))?;
+            println!("updated {}", lock_path_for_modlist(modlist_id).display());
+        }
+    }
+    Ok(())
+}
+
+fn pending_changes(
+    modlist_id: &str,
+) -> Result<Vec<crate::install_runtime::modlist_lock::LockChange>> {
+    let current = ModlistLock::load(&lock_path_for_modlist(modlist_id))
+        .map_err(|err| anyhow!(err))?
+        .ok_or_else(|| anyhow!("modlist {modlist_id} has no lock yet"))?;
+    let Some(pending) = ModlistLock::load(&pending_lock_path_for_modlist(modlist_id))
+        .map_err(|err| anyhow!(err))?
+    else {
+        return Ok(Vec::new());
+    };
+    Ok(diff_locks(&current, &pending))
+}
@@ -49,8 +49,8 @@ pub fn run(config: &NormalConfig) -> Result<()> {
         "normal install plan contains {} component(s)",
         plan.components.len()
     );
-    runner::run_plan(&plan, &config.options, &config.game_directory)?;
-    Ok(())
+    let failures = runner::run_plan(&plan, &config.options, &config.game_directory)?;
+    runner::ensure_no_failures(&failures)
 }
 
 fn ensure_existing_file(path: &Path) -> Result<()> {
@@ -0,0 +1,330 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use crate::app::prompt_eval_context::build_prompt_eval_context;
+use crate::app::prompt_eval_summary::applicable_prompt_blocks;
+use crate::app::state::{Step3ItemState, WizardState};
+use crate::app::step5::prompt_memory;
+use crate::app::step5::scripted_inputs;
+use crate::parser::prompt_eval_expr::PromptEvalContext;
+use crate::platform_defaults::compose_component_key;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnswerOrigin {
+    WlbInputs,
+    PromptMemory,
+    // @wlb-inputs from the source weidu.log winning over a different remembered sequence.
+    Override,
+}
+
+impl AnswerOrigin {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::WlbInputs => "@wlb-inputs",
+            Self::PromptMemory => "prompt memory",
+            Self::Override => "override",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedPrompt {
+    pub prompt: String,
+    pub answer: Option<String>,
+    pub origin: Option<AnswerOrigin>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentDryRun {
+    pub game_tab: &'static str,
+    pub label: String,
+    pub component_key: String,
+    pub prompts: Vec<ExpectedPrompt>,
+}
+
+impl ComponentDryRun {
+    #[must_use]
+    pub fn unanswered(&self) -> usize {
+        self.prompts.iter().filter(|p| p.answer.is_none()).count()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PromptDryRun {
+    pub checked: usize,
+    pub components: Vec<ComponentDryRun>,
+}
+
+impl PromptDryRun {
+    pub fn flagged(&self) -> impl Iterator<Item = &ComponentDryRun> {
+        self.components.iter().filter(|c| c.unanswered() > 0)
+    }
+
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let flagged = self.flagged().count();
+        let prompts: usize = self.components.iter().map(|c| c.prompts.len()).sum();
+        if flagged == 0 {
+            format!(
+                "{prompts} prompt(s) across {} component(s), all answered",
+                self.components.len()
+            )
+        } else {
+            format!(
+                "{flagged} of {} prompting component(s) have unanswered prompts",
+                self.components.len()
+            )
+        }
+    }
+
+    #[must_use]
+    pub fn render_text(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "BIO prompt dry-run");
+        let _ = writeln!(out, "Components checked: {}", self.checked);
+        let _ = writeln!(out, "{}", self.summary());
+        for component in &self.components {
+            let flag = if component.unanswered() > 0 {
+                " [UNANSWERED]"
+            } else {
+                ""
+            };
+            let _ = writeln!(out, "\n[{}] {}{flag}", component.game_tab, component.label);
+            for (idx, prompt) in component.prompts.iter().enumerate() {
+                let first = prompt.prompt.lines().next().unwrap_or_default().trim();
+                let answer = match (&prompt.answer, prompt.origin) {
+                    (Some(answer), Some(origin)) => format!("{answer} ({})", origin.label()),
+                    (Some(answer), None) => answer.clone(),
+                    (None, _) => "<no answer>".to_string(),
+                };
+                let _ = writeln!(out, "  {}. {first}\n     -> {answer}", idx + 1);
+            }
+        }
+        out
+    }
+}
+
+#[must_use]
+pub fn simulate(state: &WizardState) -> PromptDryRun {
+    let prompt_eval = build_prompt_eval_context(state);
+    let log_inputs = scripted_inputs::load_log_inputs(&state.step1);
+    let memory = prompt_memory::list_component_sequences();
+    simulate_with(
+        &ordered_tabs(state),
+        &prompt_eval,
+        &log_inputs,
+        &memory,
+        |prompt| prompt_memory::get_answer_by_alias(&prompt_memory::alias_from_preview(prompt)),
+    )
+}
+
+fn ordered_tabs(state: &WizardState) -> Vec<(&'static str, &[Step3ItemState])> {
+    match state.step1.game_install.as_str() {
+        "EET" => vec![
+            ("BGEE", state.step3.bgee_items.as_slice()),
+            ("BG2EE", state.step3.bg2ee_items.as_slice()),
+        ],
+        "BG2EE" => vec![("BG2EE", state.step3.bg2ee_items.as_slice())],
+        _ => vec![("BGEE", state.step3.bgee_items.as_slice())],
+    }
+}
+
+pub(crate) fn simulate_with(
+    tabs: &[(&'static str, &[Step3ItemState])],
+    prompt_eval: &PromptEvalContext,
+    log_inputs: &HashMap<String, Vec<String>>,
+    memory: &HashMap<String, Vec<String>>,
+    alias_answer: impl Fn(&str) -> Option<String>,
+) -> PromptDryRun {
+    let mut report = PromptDryRun::default();
+    for (game_tab, items) in tabs {
+        for item in items.iter().filter(|item| !item.is_parent) {
+            report.checked += 1;
+            let blocks = expected_prompts(item, prompt_eval);
+            if blocks.is_empty() {
+                continue;
+            }
+            let component_key = compose_component_key(&item.tp_file, &item.component_id);
+            let remembered = memory.get(&component_key).filter(|seq| !seq.is_empty());
+            let logged = log_inputs.get(&component_key).filter(|seq| !seq.is_empty());
+            let (sequence, origin) = match logged {
+                Some(inputs) => {
+                    let origin = if remembered.is_some_and(|seq| seq != inputs) {
+                        AnswerOrigin::Override
+                    } else {
+                        AnswerOrigin::WlbInputs
+                    };
+                    (inputs.clone(), Some(origin))
+                }
+                None => (
+                    remembered.cloned().unwrap_or_default(),
+                    remembered.map(|_| AnswerOrigin::PromptMemory),
+                ),
+            };
+            let prompts = blocks
+                .into_iter()
+                .enumerate()
+                .map(|(idx, prompt)| {
+                    let (answer, origin) = match sequence.get(idx) {
+                        Some(answer) => (Some(answer.clone()), origin),
+                        None => {
+                            let answer = alias_answer(&prompt);
+                            let origin = answer.as_ref().map(|_| AnswerOrigin::PromptMemory);
+                            (answer, origin)
+                        }
+                    };
+                    ExpectedPrompt {
+                        prompt,
+                        answer,
+                        origin,
+                    }
+                })
+                .collect();
+            report.components.push(ComponentDryRun {
+                game_tab,
+                label: format!(
+                    "{} #{} {}",
+                    item.tp_file, item.component_id, item.component_label
+                )
+                .trim_end()
+                .to_string(),
+                component_key,
+                prompts,
+            });
+        }
+    }
+    report
+}
+
+fn expected_prompts(item: &Step3ItemState, prompt_eval: &PromptEvalContext) -> Vec<String> {
+    if item.prompt_events.is_empty() {
+        return item
+            .prompt_summary
+            .as_deref()
+            .map(str::trim)
+            .filter(|summary| !summary.is_empty())
+            .map(|summary| vec![summary.to_string()])
+            .unwrap_or_default();
+    }
+    applicable_prompt_blocks(
+        &item.component_id,
+        &item.raw_line,
+        &item.prompt_events,
+        prompt_eval,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::PromptSummaryEvent;
+
+    fn item(tp: &str, id: &str, raw_line: &str, prompts: &[&str]) -> Step3ItemState {
+        Step3ItemState {
+            tp_file: tp.to_string(),
+            component_id: id.to_string(),
+            mod_name: tp.trim_end_matches(".TP2").to_string(),
+            component_label: format!("comp {id}"),
+            raw_line: raw_line.to_string(),
+            prompt_summary: None,
+            prompt_events: prompts
+                .iter()
+                .map(|text| PromptSummaryEvent {
+                    kind: "action_read_line".to_string(),
+                    summary_line: (*text).to_string(),
+                    ..PromptSummaryEvent::default()
+                })
+                .collect(),
+            selected_order: 0,
+            block_id: String::new(),
+            is_parent: false,
+            parent_placeholder: false,
+        }
+    }
+
+    #[test]
+    fn log_inputs_answer_and_override_differing_memory() {
+        let items = vec![
+            item("SETUP-A.TP2", "0", "", &["How many?", "Continue?"]),
+            item("SETUP-B.TP2", "1", "", &["Level cap?"]),
+            item("SETUP-C.TP2", "2", "", &["Same?"]),
+        ];
+        let log_inputs = HashMap::from([
+            (
+                "SETUP-A.TP2#0".to_string(),
+                vec!["2".to_string(), "y".to_string()],
+            ),
+            ("SETUP-B.TP2#1".to_string(), vec!["5".to_string()]),
+            ("SETUP-C.TP2#2".to_string(), vec!["1".to_string()]),
+        ]);
+        let memory = HashMap::from([
+            ("SETUP-B.TP2#1".to_string(), vec!["9".to_string()]),
+            ("SETUP-C.TP2#2".to_string(), vec!["1".to_string()]),
+        ]);
+        let report = simulate_with(
+            &[("BGEE", items.as_slice())],
+            &PromptEvalContext::default(),
+            &log_inputs,
+            &memory,
+            |_| None,
+        );
+        assert_eq!(report.checked, 3);
+        assert_eq!(report.flagged().count(), 0);
+        let a = &report.components[0].prompts;
+        assert_eq!(a[1].answer.as_deref(), Some("y"));
+        assert_eq!(a[1].origin, Some(AnswerOrigin::WlbInputs));
+        let b = &report.components[1].prompts[0];
+        assert_eq!(b.answer.as_deref(), Some("5"));
+        assert_eq!(b.origin, Some(AnswerOrigin::Override));
+        let c = &report.components[2].prompts[0];
+        assert_eq!(c.origin, Some(AnswerOrigin::WlbInputs));
+    }
+
+    #[test]
+    fn memory_and_alias_fill_gaps_and_leftovers_are_flagged() {
+        let items = vec![
+            item("SETUP-A.TP2", "0", "", &["First?", "Second?"]),
+            item("SETUP-C.TP2", "3", "", &["Unknown?"]),
+            item("SETUP-D.TP2", "4", "", &[]),
+        ];
+        let memory = HashMap::from([("SETUP-A.TP2#0".to_string(), vec!["1".to_string()])]);
+        let report = simulate_with(
+            &[("BG2EE", items.as_slice())],
+            &PromptEvalContext::default(),
+            &HashMap::new(),
+            &memory,
+            |prompt| (prompt == "Second?").then(|| "n".to_string()),
+        );
+        assert_eq!(report.checked, 3);
+        assert_eq!(report.components.len(), 2);
+        let a = &report.components[0].prompts;
+        assert_eq!(a[0].origin, Some(AnswerOrigin::PromptMemory));
+        assert_eq!(a[1].answer.as_deref(), Some("n"));
+        let flagged: Vec<_> = report.flagged().map(|c| c.component_key.as_str()).collect();
+        assert_eq!(flagged, vec!["SETUP-C.TP2#3"]);
+        let text = report.render_text();
+        assert!(text.contains("[BG2EE] SETUP-C.TP2 #3 comp 3 [UNANSWERED]"));
+        assert!(text.contains("-> <no answer>"));
+    }
+
+    #[test]
+    fn prompts_outside_active_games_are_skipped() {
+        let mut it = item("SETUP-A.TP2", "0", "", &["BG2 only?"]);
+        it.prompt_events[0].game_allow = vec!["bg2ee".to_string()];
+        let mut eval = PromptEvalContext::default();
+        eval.active_games.insert("bgee".to_string());
+        let report = simulate_with(
+            &[("BGEE", std::slice::from_ref(&it))],
+            &eval,
+            &HashMap::new(),
+            &HashMap::new(),
+            |_| None,
+        );
+        assert!(report.components.is_empty());
+        assert_eq!(report.flagged().count(), 0);
+    }
+}
@@ -60,33 +60,11 @@ pub(crate) fn evaluate_prompt_summary_input(
     let result = if prompt_events.is_empty() {
         prompt_summary.map_or("", str::trim).to_string()
     } else {
-        let prompt_vars = build_prompt_var_context(
-            PromptComponentInput {
-                raw_line,
-                component_id,
-                prompt_events,
-            },
-            prompt_eval,
-        );
-        let mut out = Vec::<String>::new();
-        for event in prompt_events {
-            if !event_applies_with_vars(event, prompt_eval, Some(&prompt_vars)) {
-                continue;
-            }
-            let line = event.summary_line.trim();
-            if line.is_empty() {
-                continue;
-            }
-            if !out.iter().any(|existing| existing == line) {
-                out.push(line.to_string());
-            }
-        }
-        out = normalize_prompt_blocks(out);
-        if out.is_empty() {
-            String::new()
-        } else {
-            out.into_iter().take(6).collect::<Vec<_>>().join("\n\n")
-        }
+        applicable_prompt_blocks(component_id, raw_line, prompt_events, prompt_eval)
+            .into_iter()
+            .take(6)
+            .collect::<Vec<_>>()
+            .join("\n\n")
     };
 
     let mut cache = prompt_summary_cache()
@@ -99,6 +77,36 @@ pub(crate) fn evaluate_prompt_summary_input(
     result
 }
 
+pub(crate) fn applicable_prompt_blocks(
+    component_id: &str,
+    raw_line: &str,
+    prompt_events: &[PromptSummaryEvent],
+    prompt_eval: &PromptEvalContext,
+) -> Vec<String> {
+    let prompt_vars = build_prompt_var_context(
+        PromptComponentInput {
+            raw_line,
+            component_id,
+            prompt_events,
+        },
+        prompt_eval,
+    );
+    let mut out = Vec::<String>::new();
+    for event in prompt_events {
+        if !event_applies_with_vars(event, prompt_eval, Some(&prompt_vars)) {
+            continue;
+        }
+        let line = event.summary_line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if !out.iter().any(|existing| existing == line) {
+            out.push(line.to_string());
+        }
+    }
+    normalize_prompt_blocks(out)
+}
+
 pub(crate) fn event_applies(event: &PromptSummaryEvent, prompt_eval: &PromptEvalContext) -> bool {
     event_applies_with_vars(event, prompt_eval, None)
 }
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::path::Path;
+
+use anyhow::Result;
+use tracing::info;
+
+use crate::registry::migrations::{FormatKind, Migration, migrate_file};
+use crate::registry::model::ModlistRegistry;
+use crate::registry::store::RegistryStore;
+use crate::registry::store_workspace::WorkspaceStore;
+
+pub fn run(dry_run: bool) -> Result<()> {
+    info!("command=migrate dry_run={dry_run}");
+    let registry_store = RegistryStore::new_default();
+    let registry_path = registry_store.path();
+    if !registry_path.exists() {
+        println!("no registry at {}", registry_path.display());
+        return Ok(());
+    }
+    let migration = migrate_file(FormatKind::Registry, registry_path, dry_run)?;
+    print_migration(registry_path, &migration, dry_run);
+    let registry: ModlistRegistry = serde_json::from_value(migration.value)?;
+    for entry in &registry.entries {
+        let store = WorkspaceStore::new_for_id(&entry.id);
+        if !store.path().exists() {
+            continue;
+        }
+        match migrate_file(FormatKind::Workspace, store.path(), dry_run) {
+            Ok(migration) => print_migration(store.path(), &migration, dry_run),
+            Err(err) => println!("{}: {err}", store.path().display()),
+        }
+    }
+    Ok(())
+}
+
+fn print_migration(path: &Path, migration: &Migration, dry_run: bool) {
+    if migration.is_noop() {
+        println!("{}: format {} (current)", path.display(), migration.from);
+        return;
+    }
+    let verb = if dry_run { "would migrate" } else { "migrated" };
+    println!(
+        "{}: {verb} format {} -> {}",
+        path.display(),
+        migration.from,
+        migration.to
+    );
+    for step in &migration.steps {
+        println!("  - {step}");
+    }
+}
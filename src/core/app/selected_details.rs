@@ -11,6 +11,7 @@ use crate::app::component_details::{
 };
 use crate::app::controller::log_apply_match::parse_component_tp2_from_raw;
 use crate::app::state::{Step2Selection, WizardState};
+use crate::app::tp2_browser::{Tp2SourceState, load_component_source};
 use crate::parser::weidu_component_line::parse_lang;
 use crate::parser::weidu_version::parse_version;
 
@@ -38,6 +39,7 @@ pub(crate) struct SelectedDetailsData {
     pub(crate) compat_graph: Option<String>,
     pub(crate) compat_evidence: Option<String>,
     pub(crate) compat_component_block: Option<String>,
+    pub(crate) tp2_source: Tp2SourceState,
     pub(crate) raw_line: Option<String>,
     pub(crate) tp_file: Option<String>,
     pub(crate) tp2_folder: Option<String>,
@@ -163,6 +165,12 @@ fn build_component_details(
     });
     let compat_component_block =
         load_component_block_preview(&mod_state.tp2_path, &component.component_id);
+    let component_lang = parse_lang(&component.raw_line);
+    let tp2_source = load_component_source(
+        &mod_state.tp2_path,
+        &component.component_id,
+        component_lang.as_deref().and_then(|lang| lang.parse().ok()),
+    );
 
     SelectedDetailsData {
         mod_name: Some(display_name_from_tp2(&component_tp2)),
@@ -172,7 +180,7 @@ fn build_component_details(
         hidden_component_count: Some(mod_state.hidden_components.len()),
         raw_component_count: Some(mod_state.components.len() + mod_state.hidden_components.len()),
         component_version: details_mod_version(mod_state),
-        component_lang: parse_lang(&component.raw_line),
+        component_lang,
         selected_order: component.selected_order,
         is_checked: Some(component.checked),
         is_disabled: Some(component.disabled),
@@ -189,6 +197,7 @@ fn build_component_details(
         compat_graph: component.compat_graph.clone(),
         compat_evidence: component.compat_evidence.clone(),
         compat_component_block,
+        tp2_source,
         raw_line: Some(component.raw_line.clone()),
         tp_file: Some(tp2_file_name(&component_tp2)),
         tp2_folder: details_parent_folder(&mod_state.tp2_path),
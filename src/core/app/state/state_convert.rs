@@ -91,6 +91,7 @@ mod step1_settings_to_state {
                 lookback,
                 casefold: value.casefold,
                 backup_targets_before_eet_copy: value.backup_targets_before_eet_copy,
+                clean_game_policy: value.clean_game_policy,
             }
         }
     }
@@ -259,6 +260,7 @@ mod step1_state_to_settings {
                 lookback: value.lookback,
                 casefold: value.casefold,
                 backup_targets_before_eet_copy: value.backup_targets_before_eet_copy,
+                clean_game_policy: value.clean_game_policy,
             }
         }
     }
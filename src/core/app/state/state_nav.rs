@@ -23,7 +23,10 @@ impl WizardState {
     }
 
     pub fn run_step1_path_check(&mut self) {
-        self.step1_path_check = Some(state_validation::run_path_check(&self.step1));
+        self.step1_path_check = Some(state_validation::run_path_check(
+            &self.step1,
+            self.step5.resume_available,
+        ));
         self.step1_mods_folder_has_tp2 =
             Some(state_validation::step1_mods_folder_has_tp2(&self.step1));
     }
@@ -97,6 +100,8 @@ impl WizardState {
             modlist_auto_build_active: false,
             modlist_auto_build_waiting_for_install: false,
             reproduce_exact: false,
+            install_from_lock: false,
+            install_offline: false,
             last_step2_sync_signature: None,
             step1_clean_confirm_open: false,
             step4_save_error_open: false,
@@ -133,6 +138,8 @@ impl WizardState {
         self.modlist_auto_build_active = false;
         self.modlist_auto_build_waiting_for_install = false;
         self.reproduce_exact = false;
+        self.install_from_lock = false;
+        self.install_offline = false;
         self.last_step2_sync_signature = None;
         self.step1_clean_confirm_open = false;
         self.step4_save_error_open = false;
@@ -68,6 +68,7 @@ pub struct Step1State<Flag = bool> {
     pub lookback: usize,
     pub casefold: Flag,
     pub backup_targets_before_eet_copy: Flag,
+    pub clean_game_policy: String,
 }
 
 impl Step1State {
@@ -198,6 +199,7 @@ impl Default for Step1State {
             lookback: 10,
             casefold: false,
             backup_targets_before_eet_copy: false,
+            clean_game_policy: "warn".to_string(),
         }
     }
 }
@@ -52,6 +52,7 @@ pub struct Step2State<Flag = bool> {
     pub update_selected_download_failed_sources: Vec<String>,
     pub update_selected_extracted_sources: Vec<String>,
     pub update_selected_extract_failed_sources: Vec<String>,
+    pub update_selected_lock_drift_sources: Vec<String>,
     pub update_selected_known_sources: Vec<String>,
     pub update_selected_manual_sources: Vec<String>,
     pub update_selected_unknown_sources: Vec<String>,
@@ -134,6 +135,7 @@ impl Default for Step2State {
             update_selected_download_failed_sources: Vec::new(),
             update_selected_extracted_sources: Vec::new(),
             update_selected_extract_failed_sources: Vec::new(),
+            update_selected_lock_drift_sources: Vec::new(),
             update_selected_known_sources: Vec::new(),
             update_selected_manual_sources: Vec::new(),
             update_selected_unknown_sources: Vec::new(),
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Copyright (c) 2026 Born2BSalty
 
+use crate::install::failure_class::ComponentFailure;
+
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct ResumeTargets {
     pub bg1_game_dir: Option<String>,
@@ -16,6 +18,7 @@ pub struct Step5State<Flag = bool> {
     pub prep_running: Flag,
     pub install_running: Flag,
     pub last_install_failed: Flag,
+    pub failure_summary: Vec<ComponentFailure>,
     pub last_exit_code: Option<i32>,
     pub last_status_text: String,
     pub install_started_unix_secs: Option<u64>,
@@ -74,6 +77,7 @@ impl Default for Step5State {
             prep_running: false,
             install_running: false,
             last_install_failed: false,
+            failure_summary: Vec::new(),
             last_exit_code: None,
             last_status_text: "Idle".to_string(),
             install_started_unix_secs: None,
@@ -73,9 +73,19 @@ pub fn is_step1_valid(s: &Step1State) -> bool {
     }
 }
 
+pub const PATH_CHECK_WARNINGS_MARKER: &str = " with warnings: ";
+
+#[must_use]
+pub fn run_path_check(s: &Step1State, resuming: bool) -> (bool, String) {
+    paths::run_path_check(s, resuming)
+}
+
 #[must_use]
-pub fn run_path_check(s: &Step1State) -> (bool, String) {
-    paths::run_path_check(s)
+pub fn split_path_check_warnings(msg: &str) -> (&str, Vec<String>) {
+    msg.split_once(PATH_CHECK_WARNINGS_MARKER)
+        .map_or((msg, Vec::new()), |(head, tail)| {
+            (head, split_path_check_lines(tail))
+        })
 }
 
 pub fn split_path_check_lines(msg: &str) -> Vec<String> {
@@ -9,9 +9,9 @@ use crate::app::state_validation_fs as fs_checks;
 use crate::app::state_validation_modes;
 use crate::platform_defaults::{resolve_mod_installer_binary, resolve_weidu_binary};
 
-use super::step1_validation_messages;
+use super::{PATH_CHECK_WARNINGS_MARKER, step1_validation_messages};
 
-pub(super) fn run_path_check(s: &Step1State) -> (bool, String) {
+pub(super) fn run_path_check(s: &Step1State, resuming: bool) -> (bool, String) {
     let mut errors: Vec<String> = Vec::new();
     let mut checked = 0usize;
     let required = step1_validation_messages(s);
@@ -82,7 +82,10 @@ pub(super) fn run_path_check(s: &Step1State) -> (bool, String) {
         );
     }
 
-    format_path_check_result(&errors, checked)
+    let clean_game = crate::game_fingerprint::preinstall_report(s, resuming);
+    errors.extend(clean_game.errors());
+
+    format_path_check_result(&errors, &clean_game.warnings(), checked)
 }
 
 pub(super) fn step1_mods_folder_has_tp2(s: &Step1State) -> bool {
@@ -99,12 +102,24 @@ fn has_value(value: &str) -> bool {
     !value.trim().is_empty()
 }
 
-fn format_path_check_result(errors: &[String], checked: usize) -> (bool, String) {
-    if errors.is_empty() {
+fn format_path_check_result(
+    errors: &[String],
+    warnings: &[String],
+    checked: usize,
+) -> (bool, String) {
+    if errors.is_empty() && warnings.is_empty() {
         (
             true,
             format!("Path check passed ({checked} path(s) validated)"),
         )
+    } else if errors.is_empty() {
+        (
+            true,
+            format!(
+                "Path check passed ({checked} path(s) validated){PATH_CHECK_WARNINGS_MARKER}{}",
+                warnings.join(" | ")
+            ),
+        )
     } else {
         (false, format!("Path check failed: {}", errors.join(" | ")))
     }
@@ -33,6 +33,8 @@ pub struct WizardState<Flag = bool> {
     pub modlist_auto_build_active: Flag,
     pub modlist_auto_build_waiting_for_install: Flag,
     pub reproduce_exact: Flag,
+    pub install_from_lock: Flag,
+    pub install_offline: Flag,
     pub last_step2_sync_signature: Option<String>,
     pub step1_clean_confirm_open: Flag,
     pub step4_save_error_open: Flag,
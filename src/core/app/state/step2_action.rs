@@ -62,4 +62,8 @@ pub enum Step2Action {
         component_id: String,
         component_key: String,
     },
+    JumpToComponent {
+        tp2: String,
+        component_id: u32,
+    },
 }
@@ -14,7 +14,7 @@ pub mod worker;
 
 pub const ENABLE_TWO_PHASE_PREVIEW: bool = true;
 pub const SCAN_CACHE_FILE: &str = "bio_scan_cache.json";
-pub const SCAN_CACHE_VERSION: u32 = 6;
+pub const SCAN_CACHE_VERSION: u32 = 7;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScannedComponent {
@@ -10,6 +10,7 @@ use crate::app::compat_conflict_parse::load_component_conflicts;
 use crate::app::compat_dependency_parse::load_component_requirements;
 use crate::app::scan::worker::scan_impl;
 use crate::app::state::{Step1State, Step2ModState};
+use crate::app::tra_resolver::mod_language;
 
 pub use crate::app::scan::Step2ScanEvent;
 
@@ -45,6 +46,6 @@ fn prewarm_import_compat_caches(
             continue;
         }
         let _ = load_component_requirements(tp2_path);
-        let _ = load_component_conflicts(tp2_path);
+        let _ = load_component_conflicts(tp2_path, mod_language(mod_state));
     }
 }
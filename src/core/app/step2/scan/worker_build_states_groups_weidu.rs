@@ -5,10 +5,11 @@ use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
 use crate::app::scan::ScannedComponent;
+use crate::app::tra_resolver::{language_from_raw_lines, tra_table};
 
 use super::super::order::{normalize_component_order_label, parse_begin_label};
 use super::super::tp2_blocks::{Tp2ComponentBlock, parse_tp2_component_blocks_in_order};
-use super::super::tra::{load_tp2_setup_tra_map, resolve_group_token_label};
+use super::super::tra::resolve_group_token_label;
 use super::block_is_deprecated_placeholder;
 
 pub(super) fn detect_weidu_groups(
@@ -21,7 +22,11 @@ pub(super) fn detect_weidu_groups(
         return HashMap::new();
     }
 
-    let tra_map = load_tp2_setup_tra_map(Path::new(tp2_path));
+    let tra = tra_table(
+        Path::new(tp2_path),
+        language_from_raw_lines(components.iter().map(|c| c.raw_line.as_str())),
+    );
+    let tra_map = tra.entries();
     let component_ids = components
         .iter()
         .map(|component| component.component_id.trim().to_string())
@@ -44,7 +49,7 @@ pub(super) fn detect_weidu_groups(
     for (index, block) in ordered_blocks.iter().enumerate() {
         let Some(bound_component_id) = bind_block_to_component_id(
             block,
-            &tra_map,
+            tra_map,
             &component_ids,
             &ordered_components,
             &matched_components,
@@ -56,14 +61,14 @@ pub(super) fn detect_weidu_groups(
         let group_label = block
             .group_key
             .as_deref()
-            .and_then(|group_token| resolve_group_token_label(group_token, &tra_map))
+            .and_then(|group_token| resolve_group_token_label(group_token, tra_map))
             .map(|value| value.trim().to_string())
             .filter(|value| !value.is_empty())
             .or_else(|| {
                 inherit_group_for_deprecated_placeholder(
                     &ordered_blocks,
                     index,
-                    &tra_map,
+                    tra_map,
                     previous_group.as_deref(),
                 )
             });
@@ -5,18 +5,23 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use crate::app::scan::ScannedComponent;
+use crate::app::tra_resolver::{language_from_raw_lines, tra_table};
 
 use super::tp2_blocks::parse_tp2_component_blocks_in_order;
-use super::tra::{load_tp2_setup_tra_map, resolve_group_token_label};
+use super::tra::resolve_group_token_label;
 
 pub(super) fn reorder_components_by_tp2_order(
     components: &mut [ScannedComponent],
     tp2_path: &str,
     tp2_text: &str,
 ) {
-    let tra_map = load_tp2_setup_tra_map(Path::new(tp2_path));
+    let tra = tra_table(
+        Path::new(tp2_path),
+        language_from_raw_lines(components.iter().map(|c| c.raw_line.as_str())),
+    );
+    let tra_map = tra.entries();
     let (order_by_designated_id, order_by_label, order_by_begin_at_id) =
-        parse_tp2_component_order(tp2_text, &tra_map);
+        parse_tp2_component_order(tp2_text, tra_map);
     if order_by_designated_id.is_empty()
         && order_by_label.is_empty()
         && order_by_begin_at_id.is_empty()
@@ -9,6 +9,7 @@ use crate::app::scan::ScannedComponent;
 use crate::app::scan::cache::{ScanCache, cache_get, cache_put};
 use crate::app::scan::parse::{normalize_tp_file, parse_component_line};
 use crate::app::state::Step2Tp2ProbeReport;
+use crate::app::tra_resolver::{TraTable, language_from_raw_lines, tra_table};
 use crate::install::weidu_scan;
 use crate::parser;
 
@@ -41,7 +42,7 @@ pub(super) fn scan_tp2_group(
             Some(scan_ctx.game_install),
         );
         apply_parser_probe_meta(&mut probe, &prompt_index);
-        if let Some(cached) = cached_components(scan_ctx, tp2, &prompt_index, &mut probe) {
+        if let Some(cached) = cached_components(scan_ctx, tp2, &mut probe) {
             entries.extend(cached);
             reports.push(probe);
             continue;
@@ -70,7 +71,6 @@ fn new_probe(group_label: &str, tp2: &Path, work_dir: &Path) -> Step2Tp2ProbeRep
 fn cached_components(
     scan_ctx: &ScanGroupContext<'_>,
     tp2: &Path,
-    prompt_index: &parser::PromptSummaryIndex,
     probe: &mut Step2Tp2ProbeReport,
 ) -> Option<Vec<ScannedComponent>> {
     let cached = cache_get(scan_ctx.cache, scan_ctx.ctx, tp2)?;
@@ -79,7 +79,6 @@ fn cached_components(
     }
     probe.used_cache = true;
     probe.selected_from_cache = true;
-    let cached = apply_prompt_index(cached, prompt_index);
     probe.parsed_count = cached.len();
     probe.undefined_count = count_undefined_components(&cached);
     Some(cached)
@@ -130,7 +129,7 @@ fn scan_components_for_tp2(
         let undefined = count_undefined_components(&parsed_for_tp2);
         if undefined < parsed_for_tp2.len() {
             probe.selected_language_id = Some(lang_id);
-            let parsed_for_tp2 = apply_prompt_index(parsed_for_tp2, prompt_index);
+            let parsed_for_tp2 = apply_prompt_index(parsed_for_tp2, tp2, prompt_index);
             probe.parsed_count = parsed_for_tp2.len();
             probe.undefined_count = undefined;
             cache_put(scan_ctx.cache, scan_ctx.ctx, tp2, parsed_for_tp2.clone());
@@ -146,7 +145,7 @@ fn scan_components_for_tp2(
         return Ok(Vec::new());
     }
     probe.selected_language_id = fallback_language;
-    let fallback_components = apply_prompt_index(fallback_components, prompt_index);
+    let fallback_components = apply_prompt_index(fallback_components, tp2, prompt_index);
     probe.parsed_count = fallback_components.len();
     probe.undefined_count = count_undefined_components(&fallback_components);
     cache_put(
@@ -160,11 +159,15 @@ fn scan_components_for_tp2(
 
 fn apply_prompt_index(
     mut components: Vec<ScannedComponent>,
+    tp2: &Path,
     prompt_index: &parser::PromptSummaryIndex,
 ) -> Vec<ScannedComponent> {
     if components.is_empty() {
         return components;
     }
+    let language = language_from_raw_lines(components.iter().map(|c| c.raw_line.as_str()));
+    let tra = tra_table(tp2, language);
+    let mod_events = resolve_prompt_events(&prompt_index.mod_events, &tra);
 
     let mut has_component_prompt = false;
     for component in &mut components {
@@ -172,13 +175,13 @@ fn apply_prompt_index(
             .by_component_id
             .get(component.component_id.trim())
         {
-            component.prompt_summary = Some(summary.clone());
+            component.prompt_summary = Some(tra.resolve_refs(summary));
             has_component_prompt = true;
         }
         component.prompt_events = prompt_index
             .by_component_id_events
             .get(component.component_id.trim())
-            .cloned()
+            .map(|events| resolve_prompt_events(events, &tra))
             .unwrap_or_default();
     }
 
@@ -195,30 +198,45 @@ fn apply_prompt_index(
             lines.push(format!("{}:\n{}", component.display.trim(), summary));
         }
         let mod_summary = if lines.is_empty() {
-            prompt_index.mod_summary.clone()
+            prompt_index
+                .mod_summary
+                .as_deref()
+                .map(|summary| tra.resolve_refs(summary))
         } else {
             Some(lines.join("\n\n"))
         };
         for component in &mut components {
             component.mod_prompt_summary.clone_from(&mod_summary);
-            component
-                .mod_prompt_events
-                .clone_from(&prompt_index.mod_events);
+            component.mod_prompt_events.clone_from(&mod_events);
         }
     } else {
+        let mod_summary = prompt_index
+            .mod_summary
+            .as_deref()
+            .map(|summary| tra.resolve_refs(summary));
         for component in &mut components {
-            component
-                .mod_prompt_summary
-                .clone_from(&prompt_index.mod_summary);
-            component
-                .mod_prompt_events
-                .clone_from(&prompt_index.mod_events);
+            component.mod_prompt_summary.clone_from(&mod_summary);
+            component.mod_prompt_events.clone_from(&mod_events);
         }
     }
 
     components
 }
 
+fn resolve_prompt_events(
+    events: &[parser::PromptSummaryEvent],
+    tra: &TraTable,
+) -> Vec<parser::PromptSummaryEvent> {
+    events
+        .iter()
+        .map(|event| parser::PromptSummaryEvent {
+            text: tra.resolve_refs(&event.text),
+            summary_line: tra.resolve_refs(&event.summary_line),
+            ..event.clone()
+        })
+        .collect()
+}
+
 fn apply_parser_probe_meta(
     probe: &mut Step2Tp2ProbeReport,
     prompt_index: &parser::PromptSummaryIndex,
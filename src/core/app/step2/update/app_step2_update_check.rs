@@ -12,6 +12,7 @@ use crate::app::state::{Step2UpdateAsset, Step2UpdateRetryRequest, WizardState};
 #[derive(Debug, Clone)]
 pub(crate) struct Step2UpdateCheckRequest {
     pub(crate) game_tab: String,
+    pub(crate) modlist_id: String,
     pub(crate) tp_file: String,
     pub(crate) label: String,
     pub(crate) source_id: String,
@@ -35,6 +36,7 @@ pub(crate) enum Step2PackageKind {
 #[derive(Debug, Clone)]
 pub(crate) struct Step2UpdateCheckOutcome {
     pub(crate) game_tab: String,
+    pub(crate) modlist_id: String,
     pub(crate) tp_file: String,
     pub(crate) label: String,
     pub(crate) source_id: String,
@@ -75,8 +77,11 @@ pub(crate) fn start_step2_update_check(
         state.step2.update_selected_check_running = false;
         return;
     }
-    *step2_update_check_rx =
-        Some(super::app_step2_update_check_worker::spawn_update_check_worker(requests));
+    *step2_update_check_rx = Some(if state.install_offline {
+        crate::install_runtime::offline_bundle::spawn_offline_update_check(requests)
+    } else {
+        super::app_step2_update_check_worker::spawn_update_check_worker(requests)
+    });
     state.step2.update_selected_check_running = true;
 }
 pub(crate) fn poll_step2_update_check(
@@ -372,7 +377,7 @@ pub(super) fn check_latest_release_for_worker(
     }
 }
 
-pub(super) fn failed_outcome(
+pub(crate) fn failed_outcome(
     request: Step2UpdateCheckRequest,
     error: &str,
 ) -> Step2UpdateCheckOutcome {
@@ -383,6 +388,7 @@ pub(super) fn failed_outcome(
     };
     Step2UpdateCheckOutcome {
         game_tab: request.game_tab,
+        modlist_id: request.modlist_id,
         tp_file: request.tp_file,
         label: request.label,
         source_id: request.source_id,
@@ -632,6 +638,7 @@ mod tests {
 
         let outcome = Step2UpdateCheckOutcome {
             game_tab: "BGEE".to_string(),
+            modlist_id: String::new(),
             tp_file: "ISNF.tp2".to_string(),
             label: "ISNF".to_string(),
             source_id: "weaselmods".to_string(),
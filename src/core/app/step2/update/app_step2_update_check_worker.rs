@@ -192,6 +192,7 @@ fn fan_out_update_check_outcome(
             expanded.tp_file.clone_from(&target.tp_file);
             expanded.label.clone_from(&target.label);
             expanded.source_id.clone_from(&target.source_id);
+            expanded.modlist_id.clone_from(&target.modlist_id);
             expanded
         })
         .collect()
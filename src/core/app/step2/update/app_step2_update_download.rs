@@ -34,18 +34,18 @@ pub(crate) fn start_step2_update_download(
         state.step2.scan_status = "Download Archive is disabled in Step 1".to_string();
         return;
     }
-    let archive_dir = state.step1.mods_archive_folder.trim();
-    if archive_dir.is_empty() {
+    if state.step1.mods_archive_folder.trim().is_empty() {
         state.step2.scan_status = "Mods Archive folder is empty".to_string();
         return;
     }
+    crate::install_runtime::modlist_lock::pin_assets_to_lock(state);
     let assets = state.step2.update_selected_update_assets.clone();
     if assets.is_empty() {
         state.step2.scan_status = "No update archives to download".to_string();
         return;
     }
 
-    let archive_dir = PathBuf::from(archive_dir);
+    let archive_dir = PathBuf::from(state.step1.mods_archive_folder.trim());
     let (tx, rx) = mpsc::channel::<Step2UpdateDownloadEvent>();
     *step2_update_download_rx = Some(rx);
     state.step2.update_selected_download_running = true;
@@ -54,6 +54,7 @@ pub(crate) fn start_step2_update_download(
     state.step2.update_selected_download_failed_sources.clear();
     state.step2.update_selected_extracted_sources.clear();
     state.step2.update_selected_extract_failed_sources.clear();
+    state.step2.update_selected_lock_drift_sources.clear();
     state.step2.scan_status = format!("Downloading updates: 0/{}", assets.len());
 
     thread::spawn(move || {
@@ -93,6 +93,7 @@ pub(crate) fn poll_step2_update_extract(
     *step2_update_extract_rx = None;
     state.step2.update_selected_extract_running = false;
     state.step2.update_selected_extracted_sources = result.extracted;
+    crate::install_runtime::modlist_lock::record_extracted(state);
     remove_extracted_update_entries(state);
     state
         .step2
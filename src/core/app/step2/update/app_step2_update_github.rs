@@ -280,6 +280,7 @@ fn release_asset_outcome(
         super::app_step2_update_asset_pick::pick_release_asset_for_current_os(&assets)?;
     Some(Step2UpdateCheckOutcome {
         game_tab: request.game_tab.clone(),
+        modlist_id: request.modlist_id.clone(),
         tp_file: request.tp_file.clone(),
         label: request.label.clone(),
         source_id: request.source_id.clone(),
@@ -307,6 +308,7 @@ fn packaged_release_outcome(
         super::app_step2_update_asset_pick::pick_release_asset_for_pkg_list(&assets, pkg_list)?;
     Some(Step2UpdateCheckOutcome {
         game_tab: request.game_tab.clone(),
+        modlist_id: request.modlist_id.clone(),
         tp_file: request.tp_file.clone(),
         label: request.label.clone(),
         source_id: request.source_id.clone(),
@@ -334,6 +336,7 @@ fn named_release_asset_outcome(
         super::app_step2_update_asset_pick::pick_release_asset_by_name(&assets, asset_name)?;
     Some(Step2UpdateCheckOutcome {
         game_tab: request.game_tab.clone(),
+        modlist_id: request.modlist_id.clone(),
         tp_file: request.tp_file.clone(),
         label: request.label.clone(),
         source_id: request.source_id.clone(),
@@ -359,6 +362,7 @@ fn tagged_source_outcome(
         .unwrap_or("source");
     Step2UpdateCheckOutcome {
         game_tab: request.game_tab.clone(),
+        modlist_id: request.modlist_id.clone(),
         tp_file: request.tp_file.clone(),
         label: request.label.clone(),
         source_id: request.source_id.clone(),
@@ -382,6 +386,7 @@ fn branch_source_outcome(
     {
         return Step2UpdateCheckOutcome {
             game_tab: request.game_tab.clone(),
+            modlist_id: request.modlist_id.clone(),
             tp_file: request.tp_file.clone(),
             label: request.label.clone(),
             source_id: request.source_id.clone(),
@@ -417,6 +422,7 @@ fn commit_source_outcome(
     let short = commit.chars().take(7).collect::<String>();
     Step2UpdateCheckOutcome {
         game_tab: request.game_tab.clone(),
+        modlist_id: request.modlist_id.clone(),
         tp_file: request.tp_file.clone(),
         label: request.label.clone(),
         source_id: request.source_id.clone(),
@@ -464,6 +470,7 @@ fn tagged_tag_source_outcome(
         .unwrap_or("source");
     Step2UpdateCheckOutcome {
         game_tab: request.game_tab.clone(),
+        modlist_id: request.modlist_id.clone(),
         tp_file: request.tp_file.clone(),
         label: request.label.clone(),
         source_id: request.source_id.clone(),
@@ -490,6 +497,7 @@ fn repo_source_outcome(
     };
     Step2UpdateCheckOutcome {
         game_tab: request.game_tab.clone(),
+        modlist_id: request.modlist_id.clone(),
         tp_file: request.tp_file.clone(),
         label: request.label.clone(),
         source_id: request.source_id.clone(),
@@ -734,6 +742,7 @@ mod tests {
     fn make_request(asset: Option<&str>) -> Step2UpdateCheckRequest {
         Step2UpdateCheckRequest {
             game_tab: String::new(),
+            modlist_id: String::new(),
             tp_file: String::new(),
             label: String::new(),
             source_id: String::new(),
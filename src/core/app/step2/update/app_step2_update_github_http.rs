@@ -2,6 +2,30 @@
 // Copyright (c) 2026 Born2BSalty
 
 use chrono::{DateTime, Local, Utc};
+use serde::Deserialize;
+
+const GITHUB_RATE_LIMIT_URL: &str = "https://api.github.com/rate_limit";
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub(super) struct GitHubRateBucket {
+    pub(super) remaining: u32,
+    pub(super) reset: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRateLimitResources {
+    core: GitHubRateBucket,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRateLimitResponse {
+    resources: GitHubRateLimitResources,
+}
+
+pub(super) fn github_core_rate_limit(agent: &ureq::Agent) -> Result<GitHubRateBucket, String> {
+    github_api_get_json::<GitHubRateLimitResponse>(agent, GITHUB_RATE_LIMIT_URL)
+        .map(|response| response.resources.core)
+}
 
 pub(super) fn github_api_get_json<T: serde::de::DeserializeOwned>(
     agent: &ureq::Agent,
@@ -32,6 +32,7 @@ pub(super) fn check_morpheus_mart_download_page(
     let pin_overridden = version_override(request.requested_version.as_deref(), &version);
     Step2UpdateCheckOutcome {
         game_tab: request.game_tab.clone(),
+        modlist_id: request.modlist_id.clone(),
         tp_file: request.tp_file.clone(),
         label: request.label.clone(),
         source_id: request.source_id.clone(),
@@ -81,6 +82,7 @@ fn failed_morpheus_mart_outcome(
 ) -> Step2UpdateCheckOutcome {
     Step2UpdateCheckOutcome {
         game_tab: request.game_tab.clone(),
+        modlist_id: request.modlist_id.clone(),
         tp_file: request.tp_file.clone(),
         label: request.label.clone(),
         source_id: request.source_id.clone(),
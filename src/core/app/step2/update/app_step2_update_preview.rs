@@ -271,6 +271,7 @@ fn clear_full_update_preview_results(state: &mut WizardState) {
     state.step2.update_selected_download_failed_sources.clear();
     state.step2.update_selected_extracted_sources.clear();
     state.step2.update_selected_extract_failed_sources.clear();
+    state.step2.update_selected_lock_drift_sources.clear();
     state
         .step2
         .update_selected_exact_version_failed_sources
@@ -531,7 +532,7 @@ fn replace_label_entries(target: &mut Vec<String>, label: &str, replacement: Vec
     target.extend(replacement);
 }
 
-fn queue_source_request(
+pub(super) fn queue_source_request(
     game_tab: &str,
     tp_file: &str,
     label: &str,
@@ -542,6 +543,7 @@ fn queue_source_request(
     if let Some(repo) = source.github.as_deref() {
         update_requests.push(super::app_step2_update_check::Step2UpdateCheckRequest {
             game_tab: game_tab.to_string(),
+            modlist_id: String::new(),
             tp_file: tp_file.to_string(),
             label: label.to_string(),
             source_id: source.source_id.clone(),
@@ -570,6 +572,7 @@ fn queue_source_request(
     } else if mod_downloads::source_is_page_archive_url(&source.url) {
         update_requests.push(super::app_step2_update_check::Step2UpdateCheckRequest {
             game_tab: game_tab.to_string(),
+            modlist_id: String::new(),
             tp_file: tp_file.to_string(),
             label: label.to_string(),
             source_id: source.source_id.clone(),
@@ -590,6 +593,7 @@ fn queue_source_request(
     } else if mod_downloads::source_is_sentrizeal_download_url(&source.url) {
         update_requests.push(super::app_step2_update_check::Step2UpdateCheckRequest {
             game_tab: game_tab.to_string(),
+            modlist_id: String::new(),
             tp_file: tp_file.to_string(),
             label: label.to_string(),
             source_id: source.source_id.clone(),
@@ -610,6 +614,7 @@ fn queue_source_request(
     } else if mod_downloads::is_direct_archive_url(&source.url) {
         update_requests.push(super::app_step2_update_check::Step2UpdateCheckRequest {
             game_tab: game_tab.to_string(),
+            modlist_id: String::new(),
             tp_file: tp_file.to_string(),
             label: label.to_string(),
             source_id: source.source_id.clone(),
@@ -28,6 +28,10 @@ pub(crate) fn installed_source_refs_path() -> std::path::PathBuf {
     )
 }
 
+pub(crate) fn installed_source_refs_path_for_modlist(modlist_dir: &Path) -> std::path::PathBuf {
+    modlist_dir.join(MOD_SOURCE_REFS_FILE_NAME)
+}
+
 pub(crate) fn load_refs_file_at(path: &Path) -> ModSourceRefsFile {
     fs::read_to_string(path).map_or_else(
         |_| ModSourceRefsFile::default(),
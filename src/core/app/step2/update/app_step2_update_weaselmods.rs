@@ -34,6 +34,7 @@ pub(super) fn check_weaselmods_download_page(
     let asset_name = format!("{file_stem}-{version}.zip");
     Step2UpdateCheckOutcome {
         game_tab: request.game_tab.clone(),
+        modlist_id: request.modlist_id.clone(),
         tp_file: request.tp_file.clone(),
         label: request.label.clone(),
         source_id: request.source_id.clone(),
@@ -61,6 +62,7 @@ fn failed_weaselmods_outcome(
 ) -> Step2UpdateCheckOutcome {
     Step2UpdateCheckOutcome {
         game_tab: request.game_tab.clone(),
+        modlist_id: request.modlist_id.clone(),
         tp_file: request.tp_file.clone(),
         label: request.label.clone(),
         source_id: request.source_id.clone(),
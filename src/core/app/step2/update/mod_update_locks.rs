@@ -4,7 +4,7 @@
 use std::collections::BTreeSet;
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Mutex, OnceLock};
 
 use serde::{Deserialize, Serialize};
@@ -75,9 +75,16 @@ pub(crate) fn take_last_load_error() -> Option<String> {
         .and_then(|mut guard| guard.take())
 }
 
+pub(crate) fn load_mod_update_locks_for_modlist(modlist_dir: &Path) -> BTreeSet<String> {
+    load_mod_update_locks_at(&modlist_dir.join(MOD_UPDATE_LOCKS_FILE_NAME)).unwrap_or_default()
+}
+
 fn load_mod_update_locks() -> Result<BTreeSet<String>, String> {
-    let path = mod_update_locks_path();
-    let content = match fs::read_to_string(&path) {
+    load_mod_update_locks_at(&mod_update_locks_path())
+}
+
+fn load_mod_update_locks_at(path: &Path) -> Result<BTreeSet<String>, String> {
+    let content = match fs::read_to_string(path) {
         Ok(value) => value,
         Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(BTreeSet::new()),
         Err(err) => {
@@ -0,0 +1,371 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use super::app_step2_update_check::Step2UpdateCheckRequest;
+use super::app_step2_update_check_worker::{Step2UpdateCheckEvent, spawn_update_check_worker};
+use super::app_step2_update_source_refs::{
+    ModSourceRefsFile, installed_source_refs_path_for_modlist, load_refs_file_at,
+};
+use crate::app::mod_downloads::{
+    load_mod_download_sources_for_modlist, normalize_mod_download_tp2, source_is_auto_resolvable,
+};
+use crate::app::mod_update_locks::load_mod_update_locks_for_modlist;
+use crate::parser::weidu_version::{normalize_version_text, parse_version};
+use crate::registry::model::{Game, ModUpdateDelta, ModlistRegistry, ModlistUpdateStatus};
+use crate::registry::store_workspace::{WorkspaceStore, modlist_data_dir};
+
+pub(crate) const UPDATE_SCHEDULE_POLL: Duration = Duration::from_secs(10 * 60);
+const GITHUB_RATE_RESERVE: u32 = 25;
+const GITHUB_CALLS_PER_REPO: u32 = 3;
+const RATE_LIMIT_BACKOFF_MINUTES: i64 = 60;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ScheduledUpdateTarget {
+    pub(crate) modlist_id: String,
+    pub(crate) game: Game,
+    pub(crate) destination: PathBuf,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ScheduledUpdateReport {
+    pub(crate) statuses: Vec<(String, ModlistUpdateStatus)>,
+    pub(crate) rate_limited_until: Option<DateTime<Utc>>,
+}
+
+struct ModlistPlan {
+    modlist_id: String,
+    refs: ModSourceRefsFile,
+    log_versions: HashMap<String, String>,
+    skipped_locked: u32,
+}
+
+pub(crate) fn due_targets(
+    registry: &ModlistRegistry,
+    interval_hours: u32,
+    now: DateTime<Utc>,
+) -> Vec<ScheduledUpdateTarget> {
+    let interval = chrono::Duration::hours(i64::from(interval_hours.max(1)));
+    registry
+        .entries
+        .iter()
+        .filter(|entry| {
+            entry
+                .update_status
+                .as_ref()
+                .and_then(|status| status.checked_at)
+                .is_none_or(|checked_at| now - checked_at >= interval)
+        })
+        .map(|entry| ScheduledUpdateTarget {
+            modlist_id: entry.id.clone(),
+            game: entry.game,
+            destination: PathBuf::from(&entry.destination_folder),
+        })
+        .collect()
+}
+
+pub(crate) fn spawn_scheduled_update_check(
+    targets: Vec<ScheduledUpdateTarget>,
+) -> mpsc::Receiver<ScheduledUpdateReport> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = tx.send(run_scheduled_update_check(&targets));
+    });
+    rx
+}
+
+fn run_scheduled_update_check(targets: &[ScheduledUpdateTarget]) -> ScheduledUpdateReport {
+    let mut plans = Vec::new();
+    let mut requests = Vec::new();
+    let mut report = ScheduledUpdateReport::default();
+    for target in targets {
+        match plan_modlist(target, &mut requests) {
+            Ok(plan) => plans.push(plan),
+            Err(err) => report.statuses.push((
+                target.modlist_id.clone(),
+                ModlistUpdateStatus {
+                    checked_at: Some(Utc::now()),
+                    error: Some(format!("workspace could not be loaded: {err}")),
+                    ..ModlistUpdateStatus::default()
+                },
+            )),
+        }
+    }
+
+    let mut deferred = HashSet::<String>::new();
+    let agent = ureq::AgentBuilder::new()
+        .timeout_connect(Duration::from_secs(10))
+        .timeout_read(Duration::from_secs(20))
+        .build();
+    let github_repos: BTreeSet<String> = requests
+        .iter()
+        .filter(|request| !request.repo.trim().is_empty())
+        .map(|request| request.repo.trim().to_ascii_lowercase())
+        .collect();
+    if !github_repos.is_empty()
+        && let Ok(bucket) = super::app_step2_update_github_http::github_core_rate_limit(&agent)
+    {
+        let budget = bucket.remaining.saturating_sub(GITHUB_RATE_RESERVE) / GITHUB_CALLS_PER_REPO;
+        let allowed: BTreeSet<String> = github_repos
+            .iter()
+            .take(usize::try_from(budget).unwrap_or(usize::MAX))
+            .cloned()
+            .collect();
+        if allowed.len() < github_repos.len() {
+            report.rate_limited_until = DateTime::from_timestamp(bucket.reset, 0);
+            requests.retain(|request| {
+                let repo = request.repo.trim().to_ascii_lowercase();
+                let keep = repo.is_empty() || allowed.contains(&repo);
+                if !keep {
+                    deferred.insert(request.modlist_id.clone());
+                }
+                keep
+            });
+        }
+    }
+
+    let outcomes = run_requests(requests);
+    let now = Utc::now();
+    let mut statuses: HashMap<String, ModlistUpdateStatus> = plans
+        .iter()
+        .map(|plan| {
+            (
+                plan.modlist_id.clone(),
+                ModlistUpdateStatus {
+                    checked_at: Some(now),
+                    skipped_locked: plan.skipped_locked,
+                    ..ModlistUpdateStatus::default()
+                },
+            )
+        })
+        .collect();
+    for outcome in outcomes {
+        let Some(status) = statuses.get_mut(&outcome.modlist_id) else {
+            continue;
+        };
+        if let Some(error) = outcome.error.as_deref() {
+            if error.contains("rate limit") {
+                deferred.insert(outcome.modlist_id.clone());
+                report.rate_limited_until.get_or_insert_with(|| {
+                    now + chrono::Duration::minutes(RATE_LIMIT_BACKOFF_MINUTES)
+                });
+            }
+            status.failed.push(outcome.tp_file.clone());
+            continue;
+        }
+        let Some(plan) = plans
+            .iter()
+            .find(|plan| plan.modlist_id == outcome.modlist_id)
+        else {
+            continue;
+        };
+        let key = normalize_mod_download_tp2(&outcome.tp_file);
+        let current = plan
+            .refs
+            .refs
+            .get(&key)
+            .or_else(|| plan.log_versions.get(&key))
+            .map(String::as_str);
+        let latest = outcome.tag.as_deref().or(outcome.source_ref.as_deref());
+        if let Some(delta) =
+            latest.and_then(|latest| update_delta(&outcome.tp_file, current, latest))
+        {
+            status.updates.push(delta);
+        }
+    }
+
+    report.statuses.extend(
+        plans
+            .into_iter()
+            .filter(|plan| !deferred.contains(&plan.modlist_id))
+            .filter_map(|plan| {
+                statuses
+                    .remove(&plan.modlist_id)
+                    .map(|status| (plan.modlist_id, status))
+            }),
+    );
+    report
+}
+
+fn plan_modlist(
+    target: &ScheduledUpdateTarget,
+    requests: &mut Vec<Step2UpdateCheckRequest>,
+) -> Result<ModlistPlan, String> {
+    let workspace = WorkspaceStore::new_for_id(&target.modlist_id)
+        .load()
+        .map_err(|err| err.to_string())?;
+    let modlist_dir = modlist_data_dir(&target.modlist_id);
+    let locks = load_mod_update_locks_for_modlist(&modlist_dir);
+    let sources = load_mod_download_sources_for_modlist(&modlist_dir);
+    let refs = load_refs_file_at(&installed_source_refs_path_for_modlist(&modlist_dir));
+
+    let mut seen = HashSet::<String>::new();
+    let mut skipped_locked = 0u32;
+    for component in workspace
+        .order_bgee
+        .iter()
+        .chain(&workspace.order_bg2ee)
+        .chain(&workspace.order_iwdee)
+    {
+        let key = normalize_mod_download_tp2(&component.tp2);
+        if key.is_empty() || !seen.insert(key.clone()) {
+            continue;
+        }
+        if locks.contains(&key) {
+            skipped_locked += 1;
+            continue;
+        }
+        let Some(source) =
+            sources.resolve_source(&component.tp2, refs.sources.get(&key).map(String::as_str))
+        else {
+            continue;
+        };
+        if source_is_auto_resolvable(&source) {
+            let queued = requests.len();
+            super::app_step2_update_preview::queue_source_request(
+                target.game.to_legacy_string(),
+                &component.tp2,
+                &component.tp2,
+                None,
+                &source,
+                requests,
+            );
+            for request in &mut requests[queued..] {
+                request.modlist_id.clone_from(&target.modlist_id);
+            }
+        }
+    }
+
+    Ok(ModlistPlan {
+        modlist_id: target.modlist_id.clone(),
+        refs,
+        log_versions: installed_log_versions(&target.destination),
+        skipped_locked,
+    })
+}
+
+fn run_requests(
+    requests: Vec<Step2UpdateCheckRequest>,
+) -> Vec<super::app_step2_update_check::Step2UpdateCheckOutcome> {
+    let rx = spawn_update_check_worker(requests);
+    while let Ok(event) = rx.recv() {
+        if let Step2UpdateCheckEvent::Finished(outcomes) = event {
+            return outcomes;
+        }
+    }
+    Vec::new()
+}
+
+fn installed_log_versions(destination: &Path) -> HashMap<String, String> {
+    let mut out = HashMap::new();
+    if destination.as_os_str().is_empty() {
+        return out;
+    }
+    let mut dirs = vec![destination.to_path_buf()];
+    if let Ok(children) = fs::read_dir(destination) {
+        dirs.extend(
+            children
+                .flatten()
+                .map(|child| child.path())
+                .filter(|path| path.is_dir()),
+        );
+    }
+    for dir in dirs {
+        let Ok(text) = fs::read_to_string(dir.join("weidu.log")) else {
+            continue;
+        };
+        for line in text.lines() {
+            let Some(tp2) = line
+                .trim_start()
+                .strip_prefix('~')
+                .and_then(|rest| rest.split('~').next())
+            else {
+                continue;
+            };
+            if let Some(version) = parse_version(line) {
+                out.entry(normalize_mod_download_tp2(tp2))
+                    .or_insert(version);
+            }
+        }
+    }
+    out
+}
+
+fn update_delta(tp2: &str, current: Option<&str>, latest: &str) -> Option<ModUpdateDelta> {
+    let current = current.map(str::trim).filter(|value| !value.is_empty())?;
+    let latest = latest.trim();
+    if latest.is_empty()
+        || current == latest
+        || normalize_version_text(current) == normalize_version_text(latest)
+    {
+        return None;
+    }
+    Some(ModUpdateDelta {
+        tp2: tp2.to_string(),
+        current: Some(current.to_string()),
+        latest: latest.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::model::ModlistEntry;
+
+    #[test]
+    fn due_targets_and_deltas_respect_interval_and_versions() {
+        let now = Utc::now();
+        let entry = |id: &str, checked_at: Option<DateTime<Utc>>| ModlistEntry {
+            id: id.to_string(),
+            destination_folder: format!("/games/{id}"),
+            update_status: checked_at.map(|checked_at| ModlistUpdateStatus {
+                checked_at: Some(checked_at),
+                ..ModlistUpdateStatus::default()
+            }),
+            ..ModlistEntry::default()
+        };
+        let registry = ModlistRegistry {
+            entries: vec![
+                entry("never", None),
+                entry("fresh", Some(now - chrono::Duration::hours(2))),
+                entry("stale", Some(now - chrono::Duration::hours(30))),
+            ],
+            ..ModlistRegistry::default()
+        };
+        let ids: Vec<String> = due_targets(&registry, 24, now)
+            .into_iter()
+            .map(|target| target.modlist_id)
+            .collect();
+        assert_eq!(ids, vec!["never", "stale"]);
+
+        assert_eq!(update_delta("scs", Some("v35.1"), "35.1"), None);
+        assert_eq!(update_delta("scs", None, "v36"), None);
+        let delta = update_delta("scs", Some("v35"), "v36").expect("delta");
+        assert_eq!(delta.current.as_deref(), Some("v35"));
+        assert_eq!(delta.latest, "v36");
+    }
+
+    #[test]
+    fn unloadable_workspace_is_recorded_as_checked_with_an_error() {
+        let report = run_scheduled_update_check(&[ScheduledUpdateTarget {
+            modlist_id: "missing-workspace-for-schedule-test".to_string(),
+            game: Game::BG2EE,
+            destination: PathBuf::new(),
+        }]);
+        let [(id, status)] = report.statuses.as_slice() else {
+            panic!("expected one status, got {:?}", report.statuses);
+        };
+        assert_eq!(id, "missing-workspace-for-schedule-test");
+        assert!(status.checked_at.is_some());
+        assert!(status.error.is_some());
+        assert_eq!(status.summary().as_deref(), Some("Update check failed"));
+    }
+}
@@ -0,0 +1,491 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::app::compat_step3_rules::{Step3CompatMarker, marker_key};
+use crate::app::state::{Step2ModState, Step3ItemState};
+
+const FAMILY_MARKER_SOURCE: &str = "Step 3 subcomponent family";
+const FAMILY_INDEX_CACHE_LIMIT: usize = 8;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Step3ComponentGroups {
+    pub(crate) family: Option<String>,
+    pub(crate) exclusive: bool,
+    pub(crate) weidu_group: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Step3FamilyChoice {
+    pub(crate) component_id: String,
+    pub(crate) label: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Step3FamilyViolation {
+    pub(crate) tp_file: String,
+    pub(crate) family: String,
+    pub(crate) component_ids: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Step3FamilyIndex {
+    components: HashMap<(String, String), Step3ComponentGroups>,
+    choices: HashMap<String, Vec<Step3FamilyChoice>>,
+}
+
+impl Step3FamilyIndex {
+    pub(crate) fn cached(mods: &[Step2ModState]) -> Arc<Self> {
+        let key = family_index_cache_key(mods);
+        let mut cache = family_index_cache()
+            .lock()
+            .expect("step3 family cache lock poisoned");
+        if let Some(index) = cache.get(&key) {
+            return Arc::clone(index);
+        }
+        if cache.len() >= FAMILY_INDEX_CACHE_LIMIT {
+            cache.clear();
+        }
+        let index = Arc::new(Self::build(mods));
+        cache.insert(key, Arc::clone(&index));
+        index
+    }
+
+    pub(crate) fn build(mods: &[Step2ModState]) -> Self {
+        let mut index = Self::default();
+        for mod_state in mods {
+            for component in &mod_state.components {
+                let groups = Step3ComponentGroups {
+                    family: component
+                        .collapsible_group
+                        .as_deref()
+                        .map(str::trim)
+                        .filter(|group| !group.is_empty())
+                        .map(ToString::to_string),
+                    exclusive: !component.collapsible_group_combinable,
+                    weidu_group: component.weidu_group.clone(),
+                };
+                if groups.exclusive
+                    && !component.collapsible_group_is_umbrella
+                    && !component.disabled
+                    && let Some(family) = groups.family.as_deref()
+                {
+                    index
+                        .choices
+                        .entry(family_key(&mod_state.tp_file, family))
+                        .or_default()
+                        .push(Step3FamilyChoice {
+                            component_id: component.component_id.trim().to_string(),
+                            label: component.label.clone(),
+                        });
+                }
+                index.components.insert(
+                    component_lookup_key(&mod_state.tp_file, &component.component_id),
+                    groups,
+                );
+            }
+        }
+        index
+    }
+
+    pub(crate) fn groups(&self, item: &Step3ItemState) -> Option<&Step3ComponentGroups> {
+        self.components
+            .get(&component_lookup_key(&item.tp_file, &item.component_id))
+    }
+
+    fn item_family(&self, item: &Step3ItemState) -> Option<(String, bool)> {
+        if item.is_parent {
+            return None;
+        }
+        let groups = self.groups(item)?;
+        groups
+            .family
+            .as_deref()
+            .map(|family| (family_key(&item.tp_file, family), groups.exclusive))
+    }
+
+    pub(crate) fn family_indices(&self, items: &[Step3ItemState], idx: usize) -> Vec<usize> {
+        let Some((key, true)) = items.get(idx).and_then(|item| self.item_family(item)) else {
+            return vec![idx];
+        };
+        items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| {
+                self.item_family(item)
+                    .is_some_and(|(other, _)| other == key)
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    pub(crate) fn swap_choices(&self, item: &Step3ItemState) -> Vec<&Step3FamilyChoice> {
+        let Some((key, true)) = self.item_family(item) else {
+            return Vec::new();
+        };
+        self.choices
+            .get(&key)
+            .map(|choices| {
+                choices
+                    .iter()
+                    .filter(|choice| choice.component_id != item.component_id.trim())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn violations(&self, items: &[Step3ItemState]) -> Vec<Step3FamilyViolation> {
+        let mut order = Vec::<String>::new();
+        let mut members = HashMap::<String, Step3FamilyViolation>::new();
+        for item in items {
+            let Some((key, true)) = self.item_family(item) else {
+                continue;
+            };
+            let entry = members.entry(key.clone()).or_insert_with(|| {
+                order.push(key.clone());
+                Step3FamilyViolation {
+                    tp_file: item.tp_file.clone(),
+                    family: self
+                        .groups(item)
+                        .and_then(|groups| groups.family.clone())
+                        .unwrap_or_default(),
+                    component_ids: Vec::new(),
+                }
+            });
+            let component_id = item.component_id.trim().to_string();
+            if !entry.component_ids.contains(&component_id) {
+                entry.component_ids.push(component_id);
+            }
+        }
+        order
+            .into_iter()
+            .filter_map(|key| members.remove(&key))
+            .filter(|violation| violation.component_ids.len() > 1)
+            .collect()
+    }
+}
+
+pub(crate) fn apply_family_markers(
+    index: &Step3FamilyIndex,
+    items: &[Step3ItemState],
+    markers: &mut HashMap<String, Step3CompatMarker>,
+) {
+    for violation in index.violations(items) {
+        for item in items.iter().filter(|item| {
+            !item.is_parent
+                && item.tp_file.eq_ignore_ascii_case(&violation.tp_file)
+                && violation
+                    .component_ids
+                    .contains(&item.component_id.trim().to_string())
+        }) {
+            let others: Vec<&str> = violation
+                .component_ids
+                .iter()
+                .map(String::as_str)
+                .filter(|id| *id != item.component_id.trim())
+                .collect();
+            markers.insert(
+                marker_key(item),
+                Step3CompatMarker {
+                    kind: "conflict".to_string(),
+                    message: Some(format!(
+                        "Only one choice of \"{}\" can be installed; also selected: #{}",
+                        violation.family,
+                        others.join(", #")
+                    )),
+                    related_mod: Some(violation.tp_file.clone()),
+                    related_component: (others.len() == 1).then(|| others[0].to_string()),
+                    source: Some(FAMILY_MARKER_SOURCE.to_string()),
+                    raw_evidence: None,
+                },
+            );
+        }
+    }
+}
+
+pub(crate) fn swap_family_choice(
+    mods: &mut [Step2ModState],
+    items: &mut [Step3ItemState],
+    tp_file: &str,
+    from_component_id: &str,
+    to_component_id: &str,
+) -> bool {
+    let Some(mod_state) = mods.iter_mut().find(|mod_state| {
+        mod_state
+            .tp_file
+            .trim()
+            .eq_ignore_ascii_case(tp_file.trim())
+    }) else {
+        return false;
+    };
+    let Some(from_idx) = mod_state
+        .components
+        .iter()
+        .position(|component| component.component_id.trim() == from_component_id.trim())
+    else {
+        return false;
+    };
+    let Some(to_idx) = mod_state
+        .components
+        .iter()
+        .position(|component| component.component_id.trim() == to_component_id.trim())
+    else {
+        return false;
+    };
+    let same_tp = |item: &Step3ItemState| {
+        !item.is_parent && item.tp_file.trim().eq_ignore_ascii_case(tp_file.trim())
+    };
+    if mod_state.components[to_idx].checked
+        || items
+            .iter()
+            .any(|item| same_tp(item) && item.component_id.trim() == to_component_id.trim())
+    {
+        return false;
+    }
+    let Some(item) = items
+        .iter_mut()
+        .find(|item| same_tp(item) && item.component_id.trim() == from_component_id.trim())
+    else {
+        return false;
+    };
+
+    let selected_order = mod_state.components[from_idx].selected_order;
+    mod_state.components[from_idx].checked = false;
+    mod_state.components[from_idx].selected_order = None;
+    let target = &mut mod_state.components[to_idx];
+    target.checked = true;
+    target.selected_order = selected_order;
+
+    item.component_id.clone_from(&target.component_id);
+    item.component_label.clone_from(&target.label);
+    item.raw_line.clone_from(&target.raw_line);
+    item.prompt_summary.clone_from(&target.prompt_summary);
+    item.prompt_events.clone_from(&target.prompt_events);
+    true
+}
+
+pub(crate) fn reconcile_family_checks(mods: &mut [Step2ModState], items: &[Step3ItemState]) {
+    let index = Step3FamilyIndex::cached(mods);
+    let present: HashMap<(String, String), usize> = items
+        .iter()
+        .filter(|item| !item.is_parent)
+        .map(|item| {
+            (
+                component_lookup_key(&item.tp_file, &item.component_id),
+                item.selected_order,
+            )
+        })
+        .collect();
+    let families: HashSet<String> = items
+        .iter()
+        .filter_map(|item| index.item_family(item))
+        .filter_map(|(key, exclusive)| exclusive.then_some(key))
+        .collect();
+    for mod_state in mods {
+        for component in &mut mod_state.components {
+            let lookup = component_lookup_key(&mod_state.tp_file, &component.component_id);
+            let in_family = index.components.get(&lookup).is_some_and(|groups| {
+                groups.exclusive
+                    && groups.family.as_deref().is_some_and(|family| {
+                        families.contains(&family_key(&mod_state.tp_file, family))
+                    })
+            });
+            if !in_family || component.collapsible_group_is_umbrella {
+                continue;
+            }
+            match present.get(&lookup) {
+                Some(order) => {
+                    component.checked = true;
+                    component.selected_order = component.selected_order.or(Some(*order));
+                }
+                None => {
+                    component.checked = false;
+                    component.selected_order = None;
+                }
+            }
+        }
+    }
+}
+
+fn family_index_cache() -> &'static Mutex<HashMap<u64, Arc<Step3FamilyIndex>>> {
+    static CACHE: OnceLock<Mutex<HashMap<u64, Arc<Step3FamilyIndex>>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn family_index_cache_key(mods: &[Step2ModState]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    "step3-families-v1".hash(&mut hasher);
+    for mod_state in mods {
+        mod_state.tp_file.to_ascii_uppercase().hash(&mut hasher);
+        for component in &mod_state.components {
+            component.component_id.hash(&mut hasher);
+            component.label.hash(&mut hasher);
+            component.collapsible_group.hash(&mut hasher);
+            component.collapsible_group_combinable.hash(&mut hasher);
+            component.collapsible_group_is_umbrella.hash(&mut hasher);
+            component.disabled.hash(&mut hasher);
+            component.weidu_group.hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+fn family_key(tp_file: &str, family: &str) -> String {
+    format!(
+        "{}|{}",
+        tp_file.trim().to_ascii_uppercase(),
+        family.trim().to_ascii_lowercase()
+    )
+}
+
+fn component_lookup_key(tp_file: &str, component_id: &str) -> (String, String) {
+    (
+        tp_file.trim().to_ascii_uppercase(),
+        component_id.trim().to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::state::Step2ComponentState;
+
+    fn component(id: &str, label: &str, group: Option<&str>, checked: bool) -> Step2ComponentState {
+        Step2ComponentState {
+            component_id: id.to_string(),
+            label: label.to_string(),
+            weidu_group: Some("Tweaks".to_string()),
+            collapsible_group: group.map(ToString::to_string),
+            collapsible_group_is_umbrella: false,
+            collapsible_group_combinable: false,
+            raw_line: format!("~SETUP-X.TP2~ #0 #{id} // {label}"),
+            prompt_summary: None,
+            prompt_events: Vec::new(),
+            is_meta_mode_component: false,
+            disabled: false,
+            compat_kind: None,
+            compat_source: None,
+            compat_related_mod: None,
+            compat_related_component: None,
+            compat_graph: None,
+            compat_evidence: None,
+            disabled_reason: None,
+            checked,
+            selected_order: checked.then_some(1),
+        }
+    }
+
+    fn item(id: &str, label: &str) -> Step3ItemState {
+        Step3ItemState {
+            tp_file: "SETUP-X.TP2".to_string(),
+            component_id: id.to_string(),
+            mod_name: "x".to_string(),
+            component_label: label.to_string(),
+            raw_line: format!("~SETUP-X.TP2~ #0 #{id} // {label}"),
+            prompt_summary: None,
+            prompt_events: Vec::new(),
+            selected_order: 1,
+            block_id: "SETUP-X.TP2::X::segment1".to_string(),
+            is_parent: false,
+            parent_placeholder: false,
+        }
+    }
+
+    fn mods() -> Vec<Step2ModState> {
+        vec![Step2ModState {
+            name: "x".to_string(),
+            tp_file: "setup-x.tp2".to_string(),
+            tp2_path: String::new(),
+            readme_path: None,
+            ini_path: None,
+            web_url: None,
+            package_marker: None,
+            latest_checked_version: None,
+            update_locked: false,
+            mod_prompt_summary: None,
+            mod_prompt_events: Vec::new(),
+            checked: true,
+            hidden_components: Vec::new(),
+            components: vec![
+                component("10", "Speed -> Fast", Some("Speed"), true),
+                component("11", "Speed -> Slow", Some("Speed"), true),
+                component("20", "Other", None, true),
+                component("30", "Portraits -> Classic", None, true),
+                component("31", "Portraits -> New", None, true),
+            ],
+        }]
+    }
+
+    #[test]
+    fn groups_families_flags_duplicates_and_swaps_in_place() {
+        let mut mods = mods();
+        let mut items = vec![
+            item("10", "Speed -> Fast"),
+            item("20", "Other"),
+            item("11", "Speed -> Slow"),
+        ];
+        let index = Step3FamilyIndex::build(&mods);
+        assert_eq!(index.family_indices(&items, 0), vec![0, 2]);
+        assert_eq!(index.family_indices(&items, 1), vec![1]);
+        let arrows = [
+            item("30", "Portraits -> Classic"),
+            item("31", "Portraits -> New"),
+        ];
+        assert!(index.violations(&arrows).is_empty());
+        assert!(index.swap_choices(&arrows[0]).is_empty());
+        assert_eq!(
+            index
+                .groups(&items[1])
+                .and_then(|g| g.weidu_group.as_deref()),
+            Some("Tweaks")
+        );
+        let violations = index.violations(&items);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].family, "Speed");
+        assert_eq!(violations[0].component_ids, vec!["10", "11"]);
+
+        let mut markers = HashMap::new();
+        apply_family_markers(&index, &items, &mut markers);
+        assert_eq!(markers.len(), 2);
+        assert_eq!(markers[&marker_key(&items[0])].kind, "conflict");
+
+        items.remove(2);
+        mods[0].components[1].checked = false;
+        assert!(index.violations(&items).is_empty());
+        let choices = index.swap_choices(&items[0]);
+        assert_eq!(choices.len(), 1);
+        assert_eq!(choices[0].component_id, "11");
+
+        let mut duplicated = vec![item("10", "Speed -> Fast"), item("11", "Speed -> Slow")];
+        assert!(!swap_family_choice(
+            &mut mods,
+            &mut duplicated,
+            "SETUP-X.TP2",
+            "10",
+            "11"
+        ));
+
+        let before_swap = items.clone();
+        assert!(swap_family_choice(
+            &mut mods,
+            &mut items,
+            "SETUP-X.TP2",
+            "10",
+            "11"
+        ));
+        assert_eq!(items[0].component_id, "11");
+        assert_eq!(items[0].component_label, "Speed -> Slow");
+        assert!(!mods[0].components[0].checked);
+        assert!(mods[0].components[1].checked);
+        assert_eq!(mods[0].components[1].selected_order, Some(1));
+
+        reconcile_family_checks(&mut mods, &before_swap);
+        assert!(mods[0].components[0].checked);
+        assert!(!mods[0].components[1].checked);
+        assert!(mods[0].components[2].checked);
+    }
+}
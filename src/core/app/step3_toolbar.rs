@@ -6,6 +6,7 @@ use crate::app::compat_step3_rules::Step3CompatMarker;
 use crate::app::prompt_eval_context::build_prompt_eval_context;
 use crate::app::prompt_popup_text::collect_step3_prompt_toolbar_entries;
 use crate::app::state::{Step2Selection, Step3ItemState, WizardState};
+use crate::app::step3_families::{Step3FamilyIndex, apply_family_markers};
 
 #[derive(Clone)]
 pub(crate) struct Step3ToolbarIssueTarget {
@@ -33,7 +34,7 @@ pub(crate) struct Step3ToolbarSummary {
 pub(crate) fn build_toolbar_summary(state: &WizardState) -> Step3ToolbarSummary {
     let has_first_game_tab = matches!(state.step1.game_install.as_str(), "BGEE" | "EET");
     let has_second_game_tab = matches!(state.step1.game_install.as_str(), "BG2EE" | "EET");
-    let first_game_markers = if has_first_game_tab {
+    let mut first_game_markers = if has_first_game_tab {
         crate::app::compat_step3_rules::collect_step3_compat_markers(
             &state.step1,
             "BGEE",
@@ -43,7 +44,7 @@ pub(crate) fn build_toolbar_summary(state: &WizardState) -> Step3ToolbarSummary
     } else {
         std::collections::HashMap::new()
     };
-    let second_game_markers = if has_second_game_tab {
+    let mut second_game_markers = if has_second_game_tab {
         crate::app::compat_step3_rules::collect_step3_compat_markers(
             &state.step1,
             "BG2EE",
@@ -53,6 +54,20 @@ pub(crate) fn build_toolbar_summary(state: &WizardState) -> Step3ToolbarSummary
     } else {
         std::collections::HashMap::new()
     };
+    if has_first_game_tab {
+        apply_family_markers(
+            &Step3FamilyIndex::cached(&state.step2.bgee_mods),
+            &state.step3.bgee_items,
+            &mut first_game_markers,
+        );
+    }
+    if has_second_game_tab {
+        apply_family_markers(
+            &Step3FamilyIndex::cached(&state.step2.bg2ee_mods),
+            &state.step3.bg2ee_items,
+            &mut second_game_markers,
+        );
+    }
     let prompt_eval = build_prompt_eval_context(state);
     let first_game_prompt_count = if has_first_game_tab {
         collect_step3_prompt_toolbar_entries(&state.step3.bgee_items, &prompt_eval)
@@ -0,0 +1,528 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow, bail};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use crate::app::state::WizardState;
+use crate::app::step5::diagnostics::{DiagnosticsContext, export_diagnostics};
+use crate::app::terminal::EmbeddedTerminal;
+use crate::settings::redesign_fields::DiagnosticsRedaction;
+
+pub const DIAGNOSTICS_BUNDLE_EXTENSION: &str = "bio-diag.zip";
+
+const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_ENTRY: &str = "manifest.json";
+
+const FILES_PREFIX: &str = "files/";
+
+const INSPECT_MAX_ENTRY_BYTES: u64 = 32 * 1024 * 1024;
+
+const TOKEN_PREFIXES: &[&str] = &[
+    "ghp_",
+    "gho_",
+    "ghu_",
+    "ghs_",
+    "ghr_",
+    "github_pat_",
+    "glpat-",
+    "Bearer ",
+];
+
+const MIN_TOKEN_CHARS: usize = 16;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BundledFile {
+    pub path: String,
+
+    pub original_bytes: u64,
+
+    pub stored_bytes: u64,
+
+    pub redactions: u32,
+
+    pub truncated: bool,
+
+    pub omitted: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DiagnosticsBundleManifest {
+    pub format_version: u32,
+
+    pub bio_version: String,
+
+    pub created_at: Option<DateTime<Utc>>,
+
+    pub run_id: String,
+
+    pub redaction: DiagnosticsRedaction,
+
+    pub files: Vec<BundledFile>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiagnosticsBundleReport {
+    pub output: PathBuf,
+
+    pub files: usize,
+
+    pub redactions: u32,
+
+    pub truncated: usize,
+
+    pub omitted: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectedFile {
+    pub path: String,
+
+    pub bytes: u64,
+
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectedBundle {
+    pub source: PathBuf,
+
+    pub manifest: DiagnosticsBundleManifest,
+
+    pub files: Vec<InspectedFile>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Redactor {
+    options: DiagnosticsRedaction,
+    home_dirs: Vec<String>,
+    username: Option<String>,
+}
+
+impl Redactor {
+    #[must_use]
+    pub fn from_env(options: DiagnosticsRedaction) -> Self {
+        let home = ["HOME", "USERPROFILE"]
+            .iter()
+            .filter_map(|key| std::env::var(key).ok())
+            .find(|value| !value.trim().is_empty());
+        let username = ["USER", "USERNAME", "LOGNAME"]
+            .iter()
+            .filter_map(|key| std::env::var(key).ok())
+            .find(|value| !value.trim().is_empty());
+        Self::with_identity(options, home.as_deref(), username.as_deref())
+    }
+
+    #[must_use]
+    pub fn with_identity(
+        options: DiagnosticsRedaction,
+        home: Option<&str>,
+        username: Option<&str>,
+    ) -> Self {
+        let mut home_dirs = Vec::new();
+        if let Some(home) = home.map(|h| h.trim_end_matches(['/', '\\'])) {
+            for variant in [
+                home.to_string(),
+                home.replace('\\', "/"),
+                home.replace('/', "\\"),
+                home.replace('\\', "\\\\"),
+                home.replace('/', "\\\\"),
+            ] {
+                if variant.len() > 1 && !home_dirs.contains(&variant) {
+                    home_dirs.push(variant);
+                }
+            }
+        }
+        home_dirs.sort_by_key(|dir| std::cmp::Reverse(dir.len()));
+        Self {
+            options,
+            home_dirs,
+            username: username
+                .map(str::trim)
+                .filter(|name| name.len() >= 3)
+                .map(str::to_string),
+        }
+    }
+
+    #[must_use]
+    pub fn redact(&self, text: &str) -> (String, u32) {
+        let mut out = text.to_string();
+        let mut count = 0;
+        if self.options.user_paths {
+            for home in &self.home_dirs {
+                let (next, n) = replace_ascii_ci(&out, home, "<HOME>", false);
+                out = next;
+                count += n;
+            }
+        }
+        if self.options.usernames
+            && let Some(name) = self.username.as_deref()
+        {
+            let (next, n) = replace_ascii_ci(&out, name, "<USER>", true);
+            out = next;
+            count += n;
+        }
+        if self.options.tokens {
+            let (next, n) = redact_tokens(&out);
+            out = next;
+            count += n;
+        }
+        (out, count)
+    }
+
+    fn max_file_bytes(&self) -> Option<usize> {
+        (self.options.max_file_kib > 0)
+            .then(|| usize::try_from(self.options.max_file_kib).unwrap_or(usize::MAX) * 1024)
+    }
+}
+
+const fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+fn replace_ascii_ci(text: &str, needle: &str, with: &str, whole_word: bool) -> (String, u32) {
+    if needle.is_empty() {
+        return (text.to_string(), 0);
+    }
+    let haystack = text.to_ascii_lowercase();
+    let needle_lower = needle.to_ascii_lowercase();
+    let bytes = text.as_bytes();
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    let mut count = 0;
+    for (start, _) in haystack.match_indices(&needle_lower) {
+        let end = start + needle.len();
+        if start < last {
+            continue;
+        }
+        if whole_word
+            && (start > 0 && is_word_byte(bytes[start - 1])
+                || end < bytes.len() && is_word_byte(bytes[end]))
+        {
+            continue;
+        }
+        out.push_str(&text[last..start]);
+        out.push_str(with);
+        last = end;
+        count += 1;
+    }
+    out.push_str(&text[last..]);
+    (out, count)
+}
+
+fn redact_tokens(text: &str) -> (String, u32) {
+    let mut out = text.to_string();
+    let mut count = 0;
+    for prefix in TOKEN_PREFIXES {
+        let mut next = String::with_capacity(out.len());
+        let mut rest = out.as_str();
+        while let Some(pos) = rest.find(prefix) {
+            let after = &rest[pos + prefix.len()..];
+            let token_len = after
+                .bytes()
+                .take_while(|b| is_word_byte(*b) || matches!(b, b'-' | b'.'))
+                .count();
+            next.push_str(&rest[..pos + prefix.len()]);
+            if token_len >= MIN_TOKEN_CHARS {
+                next.push_str("<TOKEN>");
+                count += 1;
+            } else {
+                next.push_str(&after[..token_len]);
+            }
+            rest = &after[token_len..];
+        }
+        next.push_str(rest);
+        out = next;
+    }
+    (out, count)
+}
+
+fn truncate_middle(text: &str, max_bytes: usize) -> (String, bool) {
+    if text.len() <= max_bytes {
+        return (text.to_string(), false);
+    }
+    let half = max_bytes / 2;
+    let mut head_end = half;
+    while !text.is_char_boundary(head_end) {
+        head_end -= 1;
+    }
+    let mut tail_start = text.len() - half;
+    while !text.is_char_boundary(tail_start) {
+        tail_start += 1;
+    }
+    let dropped = tail_start - head_end;
+    (
+        format!(
+            "{}\n\n[... {dropped} bytes truncated by BIO diagnostics bundle ...]\n\n{}",
+            &text[..head_end],
+            &text[tail_start..]
+        ),
+        true,
+    )
+}
+
+fn prepare_file(redactor: &Redactor, raw: Vec<u8>) -> (Option<Vec<u8>>, u32, bool) {
+    let limit = redactor.max_file_bytes();
+    match String::from_utf8(raw) {
+        Ok(text) => {
+            let (text, redactions) = redactor.redact(&text);
+            let (text, truncated) = match limit {
+                Some(limit) => truncate_middle(&text, limit),
+                None => (text, false),
+            };
+            (Some(text.into_bytes()), redactions, truncated)
+        }
+        Err(err) => {
+            let raw = err.into_bytes();
+            if limit.is_some_and(|limit| raw.len() > limit) {
+                (None, 0, false)
+            } else {
+                (Some(raw), 0, false)
+            }
+        }
+    }
+}
+
+pub fn export_run_bundle(
+    run_dir: &Path,
+    run_id: &str,
+    output: &Path,
+    redactor: &Redactor,
+) -> Result<DiagnosticsBundleReport> {
+    let mut entries: Vec<(String, PathBuf)> = WalkDir::new(run_dir)
+        .follow_links(false)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(std::result::Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let rel = entry.path().strip_prefix(run_dir).ok()?;
+            let rel = rel.to_string_lossy().replace('\\', "/");
+            Some((rel, entry.path().to_path_buf()))
+        })
+        .collect();
+    if entries.is_empty() {
+        bail!("{} has no diagnostics files to bundle", run_dir.display());
+    }
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    if let Some(parent) = output.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = output.with_extension("zip.tmp");
+    let written = write_bundle(&tmp, run_id, &entries, redactor).and_then(|report| {
+        std::fs::rename(&tmp, output)?;
+        Ok(report)
+    });
+    if written.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    let mut report = written?;
+    report.output = output.to_path_buf();
+    Ok(report)
+}
+
+fn write_bundle(
+    path: &Path,
+    run_id: &str,
+    entries: &[(String, PathBuf)],
+    redactor: &Redactor,
+) -> Result<DiagnosticsBundleReport> {
+    let mut zip = ZipWriter::new(File::create(path)?);
+    let options = SimpleFileOptions::default();
+    let mut manifest = DiagnosticsBundleManifest {
+        format_version: BUNDLE_FORMAT_VERSION,
+        bio_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: Some(Utc::now()),
+        run_id: redactor.redact(run_id).0,
+        redaction: redactor.options,
+        files: Vec::with_capacity(entries.len()),
+    };
+    let mut report = DiagnosticsBundleReport::default();
+    for (rel, source) in entries {
+        let raw = std::fs::read(source).with_context(|| format!("read {}", source.display()))?;
+        let original_bytes = raw.len() as u64;
+        let (stored, redactions, truncated) = prepare_file(redactor, raw);
+        let rel = redactor.redact(rel).0;
+        let mut file = BundledFile {
+            path: rel.clone(),
+            original_bytes,
+            redactions,
+            truncated,
+            ..BundledFile::default()
+        };
+        match stored {
+            Some(bytes) => {
+                zip.start_file(format!("{FILES_PREFIX}{rel}"), options)?;
+                zip.write_all(&bytes)?;
+                file.stored_bytes = bytes.len() as u64;
+                report.files += 1;
+            }
+            None => {
+                file.omitted = true;
+                report.omitted += 1;
+            }
+        }
+        report.redactions += redactions;
+        report.truncated += usize::from(truncated);
+        manifest.files.push(file);
+    }
+    zip.start_file(MANIFEST_ENTRY, options)?;
+    zip.write_all(serde_json::to_string_pretty(&manifest)?.as_bytes())?;
+    zip.finish()?;
+    Ok(report)
+}
+
+pub fn export_diagnostics_bundle(
+    state: &WizardState,
+    terminal: Option<&EmbeddedTerminal>,
+    ctx: &DiagnosticsContext,
+    redaction: DiagnosticsRedaction,
+    output: &Path,
+) -> Result<DiagnosticsBundleReport> {
+    let diag_path = export_diagnostics(state, terminal, ctx)?;
+    let run_dir = diag_path
+        .parent()
+        .ok_or_else(|| anyhow!("diagnostics were written outside a run folder"))?;
+    let run_id = run_dir
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    let run_id = run_id.strip_prefix("run_").unwrap_or(&run_id).to_string();
+    export_run_bundle(run_dir, &run_id, output, &Redactor::from_env(redaction))
+}
+
+pub fn inspect_bundle(path: &Path) -> Result<InspectedBundle> {
+    let file = File::open(path).with_context(|| format!("open {}", path.display()))?;
+    let mut zip = ZipArchive::new(file)
+        .with_context(|| format!("{} is not a BIO diagnostics bundle", path.display()))?;
+    let manifest_text = read_entry(&mut zip, MANIFEST_ENTRY)?
+        .ok_or_else(|| anyhow!("bundle has no {MANIFEST_ENTRY}"))?;
+    let manifest: DiagnosticsBundleManifest = serde_json::from_slice(&manifest_text)
+        .with_context(|| format!("{MANIFEST_ENTRY} is not valid"))?;
+    if manifest.format_version > BUNDLE_FORMAT_VERSION {
+        bail!(
+            "bundle was written by a newer BIO (format {}); this build reads up to format \
+             {BUNDLE_FORMAT_VERSION}",
+            manifest.format_version
+        );
+    }
+    let mut files = Vec::new();
+    for entry in manifest.files.iter().filter(|f| !f.omitted) {
+        let Some(bytes) = read_entry(&mut zip, &format!("{FILES_PREFIX}{}", entry.path))? else {
+            continue;
+        };
+        files.push(InspectedFile {
+            path: entry.path.clone(),
+            bytes: bytes.len() as u64,
+            text: String::from_utf8(bytes).ok(),
+        });
+    }
+    Ok(InspectedBundle {
+        source: path.to_path_buf(),
+        manifest,
+        files,
+    })
+}
+
+fn read_entry(zip: &mut ZipArchive<File>, name: &str) -> Result<Option<Vec<u8>>> {
+    let entry = match zip.by_name(name) {
+        Ok(entry) => entry,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+        Err(err) => return Err(anyhow!("bundle entry {name}: {err}")),
+    };
+    let mut bytes = Vec::new();
+    entry
+        .take(INSPECT_MAX_ENTRY_BYTES)
+        .read_to_end(&mut bytes)
+        .with_context(|| format!("bundle entry {name}"))?;
+    Ok(Some(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    fn redactor(max_file_kib: u32) -> Redactor {
+        Redactor::with_identity(
+            DiagnosticsRedaction {
+                max_file_kib,
+                ..DiagnosticsRedaction::default()
+            },
+            Some("/home/alice"),
+            Some("alice"),
+        )
+    }
+
+    #[test]
+    fn redacts_home_paths_usernames_and_tokens() {
+        let text = "game=/home/alice/Games/BG2EE\nuser=alice malice\n\
+                    auth=Bearer abcdefghijklmnopqrstuvwxyz ghp_0123456789abcdefABCDEF short=ghp_x";
+        let (out, count) = redactor(0).redact(text);
+        assert_eq!(
+            out,
+            "game=<HOME>/Games/BG2EE\nuser=<USER> malice\n\
+             auth=Bearer <TOKEN> ghp_<TOKEN> short=ghp_x"
+        );
+        assert_eq!(count, 4);
+
+        let windows = Redactor::with_identity(
+            DiagnosticsRedaction::default(),
+            Some("C:\\Users\\Alice"),
+            Some("Alice"),
+        );
+        let (out, _) =
+            windows.redact("{\"path\":\"C:\\\\Users\\\\Alice\\\\bio\"} c:/users/alice/x");
+        assert_eq!(out, "{\"path\":\"<HOME>\\\\bio\"} <HOME>/x");
+    }
+
+    #[test]
+    fn truncation_keeps_head_and_tail_on_char_boundaries() {
+        let text = format!("{}é{}", "a".repeat(10), "b".repeat(10));
+        let (out, truncated) = truncate_middle(&text, 8);
+        assert!(truncated);
+        assert!(out.starts_with("aaaa\n"));
+        assert!(out.ends_with("\nbbbb"));
+        assert_eq!(truncate_middle("short", 8), ("short".to_string(), false));
+    }
+
+    #[test]
+    fn export_then_inspect_round_trips_redacted_files() {
+        let root = temp_dir("diag_bundle");
+        let run_dir = root.join("run_1");
+        std::fs::create_dir_all(run_dir.join("logs")).unwrap();
+        std::fs::write(run_dir.join("bio_diag.txt"), "dest=/home/alice/bg2").unwrap();
+        std::fs::write(run_dir.join("logs/console.log"), "x".repeat(4096)).unwrap();
+        std::fs::write(run_dir.join("blob.bin"), vec![0xff_u8; 4096]).unwrap();
+        let output = root.join(format!("out.{DIAGNOSTICS_BUNDLE_EXTENSION}"));
+
+        let report = export_run_bundle(&run_dir, "1", &output, &redactor(1)).expect("export");
+        assert_eq!((report.files, report.omitted, report.truncated), (2, 1, 1));
+
+        let bundle = inspect_bundle(&output).expect("inspect");
+        assert_eq!(bundle.manifest.run_id, "1");
+        let paths: Vec<_> = bundle.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["bio_diag.txt", "logs/console.log"]);
+        assert_eq!(bundle.files[0].text.as_deref(), Some("dest=<HOME>/bg2"));
+        assert!(
+            bundle
+                .manifest
+                .files
+                .iter()
+                .any(|f| f.path == "blob.bin" && f.omitted)
+        );
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -4,6 +4,7 @@
 pub mod auto_answer;
 pub mod command_config;
 pub mod diagnostics;
+pub mod diagnostics_bundle;
 pub mod install_flow;
 pub mod log_files;
 pub mod prompt_memory;
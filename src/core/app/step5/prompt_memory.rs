@@ -17,7 +17,7 @@ mod types;
 use std::path::Path;
 
 pub use mutate::PromptAnswerContext;
-pub use types::PromptAnswerEntry;
+pub use types::{PromptAnswerEntry, PromptMergeReport};
 
 #[must_use]
 pub fn get_answer(prompt_key: &str) -> Option<String> {
@@ -103,3 +103,13 @@ pub fn export_json(path: &Path) -> std::io::Result<usize> {
 pub fn import_json(path: &Path) -> std::io::Result<usize> {
     io_ops::import_json(path)
 }
+
+pub fn export_string_for_components(
+    component_keys: &std::collections::HashSet<String>,
+) -> std::io::Result<String> {
+    io_ops::export_string_for_components(component_keys)
+}
+
+pub fn merge_json_str(content: &str) -> std::io::Result<PromptMergeReport> {
+    io_ops::merge_str(content)
+}
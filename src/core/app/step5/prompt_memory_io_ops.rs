@@ -1,10 +1,12 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Copyright (c) 2026 Born2BSalty
 
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
 use super::storage;
+use super::types::PromptMergeReport;
 
 pub(super) fn export_json(path: &Path) -> std::io::Result<usize> {
     let guard = storage::memory()
@@ -29,3 +31,48 @@ pub(super) fn import_json(path: &Path) -> std::io::Result<usize> {
     storage::save_to_disk(&guard)?;
     Ok(guard.len())
 }
+
+pub(super) fn export_string_for_components(
+    component_keys: &HashSet<String>,
+) -> std::io::Result<String> {
+    let guard = storage::memory()
+        .lock()
+        .map_err(|_| std::io::Error::other("prompt memory lock poisoned"))?;
+    let scoped: HashMap<_, _> = guard
+        .iter()
+        .filter(|(_, entry)| component_keys.contains(entry.component_key.trim()))
+        .map(|(key, entry)| (key.clone(), entry.clone()))
+        .collect();
+    storage::serialize_map(&scoped)
+}
+
+pub(super) fn merge_str(content: &str) -> std::io::Result<PromptMergeReport> {
+    let imported = storage::parse_content(content)
+        .ok_or_else(|| std::io::Error::other("invalid prompt answers json"))?;
+    let mut guard = storage::memory()
+        .lock()
+        .map_err(|_| std::io::Error::other("prompt memory lock poisoned"))?;
+    let mut report = PromptMergeReport::default();
+    for (key, entry) in imported {
+        match guard.entry(key) {
+            std::collections::hash_map::Entry::Vacant(slot) => {
+                slot.insert(entry);
+                report.added += 1;
+            }
+            std::collections::hash_map::Entry::Occupied(slot) => {
+                if slot.get().answer.trim() != entry.answer.trim() {
+                    let name = if entry.alias.trim().is_empty() {
+                        slot.key().clone()
+                    } else {
+                        entry.alias.trim().to_string()
+                    };
+                    report.conflicts.push(name);
+                }
+            }
+        }
+    }
+    if report.added > 0 {
+        storage::save_to_disk(&guard)?;
+    }
+    Ok(report)
+}
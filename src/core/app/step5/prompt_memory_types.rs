@@ -70,6 +70,12 @@ pub struct PromptAnswerEntry {
     pub hit_count: u64,
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PromptMergeReport {
+    pub added: usize,
+    pub conflicts: Vec<String>,
+}
+
 pub(super) const fn default_enabled() -> bool {
     false
 }
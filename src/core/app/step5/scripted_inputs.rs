@@ -14,11 +14,17 @@ use super::prompt_memory;
 
 #[must_use]
 pub fn load_from_step1(step1: &Step1State) -> HashMap<String, Vec<String>> {
+    let mut out = load_log_inputs(step1);
+    merge_from_prompt_memory(&mut out);
+    out
+}
+
+#[must_use]
+pub fn load_log_inputs(step1: &Step1State) -> HashMap<String, Vec<String>> {
     let mut out: HashMap<String, Vec<String>> = HashMap::new();
     for path in source_log_paths(step1) {
         merge_from_path(&mut out, Path::new(&path));
     }
-    merge_from_prompt_memory(&mut out);
     out
 }
 
@@ -67,6 +67,11 @@ pub(crate) fn process_exit_event(step5: &mut Step5State, term: &mut EmbeddedTerm
         step5.last_runtime_secs = Some(now.saturating_sub(start));
     }
     step5.last_install_failed = term.likely_failure_visible();
+    step5.failure_summary = if step5.last_install_failed {
+        term.failure_summary()
+    } else {
+        Vec::new()
+    };
     step5.last_exit_code = finished_exit;
     step5.install_running = false;
     step5.cancel_requested = false;
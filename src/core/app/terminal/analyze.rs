@@ -165,6 +165,8 @@ mod model {
     }
 }
 
+#[path = "analyze_failures.rs"]
+mod failures;
 #[path = "analyze_prompt_block.rs"]
 mod prompt_block;
 mod prompt_detect {
@@ -307,6 +309,7 @@ mod utils {
 
 pub use model::PromptInfo;
 
+pub(in crate::app::terminal) use failures::failure_summary;
 pub(in crate::app::terminal) use filters::extract_error_block;
 pub(in crate::app::terminal) use filters::important_line;
 pub(in crate::app::terminal) use filters::installed_line;
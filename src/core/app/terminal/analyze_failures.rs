@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use crate::install::failure_class::{ComponentFailure, classify_failure, failure_label};
+
+use super::super::scripted_inputs::parse_component_key_from_installer_line;
+use super::filters::{extract_error_block, likely_failure_visible};
+
+struct Segment<'a> {
+    key: String,
+    label: String,
+    lines: Vec<&'a str>,
+}
+
+pub(in crate::app::terminal) fn failure_summary(output: &str) -> Vec<ComponentFailure> {
+    let mut segments = vec![Segment {
+        key: String::new(),
+        label: "(before first component)".to_string(),
+        lines: Vec::new(),
+    }];
+    for line in output.lines() {
+        if line.to_ascii_uppercase().contains("INSTALLING MOD")
+            && let Some(info) = parse_component_key_from_installer_line(line)
+        {
+            let label = failure_label(
+                &info.tp_file,
+                &info.component,
+                info.component_name.as_deref(),
+            );
+            segments.push(Segment {
+                key: info.key,
+                label,
+                lines: Vec::new(),
+            });
+        }
+        if let Some(segment) = segments.last_mut() {
+            segment.lines.push(line);
+        }
+    }
+    segments
+        .into_iter()
+        .filter_map(|segment| {
+            let text = segment.lines.join("\n");
+            if !likely_failure_visible(&text) {
+                return None;
+            }
+            Some(ComponentFailure {
+                component_key: segment.key,
+                label: segment.label,
+                kind: classify_failure(&text),
+                attempts: 1,
+                error_block: extract_error_block(&text),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::failure_summary;
+    use crate::install::failure_class::FailureKind;
+
+    fn start(tp: &str, id: &str, name: &str) -> String {
+        format!(
+            "[2026-06-22T00:00:00Z INFO  mod_installer::installers] Installing mod component \
+             Component {{ tp_file: \"{tp}\", component: \"{id}\", component_name: \"{name}\" }}"
+        )
+    }
+
+    #[test]
+    fn failures_are_split_per_component_and_classified() {
+        let output = [
+            start("SETUP-A.TP2", "0", "Core"),
+            "SUCCESSFULLY INSTALLED Core".to_string(),
+            start("SETUP-B.TP2", "10", "Tweaks"),
+            "ERROR: REQUIRE_PREDICATE failed".to_string(),
+            "NOT INSTALLED DUE TO ERRORS Tweaks".to_string(),
+            start("SETUP-C.TP2", "2", "Items"),
+            "[C/items.tp2] PARSE ERROR at line 4".to_string(),
+        ]
+        .join("\n");
+        let failures = failure_summary(&output);
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].component_key, "SETUP-B.TP2#10");
+        assert_eq!(failures[0].label, "SETUP-B.TP2 #10 Tweaks");
+        assert_eq!(failures[0].kind, FailureKind::RequireFailed);
+        assert!(
+            failures[0]
+                .error_block
+                .contains("NOT INSTALLED DUE TO ERRORS")
+        );
+        assert!(!failures[0].error_block.contains("PARSE ERROR"));
+        assert_eq!(failures[1].kind, FailureKind::ParseError);
+    }
+
+    #[test]
+    fn clean_output_has_no_failures() {
+        let output = format!(
+            "{}\nSUCCESSFULLY INSTALLED Core",
+            start("A.TP2", "0", "Core")
+        );
+        assert!(failure_summary(&output).is_empty());
+    }
+}
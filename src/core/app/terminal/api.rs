@@ -42,6 +42,10 @@ impl EmbeddedTerminal {
         self.events.has_new_data = true;
     }
 
+    pub fn set_working_dir(&mut self, dir: Option<std::path::PathBuf>) {
+        self.child_cwd = dir;
+    }
+
     pub fn shutdown(&mut self) {
         input::shutdown(self);
     }
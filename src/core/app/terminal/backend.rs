@@ -2,6 +2,7 @@
 // Copyright (c) 2026 Born2BSalty
 
 use std::io::Read;
+use std::path::Path;
 use std::process::{Child, ChildStdin, Command, Stdio};
 use std::sync::mpsc::{self, Receiver, Sender};
 use std::thread;
@@ -22,8 +23,12 @@ pub(super) fn spawn_process(
     program: &str,
     args: &[String],
     extra_env: &[(String, String)],
+    cwd: Option<&Path>,
 ) -> Result<SpawnedProcess> {
     let mut cmd = Command::new(program);
+    if let Some(dir) = cwd {
+        cmd.current_dir(dir);
+    }
     cmd.args(args)
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
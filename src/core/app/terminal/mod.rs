@@ -51,6 +51,7 @@ pub struct EmbeddedTerminal {
     boundary_event_count: u64,
     boundary_scan_tail: String,
     child_env: Vec<(String, String)>,
+    child_cwd: Option<std::path::PathBuf>,
     scripted_inputs_by_component: HashMap<String, VecDeque<String>>,
     current_component_key: Option<String>,
     current_component_tp2: Option<String>,
@@ -82,6 +83,7 @@ impl EmbeddedTerminal {
             boundary_event_count: 0,
             boundary_scan_tail: String::new(),
             child_env: Vec::new(),
+            child_cwd: None,
             scripted_inputs_by_component: HashMap::new(),
             current_component_key: None,
             current_component_tp2: None,
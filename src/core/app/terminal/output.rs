@@ -3,6 +3,7 @@
 
 mod accessors {
     use super::super::{EmbeddedTerminal, PromptInfo, analyze};
+    use crate::install::failure_class::ComponentFailure;
 
     impl EmbeddedTerminal {
         #[must_use]
@@ -35,6 +36,11 @@ mod accessors {
             analyze::extract_error_block(&self.output_buffer)
         }
 
+        #[must_use]
+        pub fn failure_summary(&self) -> Vec<ComponentFailure> {
+            analyze::failure_summary(&self.output_buffer)
+        }
+
         #[must_use]
         pub fn console_excerpt(&self, max_chars: usize) -> String {
             if self.output_buffer.chars().count() <= max_chars {
@@ -108,7 +108,8 @@ mod lifecycle {
                 self.bio_debug_log_file = None;
             }
 
-            let spawned = backend::spawn_process(program, args, &self.child_env)?;
+            let spawned =
+                backend::spawn_process(program, args, &self.child_env, self.child_cwd.as_deref())?;
             self.child = Some(spawned.child);
             self.stdin = spawned.stdin;
             self.output_rx = Some(spawned.rx);
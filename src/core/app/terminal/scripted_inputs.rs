@@ -7,11 +7,11 @@ use crate::platform_defaults::{compose_component_key, normalize_tp2_filename};
 
 use super::EmbeddedTerminal;
 
-struct ComponentContext {
-    key: String,
-    tp_file: String,
-    component: String,
-    component_name: Option<String>,
+pub(super) struct ComponentContext {
+    pub(super) key: String,
+    pub(super) tp_file: String,
+    pub(super) component: String,
+    pub(super) component_name: Option<String>,
 }
 
 pub(super) fn set_scripted_inputs(
@@ -63,7 +63,7 @@ pub(super) fn peek_next_scripted_input_for_current(term: &EmbeddedTerminal) -> O
     queue.front().map(String::as_str)
 }
 
-fn parse_component_key_from_installer_line(line: &str) -> Option<ComponentContext> {
+pub(super) fn parse_component_key_from_installer_line(line: &str) -> Option<ComponentContext> {
     let upper = line.to_ascii_uppercase();
     let is_install_component_line = upper.contains("INSTALLING MOD COMPONENT")
         || upper.contains("INSTALLED MOD COMPONENT")
@@ -0,0 +1,464 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::HashMap;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::app::tra_resolver::{
+    FileStamp, ModPaths, file_stamp, load_tra_files, load_tra_statement_files,
+    mod_paths_for_language, tra_table,
+};
+use crate::parser::lapdu::source_tokens::{self, SourceToken};
+
+#[path = "tp2_browser_tokens.rs"]
+pub(crate) mod tokens;
+
+pub use tokens::{Tp2Span, Tp2TokenKind};
+
+const MAX_INCLUDE_DEPTH: usize = 3;
+const MAX_SOURCE_FILES: usize = 16;
+const MAX_FILE_LINES: usize = 4_000;
+const MAX_CACHED_SOURCES: usize = 32;
+const COMPONENT_REF_KEYWORDS: &[(&str, bool)] = &[
+    ("REQUIRE_COMPONENT", false),
+    ("FORBID_COMPONENT", true),
+    ("MOD_IS_INSTALLED", false),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tp2ComponentRef {
+    pub tp2: String,
+    pub component_id: u32,
+    pub forbid: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tp2TraText {
+    pub reference: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tp2SourceLine {
+    pub number: usize,
+    pub spans: Vec<Tp2Span>,
+    pub tra: Vec<Tp2TraText>,
+    pub component_refs: Vec<Tp2ComponentRef>,
+    pub include: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tp2SourceFile {
+    pub path: PathBuf,
+    pub label: String,
+    pub lines: Vec<Tp2SourceLine>,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tp2SourceView {
+    pub files: Vec<Tp2SourceFile>,
+    pub tra_files: Vec<PathBuf>,
+}
+
+impl Tp2SourceView {
+    #[must_use]
+    pub fn plain_text(&self, file: usize) -> String {
+        self.files
+            .get(file)
+            .map(|file| {
+                file.lines
+                    .iter()
+                    .map(|line| {
+                        line.spans
+                            .iter()
+                            .map(|s| s.text.as_str())
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Tp2SourceState {
+    Loading,
+    Ready(Arc<Tp2SourceView>),
+    #[default]
+    Unavailable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct SourceKey {
+    tp2_path: String,
+    component_id: String,
+    language: Option<usize>,
+    stamp: FileStamp,
+}
+
+struct CachedSource {
+    state: Tp2SourceState,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct SourceCache {
+    entries: HashMap<SourceKey, CachedSource>,
+    clock: u64,
+}
+
+impl SourceCache {
+    fn touch(&mut self, key: &SourceKey) -> Option<Tp2SourceState> {
+        self.clock += 1;
+        let clock = self.clock;
+        self.entries.get_mut(key).map(|cached| {
+            cached.last_used = clock;
+            cached.state.clone()
+        })
+    }
+
+    fn store(&mut self, key: SourceKey, state: Tp2SourceState) {
+        if !self.entries.contains_key(&key) && self.entries.len() >= MAX_CACHED_SOURCES {
+            let oldest = self
+                .entries
+                .iter()
+                .filter(|(_, cached)| cached.state != Tp2SourceState::Loading)
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.clock += 1;
+        let last_used = self.clock;
+        self.entries.insert(key, CachedSource { state, last_used });
+    }
+}
+
+static SOURCE_CACHE: OnceLock<Mutex<SourceCache>> = OnceLock::new();
+
+pub fn load_component_source(
+    tp2_path: &str,
+    component_id: &str,
+    language: Option<usize>,
+) -> Tp2SourceState {
+    if tp2_path.trim().is_empty() || component_id.trim().is_empty() {
+        return Tp2SourceState::Unavailable;
+    }
+    let Some(stamp) = file_stamp(Path::new(tp2_path)) else {
+        return Tp2SourceState::Unavailable;
+    };
+    let key = SourceKey {
+        tp2_path: tp2_path.to_string(),
+        component_id: component_id.to_string(),
+        language,
+        stamp,
+    };
+    let cache = SOURCE_CACHE.get_or_init(|| Mutex::new(SourceCache::default()));
+    let Ok(mut guard) = cache.lock() else {
+        return Tp2SourceState::Unavailable;
+    };
+    if let Some(state) = guard.touch(&key) {
+        return state;
+    }
+    guard.store(key.clone(), Tp2SourceState::Loading);
+    drop(guard);
+
+    let job_key = key.clone();
+    let spawned = std::thread::Builder::new()
+        .name("bio-tp2-source".to_string())
+        .spawn(move || {
+            let state = build_component_source(
+                Path::new(&job_key.tp2_path),
+                &job_key.component_id,
+                job_key.language,
+            )
+            .map_or(Tp2SourceState::Unavailable, |view| {
+                Tp2SourceState::Ready(Arc::new(view))
+            });
+            if let Ok(mut cache) = cache.lock() {
+                cache.store(job_key, state);
+            }
+        });
+    if let Err(err) = spawned {
+        tracing::warn!(
+            target = "tp2_browser",
+            "could not start TP2 source loader: {err}"
+        );
+        if let Ok(mut cache) = cache.lock() {
+            cache.store(key, Tp2SourceState::Unavailable);
+        }
+        return Tp2SourceState::Unavailable;
+    }
+    Tp2SourceState::Loading
+}
+
+fn build_component_source(
+    tp2_path: &Path,
+    component_id: &str,
+    language: Option<usize>,
+) -> Option<Tp2SourceView> {
+    let text = read_lossy(tp2_path)?;
+    let tokens = source_tokens::lex(&text);
+    let (start, end) = source_tokens::component_line_range(&text, &tokens, component_id)?;
+    let ctx = mod_paths_for_language(tp2_path, &text, &tokens, language);
+
+    let table = tra_table(tp2_path, language);
+    let mut tra_map = table.entries().clone();
+    let mut tra_files = table.files.clone();
+    let block_tokens = source_tokens::tokens_in_lines(&tokens, start, end);
+    let block_files = load_tra_statement_files(&text, block_tokens, &ctx);
+    tra_map.extend(load_tra_files(&block_files));
+    tra_files.extend(
+        block_files
+            .into_iter()
+            .filter(|path| !table.files.contains(path)),
+    );
+
+    let mut builder = SourceBuilder {
+        ctx,
+        tra_map,
+        files: Vec::new(),
+    };
+    let label = tp2_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    builder.push_file(
+        tp2_path.to_path_buf(),
+        label,
+        &SourceText {
+            text: &text,
+            tokens: &tokens,
+        },
+        start..end,
+        0,
+    );
+    Some(Tp2SourceView {
+        files: builder.files,
+        tra_files,
+    })
+}
+
+struct SourceText<'a> {
+    text: &'a str,
+    tokens: &'a [SourceToken],
+}
+
+impl SourceText<'_> {
+    fn keyword_lines(&self, keywords: &[&str]) -> HashMap<usize, Vec<Vec<&str>>> {
+        let mut out = HashMap::<usize, Vec<Vec<&str>>>::new();
+        for keyword in keywords {
+            for (line, args) in source_tokens::keyword_arguments(self.text, self.tokens, keyword) {
+                if let Ok(line) = usize::try_from(line) {
+                    out.entry(line).or_default().push(args);
+                }
+            }
+        }
+        out
+    }
+}
+
+struct SourceBuilder {
+    ctx: ModPaths,
+    tra_map: HashMap<String, String>,
+    files: Vec<Tp2SourceFile>,
+}
+
+impl SourceBuilder {
+    fn push_file(
+        &mut self,
+        path: PathBuf,
+        label: String,
+        source: &SourceText<'_>,
+        lines: Range<usize>,
+        depth: usize,
+    ) -> usize {
+        let index = self.files.len();
+        self.files.push(Tp2SourceFile {
+            path,
+            label,
+            lines: Vec::new(),
+            truncated: lines.len() > MAX_FILE_LINES,
+        });
+
+        let highlighted = tokens::highlight(source.text, source.tokens);
+        let includes = source.keyword_lines(tokens::INCLUDE_KEYWORDS);
+        let refs: Vec<_> = COMPONENT_REF_KEYWORDS
+            .iter()
+            .map(|(keyword, forbid)| (source.keyword_lines(&[keyword]), *forbid))
+            .collect();
+        let mut out = Vec::with_capacity(lines.len().min(MAX_FILE_LINES));
+        for line in lines.take(MAX_FILE_LINES) {
+            let spans = highlighted.get(line).cloned().unwrap_or_default();
+            let number = line + 1;
+            let tra = spans
+                .iter()
+                .filter(|span| span.kind == Tp2TokenKind::TraRef)
+                .filter_map(|span| {
+                    self.tra_map.get(&span.text).map(|text| Tp2TraText {
+                        reference: span.text.clone(),
+                        text: text.clone(),
+                    })
+                })
+                .collect();
+            let include = includes
+                .get(&number)
+                .and_then(|found| found.iter().find_map(|args| args.first()))
+                .filter(|_| depth < MAX_INCLUDE_DEPTH)
+                .and_then(|raw| self.follow_include(raw, depth));
+            let component_refs = refs
+                .iter()
+                .flat_map(|(found, forbid)| {
+                    found
+                        .get(&number)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|args| component_ref(args, *forbid))
+                })
+                .collect();
+            out.push(Tp2SourceLine {
+                number,
+                spans,
+                tra,
+                component_refs,
+                include,
+            });
+        }
+        self.files[index].lines = out;
+        index
+    }
+
+    fn follow_include(&mut self, raw: &str, depth: usize) -> Option<usize> {
+        let path = self.ctx.resolve(raw)?;
+        if let Some(existing) = self.files.iter().position(|file| file.path == path) {
+            return Some(existing);
+        }
+        if self.files.len() >= MAX_SOURCE_FILES {
+            return None;
+        }
+        let text = read_lossy(&path)?;
+        let tokens = source_tokens::lex(&text);
+        let label = path
+            .strip_prefix(&self.ctx.mods_root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let line_count = text.lines().count();
+        Some(self.push_file(
+            path,
+            label,
+            &SourceText {
+                text: &text,
+                tokens: &tokens,
+            },
+            0..line_count,
+            depth + 1,
+        ))
+    }
+}
+
+fn component_ref(args: &[&str], forbid: bool) -> Option<Tp2ComponentRef> {
+    let [tp2, component, ..] = args else {
+        return None;
+    };
+    let tp2 = tp2.trim();
+    if !tp2.to_ascii_lowercase().ends_with(".tp2") {
+        return None;
+    }
+    let component_id = component.trim().parse::<u32>().ok()?;
+    Some(Tp2ComponentRef {
+        tp2: tp2.to_string(),
+        component_id,
+        forbid,
+    })
+}
+
+fn read_lossy(path: &Path) -> Option<String> {
+    fs::read(path)
+        .ok()
+        .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    fn wait_for_source(tp2: &str, component_id: &str) -> Arc<Tp2SourceView> {
+        for _ in 0..500 {
+            match load_component_source(tp2, component_id, None) {
+                Tp2SourceState::Ready(view) => return view,
+                Tp2SourceState::Loading => std::thread::sleep(std::time::Duration::from_millis(10)),
+                Tp2SourceState::Unavailable => panic!("source unavailable"),
+            }
+        }
+        panic!("source never loaded");
+    }
+
+    #[test]
+    fn builds_component_view_with_includes_tra_and_refs() {
+        let root = temp_dir("tp2_browser");
+        let mod_dir = root.join("mymod");
+        fs::create_dir_all(mod_dir.join("lang/english")).expect("lang dir");
+        fs::create_dir_all(mod_dir.join("lib")).expect("lib dir");
+        fs::write(
+            mod_dir.join("lang/english/setup.tra"),
+            "@1 = ~Core~\n@2 = ~Second~\n@3 = ~Prompt\ntext~\n",
+        )
+        .expect("tra");
+        fs::write(mod_dir.join("lib/Helpers.tpa"), "PRINT @3\n").expect("include");
+        let tp2 = mod_dir.join("setup-mymod.tp2");
+        fs::write(
+            &tp2,
+            "BACKUP ~mymod/backup~\nLANGUAGE ~English~ ~english~ ~%MOD_FOLDER%/lang/english/setup.tra~\n\
+             BEGIN @1 DESIGNATED 0\n\n// second\nBEGIN @2 DESIGNATED 10\n\
+             REQUIRE_COMPONENT ~setup-other.tp2~ ~5~ @2\nINCLUDE ~%MOD_FOLDER%/lib/helpers.tpa~\n",
+        )
+        .expect("tp2");
+
+        let view = wait_for_source(&tp2.to_string_lossy(), "10");
+        assert_eq!(view.files.len(), 2);
+        let main = &view.files[0];
+        assert_eq!(main.lines[0].number, 5);
+        assert_eq!(main.lines[1].number, 6);
+        assert_eq!(main.lines[1].tra[0].text, "Second");
+        assert_eq!(
+            main.lines[2].component_refs,
+            vec![Tp2ComponentRef {
+                tp2: "setup-other.tp2".to_string(),
+                component_id: 5,
+                forbid: false,
+            }]
+        );
+        assert_eq!(main.lines[3].include, Some(1));
+        assert_eq!(view.files[1].label, "mymod/lib/Helpers.tpa");
+        assert_eq!(view.files[1].lines[0].tra[0].text, "Prompt\ntext");
+        assert!(view.plain_text(0).starts_with("// second\nBEGIN @2"));
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn source_cache_evicts_least_recently_used_entry() {
+        let key = |component: usize| SourceKey {
+            tp2_path: "setup-mymod.tp2".to_string(),
+            component_id: component.to_string(),
+            language: None,
+            stamp: FileStamp::default(),
+        };
+        let mut cache = SourceCache::default();
+        for component in 0..MAX_CACHED_SOURCES {
+            cache.store(key(component), Tp2SourceState::Unavailable);
+        }
+        assert_eq!(cache.touch(&key(0)), Some(Tp2SourceState::Unavailable));
+        cache.store(key(MAX_CACHED_SOURCES), Tp2SourceState::Unavailable);
+        assert_eq!(cache.entries.len(), MAX_CACHED_SOURCES);
+        assert!(cache.entries.contains_key(&key(0)));
+        assert!(!cache.entries.contains_key(&key(1)));
+    }
+}
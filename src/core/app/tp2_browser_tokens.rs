@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use crate::parser::lapdu::source_tokens::{self, SourceToken, TokenKind};
+
+pub(crate) const INCLUDE_KEYWORDS: &[&str] = &[
+    "ACTION_INCLUDE",
+    "PATCH_INCLUDE",
+    "ACTION_REINCLUDE",
+    "PATCH_REINCLUDE",
+    "INCLUDE",
+];
+
+const PROMPT_KEYWORDS: &[&str] = &[
+    "PRINT",
+    "PATCH_PRINT",
+    "READLN",
+    "ACTION_READLN",
+    "PATCH_READLN",
+    "SUBCOMPONENT",
+    "ASK_EVERY_COMPONENT",
+];
+
+const CONSTRAINT_KEYWORDS: &[&str] = &[
+    "MOD_IS_INSTALLED",
+    "GAME_IS",
+    "GAME_INCLUDES",
+    "ENGINE_IS",
+    "FILE_EXISTS",
+    "FILE_EXISTS_IN_GAME",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tp2TokenKind {
+    Plain,
+    Keyword,
+    Constraint,
+    Prompt,
+    Include,
+    String,
+    TraRef,
+    Comment,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tp2Span {
+    pub kind: Tp2TokenKind,
+    pub text: String,
+}
+
+pub(crate) fn highlight(text: &str, tokens: &[SourceToken]) -> Vec<Vec<Tp2Span>> {
+    let mut lines = vec![Vec::new()];
+    let mut cursor = 0;
+    for token in tokens {
+        if token.start < cursor {
+            continue;
+        }
+        push_gap(&mut lines, &text[cursor..token.start]);
+        push_text(
+            &mut lines,
+            token_kind(text, token),
+            source_tokens::token_text(text, token),
+        );
+        cursor = token.end;
+    }
+    push_gap(&mut lines, &text[cursor..]);
+    if text.is_empty() || text.ends_with('\n') {
+        lines.pop();
+    }
+    lines
+}
+
+fn token_kind(text: &str, token: &SourceToken) -> Tp2TokenKind {
+    match token.kind {
+        TokenKind::TraRef => Tp2TokenKind::TraRef,
+        TokenKind::UnterminatedComment => Tp2TokenKind::Comment,
+        TokenKind::LongTildeString
+        | TokenKind::UnterminatedString
+        | TokenKind::TildeString
+        | TokenKind::QuoteString
+        | TokenKind::SoundString => Tp2TokenKind::String,
+        TokenKind::Word if source_tokens::is_keyword(text, token) => {
+            classify_keyword(source_tokens::token_text(text, token))
+        }
+        _ => Tp2TokenKind::Plain,
+    }
+}
+
+fn classify_keyword(word: &str) -> Tp2TokenKind {
+    if INCLUDE_KEYWORDS.contains(&word) {
+        Tp2TokenKind::Include
+    } else if PROMPT_KEYWORDS.contains(&word) {
+        Tp2TokenKind::Prompt
+    } else if word.starts_with("REQUIRE_")
+        || word.starts_with("FORBID_")
+        || CONSTRAINT_KEYWORDS.contains(&word)
+    {
+        Tp2TokenKind::Constraint
+    } else {
+        Tp2TokenKind::Keyword
+    }
+}
+
+fn push_gap(lines: &mut Vec<Vec<Tp2Span>>, gap: &str) {
+    for (index, part) in gap.split('\n').enumerate() {
+        if index > 0 {
+            lines.push(Vec::new());
+        }
+        let part = part.strip_suffix('\r').unwrap_or(part);
+        let code = part.trim();
+        let lead = part.len() - part.trim_start().len();
+        let Some(line) = lines.last_mut() else {
+            continue;
+        };
+        push(line, Tp2TokenKind::Plain, &part[..lead]);
+        push(line, Tp2TokenKind::Comment, code);
+        push(line, Tp2TokenKind::Plain, &part[lead + code.len()..]);
+    }
+}
+
+fn push_text(lines: &mut Vec<Vec<Tp2Span>>, kind: Tp2TokenKind, text: &str) {
+    for (index, part) in text.split('\n').enumerate() {
+        if index > 0 {
+            lines.push(Vec::new());
+        }
+        let part = part.strip_suffix('\r').unwrap_or(part);
+        if let Some(line) = lines.last_mut() {
+            push(line, kind, part);
+        }
+    }
+}
+
+fn push(spans: &mut Vec<Tp2Span>, kind: Tp2TokenKind, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    match spans.last_mut() {
+        Some(last) if last.kind == kind && kind != Tp2TokenKind::TraRef => {
+            last.text.push_str(text);
+        }
+        _ => spans.push(Tp2Span {
+            kind,
+            text: text.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlights_tokens_and_the_comment_gaps_between_them() {
+        let text = "BEGIN @100 DESIGNATED 4000 // core\n\
+                    REQUIRE_COMPONENT ~setup-x.tp2~ ~1~ /* note\n\
+                    still comment */ PRINT ~multi\n\
+                    line~ ACTION_INCLUDE ~a.tpa~\n";
+        let lines = highlight(text, &source_tokens::lex(text));
+        assert_eq!(lines.len(), 4);
+        let kinds: Vec<_> = lines[0].iter().map(|s| (s.kind, s.text.as_str())).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (Tp2TokenKind::Keyword, "BEGIN"),
+                (Tp2TokenKind::Plain, " "),
+                (Tp2TokenKind::TraRef, "@100"),
+                (Tp2TokenKind::Plain, " "),
+                (Tp2TokenKind::Keyword, "DESIGNATED"),
+                (Tp2TokenKind::Plain, " 4000 "),
+                (Tp2TokenKind::Comment, "// core"),
+            ]
+        );
+
+        assert_eq!(lines[1][0].kind, Tp2TokenKind::Constraint);
+        assert_eq!(lines[1][2].kind, Tp2TokenKind::String);
+        assert_eq!(lines[1].last().map(|s| s.kind), Some(Tp2TokenKind::Comment));
+        assert_eq!(lines[2][0].kind, Tp2TokenKind::Comment);
+        assert!(lines[2].iter().any(|s| s.kind == Tp2TokenKind::Prompt));
+        assert_eq!(lines[3][0].kind, Tp2TokenKind::String);
+        assert!(lines[3].iter().any(|s| s.kind == Tp2TokenKind::Include));
+    }
+}
@@ -0,0 +1,513 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+
+use crate::app::state::Step2ModState;
+use crate::parser::lapdu::source_tokens::{self, SourceToken, TokenKind};
+use crate::parser::weidu_component_line::parse_lang;
+
+const MAX_CACHED_TABLES: usize = 256;
+const ENGLISH_HINTS: &[&str] = &["english", "en_us", "en-us", "american"];
+const LEGACY_LANGUAGE_DIRS: &[&str] = &["english", "en_us", "en_US"];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct TraTable {
+    entries: HashMap<String, String>,
+    pub(crate) language: Option<String>,
+    pub(crate) files: Vec<PathBuf>,
+}
+
+impl TraTable {
+    pub(crate) const fn entries(&self) -> &HashMap<String, String> {
+        &self.entries
+    }
+
+    pub(crate) fn get(&self, reference: &str) -> Option<&str> {
+        let reference = reference.trim();
+        if reference.starts_with('@') {
+            self.entries.get(reference)
+        } else {
+            self.entries.get(&format!("@{reference}"))
+        }
+        .map(String::as_str)
+    }
+
+    pub(crate) fn resolve_refs(&self, text: &str) -> String {
+        if self.entries.is_empty() || !text.contains('@') {
+            return text.to_string();
+        }
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(at) = rest.find('@') {
+            let preceded_by_word = rest[..at]
+                .chars()
+                .next_back()
+                .or_else(|| out.chars().next_back())
+                .is_some_and(|ch| ch.is_alphanumeric() || ch == '_');
+            out.push_str(&rest[..at]);
+            rest = &rest[at..];
+            let digits = rest[1..].bytes().take_while(u8::is_ascii_digit).count();
+            let resolved = (!preceded_by_word && digits > 0)
+                .then(|| self.entries.get(&rest[..=digits]))
+                .flatten();
+            if let Some(resolved) = resolved {
+                out.push_str(resolved);
+                rest = &rest[1 + digits..];
+            } else {
+                out.push('@');
+                rest = &rest[1..];
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+pub(crate) struct ModPaths {
+    mod_dir: PathBuf,
+    pub(crate) mods_root: PathBuf,
+    mod_folder: String,
+    language_dir: Option<String>,
+}
+
+impl ModPaths {
+    pub(crate) fn new(tp2_path: &Path) -> Self {
+        let mod_dir = tp2_path.parent().map(Path::to_path_buf).unwrap_or_default();
+        let mods_root = mod_dir
+            .parent()
+            .map_or_else(|| mod_dir.clone(), Path::to_path_buf);
+        let mod_folder = mod_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Self {
+            mod_dir,
+            mods_root,
+            mod_folder,
+            language_dir: None,
+        }
+    }
+
+    pub(crate) fn resolve(&self, raw: &str) -> Option<PathBuf> {
+        let mut value = raw.trim().replace('\\', "/");
+        for var in ["%MOD_FOLDER%", "%mod_folder%"] {
+            value = value.replace(var, &self.mod_folder);
+        }
+        if let Some(language_dir) = self.language_dir.as_deref() {
+            for var in ["%LANGUAGE%", "%language%", "%s"] {
+                value = value.replace(var, language_dir);
+            }
+        }
+        if value.is_empty() || value.contains('%') {
+            return None;
+        }
+        if Path::new(&value).is_absolute() {
+            return Path::new(&value).is_file().then(|| PathBuf::from(&value));
+        }
+        [&self.mods_root, &self.mod_dir]
+            .into_iter()
+            .find_map(|base| resolve_case_insensitive(base, &value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LanguageEntry {
+    name: String,
+    dir: String,
+    files: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub(crate) struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ModLanguages(HashMap<String, usize>);
+
+impl ModLanguages {
+    pub(crate) fn from_mods(mods: &[Step2ModState]) -> Self {
+        Self(
+            mods.iter()
+                .filter_map(|mod_state| {
+                    Some((mod_state.tp2_path.clone(), mod_language(mod_state)?))
+                })
+                .collect(),
+        )
+    }
+
+    pub(crate) fn get(&self, tp2_path: &str) -> Option<usize> {
+        self.0.get(tp2_path).copied()
+    }
+}
+
+struct CachedTable {
+    stamps: Vec<(PathBuf, Option<FileStamp>)>,
+    table: Arc<TraTable>,
+}
+
+type TableKey = (PathBuf, Option<usize>);
+
+static TABLE_CACHE: OnceLock<Mutex<HashMap<TableKey, CachedTable>>> = OnceLock::new();
+
+pub(crate) fn language_from_raw_lines<'a>(
+    mut raw_lines: impl Iterator<Item = &'a str>,
+) -> Option<usize> {
+    raw_lines.find_map(|raw| parse_lang(raw).and_then(|lang| lang.parse().ok()))
+}
+
+pub(crate) fn mod_language(mod_state: &Step2ModState) -> Option<usize> {
+    language_from_raw_lines(mod_state.components.iter().map(|c| c.raw_line.as_str()))
+}
+
+pub(crate) fn tra_table(tp2_path: &Path, language: Option<usize>) -> Arc<TraTable> {
+    let key = (tp2_path.to_path_buf(), language);
+    let cache = TABLE_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Ok(cache) = cache.lock()
+        && let Some(cached) = cache.get(&key)
+        && cached
+            .stamps
+            .iter()
+            .all(|(path, stamp)| file_stamp(path) == *stamp)
+    {
+        return Arc::clone(&cached.table);
+    }
+
+    let table = Arc::new(load_tra_table(tp2_path, language));
+    let stamps = std::iter::once(tp2_path.to_path_buf())
+        .chain(table.files.iter().cloned())
+        .map(|path| {
+            let stamp = file_stamp(&path);
+            (path, stamp)
+        })
+        .collect();
+    if let Ok(mut cache) = cache.lock() {
+        if cache.len() >= MAX_CACHED_TABLES {
+            cache.clear();
+        }
+        cache.insert(
+            key,
+            CachedTable {
+                stamps,
+                table: Arc::clone(&table),
+            },
+        );
+    }
+    table
+}
+
+pub(crate) fn load_tra_files(paths: &[PathBuf]) -> HashMap<String, String> {
+    let mut out = HashMap::new();
+    for path in paths {
+        if let Ok(bytes) = fs::read(path) {
+            out.extend(parse_tra(&String::from_utf8_lossy(&bytes)));
+        }
+    }
+    out
+}
+
+pub(crate) fn load_tra_statement_files(
+    text: &str,
+    tokens: &[SourceToken],
+    paths: &ModPaths,
+) -> Vec<PathBuf> {
+    let mut out = Vec::new();
+    for keyword in ["LOAD_TRA", "USING"] {
+        for (_, args) in source_tokens::keyword_arguments(text, tokens, keyword) {
+            for arg in args {
+                if let Some(path) = paths.resolve(arg)
+                    && !out.contains(&path)
+                {
+                    out.push(path);
+                }
+            }
+        }
+    }
+    out
+}
+
+pub(crate) fn mod_paths_for_language(
+    tp2_path: &Path,
+    tp2_text: &str,
+    tokens: &[SourceToken],
+    language: Option<usize>,
+) -> ModPaths {
+    let mut paths = ModPaths::new(tp2_path);
+    paths.language_dir =
+        pick_language(&language_entries(tp2_text, tokens), language).map(|entry| entry.dir.clone());
+    paths
+}
+
+fn load_tra_table(tp2_path: &Path, language: Option<usize>) -> TraTable {
+    let Some(text) = fs::read(tp2_path)
+        .ok()
+        .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+    else {
+        return TraTable::default();
+    };
+    let tokens = source_tokens::lex(&text);
+    let entries = language_entries(&text, &tokens);
+    let entry = pick_language(&entries, language);
+    let mut paths = ModPaths::new(tp2_path);
+    paths.language_dir = entry.map(|entry| entry.dir.clone());
+
+    let mut files: Vec<PathBuf> = entry
+        .map(|entry| {
+            entry
+                .files
+                .iter()
+                .filter_map(|raw| paths.resolve(raw))
+                .collect()
+        })
+        .unwrap_or_default();
+    if files.is_empty() {
+        files = legacy_setup_tra_files(tp2_path, paths.language_dir.as_deref());
+    }
+    let mut table = load_tra_files(&files);
+
+    let extra: Vec<PathBuf> = load_tra_statement_files(&text, &tokens, &paths)
+        .into_iter()
+        .filter(|path| !files.contains(path))
+        .collect();
+    for (key, value) in load_tra_files(&extra) {
+        table.entry(key).or_insert(value);
+    }
+    files.extend(extra);
+
+    TraTable {
+        entries: table,
+        language: entry.map(|entry| entry.name.clone()),
+        files,
+    }
+}
+
+fn language_entries(tp2_text: &str, tokens: &[SourceToken]) -> Vec<LanguageEntry> {
+    let header = tokens
+        .iter()
+        .position(|token| source_tokens::is_word(tp2_text, token, "BEGIN"))
+        .unwrap_or(tokens.len());
+    source_tokens::keyword_arguments(tp2_text, &tokens[..header], "LANGUAGE")
+        .into_iter()
+        .filter_map(|(_, args)| {
+            let [name, dir, files @ ..] = args.as_slice() else {
+                return None;
+            };
+            Some(LanguageEntry {
+                name: (*name).to_string(),
+                dir: (*dir).to_string(),
+                files: files.iter().map(ToString::to_string).collect(),
+            })
+        })
+        .collect()
+}
+
+fn pick_language(entries: &[LanguageEntry], language: Option<usize>) -> Option<&LanguageEntry> {
+    if let Some(entry) = language.and_then(|index| entries.get(index)) {
+        return Some(entry);
+    }
+    entries
+        .iter()
+        .find(|entry| {
+            let label = format!("{} {}", entry.name, entry.dir).to_ascii_lowercase();
+            ENGLISH_HINTS.iter().any(|hint| label.contains(hint))
+        })
+        .or_else(|| entries.first())
+}
+
+fn legacy_setup_tra_files(tp2_path: &Path, language_dir: Option<&str>) -> Vec<PathBuf> {
+    let Some(base) = tp2_path.parent() else {
+        return Vec::new();
+    };
+    let tp2_stem = tp2_path
+        .file_stem()
+        .and_then(|value| value.to_str())
+        .unwrap_or_default();
+    let mod_key = tp2_stem.strip_prefix("setup-").unwrap_or(tp2_stem);
+    let mut names = vec!["setup.tra".to_string(), format!("{tp2_stem}.tra")];
+    if !mod_key.is_empty() {
+        names.push(format!("{mod_key}setup.tra"));
+    }
+
+    let mut dirs: Vec<PathBuf> = language_dir
+        .into_iter()
+        .chain(LEGACY_LANGUAGE_DIRS.iter().copied())
+        .flat_map(|dir| [base.join("lang").join(dir), base.join("tra").join(dir)])
+        .collect();
+    dirs.push(base.to_path_buf());
+
+    let mut out = Vec::new();
+    for dir in dirs {
+        for name in &names {
+            let path = dir.join(name);
+            if path.is_file() && !out.contains(&path) {
+                out.push(path);
+            }
+        }
+        if !out.is_empty() {
+            return out;
+        }
+    }
+    collect_setup_tra_files(base, &mut out);
+    out
+}
+
+fn collect_setup_tra_files(base: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(read_dir) = fs::read_dir(base) else {
+        return;
+    };
+    for entry in read_dir.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_setup_tra_files(&path, out);
+            continue;
+        }
+        let is_setup_tra = path
+            .file_name()
+            .and_then(|value| value.to_str())
+            .is_some_and(|name| {
+                let name = name.to_ascii_lowercase();
+                name.ends_with(".tra") && name.contains("setup")
+            });
+        if is_setup_tra && !out.contains(&path) {
+            out.push(path);
+        }
+    }
+}
+
+fn parse_tra(text: &str) -> HashMap<String, String> {
+    let tokens = source_tokens::lex(text);
+    tokens
+        .windows(3)
+        .filter(|window| window[0].kind == TokenKind::TraRef && window[1].kind == TokenKind::Eq)
+        .filter_map(|window| {
+            let value = source_tokens::string_value(text, &window[2])?.trim();
+            (!value.is_empty()).then(|| {
+                (
+                    source_tokens::token_text(text, &window[0]).to_string(),
+                    value.to_string(),
+                )
+            })
+        })
+        .collect()
+}
+
+fn resolve_case_insensitive(base: &Path, relative: &str) -> Option<PathBuf> {
+    let direct = base.join(relative);
+    if direct.is_file() {
+        return Some(direct);
+    }
+    let mut current = base.to_path_buf();
+    for part in relative
+        .split('/')
+        .filter(|part| !part.is_empty() && *part != ".")
+    {
+        let exact = current.join(part);
+        if exact.exists() {
+            current = exact;
+            continue;
+        }
+        let found = fs::read_dir(&current).ok()?.flatten().find(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .eq_ignore_ascii_case(part)
+        })?;
+        current = found.path();
+    }
+    current.is_file().then_some(current)
+}
+
+pub(crate) fn file_stamp(path: &Path) -> Option<FileStamp> {
+    let meta = fs::metadata(path).ok()?;
+    Some(FileStamp {
+        modified: meta.modified().ok(),
+        len: meta.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    #[test]
+    fn parses_multiline_and_tilde_delimited_entries() {
+        let map = parse_tra(
+            "// header @9 = not an entry\n@1 = ~One~\n@2 = \"Two\nlines\" [SOUND]\n@3=~~~~~has ~tilde~~~~~~\n",
+        );
+        assert_eq!(map.get("@1").map(String::as_str), Some("One"));
+        assert_eq!(map.get("@2").map(String::as_str), Some("Two\nlines"));
+        assert_eq!(map.get("@3").map(String::as_str), Some("has ~tilde"));
+        assert!(!map.contains_key("@9"));
+    }
+
+    #[test]
+    fn loads_declared_language_tra_for_begin_labels() {
+        let root = temp_dir("tra_loader");
+        let mod_dir = root.join("EpicThieving");
+        let tra_dir = mod_dir.join("tra/english");
+        fs::create_dir_all(&tra_dir).expect("create temp tra dir");
+        let tp2_path = mod_dir.join("EpicThieving.tp2");
+        fs::write(
+            &tp2_path,
+            "LANGUAGE\n\"English\"\nENGLISH\n ~EpicThieving/tra/english/english.tra~\n\nBEGIN @2\nBEGIN @3 DESIGNATED 100\n",
+        )
+        .expect("write temp tp2");
+        fs::write(
+            tra_dir.join("english.tra"),
+            "@2 = ~Epic Locks~\n@3 = ~Epic Traps~\n",
+        )
+        .expect("write temp tra");
+
+        let tra = tra_table(&tp2_path, None);
+
+        assert_eq!(tra.get("@2"), Some("Epic Locks"));
+        assert_eq!(tra.get("3"), Some("Epic Traps"));
+        assert_eq!(tra.language.as_deref(), Some("English"));
+
+        let _ = fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn follows_language_order_and_load_tra_statements() {
+        let root = temp_dir("tra_language");
+        let mod_dir = root.join("mymod");
+        for lang in ["english", "french"] {
+            fs::create_dir_all(mod_dir.join("lang").join(lang)).expect("lang dir");
+        }
+        fs::write(mod_dir.join("lang/english/setup.tra"), "@1 = ~Core~\n").expect("en");
+        fs::write(mod_dir.join("lang/french/setup.tra"), "@1 = ~Noyau~\n").expect("fr");
+        fs::write(
+            mod_dir.join("lang/french/extra.tra"),
+            "@1 = ~ignored~\n@50 = ~Voulez-vous continuer ?~\n",
+        )
+        .expect("extra");
+        let tp2_path = mod_dir.join("setup-mymod.tp2");
+        fs::write(
+            &tp2_path,
+            "LANGUAGE ~English~ ~english~ ~mymod/lang/english/setup.tra~\n\
+             LANGUAGE ~Francais~ ~french~ ~mymod/lang/french/setup.tra~\n\
+             ALWAYS LOAD_TRA ~mymod/lang/%LANGUAGE%/extra.tra~ END\n\
+             // LOAD_TRA ~mymod/lang/english/missing.tra~\n\
+             BEGIN @1 DESIGNATED 0\n",
+        )
+        .expect("tp2");
+
+        let french = tra_table(&tp2_path, Some(1));
+        assert_eq!(french.get("@1"), Some("Noyau"));
+        assert_eq!(
+            french.resolve_refs("PRINT @50 then email@50 and @999"),
+            "PRINT Voulez-vous continuer ? then email@50 and @999"
+        );
+        assert_eq!(french.files.len(), 2);
+        assert_eq!(tra_table(&tp2_path, Some(0)).get("@1"), Some("Core"));
+
+        let _ = fs::remove_dir_all(root);
+    }
+}
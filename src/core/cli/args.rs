@@ -1,7 +1,8 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Copyright (c) 2026 Born2BSalty
 
-use clap::{ArgAction, Args, Parser, Subcommand};
+use clap::parser::ValueSource;
+use clap::{ArgAction, ArgMatches, Args, Parser, Subcommand};
 
 #[derive(Parser, Debug)]
 #[command(name = "BIO")]
@@ -20,6 +21,12 @@ pub struct Cli {
     #[arg(short = 'd', long, default_value_t = false)]
     pub dev_mode: bool,
 
+    #[arg(long, env = "BIO_PROFILE")]
+    pub profile: Option<String>,
+
+    #[arg(long = "set", value_name = "KEY=VALUE", action = ArgAction::Append)]
+    pub set: Vec<String>,
+
     #[command(subcommand)]
     pub command: Option<Command>,
 }
@@ -37,6 +44,12 @@ pub enum Command {
 
     #[command(name = "scan")]
     Scan(ScanArgs),
+
+    #[command(name = "lock")]
+    Lock(LockArgs),
+
+    #[command(name = "migrate")]
+    Migrate(MigrateArgs),
 }
 
 #[derive(Args, Debug)]
@@ -220,3 +233,90 @@ pub struct ScanLanguagesArgs {
     #[command(flatten)]
     pub options: CommonOptions,
 }
+
+#[derive(Args, Debug)]
+#[command(disable_help_flag = true)]
+pub struct LockArgs {
+    #[arg(long, action = ArgAction::Help)]
+    pub help: Option<bool>,
+
+    #[command(subcommand)]
+    pub command: LockCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum LockCommand {
+    #[command(name = "diff")]
+    Diff(LockDiffArgs),
+
+    #[command(name = "update")]
+    Update(LockUpdateArgs),
+}
+
+#[derive(Args, Debug)]
+#[command(disable_help_flag = true)]
+pub struct LockDiffArgs {
+    #[arg(long, action = ArgAction::Help)]
+    pub help: Option<bool>,
+    #[arg(long)]
+    pub modlist: String,
+}
+
+#[derive(Args, Debug)]
+#[command(disable_help_flag = true)]
+pub struct LockUpdateArgs {
+    #[arg(long, action = ArgAction::Help)]
+    pub help: Option<bool>,
+    #[arg(long)]
+    pub modlist: String,
+    #[arg(long, default_value_t = false)]
+    pub yes: bool,
+}
+
+#[derive(Args, Debug)]
+#[command(disable_help_flag = true)]
+pub struct MigrateArgs {
+    #[arg(long, action = ArgAction::Help)]
+    pub help: Option<bool>,
+    #[arg(long, default_value_t = false)]
+    pub dry_run: bool,
+}
+
+/// Argument ids of the innermost subcommand whose value did not come from a clap default.
+#[must_use]
+pub fn explicit_args(matches: &ArgMatches) -> Vec<String> {
+    let mut leaf = matches;
+    while let Some((_, sub)) = leaf.subcommand() {
+        leaf = sub;
+    }
+    leaf.ids()
+        .filter(|id| {
+            leaf.value_source(id.as_str())
+                .is_some_and(|source| source != ValueSource::DefaultValue)
+        })
+        .map(|id| id.as_str().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn explicit_args_skip_clap_defaults() {
+        let matches = Cli::command().get_matches_from([
+            "bio",
+            "normal",
+            "--log-file",
+            "weidu.log",
+            "--game-directory",
+            "/games/bg2",
+            "--timeout",
+            "3600",
+        ]);
+        let explicit = explicit_args(&matches);
+        assert!(explicit.iter().any(|id| id == "timeout"));
+        assert!(!explicit.iter().any(|id| id == "depth" || id == "language"));
+    }
+}
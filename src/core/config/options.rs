@@ -3,7 +3,7 @@
 
 use std::path::PathBuf;
 
-use crate::cli::args::{Cli, Command, CommonOptions, ScanCommand};
+use crate::cli::args::{Cli, Command, CommonOptions, LockCommand, ScanCommand};
 
 #[derive(Debug, Clone)]
 pub struct CoreOptions {
@@ -48,12 +48,20 @@ pub enum ScanConfig {
     },
 }
 
+#[derive(Debug, Clone)]
+pub enum LockConfig {
+    Diff { modlist_id: String },
+    Update { modlist_id: String, confirmed: bool },
+}
+
 #[derive(Debug, Clone)]
 pub enum AppCommandConfig {
     Gui { dev_mode: bool },
     Normal(NormalConfig),
     Eet(EetConfig),
     Scan(ScanConfig),
+    Lock(LockConfig),
+    Migrate { dry_run: bool },
 }
 
 #[must_use]
@@ -87,6 +95,18 @@ pub fn from_cli(cli: &Cli) -> Option<AppCommandConfig> {
                 options: map_common(&args.options),
             })),
         },
+        Command::Lock(lock) => match &lock.command {
+            LockCommand::Diff(args) => Some(AppCommandConfig::Lock(LockConfig::Diff {
+                modlist_id: args.modlist.trim().to_string(),
+            })),
+            LockCommand::Update(args) => Some(AppCommandConfig::Lock(LockConfig::Update {
+                modlist_id: args.modlist.trim().to_string(),
+                confirmed: args.yes,
+            })),
+        },
+        Command::Migrate(args) => Some(AppCommandConfig::Migrate {
+            dry_run: args.dry_run,
+        }),
     }
 }
 
@@ -0,0 +1,563 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::app::state::Step1State;
+
+const MAX_CANDIDATE_DEPTH: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameKind {
+    Bgee,
+    Sod,
+    Bg2ee,
+    Iwdee,
+}
+
+impl GameKind {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Bgee => "BGEE",
+            Self::Sod => "BGEE + SoD",
+            Self::Bg2ee => "BG2EE",
+            Self::Iwdee => "IWDEE",
+        }
+    }
+
+    #[must_use]
+    pub const fn is_bg1(self) -> bool {
+        matches!(self, Self::Bgee | Self::Sod)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscoverySource {
+    Steam,
+    Proton,
+    Gog,
+    Heroic,
+    Lutris,
+    Beamdog,
+    Wine,
+}
+
+impl fmt::Display for DiscoverySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Steam => "Steam",
+            Self::Proton => "Steam (Proton)",
+            Self::Gog => "GOG",
+            Self::Heroic => "Heroic",
+            Self::Lutris => "Lutris",
+            Self::Beamdog => "Beamdog",
+            Self::Wine => "Wine",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredInstall {
+    pub kind: GameKind,
+    pub path: PathBuf,
+    pub version: Option<String>,
+    pub source: DiscoverySource,
+}
+
+#[must_use]
+pub fn identify_game_dir(dir: &Path) -> Option<(GameKind, Option<String>)> {
+    if !dir.join("chitin.key").is_file() {
+        return None;
+    }
+    let engine = std::fs::read_to_string(dir.join("engine.lua")).unwrap_or_default();
+    let kind = kind_from_engine(&engine).or_else(|| kind_from_layout(dir))?;
+    let kind = if kind == GameKind::Bgee && has_sod(dir) {
+        GameKind::Sod
+    } else {
+        kind
+    };
+    Some((kind, parse_version(&engine)))
+}
+
+fn kind_from_engine(engine: &str) -> Option<GameKind> {
+    let upper = engine.to_ascii_uppercase();
+    if upper.contains("IWDEE") || upper.contains("ICEWIND DALE") {
+        Some(GameKind::Iwdee)
+    } else if upper.contains("BG2EE") || upper.contains("BALDUR'S GATE II") {
+        Some(GameKind::Bg2ee)
+    } else if upper.contains("BGEE") || upper.contains("BALDUR'S GATE") {
+        Some(GameKind::Bgee)
+    } else {
+        None
+    }
+}
+
+fn kind_from_layout(dir: &Path) -> Option<GameKind> {
+    if dir.join("Icewind.exe").is_file() || dir.join("IcewindDale").is_file() {
+        return Some(GameKind::Iwdee);
+    }
+    let name = dir
+        .ancestors()
+        .take(2)
+        .filter_map(Path::file_name)
+        .map(|n| n.to_string_lossy().to_ascii_lowercase())
+        .collect::<Vec<_>>()
+        .join("/");
+    if name.contains("icewind") || name.contains("00798") {
+        Some(GameKind::Iwdee)
+    } else if name.contains("baldur's gate ii")
+        || name.contains("baldurs gate ii")
+        || name.contains("baldur's gate 2")
+        || name.contains("bg2")
+        || name.contains("00783")
+    {
+        Some(GameKind::Bg2ee)
+    } else if name.contains("baldur") || name.contains("bgee") || name.contains("00766") {
+        Some(GameKind::Bgee)
+    } else {
+        None
+    }
+}
+
+fn has_sod(dir: &Path) -> bool {
+    dir.join("sod-dlc.zip").is_file()
+        || dir.join("dlc").join("sod-dlc.zip").is_file()
+        || dir.join("movies").join("sodcin01.wbm").is_file()
+}
+
+fn parse_version(engine: &str) -> Option<String> {
+    engine.lines().find_map(|line| {
+        let lower = line.to_ascii_lowercase();
+        if !lower.contains("version") {
+            return None;
+        }
+        line.split(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .find(|tok| tok.contains('.') && tok.split('.').all(|p| !p.is_empty()))
+            .map(str::to_string)
+    })
+}
+
+#[must_use]
+pub fn steam_library_roots(vdf: &str) -> Vec<PathBuf> {
+    vdf.lines()
+        .filter_map(|line| {
+            let mut quoted = line.split('"').skip(1).step_by(2);
+            let key = quoted.next()?;
+            let value = quoted.next()?;
+            key.eq_ignore_ascii_case("path")
+                .then(|| PathBuf::from(value.replace("\\\\", "\\")))
+        })
+        .collect()
+}
+
+fn heroic_install_paths(json: &str) -> Vec<PathBuf> {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(json) else {
+        return Vec::new();
+    };
+    let entries = value
+        .get("installed")
+        .and_then(serde_json::Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    entries
+        .iter()
+        .filter_map(|e| e.get("install_path").and_then(serde_json::Value::as_str))
+        .map(PathBuf::from)
+        .collect()
+}
+
+fn lutris_game_paths(yml: &str) -> (Vec<PathBuf>, Vec<PathBuf>) {
+    let mut games = Vec::new();
+    let mut prefixes = Vec::new();
+    for line in yml.lines() {
+        let Some((key, value)) = line.trim().split_once(':') else {
+            continue;
+        };
+        let value = value.trim().trim_matches(|c| c == '\'' || c == '"');
+        if value.is_empty() {
+            continue;
+        }
+        match key.trim() {
+            "game_path" | "working_dir" => games.push(PathBuf::from(value)),
+            "exe" => {
+                if let Some(parent) = Path::new(value).parent() {
+                    games.push(parent.to_path_buf());
+                }
+            }
+            "prefix" => prefixes.push(PathBuf::from(value)),
+            _ => {}
+        }
+    }
+    (games, prefixes)
+}
+
+fn wine_prefix_roots(prefix: &Path) -> Vec<(PathBuf, DiscoverySource)> {
+    let drive_c = prefix.join("drive_c");
+    let mut roots = vec![
+        (drive_c.join("GOG Games"), DiscoverySource::Gog),
+        (
+            drive_c
+                .join("Program Files (x86)")
+                .join("GOG Galaxy")
+                .join("Games"),
+            DiscoverySource::Gog,
+        ),
+        (
+            drive_c
+                .join("Program Files")
+                .join("GOG Galaxy")
+                .join("Games"),
+            DiscoverySource::Gog,
+        ),
+        (
+            drive_c
+                .join("Program Files (x86)")
+                .join("Beamdog")
+                .join("Games"),
+            DiscoverySource::Beamdog,
+        ),
+        (
+            drive_c.join("Program Files").join("Beamdog").join("Games"),
+            DiscoverySource::Beamdog,
+        ),
+    ];
+    roots.retain(|(root, _)| root.is_dir());
+    roots
+}
+
+fn read(path: &Path) -> String {
+    std::fs::read_to_string(path).unwrap_or_default()
+}
+
+fn child_dirs(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut dirs: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+fn library_roots(home: &Path) -> Vec<(PathBuf, DiscoverySource)> {
+    let mut roots = Vec::new();
+
+    let steam_homes = [
+        home.join(".steam").join("steam"),
+        home.join(".local").join("share").join("Steam"),
+        home.join(".var")
+            .join("app")
+            .join("com.valvesoftware.Steam")
+            .join(".local")
+            .join("share")
+            .join("Steam"),
+    ];
+    let mut steam_libraries = Vec::new();
+    for steam in &steam_homes {
+        let steamapps = steam.join("steamapps");
+        if steamapps.is_dir() {
+            steam_libraries.push(steam.clone());
+        }
+        steam_libraries.extend(steam_library_roots(&read(
+            &steamapps.join("libraryfolders.vdf"),
+        )));
+    }
+    for library in steam_libraries {
+        let steamapps = library.join("steamapps");
+        roots.push((steamapps.join("common"), DiscoverySource::Steam));
+        for compat in child_dirs(&steamapps.join("compatdata")) {
+            roots.extend(
+                wine_prefix_roots(&compat.join("pfx"))
+                    .into_iter()
+                    .map(|(root, _)| (root, DiscoverySource::Proton)),
+            );
+        }
+    }
+
+    roots.push((home.join("GOG Games"), DiscoverySource::Gog));
+    roots.push((home.join("Games").join("Heroic"), DiscoverySource::Heroic));
+    for installed in [
+        home.join(".config")
+            .join("heroic")
+            .join("gog_store")
+            .join("installed.json"),
+        home.join(".var")
+            .join("app")
+            .join("com.heroicgameslauncher.hgl")
+            .join("config")
+            .join("heroic")
+            .join("gog_store")
+            .join("installed.json"),
+    ] {
+        roots.extend(
+            heroic_install_paths(&read(&installed))
+                .into_iter()
+                .map(|path| (path, DiscoverySource::Heroic)),
+        );
+    }
+
+    for yml in std::fs::read_dir(home.join(".config").join("lutris").join("games"))
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "yml"))
+    {
+        let (games, prefixes) = lutris_game_paths(&read(&yml));
+        roots.extend(games.into_iter().map(|p| (p, DiscoverySource::Lutris)));
+        for prefix in prefixes {
+            roots.extend(
+                wine_prefix_roots(&prefix)
+                    .into_iter()
+                    .map(|(root, _)| (root, DiscoverySource::Lutris)),
+            );
+        }
+    }
+    roots.push((home.join("Games"), DiscoverySource::Lutris));
+
+    roots.push((home.join("Beamdog Games"), DiscoverySource::Beamdog));
+    roots.extend(
+        wine_prefix_roots(&home.join(".wine"))
+            .into_iter()
+            .map(|(root, source)| match source {
+                DiscoverySource::Beamdog => (root, source),
+                _ => (root, DiscoverySource::Wine),
+            }),
+    );
+
+    roots
+}
+
+fn collect_game_dirs(dir: &Path, depth: usize, out: &mut Vec<PathBuf>) {
+    if dir.join("chitin.key").is_file() {
+        out.push(dir.to_path_buf());
+        return;
+    }
+    if depth == 0 {
+        return;
+    }
+    for child in child_dirs(dir) {
+        collect_game_dirs(&child, depth - 1, out);
+    }
+}
+
+#[must_use]
+pub fn discover_in(home: &Path) -> Vec<DiscoveredInstall> {
+    let mut seen = HashSet::new();
+    let mut found = Vec::new();
+    for (root, source) in library_roots(home) {
+        let mut dirs = Vec::new();
+        collect_game_dirs(&root, MAX_CANDIDATE_DEPTH, &mut dirs);
+        for dir in dirs {
+            let key = std::fs::canonicalize(&dir).unwrap_or_else(|_| dir.clone());
+            if !seen.insert(key) {
+                continue;
+            }
+            if let Some((kind, version)) = identify_game_dir(&dir) {
+                found.push(DiscoveredInstall {
+                    kind,
+                    path: dir,
+                    version,
+                    source,
+                });
+            }
+        }
+    }
+    found
+}
+
+#[must_use]
+pub fn discover() -> Vec<DiscoveredInstall> {
+    match std::env::var("HOME") {
+        Ok(home) if !home.trim().is_empty() => discover_in(Path::new(&home)),
+        _ => Vec::new(),
+    }
+}
+
+#[must_use]
+pub fn best_for(found: &[DiscoveredInstall], want: GameKind) -> Option<&DiscoveredInstall> {
+    if want.is_bg1() {
+        found
+            .iter()
+            .find(|d| d.kind == GameKind::Sod)
+            .or_else(|| found.iter().find(|d| d.kind == GameKind::Bgee))
+    } else {
+        found.iter().find(|d| d.kind == want)
+    }
+}
+
+pub fn fill_empty_game_folders(step1: &mut Step1State, found: &[DiscoveredInstall]) -> usize {
+    let path_of = |kind| best_for(found, kind).map(|d| d.path.to_string_lossy().into_owned());
+    let slots: [(&mut String, GameKind); 5] = [
+        (&mut step1.bgee_game_folder, GameKind::Bgee),
+        (&mut step1.bg2ee_game_folder, GameKind::Bg2ee),
+        (&mut step1.iwdee_game_folder, GameKind::Iwdee),
+        (&mut step1.eet_bgee_game_folder, GameKind::Bgee),
+        (&mut step1.eet_bg2ee_game_folder, GameKind::Bg2ee),
+    ];
+    let mut filled = 0;
+    for (slot, kind) in slots {
+        if slot.trim().is_empty()
+            && let Some(path) = path_of(kind)
+        {
+            *slot = path;
+            filled += 1;
+        }
+    }
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    fn write(path: &Path, contents: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).expect("mkdir");
+        std::fs::write(path, contents).expect("write fixture");
+    }
+
+    fn game(dir: &Path, engine: Option<&str>) {
+        write(&dir.join("chitin.key"), "KEY V1  ");
+        std::fs::create_dir_all(dir.join("lang").join("en_US")).expect("mkdir lang");
+        if let Some(engine) = engine {
+            write(&dir.join("engine.lua"), engine);
+        }
+    }
+
+    #[test]
+    fn parses_steam_library_folders() {
+        let vdf = r#""libraryfolders"
+{
+	"0"
+	{
+		"path"		"/home/u/.local/share/Steam"
+		"label"		""
+	}
+	"1"
+	{
+		"path"		"/mnt/games/SteamLibrary"
+	}
+}"#;
+        assert_eq!(
+            steam_library_roots(vdf),
+            vec![
+                PathBuf::from("/home/u/.local/share/Steam"),
+                PathBuf::from("/mnt/games/SteamLibrary"),
+            ]
+        );
+    }
+
+    #[test]
+    fn identifies_games_by_engine_and_layout() {
+        let home = temp_dir("game_discovery_identify");
+        let bg2 = home.join("a");
+        game(
+            &bg2,
+            Some("-- BG2EE engine\nengine_version = \"v2.6.6.0\"\n"),
+        );
+        assert_eq!(
+            identify_game_dir(&bg2),
+            Some((GameKind::Bg2ee, Some("2.6.6.0".to_string())))
+        );
+
+        let sod = home.join("Baldur's Gate Enhanced Edition");
+        game(&sod, None);
+        write(&sod.join("dlc").join("sod-dlc.zip"), "");
+        assert_eq!(identify_game_dir(&sod), Some((GameKind::Sod, None)));
+
+        let unknown = home.join("mystery");
+        game(&unknown, None);
+        assert_eq!(identify_game_dir(&unknown), None);
+        assert_eq!(identify_game_dir(&home), None);
+        let _ = std::fs::remove_dir_all(&home);
+    }
+
+    #[test]
+    fn discovers_across_library_layouts() {
+        let home = temp_dir("game_discovery_layouts");
+        let extra_library = home.join("mnt").join("SteamLibrary");
+        write(
+            &home
+                .join(".local/share/Steam/steamapps")
+                .join("libraryfolders.vdf"),
+            &format!("\"path\" \"{}\"\n", extra_library.display()),
+        );
+        game(
+            &extra_library.join("steamapps/common/Baldur's Gate II Enhanced Edition"),
+            None,
+        );
+        game(
+            &home.join(
+                ".local/share/Steam/steamapps/compatdata/228280/pfx/drive_c/Program Files (x86)/Beamdog/Games/00766",
+            ),
+            None,
+        );
+        game(
+            &home.join("GOG Games/Icewind Dale Enhanced Edition"),
+            Some("IWDEE"),
+        );
+        let lutris_bg = home.join("lutris-games/bgee");
+        game(&lutris_bg, Some("BGEE"));
+        write(
+            &home.join(".config/lutris/games/bgee.yml"),
+            &format!("game:\n  exe: {}/Baldur.exe\n", lutris_bg.display()),
+        );
+
+        let found = discover_in(&home);
+        let by_kind = |kind| found.iter().find(|d| d.kind == kind).cloned();
+        assert_eq!(found.len(), 4, "{found:?}");
+        assert_eq!(
+            by_kind(GameKind::Bg2ee).unwrap().source,
+            DiscoverySource::Steam
+        );
+        assert_eq!(
+            by_kind(GameKind::Iwdee).unwrap().source,
+            DiscoverySource::Gog
+        );
+        let bg1: Vec<_> = found.iter().filter(|d| d.kind == GameKind::Bgee).collect();
+        assert!(bg1.iter().any(|d| d.source == DiscoverySource::Proton));
+        assert!(bg1.iter().any(|d| d.source == DiscoverySource::Lutris));
+        let _ = std::fs::remove_dir_all(&home);
+    }
+
+    #[test]
+    fn fill_only_touches_empty_fields_and_prefers_sod() {
+        let found = vec![
+            DiscoveredInstall {
+                kind: GameKind::Bgee,
+                path: PathBuf::from("/g/bgee"),
+                version: None,
+                source: DiscoverySource::Gog,
+            },
+            DiscoveredInstall {
+                kind: GameKind::Sod,
+                path: PathBuf::from("/g/sod"),
+                version: None,
+                source: DiscoverySource::Steam,
+            },
+            DiscoveredInstall {
+                kind: GameKind::Bg2ee,
+                path: PathBuf::from("/g/bg2"),
+                version: None,
+                source: DiscoverySource::Steam,
+            },
+        ];
+        let mut step1 = Step1State {
+            bg2ee_game_folder: "/mine/bg2".to_string(),
+            ..Step1State::default()
+        };
+        assert_eq!(fill_empty_game_folders(&mut step1, &found), 3);
+        assert_eq!(step1.bgee_game_folder, "/g/sod");
+        assert_eq!(step1.eet_bgee_game_folder, "/g/sod");
+        assert_eq!(step1.bg2ee_game_folder, "/mine/bg2");
+        assert_eq!(step1.eet_bg2ee_game_folder, "/g/bg2");
+        assert!(step1.iwdee_game_folder.is_empty());
+    }
+}
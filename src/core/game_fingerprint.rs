@@ -0,0 +1,336 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::app::state::Step1State;
+use crate::app::step5::command_config::build_install_command_config;
+use crate::game_discovery::{GameKind, identify_game_dir};
+use crate::install::step5_command_install::install_game_directories;
+
+const TLK_MTIME_SLACK: Duration = Duration::from_secs(24 * 60 * 60);
+
+pub const POLICY_OFF: &str = "off";
+pub const POLICY_WARN: &str = "warn";
+pub const POLICY_BLOCK: &str = "block";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FindingKind {
+    WeiduLog,
+    OverrideNotEmpty,
+    DialogTlkModified,
+    BackupFiles,
+    InstallerState,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub kind: FindingKind,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameFingerprint {
+    pub path: PathBuf,
+    pub kind: Option<GameKind>,
+    pub version: Option<String>,
+    pub findings: Vec<Finding>,
+}
+
+impl GameFingerprint {
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.findings.is_empty()
+    }
+}
+
+fn file_names(dir: &Path) -> Vec<String> {
+    fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .collect()
+}
+
+fn check_override(dir: &Path, findings: &mut Vec<Finding>) {
+    let extra = file_names(&dir.join("override")).len();
+    if extra > 0 {
+        findings.push(Finding {
+            kind: FindingKind::OverrideNotEmpty,
+            detail: format!("override/ holds {extra} file(s)"),
+        });
+    }
+}
+
+fn check_dialog_tlk(dir: &Path, findings: &mut Vec<Finding>) {
+    let chitin_mtime = fs::metadata(dir.join("chitin.key"))
+        .and_then(|m| m.modified())
+        .ok();
+    for lang in file_names(&dir.join("lang")) {
+        let Ok(meta) = fs::metadata(dir.join("lang").join(&lang).join("dialog.tlk")) else {
+            continue;
+        };
+        let modified = match (meta.modified().ok(), chitin_mtime) {
+            (Some(tlk), Some(chitin)) => tlk
+                .duration_since(chitin)
+                .is_ok_and(|newer| newer > TLK_MTIME_SLACK),
+            _ => false,
+        };
+        if modified {
+            findings.push(Finding {
+                kind: FindingKind::DialogTlkModified,
+                detail: format!("lang/{lang}/dialog.tlk was changed after the game was installed"),
+            });
+        }
+    }
+}
+
+fn check_leftovers(dir: &Path, findings: &mut Vec<Finding>) {
+    let root = file_names(dir);
+    let backups = root
+        .iter()
+        .chain(file_names(&dir.join("override")).iter())
+        .filter(|name| name.to_ascii_lowercase().ends_with(".bak"))
+        .count();
+    if backups > 0 {
+        findings.push(Finding {
+            kind: FindingKind::BackupFiles,
+            detail: format!("{backups} leftover *.bak file(s)"),
+        });
+    }
+    let installer: Vec<&String> = root
+        .iter()
+        .filter(|name| {
+            let lower = name.to_ascii_lowercase();
+            lower.starts_with("mod_installer")
+                || lower.ends_with(".debug")
+                || lower == crate::install_runtime::import_code_writer::IMPORT_CODE_FILENAME
+        })
+        .collect();
+    if !installer.is_empty() {
+        findings.push(Finding {
+            kind: FindingKind::InstallerState,
+            detail: format!(
+                "leftover installer state: {}",
+                installer
+                    .iter()
+                    .map(|s| s.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        });
+    }
+}
+
+#[must_use]
+pub fn fingerprint(dir: &Path) -> GameFingerprint {
+    let (kind, version) = identify_game_dir(dir).map_or((None, None), |(k, v)| (Some(k), v));
+    let mut findings = Vec::new();
+
+    if let Some(log) = file_names(dir)
+        .into_iter()
+        .find(|n| n.eq_ignore_ascii_case("weidu.log"))
+    {
+        let components = fs::read_to_string(dir.join(&log))
+            .map(|raw| {
+                raw.lines()
+                    .filter(|l| l.trim_start().starts_with('~'))
+                    .count()
+            })
+            .unwrap_or(0);
+        findings.push(Finding {
+            kind: FindingKind::WeiduLog,
+            detail: format!("{log} lists {components} installed component(s)"),
+        });
+    }
+    check_override(dir, &mut findings);
+    check_dialog_tlk(dir, &mut findings);
+    check_leftovers(dir, &mut findings);
+
+    GameFingerprint {
+        path: dir.to_path_buf(),
+        kind,
+        version,
+        findings,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CleanGamePolicy {
+    Off,
+    Warn,
+    Block,
+}
+
+impl CleanGamePolicy {
+    #[must_use]
+    pub fn from_setting(value: &str) -> Self {
+        match value.trim() {
+            POLICY_OFF => Self::Off,
+            POLICY_BLOCK => Self::Block,
+            _ => Self::Warn,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CleanGameReport {
+    pub policy: CleanGamePolicy,
+    pub games: Vec<(&'static str, GameFingerprint)>,
+}
+
+impl CleanGameReport {
+    fn messages(&self) -> Vec<String> {
+        self.games
+            .iter()
+            .flat_map(|(label, game)| {
+                game.findings
+                    .iter()
+                    .map(move |f| format!("{label}: {}", f.detail))
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn errors(&self) -> Vec<String> {
+        if self.policy == CleanGamePolicy::Block {
+            self.messages()
+        } else {
+            Vec::new()
+        }
+    }
+
+    #[must_use]
+    pub fn warnings(&self) -> Vec<String> {
+        if self.policy == CleanGamePolicy::Warn {
+            self.messages()
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[must_use]
+pub fn preinstall_report(s: &Step1State, resuming: bool) -> CleanGameReport {
+    let policy = match CleanGamePolicy::from_setting(&s.clean_game_policy) {
+        CleanGamePolicy::Block if resuming => CleanGamePolicy::Warn,
+        policy => policy,
+    };
+    let games = if policy == CleanGamePolicy::Off || s.imports_modlist() {
+        Vec::new()
+    } else {
+        let config = build_install_command_config(s);
+        install_game_directories(&config)
+            .into_iter()
+            .map(|(game, dir)| (source_label(game), Path::new(dir.trim())))
+            .filter(|(_, dir)| dir.join("chitin.key").is_file())
+            .map(|(label, dir)| (label, fingerprint(dir)))
+            .collect()
+    };
+    CleanGameReport { policy, games }
+}
+
+fn source_label(game: &str) -> &'static str {
+    if game == "BG2EE" {
+        "BG2EE source"
+    } else {
+        "BGEE source"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::SystemTime;
+
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    fn game_dir(label: &str) -> PathBuf {
+        let dir = temp_dir(&format!("game_fingerprint_{label}"));
+        fs::create_dir_all(dir.join("lang").join("en_US")).expect("mkdir");
+        fs::create_dir_all(dir.join("override")).expect("mkdir");
+        fs::write(dir.join("chitin.key"), "KEY V1  ").expect("write");
+        fs::write(dir.join("engine.lua"), "-- BG2EE\nversion = \"2.6.6.0\"\n").expect("write");
+        fs::write(
+            dir.join("lang").join("en_US").join("dialog.tlk"),
+            "TLK V1  ",
+        )
+        .expect("write");
+        dir
+    }
+
+    #[test]
+    fn vanilla_folder_is_clean() {
+        let dir = game_dir("clean");
+        let fp = fingerprint(&dir);
+        assert_eq!(fp.kind, Some(GameKind::Bg2ee));
+        assert_eq!(fp.version.as_deref(), Some("2.6.6.0"));
+        assert!(fp.findings.is_empty(), "{:?}", fp.findings);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn modded_folder_reports_each_problem() {
+        let dir = game_dir("modded");
+        fs::write(
+            dir.join("WeiDU.log"),
+            "// Log of Currently Installed WeiDU Mods\n~EET/EET.TP2~ #0 #0 // EET core\n",
+        )
+        .expect("write");
+        fs::write(dir.join("override").join("spwi101.spl"), "x").expect("write");
+        fs::write(dir.join("override").join("ar0602.are.bak"), "x").expect("write");
+        fs::write(dir.join("SETUP-EET.DEBUG"), "x").expect("write");
+        fs::File::options()
+            .write(true)
+            .open(dir.join("lang").join("en_US").join("dialog.tlk"))
+            .and_then(|tlk| tlk.set_modified(SystemTime::now() + TLK_MTIME_SLACK * 2))
+            .expect("touch");
+        let fp = fingerprint(&dir);
+        let kinds: Vec<FindingKind> = fp.findings.iter().map(|f| f.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                FindingKind::WeiduLog,
+                FindingKind::OverrideNotEmpty,
+                FindingKind::DialogTlkModified,
+                FindingKind::BackupFiles,
+                FindingKind::InstallerState,
+            ]
+        );
+        assert!(fp.findings[0].detail.contains("1 installed component"));
+        assert!(!fp.is_clean());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn policy_decides_between_errors_and_warnings() {
+        let dir = game_dir("policy");
+        fs::write(dir.join("weidu.log"), "").expect("write");
+        let mut step1 = Step1State {
+            game_install: "BG2EE".to_string(),
+            bg2ee_game_folder: dir.to_string_lossy().into_owned(),
+            ..Step1State::default()
+        };
+
+        let report = preinstall_report(&step1, false);
+        assert_eq!(report.policy, CleanGamePolicy::Warn);
+        assert!(report.errors().is_empty());
+        assert_eq!(report.warnings().len(), 1);
+
+        step1.clean_game_policy = POLICY_BLOCK.to_string();
+        let report = preinstall_report(&step1, false);
+        assert_eq!(report.errors().len(), 1);
+        assert!(report.errors()[0].starts_with("BG2EE source: weidu.log"));
+
+        let resumed = preinstall_report(&step1, true);
+        assert!(resumed.errors().is_empty());
+        assert_eq!(resumed.warnings(), report.errors());
+
+        step1.clean_game_policy = POLICY_OFF.to_string();
+        assert!(preinstall_report(&step1, false).games.is_empty());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
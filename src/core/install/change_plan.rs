@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use crate::install::uninstall_plan::{WeiduAction, WeiduStep};
+use crate::mods::component::Component;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeRoute {
+    UpToDate,
+    Append,
+    Partial,
+    Full,
+}
+
+impl ChangeRoute {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::UpToDate => "Up to date",
+            Self::Append => "Append",
+            Self::Partial => "Partial reinstall",
+            Self::Full => "Full reinstall",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangePlan {
+    pub route: ChangeRoute,
+    pub kept: usize,
+    pub uninstall: Vec<Component>,
+    pub install: Vec<Component>,
+    pub full_runs: usize,
+}
+
+impl ChangePlan {
+    #[must_use]
+    pub const fn full(full_runs: usize) -> Self {
+        Self {
+            route: ChangeRoute::Full,
+            kept: 0,
+            uninstall: Vec::new(),
+            install: Vec::new(),
+            full_runs,
+        }
+    }
+
+    #[must_use]
+    pub const fn weidu_runs(&self) -> usize {
+        match self.route {
+            ChangeRoute::Full => self.full_runs,
+            _ => self.uninstall.len() + self.install.len(),
+        }
+    }
+
+    #[must_use]
+    pub fn steps(&self) -> Vec<WeiduStep> {
+        let uninstall = self.uninstall.iter().map(|component| WeiduStep {
+            action: WeiduAction::Uninstall,
+            component: component.clone(),
+        });
+        let install = self.install.iter().map(|component| WeiduStep {
+            action: WeiduAction::Install,
+            component: component.clone(),
+        });
+        uninstall.chain(install).collect()
+    }
+
+    #[must_use]
+    pub fn summary(&self) -> String {
+        match self.route {
+            ChangeRoute::UpToDate => {
+                "The installed components already match the edited order.".to_string()
+            }
+            ChangeRoute::Append => format!(
+                "Install {} new component(s) after the {} already installed.",
+                self.install.len(),
+                self.kept
+            ),
+            ChangeRoute::Partial => format!(
+                "Keep the first {} component(s), uninstall {} and install {} from position {}.",
+                self.kept,
+                self.uninstall.len(),
+                self.install.len(),
+                self.kept + 1
+            ),
+            ChangeRoute::Full => format!(
+                "Clear the install and reinstall all {} component(s).",
+                self.full_runs
+            ),
+        }
+    }
+}
+
+fn inputs(component: &Component) -> Option<&str> {
+    component
+        .wlb_inputs
+        .as_deref()
+        .map(str::trim)
+        .filter(|inputs| !inputs.is_empty())
+}
+
+#[must_use]
+pub fn same_install(installed: &Component, planned: &Component) -> bool {
+    installed.key_eq(planned) && inputs(installed) == inputs(planned)
+}
+
+// weidu.log does not keep prompt answers; borrow them from the plan the
+// install was made from so a changed answer counts as a change.
+pub fn with_recorded_inputs(installed: &mut [Component], recorded: &[Component]) {
+    for component in installed {
+        if let Some(source) = recorded.iter().find(|r| r.key_eq(component)) {
+            component.wlb_inputs.clone_from(&source.wlb_inputs);
+        }
+    }
+}
+
+#[must_use]
+pub fn plan_changes(installed: &[Component], planned: &[Component]) -> ChangePlan {
+    let kept = installed
+        .iter()
+        .zip(planned)
+        .take_while(|(a, b)| same_install(a, b))
+        .count();
+    let route = if kept == installed.len() && kept == planned.len() {
+        ChangeRoute::UpToDate
+    } else if kept == installed.len() {
+        ChangeRoute::Append
+    } else {
+        let partial_runs = (installed.len() - kept) + (planned.len() - kept);
+        if kept == 0 || partial_runs >= planned.len() {
+            return ChangePlan::full(planned.len());
+        }
+        ChangeRoute::Partial
+    };
+    ChangePlan {
+        route,
+        kept,
+        uninstall: installed[kept..].iter().rev().cloned().collect(),
+        install: planned[kept..].to_vec(),
+        full_runs: planned.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::component;
+
+    #[test]
+    fn picks_the_cheapest_route_for_the_edit() {
+        let installed: Vec<Component> = [
+            "~EET/EET.TP2~ #0 #0 // EET core: v14.0",
+            "~ITEM_REV/ITEM_REV.TP2~ #0 #0 // Item Revisions: v8",
+            "~SCS/SETUP-SCS.TP2~ #0 #1000 // Ease of use: v35 // @wlb-inputs: 1",
+            "~SCS/SETUP-SCS.TP2~ #0 #4000 // Smarter mages: v35",
+            "~EET_END/EET_END.TP2~ #0 #0 // EET end: v14.0",
+        ]
+        .map(component)
+        .to_vec();
+
+        assert_eq!(
+            plan_changes(&installed, &installed).route,
+            ChangeRoute::UpToDate
+        );
+
+        let mut appended = installed.clone();
+        appended.push(component(
+            "~CDTWEAKS/SETUP-CDTWEAKS.TP2~ #0 #10 // Tweak: v16",
+        ));
+        let plan = plan_changes(&installed, &appended);
+        assert_eq!(plan.route, ChangeRoute::Append);
+        assert_eq!((plan.kept, plan.weidu_runs()), (5, 1));
+
+        let mut answered = installed.clone();
+        answered[3] =
+            component("~SCS/SETUP-SCS.TP2~ #0 #4000 // Smarter mages: v35 // @wlb-inputs: 2");
+        let plan = plan_changes(&installed, &answered);
+        assert_eq!(plan.route, ChangeRoute::Partial);
+        assert_eq!(plan.kept, 3);
+        assert_eq!(
+            plan.uninstall,
+            vec![installed[4].clone(), installed[3].clone()]
+        );
+        assert_eq!(plan.install, answered[3..].to_vec());
+        assert_eq!(plan.steps()[0].action, WeiduAction::Uninstall);
+        assert!(plan.steps().iter().all(|step| {
+            step.args("en_US", "autolog")
+                .contains(&"--autolog".to_string())
+        }));
+
+        let mut early = installed.clone();
+        early[1] = component("~ITEM_REV/ITEM_REV.TP2~ #0 #1 // Other: v8");
+        assert_eq!(plan_changes(&installed, &early).route, ChangeRoute::Full);
+
+        let mut from_log = installed.clone();
+        from_log[2].wlb_inputs = None;
+        with_recorded_inputs(&mut from_log, &installed);
+        assert_eq!(from_log, installed);
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::mods::component::Component;
+use crate::platform_defaults::{app_config_file, compose_component_key};
+
+const COMPONENT_POLICIES_USER_FILE_NAME: &str = "component_policies_user.toml";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailureAction {
+    #[default]
+    Stop,
+    Continue,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct ComponentPolicy {
+    pub timeout_secs: Option<usize>,
+    pub retries: u32,
+    pub on_failure: FailureAction,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PolicyFile {
+    default: Option<ComponentPolicy>,
+    components: BTreeMap<String, ComponentPolicy>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComponentPolicies {
+    pub default: ComponentPolicy,
+    pub components: HashMap<String, ComponentPolicy>,
+}
+
+const fn default_policies_content() -> &'static str {
+    include_str!("../config/default_component_policies.toml")
+}
+
+#[must_use]
+pub fn user_policies_path() -> PathBuf {
+    app_config_file(COMPONENT_POLICIES_USER_FILE_NAME, "config")
+}
+
+impl ComponentPolicies {
+    #[must_use]
+    pub fn load() -> Self {
+        let mut policies = Self::default();
+        policies.merge_toml(default_policies_content());
+        if let Ok(raw) = fs::read_to_string(user_policies_path()) {
+            policies.merge_toml(&raw);
+        }
+        policies
+    }
+
+    fn merge_toml(&mut self, raw: &str) {
+        let Ok(file) = toml::from_str::<PolicyFile>(raw) else {
+            return;
+        };
+        if let Some(default) = file.default {
+            self.default = default;
+        }
+        for (key, policy) in file.components {
+            let Some((tp_file, component)) = key.rsplit_once('#') else {
+                continue;
+            };
+            self.components
+                .insert(compose_component_key(tp_file, component), policy);
+        }
+    }
+
+    #[must_use]
+    pub fn for_component(&self, component: &Component) -> &ComponentPolicy {
+        self.components
+            .get(&compose_component_key(
+                &component.tp_file,
+                &component.component,
+            ))
+            .unwrap_or(&self.default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_defaults_stop_without_retries() {
+        let mut policies = ComponentPolicies::default();
+        policies.merge_toml(default_policies_content());
+        assert_eq!(policies.default, ComponentPolicy::default());
+        assert!(policies.components.is_empty());
+    }
+
+    #[test]
+    fn component_override_replaces_default() {
+        let mut policies = ComponentPolicies::default();
+        policies.merge_toml(
+            r#"
+[default]
+retries = 1
+
+[components."scs/setup-scs.tp2#4000"]
+timeout_secs = 7200
+on_failure = "continue"
+"#,
+        );
+        let scs = Component::parse_weidu_line("~SCS/SETUP-SCS.TP2~ #0 #4000 // Smarter mages")
+            .expect("fixture line");
+        let other =
+            Component::parse_weidu_line("~EET/EET.TP2~ #0 #0 // EET core").expect("fixture line");
+        let policy = policies.for_component(&scs);
+        assert_eq!(policy.timeout_secs, Some(7200));
+        assert_eq!(policy.on_failure, FailureAction::Continue);
+        assert_eq!(policy.retries, 0);
+        assert_eq!(policies.for_component(&other).retries, 1);
+    }
+}
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    ParseError,
+    MissingFile,
+    RequireFailed,
+    UserAborted,
+    Timeout,
+    Other,
+}
+
+impl FailureKind {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::ParseError => "WeiDU parse error",
+            Self::MissingFile => "missing file",
+            Self::RequireFailed => "REQUIRE failed",
+            Self::UserAborted => "aborted by user",
+            Self::Timeout => "timed out",
+            Self::Other => "install error",
+        }
+    }
+
+    #[must_use]
+    pub const fn is_retryable(self) -> bool {
+        matches!(self, Self::Timeout | Self::Other)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentFailure {
+    pub component_key: String,
+    pub label: String,
+    pub kind: FailureKind,
+    pub attempts: u32,
+    pub error_block: String,
+}
+
+#[must_use]
+pub fn failure_label(tp_file: &str, component: &str, component_name: Option<&str>) -> String {
+    match component_name
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+    {
+        Some(name) => format!("{tp_file} #{component} {name}"),
+        None => format!("{tp_file} #{component}"),
+    }
+}
+
+#[must_use]
+pub fn classify_failure(output: &str) -> FailureKind {
+    let upper = output.to_ascii_uppercase();
+    let has = |needles: &[&str]| needles.iter().any(|needle| upper.contains(needle));
+    if has(&["TIMED OUT", "TIMEOUT EXCEEDED"]) {
+        FailureKind::Timeout
+    } else if has(&[
+        "ABORTED BY USER",
+        "USER ABORTED",
+        "FORCE TERMINATE REQUESTED",
+        "GRACEFUL TERMINATE REQUESTED",
+    ]) {
+        FailureKind::UserAborted
+    } else if has(&[
+        "PARSE ERROR",
+        "PARSING ERROR",
+        "ERROR PARSING",
+        "LEXER ERROR",
+    ]) {
+        FailureKind::ParseError
+    } else if has(&[
+        "REQUIRE_PREDICATE",
+        "REQUIRE_COMPONENT",
+        "REQUIRE_FILE",
+        "FORBID_COMPONENT",
+        "REQUIRE_INSTALLED",
+    ]) {
+        FailureKind::RequireFailed
+    } else if has(&[
+        "FILE NOT FOUND",
+        "NO SUCH FILE",
+        "NOT FOUND IN",
+        "CANNOT OPEN",
+        "COULD NOT OPEN",
+        "UNABLE TO FIND",
+    ]) {
+        FailureKind::MissingFile
+    } else {
+        FailureKind::Other
+    }
+}
+
+#[must_use]
+pub fn summarize_failures(failures: &[ComponentFailure]) -> String {
+    failures
+        .iter()
+        .map(|failure| format!("{} ({})", failure.label, failure.kind.label()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_common_weidu_failures() {
+        let cases = [
+            (
+                "[SETUP-X.TP2] PARSE ERROR at line 12 column 3-9",
+                FailureKind::ParseError,
+            ),
+            (
+                "Installing [Core]\nERROR: REQUIRE_PREDICATE failed: requires BG2EE",
+                FailureKind::RequireFailed,
+            ),
+            (
+                "ERROR: [foo.bam] -- file not found\nNOT INSTALLED DUE TO ERRORS",
+                FailureKind::MissingFile,
+            ),
+            (
+                "weidu timed out after 3600 seconds for scs 4000",
+                FailureKind::Timeout,
+            ),
+            (
+                "=== FORCE TERMINATE REQUESTED ===",
+                FailureKind::UserAborted,
+            ),
+            ("ERROR INSTALLING [Something]", FailureKind::Other),
+        ];
+        for (output, expected) in cases {
+            assert_eq!(classify_failure(output), expected, "{output}");
+        }
+    }
+
+    #[test]
+    fn labels_skip_a_missing_component_name() {
+        assert_eq!(
+            failure_label("SETUP-B.TP2", "10", Some("Tweaks")),
+            "SETUP-B.TP2 #10 Tweaks"
+        );
+        assert_eq!(
+            failure_label("SETUP-B.TP2", "10", Some(" ")),
+            "SETUP-B.TP2 #10"
+        );
+        assert_eq!(failure_label("SETUP-B.TP2", "10", None), "SETUP-B.TP2 #10");
+    }
+
+    #[test]
+    fn require_file_is_not_reported_as_missing_file() {
+        let output = "REQUIRE_FILE ~override/foo.itm~ failed: file not found";
+        assert_eq!(classify_failure(output), FailureKind::RequireFailed);
+        assert!(!FailureKind::RequireFailed.is_retryable());
+        assert!(FailureKind::Timeout.is_retryable());
+    }
+}
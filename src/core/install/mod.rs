@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Copyright (c) 2026 Born2BSalty
 
+pub mod change_plan;
+pub mod component_policy;
+pub mod failure_class;
 pub mod plan;
 pub mod runner;
 pub mod step5_command_common_args;
@@ -8,5 +11,6 @@ pub mod step5_command_config;
 pub mod step5_command_install;
 pub mod step5_command_log_paths;
 pub mod step5_command_resume;
+pub mod uninstall_plan;
 pub mod weidu_exec;
 pub mod weidu_scan;
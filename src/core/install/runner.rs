@@ -3,13 +3,18 @@
 
 use std::path::{Path, PathBuf};
 
-use anyhow::{Result, anyhow};
-use tracing::info;
+use anyhow::{Result, anyhow, bail};
+use tracing::{info, warn};
 
 use crate::config::options::CoreOptions;
+use crate::install::component_policy::{ComponentPolicies, FailureAction};
+use crate::install::failure_class::{
+    ComponentFailure, classify_failure, failure_label, summarize_failures,
+};
 use crate::install::plan::InstallPlan;
 use crate::install::weidu_exec;
 use crate::mods::discovery::DiscoveryIndex;
+use crate::platform_defaults::compose_component_key;
 
 pub fn check_missing_mod_folders(
     mods_dir: &Path,
@@ -39,7 +44,20 @@ pub fn check_missing_mod_folders(
     Ok(resolved)
 }
 
-pub fn run_plan(plan: &InstallPlan, options: &CoreOptions, game_directory: &Path) -> Result<()> {
+pub fn run_plan(
+    plan: &InstallPlan,
+    options: &CoreOptions,
+    game_directory: &Path,
+) -> Result<Vec<ComponentFailure>> {
+    run_plan_with(plan, options, game_directory, &ComponentPolicies::load())
+}
+
+pub fn run_plan_with(
+    plan: &InstallPlan,
+    options: &CoreOptions,
+    game_directory: &Path,
+    policies: &ComponentPolicies,
+) -> Result<Vec<ComponentFailure>> {
     let resolved_paths =
         check_missing_mod_folders(&options.mod_directories, options.depth, &plan.components)?;
     info!(
@@ -47,6 +65,7 @@ pub fn run_plan(plan: &InstallPlan, options: &CoreOptions, game_directory: &Path
         plan.components.len()
     );
 
+    let mut failures = Vec::new();
     for (component, source_folder) in plan.components.iter().zip(resolved_paths) {
         info!(
             "preflight found mod folder: name={} tp_file={} folder={}",
@@ -60,20 +79,88 @@ pub fn run_plan(plan: &InstallPlan, options: &CoreOptions, game_directory: &Path
             &component.name,
             options.overwrite,
         )?;
-        info!(
-            "install executing component: name={} component={} tp_file={}",
-            component.name, component.component, component.tp_file
-        );
-        weidu_exec::execute_component(game_directory, &mod_folder_in_game, component, options)?;
-        info!(
-            "install completed component: name={} component={}",
-            component.name, component.component
-        );
+        let policy = policies.for_component(component);
+        let mut attempt_options = options.clone();
+        if let Some(timeout) = policy.timeout_secs {
+            attempt_options.timeout = timeout;
+        }
+        let mut attempts = 0u32;
+        let outcome = loop {
+            attempts += 1;
+            info!(
+                "install executing component: name={} component={} tp_file={} attempt={attempts}",
+                component.name, component.component, component.tp_file
+            );
+            let Err(err) = weidu_exec::execute_component(
+                game_directory,
+                &mod_folder_in_game,
+                component,
+                &attempt_options,
+            ) else {
+                break None;
+            };
+            let error_block = format!("{err:#}");
+            let kind = classify_failure(&error_block);
+            if kind.is_retryable() && attempts <= policy.retries {
+                warn!(
+                    "install attempt {attempts} failed ({}) for name={} component={}; retrying",
+                    kind.label(),
+                    component.name,
+                    component.component
+                );
+                continue;
+            }
+            break Some(ComponentFailure {
+                component_key: compose_component_key(&component.tp_file, &component.component),
+                label: failure_label(
+                    &component.tp_file,
+                    &component.component,
+                    Some(&component.component_name),
+                ),
+                kind,
+                attempts,
+                error_block,
+            });
+        };
+        match (outcome, policy.on_failure) {
+            (None, _) => info!(
+                "install completed component: name={} component={}",
+                component.name, component.component
+            ),
+            (Some(failure), FailureAction::Stop) => {
+                return Err(anyhow!(
+                    "{} failed ({}) after {} attempt(s)\n{}",
+                    failure.label,
+                    failure.kind.label(),
+                    failure.attempts,
+                    failure.error_block
+                ));
+            }
+            (Some(failure), FailureAction::Continue) => {
+                warn!(
+                    "install failed ({}) for {}; continuing per component policy",
+                    failure.kind.label(),
+                    failure.label
+                );
+                failures.push(failure);
+            }
+        }
     }
-    Ok(())
+    Ok(failures)
 }
 
-fn stage_mod_folder(
+pub fn ensure_no_failures(failures: &[ComponentFailure]) -> Result<()> {
+    if failures.is_empty() {
+        return Ok(());
+    }
+    bail!(
+        "{} component(s) failed: {}",
+        failures.len(),
+        summarize_failures(failures)
+    )
+}
+
+pub fn stage_mod_folder(
     game_directory: &Path,
     source_folder: &Path,
     mod_name: &str,
@@ -91,6 +178,16 @@ fn stage_mod_folder(
     Ok(target_folder)
 }
 
+pub fn restage_mod_folder(
+    game_directory: &Path,
+    source_folder: &Path,
+    mod_name: &str,
+) -> Result<PathBuf> {
+    let target_folder = game_directory.join(mod_name);
+    copy_dir_all(source_folder, &target_folder)?;
+    Ok(target_folder)
+}
+
 fn copy_dir_all(src: &Path, dst: &Path) -> Result<()> {
     std::fs::create_dir_all(dst)?;
     for entry in std::fs::read_dir(src)? {
@@ -37,6 +37,7 @@ pub(crate) fn append_common_args(config: &InstallCommandConfig, args: &mut Vec<S
         args.push("--depth".to_string());
         args.push(config.scan.depth.to_string());
     }
+    // component_policies_user.toml is CLI-only; mod_installer has no per-component timeout.
     if config.timing.per_mod_timeout {
         args.push("--timeout".to_string());
         args.push(config.timing.timeout.to_string());
@@ -5,23 +5,38 @@ use super::step5_command_common_args::{append_common_args, installer_program};
 use super::step5_command_config::InstallCommandConfig;
 use super::step5_command_log_paths::{resolve_bg2_log_file, resolve_bgee_log_file};
 
+#[must_use]
+pub(crate) fn install_game_directories(config: &InstallCommandConfig) -> Vec<(&'static str, &str)> {
+    match config.game_install.as_str() {
+        "EET" => vec![
+            (
+                "BGEE",
+                if config.directories.pre_eet_override && !config.bgee_game_folder.trim().is_empty()
+                {
+                    config.bgee_game_folder.trim()
+                } else {
+                    config.eet_bgee_game_folder.trim()
+                },
+            ),
+            (
+                "BG2EE",
+                if config.directories.eet_override && !config.bg2ee_game_folder.trim().is_empty() {
+                    config.bg2ee_game_folder.trim()
+                } else {
+                    config.eet_bg2ee_game_folder.trim()
+                },
+            ),
+        ],
+        "BG2EE" => vec![("BG2EE", config.bg2ee_game_folder.as_str())],
+        _ => vec![("BGEE", config.bgee_game_folder.as_str())],
+    }
+}
+
 #[must_use]
 pub(crate) fn build_install_invocation(config: &InstallCommandConfig) -> (String, Vec<String>) {
     let mut args: Vec<String> = Vec::new();
     let installer = installer_program(config);
-    if config.game_install == "EET" {
-        let bg1_source =
-            if config.directories.pre_eet_override && !config.bgee_game_folder.trim().is_empty() {
-                config.bgee_game_folder.trim()
-            } else {
-                config.eet_bgee_game_folder.trim()
-            };
-        let bg2_source =
-            if config.directories.eet_override && !config.bg2ee_game_folder.trim().is_empty() {
-                config.bg2ee_game_folder.trim()
-            } else {
-                config.eet_bg2ee_game_folder.trim()
-            };
+    if let [(_, bg1_source), (_, bg2_source)] = install_game_directories(config)[..] {
         args.push("eet".to_string());
         args.push("--bg1-game-directory".to_string());
         args.push(bg1_source.to_string());
@@ -42,12 +57,11 @@ pub(crate) fn build_install_invocation(config: &InstallCommandConfig) -> (String
     } else {
         args.push("normal".to_string());
         args.push("--game-directory".to_string());
-        let game_dir = if config.game_install == "BG2EE" {
-            &config.bg2ee_game_folder
-        } else {
-            &config.bgee_game_folder
-        };
-        args.push(game_dir.clone());
+        args.extend(
+            install_game_directories(config)
+                .into_iter()
+                .map(|(_, dir)| dir.to_string()),
+        );
         args.push("--log-file".to_string());
         let log_file = if config.game_install == "BG2EE" {
             resolve_bg2_log_file(config)
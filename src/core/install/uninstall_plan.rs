@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::path::Path;
+
+use crate::install::weidu_exec;
+use crate::mods::component::Component;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeiduAction {
+    Uninstall,
+    Install,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeiduStep {
+    pub action: WeiduAction,
+    pub component: Component,
+}
+
+impl WeiduStep {
+    #[must_use]
+    pub fn label(&self) -> String {
+        let verb = match self.action {
+            WeiduAction::Uninstall => "uninstall",
+            WeiduAction::Install => "install",
+        };
+        format!("{verb} {}", component_label(&self.component))
+    }
+
+    #[must_use]
+    pub fn args(&self, game_language: &str, weidu_log_mode: &str) -> Vec<String> {
+        let flag = match self.action {
+            WeiduAction::Uninstall => "--force-uninstall",
+            WeiduAction::Install => "--force-install",
+        };
+        let tp2_path = Path::new(&self.component.name).join(&self.component.tp_file);
+        let mut args =
+            weidu_exec::component_args(&tp2_path, flag, &self.component, game_language.trim());
+        args.extend(weidu_exec::log_mode_args(weidu_log_mode));
+        args
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UninstallPlan {
+    pub removed: Vec<Component>,
+    pub uninstall: Vec<Component>,
+    pub reinstall: Vec<Component>,
+}
+
+impl UninstallPlan {
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.removed.is_empty()
+    }
+
+    #[must_use]
+    pub fn steps(&self) -> Vec<WeiduStep> {
+        let uninstall = self.uninstall.iter().map(|component| WeiduStep {
+            action: WeiduAction::Uninstall,
+            component: component.clone(),
+        });
+        let reinstall = self.reinstall.iter().map(|component| WeiduStep {
+            action: WeiduAction::Install,
+            component: component.clone(),
+        });
+        uninstall.chain(reinstall).collect()
+    }
+}
+
+#[must_use]
+pub fn component_label(component: &Component) -> String {
+    if component.component_name.is_empty() {
+        format!("{} #{}", component.tp_file, component.component)
+    } else {
+        format!(
+            "{} #{} {}",
+            component.tp_file, component.component, component.component_name
+        )
+    }
+}
+
+// WeiDU keeps installed components as a stack: removing one means peeling off
+// everything installed after it, then putting the survivors back in order.
+#[must_use]
+pub fn plan_uninstall(installed: &[Component], targets: &[usize]) -> UninstallPlan {
+    let Some(&first) = targets.iter().filter(|&&i| i < installed.len()).min() else {
+        return UninstallPlan::default();
+    };
+    let peeled = &installed[first..];
+    let is_target = |offset: usize| targets.contains(&(first + offset));
+    UninstallPlan {
+        removed: peeled
+            .iter()
+            .enumerate()
+            .filter(|(offset, _)| is_target(*offset))
+            .map(|(_, component)| component.clone())
+            .collect(),
+        uninstall: peeled.iter().rev().cloned().collect(),
+        reinstall: peeled
+            .iter()
+            .enumerate()
+            .filter(|(offset, _)| !is_target(*offset))
+            .map(|(_, component)| component.clone())
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::component;
+
+    #[test]
+    fn removing_a_middle_component_peels_and_reinstalls_later_ones() {
+        let installed = vec![
+            component("~EET/EET.TP2~ #0 #0 // EET core: v14.0"),
+            component("~STRATAGEMS/SETUP-STRATAGEMS.TP2~ #0 #1000 // Ease of use: v35"),
+            component("~ITEM_REV/ITEM_REV.TP2~ #0 #0 // Item Revisions: v8"),
+            component("~STRATAGEMS/SETUP-STRATAGEMS.TP2~ #0 #4000 // Smarter mages: v35"),
+        ];
+        let plan = plan_uninstall(&installed, &[1, 3]);
+        assert_eq!(
+            plan.removed,
+            vec![installed[1].clone(), installed[3].clone()]
+        );
+        assert_eq!(
+            plan.uninstall,
+            vec![
+                installed[3].clone(),
+                installed[2].clone(),
+                installed[1].clone()
+            ]
+        );
+        assert_eq!(plan.reinstall, vec![installed[2].clone()]);
+
+        let steps = plan.steps();
+        assert_eq!(steps.len(), 4);
+        assert_eq!(steps[0].action, WeiduAction::Uninstall);
+        assert_eq!(
+            steps[3].args("en_US", "")[..3],
+            [
+                "ITEM_REV/ITEM_REV.TP2".to_string(),
+                "--force-install".to_string(),
+                "0".to_string()
+            ]
+        );
+        assert!(plan_uninstall(&installed, &[]).is_empty());
+    }
+
+    #[test]
+    fn step_args_carry_the_weidu_log_flags() {
+        let step = WeiduStep {
+            action: WeiduAction::Uninstall,
+            component: component("~ITEM_REV/ITEM_REV.TP2~ #0 #0 // Item Revisions: v8"),
+        };
+        let args = step.args("en_US", "autolog,logapp,log-extern,log C:/BIO/logs");
+        assert_eq!(
+            args[args.len() - 5..],
+            [
+                "--autolog",
+                "--logapp",
+                "--log-extern",
+                "--log",
+                "C:/BIO/logs"
+            ]
+            .map(String::from)
+        );
+        assert!(
+            step.args("en_US", "")
+                .ends_with(&["--no-exit-pause".to_string()])
+        );
+    }
+}
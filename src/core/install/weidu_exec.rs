@@ -62,19 +62,35 @@ pub fn execute_component(
     }
 }
 
-fn build_args(tp2_path: &Path, component: &Component, options: &CoreOptions) -> Vec<String> {
-    let mut args = vec![
+#[must_use]
+pub fn component_args(
+    tp2_path: &Path,
+    action_flag: &str,
+    component: &Component,
+    game_language: &str,
+) -> Vec<String> {
+    vec![
         normalize_path(tp2_path),
-        "--force-install".to_string(),
+        action_flag.to_string(),
         component.component.clone(),
         "--use-lang".to_string(),
-        options.language.clone(),
+        game_language.to_string(),
         "--language".to_string(),
         component.lang.clone(),
         "--no-exit-pause".to_string(),
-    ];
-    for token in options
-        .weidu_log_mode
+    ]
+}
+
+fn build_args(tp2_path: &Path, component: &Component, options: &CoreOptions) -> Vec<String> {
+    let mut args = component_args(tp2_path, "--force-install", component, &options.language);
+    args.extend(log_mode_args(&options.weidu_log_mode));
+    args
+}
+
+#[must_use]
+pub fn log_mode_args(weidu_log_mode: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    for token in weidu_log_mode
         .split(',')
         .map(str::trim)
         .filter(|v| !v.is_empty())
@@ -9,6 +9,7 @@ use super::PromptSummaryIndex;
 mod map_to_bio;
 mod model;
 mod runner;
+pub mod source_tokens;
 
 #[must_use]
 pub fn collect_prompt_summary_index(
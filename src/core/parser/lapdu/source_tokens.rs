@@ -0,0 +1,349 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+const TP2_KEYWORDS_GRAMMAR: &str = include_str!(
+    "../../../../vendor/lapdu-parser-rust-master/antlr/lapdu-parser/src/main/antlr4/imports/TP2Keywords.g4"
+);
+const LONG_TILDE: &str = "~~~~~";
+const WORD_PUNCTUATION: &str = "_.#$/\\:-'";
+const OPERATOR_CHARS: &str = "=<>!+-*/|&^";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Word,
+    TraRef,
+    TildeString,
+    LongTildeString,
+    QuoteString,
+    PercentString,
+    SoundString,
+    UnterminatedString,
+    UnterminatedComment,
+    Eq,
+    Symbol,
+}
+
+/// One lexed TP2/TRA token. Whitespace and comments are not tokens, so they
+/// only show up as gaps. `start`/`end` are byte offsets, `line` is 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceToken {
+    pub kind: TokenKind,
+    pub start: usize,
+    pub end: usize,
+    pub line: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ComponentSpan {
+    id: String,
+    start_line: u32,
+    end_line: u32,
+}
+
+#[must_use]
+pub fn lex(text: &str) -> Vec<SourceToken> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    let mut line = 1u32;
+    while pos < bytes.len() {
+        let rest = &text[pos..];
+        let ch = rest.chars().next().unwrap_or_default();
+        if ch.is_whitespace() {
+            if ch == '\n' {
+                line += 1;
+            }
+            pos += ch.len_utf8();
+            continue;
+        }
+        if rest.starts_with("//") {
+            pos += rest.find('\n').unwrap_or(rest.len());
+            continue;
+        }
+        let (kind, len) = if let Some(comment) = rest.strip_prefix("/*") {
+            match comment.find("*/") {
+                Some(close) => {
+                    line += count_lines(&rest[..close + 4]);
+                    pos += close + 4;
+                    continue;
+                }
+                None => (TokenKind::UnterminatedComment, rest.len()),
+            }
+        } else {
+            scan_token(rest, ch)
+        };
+        tokens.push(SourceToken {
+            kind,
+            start: pos,
+            end: pos + len,
+            line,
+        });
+        line += count_lines(&rest[..len]);
+        pos += len;
+    }
+    tokens
+}
+
+fn scan_token(rest: &str, ch: char) -> (TokenKind, usize) {
+    if let Some(body) = rest.strip_prefix(LONG_TILDE) {
+        return body
+            .find(LONG_TILDE)
+            .map_or((TokenKind::UnterminatedString, rest.len()), |close| {
+                (TokenKind::LongTildeString, close + 2 * LONG_TILDE.len())
+            });
+    }
+    let delimited = |close: char, same_line: bool| {
+        rest[1..]
+            .find(|c: char| c == close || (same_line && c == '\n'))
+            .filter(|&end| rest.as_bytes()[end + 1] != b'\n')
+            .map(|end| end + 2)
+    };
+    let closed = match ch {
+        '~' => delimited('~', false).map(|len| (TokenKind::TildeString, len)),
+        '"' => delimited('"', false).map(|len| (TokenKind::QuoteString, len)),
+        '%' => delimited('%', true).map(|len| (TokenKind::PercentString, len)),
+        '[' => delimited(']', true).map(|len| (TokenKind::SoundString, len)),
+        '@' => {
+            let digits = rest[1..].strip_prefix('-').map_or(&rest[1..], |tail| tail);
+            let count = digits.bytes().take_while(u8::is_ascii_digit).count();
+            (count > 0).then(|| (TokenKind::TraRef, rest.len() - digits.len() + count))
+        }
+        _ => None,
+    };
+    if let Some(token) = closed {
+        return token;
+    }
+    if is_word_char(ch) {
+        let len = rest.find(|c: char| !is_word_char(c)).unwrap_or(rest.len());
+        return (TokenKind::Word, len);
+    }
+    if OPERATOR_CHARS.contains(ch) {
+        let len = rest
+            .find(|c: char| !OPERATOR_CHARS.contains(c))
+            .unwrap_or(rest.len());
+        let kind = if &rest[..len] == "=" {
+            TokenKind::Eq
+        } else {
+            TokenKind::Symbol
+        };
+        return (kind, len);
+    }
+    (TokenKind::Symbol, ch.len_utf8())
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || WORD_PUNCTUATION.contains(ch)
+}
+
+fn count_lines(text: &str) -> u32 {
+    u32::try_from(text.matches('\n').count()).unwrap_or(u32::MAX)
+}
+
+#[must_use]
+pub fn token_text<'a>(text: &'a str, token: &SourceToken) -> &'a str {
+    &text[token.start..token.end]
+}
+
+/// Bare words the lapdu TP2 grammar declares as keywords.
+#[must_use]
+pub fn is_keyword(text: &str, token: &SourceToken) -> bool {
+    token.kind == TokenKind::Word && keywords().contains(token_text(text, token))
+}
+
+fn keywords() -> &'static HashSet<&'static str> {
+    static KEYWORDS: OnceLock<HashSet<&'static str>> = OnceLock::new();
+    KEYWORDS.get_or_init(|| {
+        TP2_KEYWORDS_GRAMMAR
+            .split('\'')
+            .skip(1)
+            .step_by(2)
+            .filter(|literal| !literal.is_empty())
+            .collect()
+    })
+}
+
+#[must_use]
+pub fn is_word(text: &str, token: &SourceToken, word: &str) -> bool {
+    token.kind == TokenKind::Word && token_text(text, token) == word
+}
+
+#[must_use]
+pub fn string_value<'a>(text: &'a str, token: &SourceToken) -> Option<&'a str> {
+    let delim = match token.kind {
+        TokenKind::LongTildeString => LONG_TILDE.len(),
+        TokenKind::TildeString | TokenKind::QuoteString | TokenKind::PercentString => 1,
+        _ => return None,
+    };
+    let raw = token_text(text, token);
+    raw.get(delim..raw.len().checked_sub(delim)?)
+}
+
+#[must_use]
+pub fn argument<'a>(text: &'a str, token: &SourceToken) -> Option<&'a str> {
+    if token.kind == TokenKind::Word && !is_keyword(text, token) {
+        Some(token_text(text, token))
+    } else {
+        string_value(text, token)
+    }
+}
+
+#[must_use]
+pub fn keyword_arguments<'a>(
+    text: &'a str,
+    tokens: &[SourceToken],
+    keyword: &str,
+) -> Vec<(u32, Vec<&'a str>)> {
+    tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| is_word(text, token, keyword))
+        .map(|(index, token)| {
+            let args = tokens[index + 1..]
+                .iter()
+                .map_while(|arg| argument(text, arg))
+                .collect();
+            (token.line, args)
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn tokens_in_lines(tokens: &[SourceToken], start: usize, end: usize) -> &[SourceToken] {
+    let line = |token: &SourceToken| usize::try_from(token.line).unwrap_or(usize::MAX);
+    let first = tokens.partition_point(|token| line(token) <= start);
+    let last = tokens.partition_point(|token| line(token) <= end);
+    &tokens[first..last.max(first)]
+}
+
+/// Zero-based `[start, end)` lines of a component block, including the
+/// comment header between the previous statement and its `BEGIN`.
+#[must_use]
+pub fn component_line_range(
+    text: &str,
+    tokens: &[SourceToken],
+    component_id: &str,
+) -> Option<(usize, usize)> {
+    let wanted = canonical_id(component_id);
+    let spans = component_spans(text, tokens);
+    let span = spans.iter().find(|span| span.id == wanted)?;
+    let begin = usize::try_from(span.start_line).ok()?.checked_sub(1)?;
+    let end_line = usize::try_from(span.end_line).ok()?;
+
+    let lines: Vec<&str> = text.lines().collect();
+    let header = tokens
+        .iter()
+        .take_while(|token| token.line < span.start_line)
+        .last()
+        .map_or(0, |token| line_of(text, token.end) + 1);
+    let start = (header..begin)
+        .find(|&index| lines.get(index).is_some_and(|line| !line.trim().is_empty()))
+        .unwrap_or(begin);
+    let end = tokens_in_lines(tokens, begin, end_line)
+        .last()
+        .map_or(end_line, |token| line_of(text, token.end) + 1);
+    Some((start, end.min(lines.len())))
+}
+
+/// A component starts at a `BEGIN` followed by its name; block `BEGIN`s are
+/// followed by actions instead. Ids are the DESIGNATED number, else the
+/// component's position in the file.
+fn component_spans(text: &str, tokens: &[SourceToken]) -> Vec<ComponentSpan> {
+    let starts: Vec<usize> = tokens
+        .windows(2)
+        .enumerate()
+        .filter(|(_, pair)| {
+            is_word(text, &pair[0], "BEGIN")
+                && (pair[1].kind == TokenKind::TraRef || string_value(text, &pair[1]).is_some())
+        })
+        .map(|(index, _)| index)
+        .collect();
+    starts
+        .iter()
+        .enumerate()
+        .map(|(position, &start)| {
+            let end = starts.get(position + 1).copied().unwrap_or(tokens.len());
+            let block = &tokens[start..end];
+            let designated = block
+                .windows(2)
+                .find(|pair| is_word(text, &pair[0], "DESIGNATED"))
+                .map(|pair| token_text(text, &pair[1]));
+            ComponentSpan {
+                id: designated.map_or_else(|| position.to_string(), canonical_id),
+                start_line: block[0].line,
+                end_line: block.last().map_or(block[0].line, |token| token.line),
+            }
+        })
+        .collect()
+}
+
+fn canonical_id(id: &str) -> String {
+    let id = id.trim();
+    match id.trim_start_matches('0') {
+        "" if !id.is_empty() => "0".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+fn line_of(text: &str, offset: usize) -> usize {
+    text[..offset.saturating_sub(1)].matches('\n').count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TP2: &str = "BACKUP ~m/backup~\nAUTHOR ~me~\n\
+        BEGIN @1 DESIGNATED 0\nCOPY_EXISTING ~a.itm~ ~override~\n\n\
+        /* second\n   component */\nBEGIN ~Second~\n\
+        REQUIRE_COMPONENT ~setup-other.tp2~ ~5~ @2\nPRINT ~multi\nline~\n\
+        ACTION_IF GAME_IS ~bg2ee~ BEGIN PRINT ~x~ END\n\
+        // trailing\nBEGIN @3 DESIGNATED 020\n";
+
+    #[test]
+    fn component_ranges_skip_block_begins() {
+        let tokens = lex(TP2);
+        assert_eq!(component_line_range(TP2, &tokens, "0"), Some((2, 4)));
+        assert_eq!(component_line_range(TP2, &tokens, "1"), Some((5, 12)));
+        assert_eq!(component_line_range(TP2, &tokens, "20"), Some((12, 14)));
+        assert_eq!(component_line_range(TP2, &tokens, "7"), None);
+    }
+
+    #[test]
+    fn keyword_arguments_unquote_strings_and_stop_at_the_next_token() {
+        let tokens = lex(TP2);
+        assert_eq!(
+            keyword_arguments(TP2, &tokens, "REQUIRE_COMPONENT"),
+            vec![(9, vec!["setup-other.tp2", "5"])]
+        );
+        assert_eq!(
+            keyword_arguments(TP2, &tokens, "PRINT"),
+            vec![(10, vec!["multi\nline"]), (12, vec!["x"])]
+        );
+        let component = tokens_in_lines(&tokens, 7, 12);
+        assert!(component.first().is_some_and(|t| is_word(TP2, t, "BEGIN")));
+        assert!(component.iter().all(|t| !is_word(TP2, t, "DESIGNATED")));
+    }
+
+    #[test]
+    fn lexes_tra_entries_and_unterminated_tails() {
+        let text = "@1 = ~~~~~has ~tilde~~~~~ [SND]\n@-2=\"q\" %v% /* open";
+        let kinds: Vec<_> = lex(text).iter().map(|t| t.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::TraRef,
+                TokenKind::Eq,
+                TokenKind::LongTildeString,
+                TokenKind::SoundString,
+                TokenKind::TraRef,
+                TokenKind::Eq,
+                TokenKind::QuoteString,
+                TokenKind::PercentString,
+                TokenKind::UnterminatedComment,
+            ]
+        );
+    }
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::mods::component::Component;
+
+static TEMP_DIRS: AtomicU64 = AtomicU64::new(0);
+
+/// Fresh, empty directory under the system temp dir, unique per call.
+pub(crate) fn temp_dir(label: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "bio_{label}_test_{}_{}",
+        std::process::id(),
+        TEMP_DIRS.fetch_add(1, Ordering::Relaxed)
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("create test temp dir");
+    dir
+}
+
+pub(crate) fn component(line: &str) -> Component {
+    Component::parse_weidu_line(line).expect("fixture line")
+}
@@ -0,0 +1,308 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::path::{Path, PathBuf};
+
+use tracing::warn;
+
+use crate::app::modlist_share::preview_modlist_share_code;
+use crate::app::state::{Step3ItemState, WizardState};
+use crate::app::step5::diagnostics::build_weidu_export_lines;
+use crate::install::change_plan::{ChangePlan, ChangeRoute, plan_changes, with_recorded_inputs};
+use crate::install::runner::restage_mod_folder;
+use crate::install_runtime::modlist_health::plan_from_log;
+use crate::install_runtime::per_install_dirs::{self, WEIDU_LOG_FILENAME};
+use crate::install_runtime::weidu_step_run::WeiduStepRun;
+use crate::install_runtime::{game_launcher, install_history_record};
+use crate::install_runtime::{registry_transition, settings_profiles_driver};
+use crate::mods::component::Component;
+use crate::mods::discovery::DiscoveryIndex;
+use crate::mods::log_file::LogFile;
+use crate::registry::install_history::InstallHistoryStore;
+use crate::registry::model::{Game, ModlistEntry, ModlistState};
+use crate::ui::install::state_install::DestChoice;
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::workspace::state_workspace::WorkspaceStep;
+
+pub struct ApplyChangesSession {
+    pub modlist_id: String,
+    pub game_dir: PathBuf,
+    pub plan: ChangePlan,
+    pub note: Option<String>,
+    pub estimate_secs: Option<u64>,
+    pub run: Option<WeiduStepRun>,
+}
+
+struct GameSide<'a> {
+    dir: PathBuf,
+    items: &'a [Step3ItemState],
+    recorded: Vec<Component>,
+}
+
+fn planned_from_items(items: &[Step3ItemState]) -> Vec<Component> {
+    plan_from_log(&build_weidu_export_lines(items).join("\n"))
+}
+
+fn installed_at(dir: &Path) -> Result<Vec<Component>, String> {
+    LogFile::from_path(&dir.join(WEIDU_LOG_FILENAME))
+        .map(|log| log.components().to_vec())
+        .map_err(|err| format!("{err:#}"))
+}
+
+fn side_plan(side: &GameSide<'_>) -> Result<ChangePlan, String> {
+    let mut installed = installed_at(&side.dir)?;
+    with_recorded_inputs(&mut installed, &side.recorded);
+    Ok(plan_changes(&installed, &planned_from_items(side.items)))
+}
+
+fn sides<'a>(entry: &ModlistEntry, state: &'a WizardState) -> Vec<GameSide<'a>> {
+    let recorded = entry
+        .latest_share_code
+        .as_deref()
+        .and_then(|code| preview_modlist_share_code(code).ok());
+    let recorded_bgee = recorded
+        .as_ref()
+        .map(|preview| plan_from_log(&preview.bgee_log_text))
+        .unwrap_or_default();
+    let recorded_bg2ee = recorded
+        .as_ref()
+        .map(|preview| plan_from_log(&preview.bg2ee_log_text))
+        .unwrap_or_default();
+    let dirs = per_install_dirs::resolve(&entry.destination_folder, entry.game);
+    match (entry.game, dirs.eet_clone_dirs) {
+        (Game::EET, Some((pre, _))) => vec![
+            GameSide {
+                dir: pre,
+                items: &state.step3.bgee_items,
+                recorded: recorded_bgee,
+            },
+            GameSide {
+                dir: game_launcher::game_dir(entry),
+                items: &state.step3.bg2ee_items,
+                recorded: recorded_bg2ee,
+            },
+        ],
+        (Game::BG2EE, _) => vec![GameSide {
+            dir: game_launcher::game_dir(entry),
+            items: &state.step3.bg2ee_items,
+            recorded: recorded_bg2ee,
+        }],
+        _ => vec![GameSide {
+            dir: game_launcher::game_dir(entry),
+            items: &state.step3.bgee_items,
+            recorded: recorded_bgee,
+        }],
+    }
+}
+
+// EET imports the BGEE install into BG2EE, so only the last game can be
+// patched in place; any change on the BGEE side means starting over.
+fn plan_for(
+    entry: &ModlistEntry,
+    state: &WizardState,
+) -> Result<(PathBuf, ChangePlan, Option<String>), String> {
+    let sides = sides(entry, state);
+    let total = sides
+        .iter()
+        .map(|side| planned_from_items(side.items).len())
+        .sum();
+    let (last, earlier) = sides.split_last().ok_or("no game folder to compare")?;
+    for side in earlier {
+        if side_plan(side)?.route != ChangeRoute::UpToDate {
+            return Ok((
+                last.dir.clone(),
+                ChangePlan::full(total),
+                Some("The BGEE side of this EET install changed.".to_string()),
+            ));
+        }
+    }
+    let mut plan = side_plan(last)?;
+    plan.full_runs = total;
+    Ok((last.dir.clone(), plan, None))
+}
+
+fn estimate_secs(modlist_id: &str, runs: usize) -> Option<u64> {
+    let per_component = InstallHistoryStore::new_for_id(modlist_id)
+        .load()
+        .ok()?
+        .seconds_per_component()?;
+    Some((per_component * runs as f64).round() as u64)
+}
+
+pub fn open(orchestrator: &mut OrchestratorApp) {
+    let modlist_id = orchestrator.workspace_view.modlist_id.clone();
+    let Some(entry) = orchestrator.registry.find(&modlist_id).cloned() else {
+        return;
+    };
+    if entry.state != ModlistState::Installed {
+        return;
+    }
+    if game_launcher::install_running_for(orchestrator, &modlist_id) {
+        orchestrator.notification_manager.warn(format!(
+            "\"{}\" is being installed \u{2014} wait for the install to finish",
+            entry.name
+        ));
+        return;
+    }
+    match plan_for(&entry, &orchestrator.wizard_state) {
+        Ok((game_dir, plan, note)) => {
+            let estimate_secs = estimate_secs(&modlist_id, plan.weidu_runs());
+            orchestrator.apply_changes = Some(ApplyChangesSession {
+                modlist_id,
+                game_dir,
+                plan,
+                note,
+                estimate_secs,
+                run: None,
+            });
+        }
+        Err(err) => orchestrator.notification_manager.error(format!(
+            "Couldn't compare \"{}\" with its installed weidu.log: {err}",
+            entry.name
+        )),
+    }
+}
+
+fn stage_mods(
+    step1_mods: &str,
+    depth: usize,
+    game_dir: &Path,
+    plan: &ChangePlan,
+) -> Result<(), String> {
+    let mut reinstalled: Vec<&Component> = Vec::new();
+    for component in &plan.install {
+        if !reinstalled
+            .iter()
+            .any(|seen| seen.name.eq_ignore_ascii_case(&component.name))
+        {
+            reinstalled.push(component);
+        }
+    }
+    if reinstalled.is_empty() {
+        return Ok(());
+    }
+    let index = DiscoveryIndex::build(Path::new(step1_mods.trim()), depth)
+        .map_err(|err| format!("{err:#}"))?;
+    for component in reinstalled {
+        let source = index.find_folder(component).ok_or_else(|| {
+            format!(
+                "{}/{} is not in the mods folder",
+                component.name, component.tp_file
+            )
+        })?;
+        restage_mod_folder(game_dir, source, &component.name).map_err(|err| format!("{err:#}"))?;
+    }
+    Ok(())
+}
+
+fn reinstall_in_workspace(orchestrator: &mut OrchestratorApp) {
+    let flags = DestChoice::Clear.to_flags();
+    let step1 = &mut orchestrator.wizard_state.step1;
+    step1.prepare_target_dirs_before_install = flags.prepare_target_dirs_before_install;
+    step1.backup_targets_before_eet_copy = flags.backup_targets_before_eet_copy;
+    let view = &mut orchestrator.workspace_view;
+    view.completed_steps.insert(WorkspaceStep::Step3);
+    view.current_step = WorkspaceStep::Step4;
+    view.prompt_dry_run = None;
+    orchestrator.apply_changes = None;
+    orchestrator
+        .notification_manager
+        .info("Review the order, then install to reinstall from a cleared folder".to_string());
+}
+
+pub fn start(orchestrator: &mut OrchestratorApp) {
+    let Some(session) = orchestrator.apply_changes.as_ref() else {
+        return;
+    };
+    match session.plan.route {
+        ChangeRoute::UpToDate => return,
+        ChangeRoute::Full => return reinstall_in_workspace(orchestrator),
+        ChangeRoute::Append | ChangeRoute::Partial => {}
+    }
+    let step1 = settings_profiles_driver::modlist_step1(orchestrator, &session.modlist_id);
+    let staged = stage_mods(
+        &step1.mods_folder,
+        step1.depth,
+        &session.game_dir,
+        &session.plan,
+    );
+    let run = staged
+        .and_then(|()| WeiduStepRun::start(&step1, session.game_dir.clone(), session.plan.steps()));
+    match run {
+        Ok(run) => {
+            if let Some(session) = orchestrator.apply_changes.as_mut() {
+                session.run = Some(run);
+            }
+        }
+        Err(err) => orchestrator
+            .notification_manager
+            .error(format!("Couldn't apply changes: {err}")),
+    }
+}
+
+pub fn tick(orchestrator: &mut OrchestratorApp) -> bool {
+    let Some(run) = orchestrator
+        .apply_changes
+        .as_mut()
+        .and_then(|session| session.run.as_mut())
+    else {
+        return false;
+    };
+    if !run.is_running() {
+        return false;
+    }
+    run.tick();
+    let Some(outcome) = run.outcome.clone() else {
+        return true;
+    };
+    let modlist_id = orchestrator
+        .apply_changes
+        .as_ref()
+        .map(|session| session.modlist_id.clone())
+        .unwrap_or_default();
+    if let Some(run) = orchestrator
+        .apply_changes
+        .as_ref()
+        .and_then(|session| session.run.as_ref())
+        && let Err(err) = install_history_record::record_step_run(&modlist_id, run)
+    {
+        warn!(
+            target = "orchestrator",
+            "install history record for apply changes failed: {err}"
+        );
+    }
+    match outcome {
+        Ok(()) => finish(orchestrator),
+        Err(err) => orchestrator
+            .notification_manager
+            .error(format!("Applying changes stopped: {err}")),
+    }
+    true
+}
+
+fn finish(orchestrator: &mut OrchestratorApp) {
+    let Some(session) = orchestrator.apply_changes.as_ref() else {
+        return;
+    };
+    let modlist_id = session.modlist_id.clone();
+    let (uninstalled, installed) = (session.plan.uninstall.len(), session.plan.install.len());
+    let OrchestratorApp {
+        registry,
+        registry_store,
+        wizard_state,
+        ..
+    } = &mut *orchestrator;
+    let rx = registry_transition::flip_to_installed(
+        &modlist_id,
+        registry,
+        registry_store,
+        wizard_state,
+        None,
+    );
+    if rx.is_some() {
+        orchestrator.install_size_worker_rx = rx;
+    }
+    orchestrator.notification_manager.success(format!(
+        "Applied changes in place: {uninstalled} uninstalled, {installed} installed"
+    ));
+}
@@ -58,6 +58,32 @@ pub fn hash_file(path: &Path) -> std::io::Result<String> {
     Ok(hasher.finish_hex())
 }
 
+pub fn hash_tree(root: &Path) -> std::io::Result<String> {
+    let mut files = Vec::new();
+    for entry in walkdir::WalkDir::new(root).follow_links(false) {
+        let entry = entry.map_err(std::io::Error::other)?;
+        if entry.file_type().is_file() {
+            let rel = entry
+                .path()
+                .strip_prefix(root)
+                .unwrap_or_else(|_| entry.path())
+                .to_string_lossy()
+                .replace('\\', "/")
+                .to_ascii_lowercase();
+            files.push((rel, entry.into_path()));
+        }
+    }
+    files.sort();
+    let mut hasher = Fnv1a128::new();
+    for (rel, path) in files {
+        hasher.update(rel.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(hash_file(&path)?.as_bytes());
+        hasher.update(b"\n");
+    }
+    Ok(hasher.finish_hex())
+}
+
 #[must_use]
 pub fn stored_filename(name: &str, hash: &str) -> String {
     match name.rsplit_once('.') {
@@ -378,6 +404,32 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn hash_tree_is_order_independent_and_content_sensitive() {
+        let a = td();
+        let b = td();
+        std::fs::create_dir_all(a.join("sub")).unwrap();
+        std::fs::create_dir_all(b.join("sub")).unwrap();
+        std::fs::write(a.join("setup-mod.tp2"), b"BACKUP ~mod/backup~").unwrap();
+        std::fs::write(a.join("sub/x.tra"), b"@1 = ~x~").unwrap();
+        std::fs::write(b.join("sub/x.tra"), b"@1 = ~x~").unwrap();
+        std::fs::write(b.join("setup-mod.tp2"), b"BACKUP ~mod/backup~").unwrap();
+        assert_eq!(hash_tree(&a).unwrap(), hash_tree(&b).unwrap());
+
+        std::fs::write(b.join("sub/x.tra"), b"@1 = ~y~").unwrap();
+        assert_ne!(hash_tree(&a).unwrap(), hash_tree(&b).unwrap());
+
+        std::fs::rename(b.join("sub/x.tra"), b.join("sub/z.tra")).unwrap();
+        std::fs::write(b.join("sub/z.tra"), b"@1 = ~x~").unwrap();
+        assert_ne!(
+            hash_tree(&a).unwrap(),
+            hash_tree(&b).unwrap(),
+            "a renamed file changes the tree hash"
+        );
+        let _ = std::fs::remove_dir_all(&a);
+        let _ = std::fs::remove_dir_all(&b);
+    }
+
     #[test]
     fn stored_filename_encodes_hash_so_versions_coexist() {
         assert_eq!(
@@ -0,0 +1,467 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::app::state::Step1State;
+use crate::install_runtime::per_install_dirs;
+use crate::install_runtime::registry_transition::directory_size_bytes;
+use crate::registry::model::Game;
+
+const EXTRACT_RATIO_NUM: u64 = 5;
+const EXTRACT_RATIO_DEN: u64 = 2;
+pub const HEADROOM_BYTES: u64 = 512 * 1024 * 1024;
+const CACHE_TTL: Duration = Duration::from_secs(60);
+const PROBE_FILE_NAME: &str = ".bio_preflight_Probe.tmp";
+const PROBE_LINK_NAME: &str = ".bio_preflight_link.tmp";
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PreflightInput {
+    pub destination: PathBuf,
+    pub archive_dir: Option<PathBuf>,
+    pub archive_bytes: u64,
+    pub clone_sources: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VolumeSpace {
+    pub mount: String,
+    pub available_bytes: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VolumeCheck {
+    pub mount: String,
+    pub purposes: Vec<&'static str>,
+    pub required_bytes: u64,
+    pub available_bytes: Option<u64>,
+}
+
+impl VolumeCheck {
+    #[must_use]
+    pub fn is_short(&self) -> bool {
+        self.available_bytes
+            .is_some_and(|available| available < self.required_bytes)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FsTraits {
+    pub case_sensitive: Option<bool>,
+    pub hardlinks: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiskPreflight {
+    pub archive_bytes: u64,
+    pub extracted_bytes: u64,
+    pub game_copy_bytes: u64,
+    pub volumes: Vec<VolumeCheck>,
+    pub fs: FsTraits,
+}
+
+impl DiskPreflight {
+    #[must_use]
+    pub fn is_blocking(&self) -> bool {
+        self.volumes.iter().any(VolumeCheck::is_short)
+    }
+
+    #[must_use]
+    pub fn block_reason(&self) -> Option<String> {
+        let short: Vec<String> = self
+            .volumes
+            .iter()
+            .filter(|volume| volume.is_short())
+            .map(|volume| {
+                format!(
+                    "{} needs {} MiB, {} MiB free",
+                    volume.mount,
+                    volume.required_bytes / (1024 * 1024),
+                    volume.available_bytes.unwrap_or(0) / (1024 * 1024)
+                )
+            })
+            .collect();
+        (!short.is_empty()).then(|| format!("not enough free disk space: {}", short.join("; ")))
+    }
+
+    #[must_use]
+    pub fn warnings(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        if self.fs.case_sensitive == Some(true) {
+            out.push(
+                "destination is on a case-sensitive filesystem; mods that mix file-name case \
+                 may fail to find their files"
+                    .to_string(),
+            );
+        }
+        if self.fs.hardlinks == Some(false) {
+            out.push(
+                "destination does not support hardlinks; cached archives will be copied"
+                    .to_string(),
+            );
+        }
+        for volume in self.volumes.iter().filter(|v| v.available_bytes.is_none()) {
+            out.push(format!("couldn't read free space for {}", volume.mount));
+        }
+        out
+    }
+}
+
+#[must_use]
+pub fn input_for_install(
+    step1: &Step1State,
+    destination: &str,
+    game: Game,
+    archive_bytes: u64,
+) -> PreflightInput {
+    let clone_sources = match game {
+        Game::EET => vec![
+            per_install_dirs::source_game_folder(step1, Game::BGEE),
+            per_install_dirs::source_game_folder(step1, Game::BG2EE),
+        ],
+        game => vec![per_install_dirs::source_game_folder(step1, game)],
+    };
+    PreflightInput {
+        destination: PathBuf::from(destination.trim()),
+        archive_dir: archive_dir(step1),
+        archive_bytes,
+        clone_sources: non_empty_paths(&clone_sources),
+    }
+}
+
+#[must_use]
+pub fn input_for_step1(step1: &Step1State) -> Option<PreflightInput> {
+    let (destination, sources) = match step1.game_install.as_str() {
+        "EET" if step1.new_eet_dir_enabled => {
+            let mut sources = vec![step1.eet_bg2ee_game_folder.as_str()];
+            if step1.new_pre_eet_dir_enabled {
+                sources.push(step1.bgee_game_folder.as_str());
+            }
+            (step1.eet_new_dir.as_str(), sources)
+        }
+        "EET" if step1.new_pre_eet_dir_enabled => (
+            step1.eet_pre_dir.as_str(),
+            vec![step1.bgee_game_folder.as_str()],
+        ),
+        "EET" => return None,
+        game if step1.generate_directory_enabled => (
+            step1.generate_directory.as_str(),
+            vec![per_install_dirs::source_game_folder(
+                step1,
+                Game::from_legacy_string(game),
+            )],
+        ),
+        _ => return None,
+    };
+    let destination = destination.trim();
+    (!destination.is_empty()).then(|| PreflightInput {
+        destination: PathBuf::from(destination),
+        archive_dir: archive_dir(step1),
+        archive_bytes: 0,
+        clone_sources: non_empty_paths(&sources),
+    })
+}
+
+fn archive_dir(step1: &Step1State) -> Option<PathBuf> {
+    let folder = step1.mods_archive_folder.trim();
+    (step1.download_archive && !folder.is_empty()).then(|| PathBuf::from(folder))
+}
+
+fn non_empty_paths(paths: &[&str]) -> Vec<PathBuf> {
+    paths
+        .iter()
+        .map(|path| path.trim())
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .collect()
+}
+
+enum CacheSlot {
+    Pending,
+    Ready(Instant, Arc<DiskPreflight>),
+}
+
+static PREFLIGHT_CACHE: OnceLock<Mutex<HashMap<PreflightInput, CacheSlot>>> = OnceLock::new();
+
+pub fn preflight_cached(input: &PreflightInput) -> Option<Arc<DiskPreflight>> {
+    if input.destination.as_os_str().is_empty() {
+        return None;
+    }
+    let cache = PREFLIGHT_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut guard = cache.lock().ok()?;
+    match guard.get(input) {
+        Some(CacheSlot::Ready(at, result)) if at.elapsed() < CACHE_TTL => {
+            return Some(Arc::clone(result));
+        }
+        Some(CacheSlot::Pending) => return None,
+        _ => {}
+    }
+    guard.insert(input.clone(), CacheSlot::Pending);
+    drop(guard);
+
+    let input = input.clone();
+    std::thread::spawn(move || {
+        let result = Arc::new(run_preflight(&input));
+        if let Ok(mut guard) = cache.lock() {
+            guard.insert(input, CacheSlot::Ready(Instant::now(), result));
+        }
+    });
+    None
+}
+
+fn run_preflight(input: &PreflightInput) -> DiskPreflight {
+    let game_copy_bytes = input
+        .clone_sources
+        .iter()
+        .map(|source| directory_size_bytes(source))
+        .fold(0u64, u64::saturating_add);
+    let fs = probe_fs_traits(&input.destination);
+    assess(input, game_copy_bytes, fs, volume_space)
+}
+
+fn assess(
+    input: &PreflightInput,
+    game_copy_bytes: u64,
+    fs: FsTraits,
+    space: impl Fn(&Path) -> Option<VolumeSpace>,
+) -> DiskPreflight {
+    let extracted_bytes = input.archive_bytes.saturating_mul(EXTRACT_RATIO_NUM) / EXTRACT_RATIO_DEN;
+    let dest_space = space(&input.destination);
+    let dest_mount = dest_space.as_ref().map_or_else(
+        || input.destination.display().to_string(),
+        |s| s.mount.clone(),
+    );
+    let mut volumes = vec![VolumeCheck {
+        mount: dest_mount.clone(),
+        purposes: vec!["game copy", "extracted mods"],
+        required_bytes: game_copy_bytes
+            .saturating_add(extracted_bytes)
+            .saturating_add(HEADROOM_BYTES),
+        available_bytes: dest_space.map(|s| s.available_bytes),
+    }];
+
+    match input.archive_dir.as_deref() {
+        Some(archive_dir) => {
+            let archive_space = space(archive_dir);
+            let archive_mount = archive_space
+                .as_ref()
+                .map_or_else(|| archive_dir.display().to_string(), |s| s.mount.clone());
+            let same_volume = archive_mount == dest_mount;
+            if same_volume {
+                volumes[0].purposes.push("archives");
+                volumes[0].required_bytes = volumes[0]
+                    .required_bytes
+                    .saturating_add(input.archive_bytes);
+            } else {
+                volumes.push(VolumeCheck {
+                    mount: archive_mount,
+                    purposes: vec!["archives"],
+                    required_bytes: input.archive_bytes,
+                    available_bytes: archive_space.map(|s| s.available_bytes),
+                });
+            }
+            if !same_volume || fs.hardlinks == Some(false) {
+                volumes[0].purposes.push("archive copies");
+                volumes[0].required_bytes = volumes[0]
+                    .required_bytes
+                    .saturating_add(input.archive_bytes);
+            }
+        }
+        None => {
+            volumes[0].purposes.push("archives");
+            volumes[0].required_bytes = volumes[0]
+                .required_bytes
+                .saturating_add(input.archive_bytes);
+        }
+    }
+
+    DiskPreflight {
+        archive_bytes: input.archive_bytes,
+        extracted_bytes,
+        game_copy_bytes,
+        volumes,
+        fs,
+    }
+}
+
+fn existing_ancestor(path: &Path) -> Option<&Path> {
+    path.ancestors().find(|dir| dir.is_dir())
+}
+
+fn probe_fs_traits(destination: &Path) -> FsTraits {
+    if destination.as_os_str().is_empty() || !destination.is_dir() {
+        return FsTraits::default();
+    }
+    let dir = destination;
+    let probe = dir.join(PROBE_FILE_NAME);
+    if std::fs::write(&probe, b"bio").is_err() {
+        return FsTraits::default();
+    }
+    let case_sensitive = Some(!dir.join(PROBE_FILE_NAME.to_ascii_lowercase()).exists());
+    let link = dir.join(PROBE_LINK_NAME);
+    let hardlinks = Some(std::fs::hard_link(&probe, &link).is_ok());
+    let _ = std::fs::remove_file(&link);
+    let _ = std::fs::remove_file(&probe);
+    FsTraits {
+        case_sensitive,
+        hardlinks,
+    }
+}
+
+#[cfg(unix)]
+fn volume_space(path: &Path) -> Option<VolumeSpace> {
+    let dir = existing_ancestor(path)?;
+    let output = std::process::Command::new("df")
+        .arg("-Pk")
+        .arg(dir)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_df_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+#[cfg(any(unix, test))]
+fn parse_df_output(text: &str) -> Option<VolumeSpace> {
+    let line = text.lines().nth(1)?;
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let available_kib: u64 = fields.get(3)?.parse().ok()?;
+    let mount = fields.get(5..).map(|rest| rest.join(" "))?;
+    Some(VolumeSpace {
+        mount,
+        available_bytes: available_kib.saturating_mul(1024),
+    })
+}
+
+#[cfg(target_os = "windows")]
+fn volume_space(path: &Path) -> Option<VolumeSpace> {
+    use std::os::windows::ffi::OsStrExt;
+
+    let dir = existing_ancestor(path)?;
+    let wide: Vec<u16> = dir.as_os_str().encode_wide().chain(Some(0)).collect();
+    let mut available = 0u64;
+    let ok = unsafe {
+        GetDiskFreeSpaceExW(
+            wide.as_ptr(),
+            &raw mut available,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    if ok == 0 {
+        return None;
+    }
+    let mount = match dir.components().next() {
+        Some(std::path::Component::Prefix(prefix)) => {
+            prefix.as_os_str().to_string_lossy().to_ascii_uppercase()
+        }
+        _ => dir.display().to_string(),
+    };
+    Some(VolumeSpace {
+        mount,
+        available_bytes: available,
+    })
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+fn volume_space(_path: &Path) -> Option<VolumeSpace> {
+    None
+}
+
+#[cfg(target_os = "windows")]
+#[link(name = "kernel32")]
+unsafe extern "system" {
+    fn GetDiskFreeSpaceExW(
+        directory_name: *const u16,
+        free_bytes_available_to_caller: *mut u64,
+        total_number_of_bytes: *mut u64,
+        total_number_of_free_bytes: *mut u64,
+    ) -> i32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIB: u64 = 1024 * 1024 * 1024;
+
+    fn space<'a>(
+        mounts: &'a [(&'a str, &'a str, u64)],
+    ) -> impl Fn(&Path) -> Option<VolumeSpace> + 'a {
+        move |path: &Path| {
+            mounts
+                .iter()
+                .find(|(prefix, _, _)| path.starts_with(prefix))
+                .map(|(_, mount, available)| VolumeSpace {
+                    mount: (*mount).to_string(),
+                    available_bytes: *available,
+                })
+        }
+    }
+
+    #[test]
+    fn assess_splits_volumes_and_flags_shortfalls() {
+        let input = PreflightInput {
+            destination: PathBuf::from("/games/eet"),
+            archive_dir: Some(PathBuf::from("/archive")),
+            archive_bytes: 2 * GIB,
+            clone_sources: Vec::new(),
+        };
+        let fs = FsTraits {
+            case_sensitive: Some(true),
+            hardlinks: Some(true),
+        };
+        let mounts = [("/games", "/games", 20 * GIB), ("/archive", "/data", GIB)];
+        let result = assess(&input, 10 * GIB, fs, space(&mounts));
+        assert_eq!(result.extracted_bytes, 5 * GIB);
+        assert_eq!(result.volumes.len(), 2);
+        assert_eq!(
+            result.volumes[0].required_bytes,
+            10 * GIB + 5 * GIB + HEADROOM_BYTES + 2 * GIB,
+            "cross-volume staging copies archives into the destination"
+        );
+        assert!(!result.volumes[0].is_short());
+        assert!(result.volumes[1].is_short());
+        assert!(result.is_blocking());
+        assert!(
+            result
+                .block_reason()
+                .is_some_and(|reason| reason.contains("/data needs 2048 MiB"))
+        );
+        assert_eq!(result.warnings().len(), 1);
+
+        let shared = [("/", "/", 30 * GIB)];
+        let result = assess(&input, 10 * GIB, fs, space(&shared));
+        assert_eq!(result.volumes.len(), 1);
+        assert_eq!(
+            result.volumes[0].required_bytes,
+            10 * GIB + 5 * GIB + HEADROOM_BYTES + 2 * GIB
+        );
+        assert!(!result.is_blocking());
+        assert_eq!(result.block_reason(), None);
+    }
+
+    #[test]
+    fn probe_skips_a_destination_that_does_not_exist_yet() {
+        let missing = std::env::temp_dir().join("bio_preflight_missing_destination");
+        assert_eq!(probe_fs_traits(&missing), FsTraits::default());
+        assert!(!std::env::temp_dir().join(PROBE_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn parses_posix_df_output() {
+        let text = "Filesystem 1024-blocks Used Available Capacity Mounted on\n\
+                    /dev/sda1 1000 400 600 40% /mnt/My Games\n";
+        assert_eq!(
+            parse_df_output(text),
+            Some(VolumeSpace {
+                mount: "/mnt/My Games".to_string(),
+                available_bytes: 600 * 1024,
+            })
+        );
+    }
+}
@@ -67,6 +67,7 @@ pub fn mint_and_arm(orchestrator: &mut OrchestratorApp) -> Result<ForkMintReport
             parent_name: &parent_name,
             parent_author: &parent_author,
             parent_forked_from: &preview.forked_from,
+            parent_share_code: &code,
             parent_mod_count,
             parent_component_count,
         },
@@ -310,6 +311,7 @@ mod tests {
             parent_name: "ParentMod",
             parent_author: "@parent",
             parent_forked_from: &existing_chain,
+            parent_share_code: "BIO-MODLIST-V1:parent",
             parent_mod_count: 0,
             parent_component_count: 0,
         };
@@ -318,6 +320,10 @@ mod tests {
         assert_eq!(entry.forked_from[0].name, "Original");
         assert_eq!(entry.forked_from[1].name, "ParentMod");
         assert_eq!(entry.forked_from[1].author, "@parent");
+        assert_eq!(
+            entry.upstream_share_code.as_deref(),
+            Some("BIO-MODLIST-V1:parent")
+        );
         assert_eq!(entry.author.as_deref(), Some("@me"));
     }
 
@@ -0,0 +1,406 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use tracing::warn;
+
+use crate::install_runtime::{install_concurrency, per_install_dirs};
+use crate::registry::model::{Game, LaunchConfig, LaunchKind, ModlistEntry};
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::shell::shell_statusbar::format_elapsed;
+
+pub const SESSION_POLL: Duration = Duration::from_secs(2);
+
+const EARLY_EXIT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LaunchPlan {
+    Process {
+        program: PathBuf,
+        args: Vec<String>,
+        env: Vec<(String, String)>,
+        cwd: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SessionEnd {
+    elapsed: Duration,
+    exit_code: Option<i32>,
+}
+
+#[derive(Debug)]
+pub struct GameSession {
+    pub modlist_id: String,
+    pub started_at: Instant,
+    rx: Receiver<SessionEnd>,
+}
+
+#[must_use]
+pub const fn steam_app_id(game: Game) -> u32 {
+    match game {
+        Game::BGEE => 228_280,
+        Game::BG2EE | Game::EET => 257_350,
+        Game::IWDEE => 321_800,
+    }
+}
+
+#[must_use]
+pub const fn windows_binary(game: Game) -> &'static str {
+    match game {
+        Game::IWDEE => "Icewind.exe",
+        Game::BGEE | Game::BG2EE | Game::EET => "Baldur.exe",
+    }
+}
+
+#[must_use]
+pub const fn native_binary(game: Game) -> &'static str {
+    if cfg!(target_os = "windows") {
+        windows_binary(game)
+    } else {
+        match game {
+            Game::BGEE => "BaldursGate",
+            Game::BG2EE | Game::EET => "BaldursGateII",
+            Game::IWDEE => "IcewindDale",
+        }
+    }
+}
+
+#[must_use]
+pub fn game_dir(entry: &ModlistEntry) -> PathBuf {
+    per_install_dirs::installed_game_dirs(&entry.destination_folder, entry.game)
+        .pop()
+        .map_or_else(
+            || PathBuf::from(entry.destination_folder.trim()),
+            |installed| installed.dir,
+        )
+}
+
+pub fn plan_for(config: &LaunchConfig, game: Game, game_dir: &Path) -> Result<LaunchPlan, String> {
+    let mut env: Vec<(String, String)> = config
+        .env
+        .iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    let resolve = |program: &str, fallback: &str| -> PathBuf {
+        let program = program.trim();
+        if program.is_empty() {
+            return game_dir.join(fallback);
+        }
+        let path = Path::new(program);
+        if path.is_relative() && game_dir.join(path).exists() {
+            game_dir.join(path)
+        } else {
+            path.to_path_buf()
+        }
+    };
+    match config.kind {
+        LaunchKind::Steam => {
+            let id = config.program.trim();
+            let id = if id.is_empty() {
+                steam_app_id(game).to_string()
+            } else {
+                id.to_string()
+            };
+            let program = game_dir.join(native_binary(game));
+            if !program.is_file() {
+                return Err(format!(
+                    "{} is not in the modded folder; Steam's own launch would start the unmodded game",
+                    program.display()
+                ));
+            }
+            env.push(("SteamAppId".to_string(), id.clone()));
+            env.push(("SteamGameId".to_string(), id));
+            Ok(LaunchPlan::Process {
+                program,
+                args: split_args(&config.args),
+                env,
+                cwd: game_dir.to_path_buf(),
+            })
+        }
+        LaunchKind::Native => Ok(LaunchPlan::Process {
+            program: resolve(&config.program, native_binary(game)),
+            args: split_args(&config.args),
+            env,
+            cwd: game_dir.to_path_buf(),
+        }),
+        LaunchKind::Wine => {
+            let mut wrapper = split_args(&config.wrapper);
+            if wrapper.is_empty() {
+                wrapper.push("wine".to_string());
+            }
+            let exe = resolve(&config.program, windows_binary(game));
+            let mut args = wrapper.split_off(1);
+            args.push(exe.to_string_lossy().to_string());
+            args.extend(split_args(&config.args));
+            Ok(LaunchPlan::Process {
+                program: PathBuf::from(&wrapper[0]),
+                args,
+                env,
+                cwd: game_dir.to_path_buf(),
+            })
+        }
+        LaunchKind::Custom => {
+            if config.program.trim().is_empty() {
+                return Err("No launch script configured".to_string());
+            }
+            Ok(LaunchPlan::Process {
+                program: resolve(&config.program, ""),
+                args: split_args(&config.args),
+                env,
+                cwd: game_dir.to_path_buf(),
+            })
+        }
+    }
+}
+
+#[must_use]
+pub fn split_args(text: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut started = false;
+    for ch in text.chars() {
+        match ch {
+            '"' => {
+                quoted = !quoted;
+                started = true;
+            }
+            c if c.is_whitespace() && !quoted => {
+                if started {
+                    out.push(std::mem::take(&mut current));
+                    started = false;
+                }
+            }
+            c => {
+                current.push(c);
+                started = true;
+            }
+        }
+    }
+    if started {
+        out.push(current);
+    }
+    out
+}
+
+pub fn parse_env_lines(text: &str) -> Result<BTreeMap<String, String>, String> {
+    let mut env = BTreeMap::new();
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(format!("\"{line}\" is not KEY=VALUE"));
+        };
+        let key = key.trim();
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            return Err(format!("\"{key}\" is not a valid variable name"));
+        }
+        env.insert(key.to_string(), value.trim().to_string());
+    }
+    Ok(env)
+}
+
+#[must_use]
+pub fn env_lines(env: &BTreeMap<String, String>) -> String {
+    env.iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[must_use]
+pub fn install_running_for(orchestrator: &OrchestratorApp, modlist_id: &str) -> bool {
+    install_concurrency::install_in_progress(orchestrator)
+        .is_some_and(|running| running.modlist_id == modlist_id)
+        || (orchestrator.wizard_state.step5.install_running
+            && orchestrator.active_install_modlist_id.as_deref() == Some(modlist_id))
+        || orchestrator
+            .install_queue
+            .running()
+            .is_some_and(|index| orchestrator.install_queue.items[index].modlist_id == modlist_id)
+}
+
+pub fn launch(orchestrator: &mut OrchestratorApp, modlist_id: &str) {
+    let Some(entry) = orchestrator.registry.find(modlist_id).cloned() else {
+        return;
+    };
+    if install_running_for(orchestrator, modlist_id) {
+        orchestrator.notification_manager.warn(format!(
+            "\"{}\" is being installed \u{2014} wait for the install to finish before playing",
+            entry.name
+        ));
+        return;
+    }
+    if orchestrator
+        .game_sessions
+        .iter()
+        .any(|session| session.modlist_id == modlist_id)
+    {
+        orchestrator
+            .notification_manager
+            .info(format!("\"{}\" is already running", entry.name));
+        return;
+    }
+
+    let config = entry.launch.clone().unwrap_or_default();
+    let started = plan_for(&config, entry.game, &game_dir(&entry)).and_then(spawn);
+    match started {
+        Ok(rx) => {
+            orchestrator.game_sessions.push(GameSession {
+                modlist_id: modlist_id.to_string(),
+                started_at: Instant::now(),
+                rx,
+            });
+            if let Some(target) = orchestrator.registry.find_mut(modlist_id) {
+                target.last_played_date = Some(Utc::now());
+                target.play_stats.sessions += 1;
+            }
+            orchestrator
+                .persistence_cycle
+                .mark_registry_dirty(Instant::now());
+            orchestrator
+                .notification_manager
+                .info(format!("Launching \"{}\"", entry.name));
+        }
+        Err(err) => {
+            orchestrator
+                .notification_manager
+                .error(format!("Couldn't launch \"{}\": {err}", entry.name));
+        }
+    }
+}
+
+fn spawn(plan: LaunchPlan) -> Result<Receiver<SessionEnd>, String> {
+    let LaunchPlan::Process {
+        program,
+        args,
+        env,
+        cwd,
+    } = plan;
+    let mut command = std::process::Command::new(&program);
+    command.args(&args).envs(env);
+    if cwd.is_dir() {
+        command.current_dir(&cwd);
+    }
+    let mut child = command
+        .spawn()
+        .map_err(|err| format!("{}: {err}", program.display()))?;
+    let (tx, rx) = mpsc::channel();
+    let started = Instant::now();
+    std::thread::spawn(move || {
+        let exit_code = child.wait().ok().and_then(|status| status.code());
+        let _ = tx.send(SessionEnd {
+            elapsed: started.elapsed(),
+            exit_code,
+        });
+    });
+    Ok(rx)
+}
+
+pub fn drain_sessions(orchestrator: &mut OrchestratorApp) -> bool {
+    let mut finished = Vec::new();
+    orchestrator
+        .game_sessions
+        .retain(|session| match session.rx.try_recv() {
+            Ok(end) => {
+                finished.push((session.modlist_id.clone(), Some(end)));
+                false
+            }
+            Err(TryRecvError::Empty) => true,
+            Err(TryRecvError::Disconnected) => {
+                finished.push((session.modlist_id.clone(), None));
+                false
+            }
+        });
+    for (modlist_id, end) in finished {
+        let Some(end) = end else {
+            warn!(
+                target = "orchestrator",
+                "game session watcher for {modlist_id} disconnected"
+            );
+            continue;
+        };
+        let Some(entry) = orchestrator.registry.find_mut(&modlist_id) else {
+            continue;
+        };
+        let secs = end.elapsed.as_secs();
+        entry.play_stats.last_session_secs = Some(secs);
+        entry.play_stats.total_secs = entry.play_stats.total_secs.saturating_add(secs);
+        let name = entry.name.clone();
+        orchestrator
+            .persistence_cycle
+            .mark_registry_dirty(Instant::now());
+        if end.elapsed < EARLY_EXIT && end.exit_code.is_some_and(|code| code != 0) {
+            orchestrator.notification_manager.warn(format!(
+                "\"{name}\" exited right away (code {}); check its launch settings",
+                end.exit_code.unwrap_or_default()
+            ));
+        } else {
+            orchestrator.notification_manager.info(format!(
+                "Played \"{name}\" for {}",
+                format_elapsed(end.elapsed)
+            ));
+        }
+    }
+    !orchestrator.game_sessions.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    #[test]
+    fn plans_cover_native_steam_wine_and_custom_launches() {
+        let dir = Path::new("/games/bg2");
+        let mut config = LaunchConfig {
+            args: "--window \"two words\"".to_string(),
+            env: parse_env_lines("DXVK_HUD=fps\n\n  WINEPREFIX = /p  ").expect("env"),
+            ..LaunchConfig::default()
+        };
+        assert_eq!(config.env.len(), 2);
+        assert_eq!(env_lines(&config.env), "DXVK_HUD=fps\nWINEPREFIX=/p");
+        assert!(parse_env_lines("NOEQUALS").is_err());
+
+        let LaunchPlan::Process {
+            program, args, env, ..
+        } = plan_for(&config, Game::EET, dir).expect("native");
+        assert_eq!(program, dir.join(native_binary(Game::EET)));
+        assert_eq!(args, ["--window", "two words"]);
+        assert_eq!(env.len(), 2);
+
+        config.kind = LaunchKind::Steam;
+        assert!(plan_for(&config, Game::IWDEE, dir).is_err());
+        let modded = temp_dir("launch");
+        std::fs::write(modded.join(native_binary(Game::IWDEE)), b"").expect("binary");
+        let LaunchPlan::Process {
+            program, env, cwd, ..
+        } = plan_for(&config, Game::IWDEE, &modded).expect("steam");
+        assert_eq!(program, modded.join(native_binary(Game::IWDEE)));
+        assert_eq!(cwd, modded);
+        assert!(env.contains(&("SteamAppId".to_string(), "321800".to_string())));
+        let _ = std::fs::remove_dir_all(&modded);
+
+        config.kind = LaunchKind::Wine;
+        config.wrapper = "/opt/proton run".to_string();
+        config.args.clear();
+        let LaunchPlan::Process { program, args, .. } =
+            plan_for(&config, Game::BGEE, dir).expect("wine");
+        assert_eq!(program, PathBuf::from("/opt/proton"));
+        assert_eq!(
+            args,
+            [
+                "run".to_string(),
+                dir.join("Baldur.exe").to_string_lossy().to_string()
+            ]
+        );
+
+        config.kind = LaunchKind::Custom;
+        config.program.clear();
+        assert!(plan_for(&config, Game::BGEE, dir).is_err());
+    }
+}
@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use chrono::{Duration, Utc};
+
+use crate::app::state::WizardState;
+use crate::install_runtime::weidu_step_run::WeiduStepRun;
+use crate::registry::errors::RegistryError;
+use crate::registry::install_history::{InstallHistoryStore, InstallRunRecord};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct ConsoleTally {
+    attempted: u32,
+    succeeded: u32,
+    warnings: u32,
+    scripted: u32,
+    memory: u32,
+    sent: u32,
+}
+
+fn tally_console(console: &str) -> ConsoleTally {
+    let mut tally = ConsoleTally::default();
+    for line in console.lines() {
+        let upper = line.to_ascii_uppercase();
+        if upper.contains("MOD_INSTALLER::INSTALLERS")
+            && (upper.contains("INSTALLING MOD COMPONENT")
+                || upper.contains("INSTALLING MOD WEIDUBATCHEDCOMPONENTS"))
+        {
+            tally.attempted += 1;
+        }
+        if upper.contains("SUCCESSFULLY INSTALLED") {
+            tally.succeeded += 1;
+        }
+        if upper.contains("INSTALLED WITH WARNINGS") {
+            tally.warnings += 1;
+        }
+        let trimmed = upper.trim_start();
+        if trimmed.starts_with("=== SCRIPTED @WLB-INPUT SENT") {
+            tally.scripted += 1;
+        } else if trimmed.starts_with("=== AUTO-ANSWER MATCHED") {
+            tally.memory += 1;
+        } else if trimmed.starts_with("[SENT]") {
+            tally.sent += 1;
+        }
+    }
+    tally
+}
+
+#[must_use]
+pub fn build_record(state: &WizardState, console: &str) -> InstallRunRecord {
+    let step5 = &state.step5;
+    let tally = tally_console(console);
+    let finished_at = Utc::now();
+    let started_at = step5
+        .last_runtime_secs
+        .and_then(|secs| i64::try_from(secs).ok())
+        .map(|secs| finished_at - Duration::seconds(secs));
+    let mut answer_sources = BTreeMap::new();
+    let manual = tally.sent.saturating_sub(tally.scripted + tally.memory);
+    for (source, count) in [
+        ("@wlb-inputs", tally.scripted),
+        ("prompt memory", tally.memory),
+        ("manual", manual),
+    ] {
+        if count > 0 {
+            answer_sources.insert(source.to_string(), count);
+        }
+    }
+    let failed = u32::try_from(step5.failure_summary.len()).unwrap_or(u32::MAX);
+    InstallRunRecord {
+        run_id: step5
+            .diagnostics_run_id
+            .clone()
+            .unwrap_or_else(|| finished_at.format("%Y-%m-%d_%H-%M-%S_%3f").to_string()),
+        started_at,
+        finished_at: Some(finished_at),
+        attempted: tally.attempted.max(tally.succeeded + failed),
+        succeeded: tally.succeeded,
+        failed,
+        warnings: tally.warnings,
+        exit_code: step5.last_exit_code,
+        status: step5.last_status_text.trim().to_string(),
+        failed_components: step5
+            .failure_summary
+            .iter()
+            .map(|failure| format!("{} ({})", failure.label, failure.kind.label()))
+            .collect(),
+        answer_sources,
+    }
+}
+
+#[must_use]
+pub fn triage_text(state: &WizardState, record: &InstallRunRecord) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "BIO install run triage");
+    let _ = writeln!(out, "======================\n");
+    let _ = writeln!(out, "run_id={}", record.run_id);
+    let _ = writeln!(out, "outcome={}", record.outcome().label());
+    let _ = writeln!(out, "status={}", record.status);
+    let _ = writeln!(out, "exit_code={:?}", record.exit_code);
+    let _ = writeln!(out, "game_install={}", state.step1.game_install);
+    let _ = writeln!(
+        out,
+        "components attempted={} succeeded={} failed={} warnings={}",
+        record.attempted, record.succeeded, record.failed, record.warnings
+    );
+    for (source, count) in &record.answer_sources {
+        let _ = writeln!(out, "answers[{source}]={count}");
+    }
+    for failure in &state.step5.failure_summary {
+        let _ = writeln!(
+            out,
+            "\n--- {} ({}) ---\n{}",
+            failure.label,
+            failure.kind.label(),
+            failure.error_block
+        );
+    }
+    out
+}
+
+pub fn record_finished_run(
+    modlist_id: &str,
+    state: &WizardState,
+    console: &str,
+) -> Result<InstallRunRecord, RegistryError> {
+    let record = build_record(state, console);
+    let triage = triage_text(state, &record);
+    InstallHistoryStore::new_for_id(modlist_id).record(record.clone(), console, &triage)?;
+    Ok(record)
+}
+
+pub fn record_step_run(
+    modlist_id: &str,
+    run: &WeiduStepRun,
+) -> Result<InstallRunRecord, RegistryError> {
+    let finished_at = Utc::now();
+    let total = u32::try_from(run.steps.len()).unwrap_or(u32::MAX);
+    let completed = u32::try_from(run.current).unwrap_or(u32::MAX).min(total);
+    let failure = run
+        .outcome
+        .as_ref()
+        .and_then(|outcome| outcome.clone().err());
+    let record = InstallRunRecord {
+        run_id: finished_at.format("%Y-%m-%d_%H-%M-%S_%3f").to_string(),
+        started_at: Some(run.started_at),
+        finished_at: Some(finished_at),
+        attempted: if failure.is_some() {
+            (completed + 1).min(total)
+        } else {
+            total
+        },
+        succeeded: completed,
+        failed: u32::from(failure.is_some()),
+        exit_code: failure.is_none().then_some(0),
+        status: failure
+            .clone()
+            .unwrap_or_else(|| "Changes applied".to_string()),
+        failed_components: run
+            .current_label()
+            .filter(|_| failure.is_some())
+            .into_iter()
+            .collect(),
+        ..InstallRunRecord::default()
+    };
+    let mut triage = String::new();
+    let _ = writeln!(triage, "BIO apply changes triage");
+    let _ = writeln!(triage, "========================\n");
+    let _ = writeln!(triage, "run_id={}", record.run_id);
+    let _ = writeln!(triage, "outcome={}", record.outcome().label());
+    let _ = writeln!(triage, "status={}", record.status);
+    for (index, step) in run.steps.iter().enumerate() {
+        let _ = writeln!(triage, "step[{}]={}", index + 1, step.label());
+    }
+    InstallHistoryStore::new_for_id(modlist_id).record(
+        record.clone(),
+        &run.console_text(),
+        &triage,
+    )?;
+    Ok(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::install::failure_class::{ComponentFailure, FailureKind};
+    use crate::registry::install_history::RunOutcome;
+
+    const CONSOLE: &str = "\
+[t INFO  mod_installer::installers] Installing mod component Component { tp_file: \"A.TP2\", component: \"0\" }
+=== Scripted @wlb-input sent 2 ===
+[sent] 2
+SUCCESSFULLY INSTALLED Core
+[t INFO  mod_installer::installers] Installing mod component Component { tp_file: \"B.TP2\", component: \"1\" }
+=== Auto-answer matched level cap and sent y ===
+[sent] y
+[sent] n
+INSTALLED WITH WARNINGS Tweaks
+SUCCESSFULLY INSTALLED Tweaks
+[t INFO  mod_installer::installers] Installing mod component Component { tp_file: \"C.TP2\", component: \"2\" }
+NOT INSTALLED DUE TO ERRORS Items
+";
+
+    #[test]
+    fn record_counts_components_and_answer_sources() {
+        let mut state = WizardState::default();
+        state.step5.diagnostics_run_id = Some("2026-10-19_10-00-00_000".to_string());
+        state.step5.last_exit_code = Some(1);
+        state.step5.last_runtime_secs = Some(90);
+        state.step5.failure_summary = vec![ComponentFailure {
+            component_key: "C.TP2#2".to_string(),
+            label: "C.TP2 #2 Items".to_string(),
+            kind: FailureKind::MissingFile,
+            attempts: 1,
+            error_block: "NOT INSTALLED DUE TO ERRORS Items".to_string(),
+        }];
+        let record = build_record(&state, CONSOLE);
+        assert_eq!(record.run_id, "2026-10-19_10-00-00_000");
+        assert_eq!(
+            (
+                record.attempted,
+                record.succeeded,
+                record.failed,
+                record.warnings
+            ),
+            (3, 2, 1, 1)
+        );
+        assert_eq!(record.answer_sources.get("@wlb-inputs"), Some(&1));
+        assert_eq!(record.answer_sources.get("prompt memory"), Some(&1));
+        assert_eq!(record.answer_sources.get("manual"), Some(&1));
+        assert_eq!(record.outcome(), RunOutcome::Failed);
+        assert!(record.started_at.is_some());
+        let triage = triage_text(&state, &record);
+        assert!(triage.contains("--- C.TP2 #2 Items (missing file) ---"));
+    }
+}
@@ -0,0 +1,282 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::time::{Duration, Instant};
+
+use tracing::warn;
+
+use crate::app::step5::install_flow;
+use crate::install_runtime::install_concurrency;
+use crate::install_runtime::reinstall_route;
+use crate::registry::install_queue::{QueueItemStatus, QueuePromptPolicy, QueueWorkflow};
+use crate::ui::install::page_install::{self, PreviewStart};
+use crate::ui::install::state_install::{DestChoice, InstallStage};
+use crate::ui::orchestrator::nav_destination::NavDestination;
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::orchestrator::page_router;
+use crate::ui::workspace::step5::success_banner;
+
+const PROMPT_GRACE: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Default)]
+pub struct PromptWatch {
+    since: Option<Instant>,
+    handled: bool,
+    failure: Option<String>,
+}
+
+pub fn enqueue(orchestrator: &mut OrchestratorApp, modlist_id: &str) {
+    let Some(entry) = orchestrator.registry.find(modlist_id) else {
+        return;
+    };
+    let name = entry.name.clone();
+    let workflow = QueueWorkflow::default_for(entry);
+    if orchestrator.install_queue.push(modlist_id, workflow) {
+        save(orchestrator);
+        orchestrator.notification_manager.info(format!(
+            "Queued \"{name}\" ({}) \u{2014} {} waiting",
+            workflow.label(),
+            orchestrator.install_queue.pending_count()
+        ));
+    } else {
+        orchestrator
+            .notification_manager
+            .warn(format!("\"{name}\" is already in the install queue"));
+    }
+}
+
+pub fn save(orchestrator: &OrchestratorApp) {
+    if let Err(err) = orchestrator
+        .install_queue_store
+        .save(&orchestrator.install_queue)
+    {
+        warn!(
+            target = "orchestrator",
+            "install queue write failed: {err} (queue kept in memory)"
+        );
+    }
+}
+
+pub fn tick(orchestrator: &mut OrchestratorApp) -> bool {
+    if let Some(index) = orchestrator.install_queue.running() {
+        return watch_running(orchestrator, index);
+    }
+    if orchestrator.install_queue.paused || !ready_for_next(orchestrator) {
+        return false;
+    }
+    let Some(index) = orchestrator.install_queue.next_pending() else {
+        return false;
+    };
+    start_item(orchestrator, index);
+    true
+}
+
+pub fn on_install_finished(orchestrator: &mut OrchestratorApp) {
+    let Some(index) = orchestrator.install_queue.running() else {
+        return;
+    };
+    if let Some(reason) = orchestrator.install_queue_prompt.failure.take() {
+        finish(orchestrator, index, QueueItemStatus::Failed, Some(reason));
+    } else if success_banner::clean_exit(&orchestrator.wizard_state) {
+        finish(orchestrator, index, QueueItemStatus::Done, None);
+    } else {
+        let status = orchestrator.wizard_state.step5.last_status_text.clone();
+        finish(orchestrator, index, QueueItemStatus::Failed, Some(status));
+    }
+    page_router::reset_completed_install_runtime(orchestrator);
+}
+
+fn ready_for_next(orchestrator: &OrchestratorApp) -> bool {
+    let step5 = &orchestrator.wizard_state.step5;
+    install_concurrency::install_in_progress(orchestrator).is_none()
+        && !step5.start_install_requested
+        && !step5.prep_running
+        && orchestrator.step5_prep_rx.is_none()
+        && orchestrator.step5_pending_start.is_none()
+        && orchestrator.install_destination_prep_rx.is_none()
+        && orchestrator.install_screen_state.stage == InstallStage::Paste
+        && !orchestrator.post_install_reset_gate.is_pending()
+}
+
+fn watch_running(orchestrator: &mut OrchestratorApp, index: usize) -> bool {
+    if let Some(err) = orchestrator.install_screen_state.pipeline_arm_error.clone() {
+        finish(orchestrator, index, QueueItemStatus::Failed, Some(err));
+        page_router::reset_completed_install_runtime(orchestrator);
+        return true;
+    }
+    let step5 = &orchestrator.wizard_state.step5;
+    let idle = !step5.start_install_requested && !step5.install_running && !step5.prep_running;
+    match orchestrator.install_screen_state.stage {
+        InstallStage::Preview if !step5.install_running => {
+            match page_install::advance_from_preview(orchestrator) {
+                PreviewStart::Started => {
+                    orchestrator.install_screen_state.stage = InstallStage::Downloading;
+                    true
+                }
+                PreviewStart::Pending => false,
+                PreviewStart::Blocked(reason) => {
+                    orchestrator.pending_reinstall_id = None;
+                    orchestrator.reset_install_screen_to_paste();
+                    finish(orchestrator, index, QueueItemStatus::Failed, Some(reason));
+                    true
+                }
+            }
+        }
+        InstallStage::Paste if !step5.install_running => {
+            finish(
+                orchestrator,
+                index,
+                QueueItemStatus::Failed,
+                Some("cancelled".to_string()),
+            );
+            true
+        }
+        InstallStage::InstallingStub if idle && !step5.has_run_once => {
+            orchestrator.wizard_state.step5.start_install_requested = true;
+            true
+        }
+        InstallStage::InstallingStub if step5.install_running => watch_prompt(orchestrator),
+        _ => false,
+    }
+}
+
+fn watch_prompt(orchestrator: &mut OrchestratorApp) -> bool {
+    let waiting = orchestrator
+        .step5_terminal
+        .as_ref()
+        .is_some_and(|terminal| {
+            terminal.likely_input_needed_visible() || terminal.current_prompt_info().is_some()
+        });
+    if !waiting {
+        orchestrator.install_queue_prompt.since = None;
+        orchestrator.install_queue_prompt.handled = false;
+        return false;
+    }
+    let since = *orchestrator
+        .install_queue_prompt
+        .since
+        .get_or_insert_with(Instant::now);
+    if orchestrator.install_queue_prompt.handled || since.elapsed() < PROMPT_GRACE {
+        return false;
+    }
+    orchestrator.install_queue_prompt.handled = true;
+    match orchestrator.install_queue.prompt_policy {
+        QueuePromptPolicy::FailItem => {
+            orchestrator.install_queue_prompt.failure =
+                Some("a prompt went unanswered; the install was cancelled".to_string());
+            orchestrator.wizard_state.step5.cancel_force_checked = true;
+            install_flow::confirm_cancel_request(
+                &mut orchestrator.wizard_state,
+                orchestrator.step5_terminal.as_mut(),
+            );
+        }
+        QueuePromptPolicy::PauseQueue => {
+            orchestrator.install_queue.paused = true;
+            save(orchestrator);
+            orchestrator.notification_manager.warn(
+                "Install queue paused \u{2014} an install is waiting for an answer in the console"
+                    .to_string(),
+            );
+        }
+    }
+    true
+}
+
+fn start_item(orchestrator: &mut OrchestratorApp, index: usize) {
+    let item = orchestrator.install_queue.items[index].clone();
+    let Some(entry) = orchestrator.registry.find(&item.modlist_id).cloned() else {
+        finish(
+            orchestrator,
+            index,
+            QueueItemStatus::Skipped,
+            Some("modlist no longer exists".to_string()),
+        );
+        return;
+    };
+    if entry
+        .latest_share_code
+        .as_deref()
+        .is_none_or(|code| code.trim().is_empty())
+    {
+        finish(
+            orchestrator,
+            index,
+            QueueItemStatus::Failed,
+            Some("no import code recorded for this modlist".to_string()),
+        );
+        return;
+    }
+
+    orchestrator.reset_install_screen_to_paste();
+    let choice = match item.workflow {
+        QueueWorkflow::Fresh | QueueWorkflow::Reinstall => DestChoice::Clear,
+        QueueWorkflow::ContinuePartial => DestChoice::Continue,
+    };
+    reinstall_route::stage_entry_install(&entry, orchestrator, choice);
+
+    let blocker = orchestrator
+        .install_screen_state
+        .preview_parse_error
+        .clone()
+        .or_else(|| {
+            orchestrator
+                .install_screen_state
+                .parsed_preview
+                .as_ref()
+                .filter(|preview| !preview.allow_auto_install)
+                .map(|_| "draft import codes cannot be installed unattended".to_string())
+        });
+    if let Some(reason) = blocker {
+        orchestrator.reset_install_screen_to_paste();
+        finish(orchestrator, index, QueueItemStatus::Failed, Some(reason));
+        return;
+    }
+
+    if item.workflow == QueueWorkflow::Reinstall {
+        orchestrator.pending_reinstall_id = Some(entry.id.clone());
+    }
+    orchestrator.install_queue_prompt = PromptWatch::default();
+    orchestrator.install_screen_state.stage = InstallStage::Preview;
+    orchestrator.nav = NavDestination::Install;
+
+    orchestrator.install_queue.items[index].status = QueueItemStatus::Running;
+    save(orchestrator);
+    orchestrator.notification_manager.info(format!(
+        "Install queue: starting \"{}\" ({})",
+        entry.name,
+        item.workflow.label()
+    ));
+}
+
+fn finish(
+    orchestrator: &mut OrchestratorApp,
+    index: usize,
+    status: QueueItemStatus,
+    message: Option<String>,
+) {
+    let name = orchestrator
+        .install_queue
+        .items
+        .get(index)
+        .and_then(|item| orchestrator.registry.find(&item.modlist_id))
+        .map_or_else(|| "modlist".to_string(), |entry| entry.name.clone());
+    match (status, message.as_deref()) {
+        (QueueItemStatus::Done, _) => orchestrator
+            .notification_manager
+            .success(format!("Install queue: \"{name}\" installed")),
+        (_, Some(reason)) => orchestrator.notification_manager.error(format!(
+            "Install queue: \"{name}\" {} \u{2014} {reason}",
+            status.label()
+        )),
+        (_, None) => orchestrator
+            .notification_manager
+            .warn(format!("Install queue: \"{name}\" {}", status.label())),
+    }
+    orchestrator.install_queue.finish(index, status, message);
+    save(orchestrator);
+    if orchestrator.install_queue.next_pending().is_none() {
+        orchestrator
+            .notification_manager
+            .info("Install queue finished".to_string());
+    }
+}
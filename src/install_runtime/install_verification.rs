@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::path::PathBuf;
+
+use chrono::Utc;
+
+use crate::app::state::{Step3ItemState, WizardState};
+use crate::app::step5::diagnostics::format_step4_item;
+use crate::install_runtime::per_install_dirs::{self, InstallSlot};
+use crate::mods::component::Component;
+use crate::mods::log_file::LogFile;
+use crate::registry::model::InstallVerdict;
+
+#[must_use]
+pub fn planned_components(items: &[Step3ItemState]) -> Vec<Component> {
+    items
+        .iter()
+        .filter(|item| !item.is_parent)
+        .filter_map(|item| Component::parse_weidu_line(&format_step4_item(item)).ok())
+        .collect()
+}
+
+fn label(component: &Component) -> String {
+    if component.component_name.is_empty() {
+        format!("{} #{}", component.tp_file, component.component)
+    } else {
+        format!(
+            "{} #{} {}",
+            component.tp_file, component.component, component.component_name
+        )
+    }
+}
+
+fn same_component_any_lang(a: &Component, b: &Component) -> bool {
+    a.tp_file.eq_ignore_ascii_case(&b.tp_file)
+        && a.name.eq_ignore_ascii_case(&b.name)
+        && a.component.eq_ignore_ascii_case(&b.component)
+}
+
+fn longest_increasing(seq: &[usize]) -> Vec<bool> {
+    let mut tails: Vec<usize> = Vec::new();
+    let mut prev = vec![None; seq.len()];
+    for (i, &value) in seq.iter().enumerate() {
+        let pos = tails.partition_point(|&t| seq[t] < value);
+        if pos > 0 {
+            prev[i] = Some(tails[pos - 1]);
+        }
+        if pos == tails.len() {
+            tails.push(i);
+        } else {
+            tails[pos] = i;
+        }
+    }
+    let mut keep = vec![false; seq.len()];
+    let mut cursor = tails.last().copied();
+    while let Some(i) = cursor {
+        keep[i] = true;
+        cursor = prev[i];
+    }
+    keep
+}
+
+#[must_use]
+pub fn compare(plan: &[Component], installed: &[Component]) -> InstallVerdict {
+    let mut verdict = InstallVerdict {
+        planned: u32::try_from(plan.len()).unwrap_or(u32::MAX),
+        installed: u32::try_from(installed.len()).unwrap_or(u32::MAX),
+        ..InstallVerdict::default()
+    };
+    let mut used = vec![false; installed.len()];
+    let mut matched: Vec<(usize, usize)> = Vec::new();
+    let mut unmatched = Vec::new();
+
+    for (p, planned) in plan.iter().enumerate() {
+        let hit = installed
+            .iter()
+            .enumerate()
+            .position(|(l, c)| !used[l] && c.key_eq(planned));
+        match hit {
+            Some(l) => {
+                used[l] = true;
+                matched.push((p, l));
+                if !planned.version.is_empty() && !installed[l].strict_eq(planned) {
+                    verdict.changed.push(label(planned));
+                }
+            }
+            None => unmatched.push(p),
+        }
+    }
+    for p in unmatched {
+        let planned = &plan[p];
+        let other_lang = installed
+            .iter()
+            .enumerate()
+            .position(|(l, c)| !used[l] && same_component_any_lang(c, planned));
+        if let Some(l) = other_lang {
+            used[l] = true;
+            verdict.language_mismatch.push(format!(
+                "{} (planned #{}, installed #{})",
+                label(planned),
+                planned.lang,
+                installed[l].lang
+            ));
+        } else {
+            verdict.missing.push(label(planned));
+        }
+    }
+    verdict.extra = installed
+        .iter()
+        .zip(&used)
+        .filter(|(_, used)| !**used)
+        .map(|(c, _)| label(c))
+        .collect();
+
+    let order: Vec<usize> = matched.iter().map(|&(_, l)| l).collect();
+    let in_order = longest_increasing(&order);
+    verdict.reordered = matched
+        .iter()
+        .zip(in_order)
+        .filter(|(_, keep)| !keep)
+        .map(|(&(p, _), _)| label(&plan[p]))
+        .collect();
+    verdict
+}
+
+fn installed_log_dirs(state: &WizardState) -> Vec<(&[Step3ItemState], PathBuf)> {
+    per_install_dirs::installed_game_dirs_for_step1(&state.step1)
+        .into_iter()
+        .map(|installed| {
+            let items = match installed.slot {
+                InstallSlot::Bgee => state.step3.bgee_items.as_slice(),
+                InstallSlot::Bg2ee => state.step3.bg2ee_items.as_slice(),
+            };
+            (items, installed.dir)
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn verify_install(state: &WizardState) -> InstallVerdict {
+    let mut plan = Vec::new();
+    let mut installed = Vec::new();
+    for (items, dir) in installed_log_dirs(state) {
+        let planned = planned_components(items);
+        if planned.is_empty() {
+            continue;
+        }
+        let log_path = dir.join("weidu.log");
+        match LogFile::from_path(&log_path) {
+            Ok(log) => installed.extend_from_slice(log.components()),
+            Err(err) => {
+                return InstallVerdict {
+                    checked_at: Some(Utc::now()),
+                    planned: u32::try_from(planned.len()).unwrap_or(u32::MAX),
+                    error: Some(format!("{err:#}")),
+                    ..InstallVerdict::default()
+                };
+            }
+        }
+        plan.extend(planned);
+    }
+    InstallVerdict {
+        checked_at: Some(Utc::now()),
+        ..compare(&plan, &installed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::component;
+
+    const PLAN: &[&str] = &[
+        "~EET/EET.TP2~ #0 #0 // EET core: v14.0",
+        "~STRATAGEMS/SETUP-STRATAGEMS.TP2~ #0 #1000 // Ease of use: v35",
+        "~STRATAGEMS/SETUP-STRATAGEMS.TP2~ #0 #1010 // Faster Bears: v35",
+        "~SCS/SETUP-SCS.TP2~ #0 #4000 // Smarter mages: v35",
+    ];
+
+    fn plan() -> Vec<Component> {
+        PLAN.iter().map(|l| component(l)).collect()
+    }
+
+    #[test]
+    fn identical_log_is_clean() {
+        let verdict = compare(&plan(), &plan());
+        assert!(verdict.is_clean(), "{verdict:?}");
+        assert_eq!(verdict.summary(), None);
+        assert_eq!((verdict.planned, verdict.installed), (4, 4));
+    }
+
+    #[test]
+    fn skipped_extra_and_language_are_reported() {
+        let installed = vec![
+            component(PLAN[0]),
+            component("~STRATAGEMS/SETUP-STRATAGEMS.TP2~ #2 #1000 // Ease of use: v35"),
+            component(PLAN[3]),
+            component("~BG1NPC/BG1NPC.TP2~ #0 #0 // Core: v27"),
+        ];
+        let verdict = compare(&plan(), &installed);
+        assert_eq!(verdict.missing.len(), 1);
+        assert!(verdict.missing[0].contains("#1010"));
+        assert_eq!(verdict.extra.len(), 1);
+        assert!(verdict.extra[0].starts_with("BG1NPC.TP2"));
+        assert_eq!(verdict.language_mismatch.len(), 1);
+        assert!(verdict.language_mismatch[0].contains("planned #0, installed #2"));
+        assert!(verdict.reordered.is_empty());
+        assert_eq!(
+            verdict.summary().as_deref(),
+            Some("1 component skipped, 1 extra, 1 in another language")
+        );
+    }
+
+    #[test]
+    fn moved_component_is_reordered_not_missing() {
+        let p = plan();
+        let installed = vec![p[0].clone(), p[3].clone(), p[1].clone(), p[2].clone()];
+        let verdict = compare(&p, &installed);
+        assert!(verdict.missing.is_empty() && verdict.extra.is_empty());
+        assert_eq!(verdict.reordered, vec![label(&p[3])]);
+    }
+
+    #[test]
+    fn version_change_uses_strict_eq_only_when_plan_has_version() {
+        let installed = vec![
+            component(PLAN[0]),
+            component(PLAN[1]),
+            component(PLAN[2]),
+            component("~SCS/SETUP-SCS.TP2~ #0 #4000 // Smarter mages: v36"),
+        ];
+        let verdict = compare(&plan(), &installed);
+        assert_eq!(verdict.changed.len(), 1);
+
+        let unversioned = vec![component("~SCS/SETUP-SCS.TP2~ #0 #4000 // Smarter mages")];
+        let verdict = compare(&unversioned, &installed[3..]);
+        assert!(verdict.is_clean(), "{verdict:?}");
+    }
+
+    #[test]
+    fn unreadable_log_records_an_error_verdict() {
+        let mut state = WizardState::<bool>::default();
+        state.step1.game_install = "BGEE".to_string();
+        state.step1.bgee_game_folder = std::env::temp_dir()
+            .join("bio_verify_missing_game_dir")
+            .to_string_lossy()
+            .into_owned();
+        state.step3.bgee_items = vec![Step3ItemState {
+            tp_file: "SETUP-SCS.TP2".to_string(),
+            component_id: "4000".to_string(),
+            mod_name: "SCS".to_string(),
+            component_label: "Smarter mages".to_string(),
+            raw_line: PLAN[3].to_string(),
+            prompt_summary: None,
+            prompt_events: Vec::new(),
+            selected_order: 0,
+            block_id: String::new(),
+            is_parent: false,
+            parent_placeholder: false,
+        }];
+        let verdict = verify_install(&state);
+        assert!(verdict.error.is_some());
+        assert!(
+            verdict
+                .summary()
+                .is_some_and(|s| s.starts_with("not verified"))
+        );
+    }
+}
@@ -2,21 +2,34 @@
 // Copyright (c) 2026 Born2BSalty
 
 pub mod active_modlist_source_path;
+pub mod apply_changes_driver;
 pub mod archive_skip;
 pub mod archive_skip_async;
 pub mod archive_store;
 pub mod auto_build_driver;
 pub mod destination_prep;
+pub mod disk_preflight;
 pub mod extract_parallel;
 pub mod flag_policies;
 pub mod fork_pipeline_arm;
+pub mod game_launcher;
 pub mod import_code_writer;
 pub mod install_concurrency;
+pub mod install_history_record;
 pub mod install_modlist_registration;
+pub mod install_queue_driver;
+pub mod install_verification;
+pub mod modlist_health;
+pub mod modlist_layers_driver;
+pub mod modlist_lock;
+pub mod offline_bundle;
 pub mod per_install_dirs;
 pub mod rail_lock_reason;
 pub mod registry_transition;
 pub mod reinstall_route;
+pub mod settings_profiles_driver;
 pub mod settings_sanitizer;
 pub mod start_hooks;
 pub mod stream_downloader;
+pub mod uninstall_driver;
+pub mod weidu_step_run;
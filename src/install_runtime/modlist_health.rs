@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::time::Instant;
+
+use chrono::Utc;
+
+use crate::app::state::WizardState;
+use crate::install_runtime::archive_store::{InstallArchiveLock, hash_file, stored_filename};
+use crate::install_runtime::game_launcher::{self, native_binary, windows_binary};
+use crate::install_runtime::registry_transition::directory_size_bytes;
+use crate::install_runtime::{install_verification, settings_profiles_driver};
+use crate::mods::component::Component;
+use crate::registry::model::{HealthReport, InstallVerdict, LaunchKind, ModlistEntry};
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+
+#[derive(Debug)]
+pub struct HealthCheck {
+    pub modlist_id: String,
+    rx: Receiver<HealthReport>,
+}
+
+#[must_use]
+pub fn plan_from_log(text: &str) -> Vec<Component> {
+    text.lines()
+        .filter(|line| line.trim_start().starts_with('~'))
+        .filter_map(|line| Component::parse_weidu_line(line).ok())
+        .collect()
+}
+
+#[must_use]
+pub fn check_log(state: &WizardState) -> Option<InstallVerdict> {
+    Some(install_verification::verify_install(state))
+        .filter(|verdict| verdict.planned > 0 || verdict.error.is_some())
+}
+
+#[must_use]
+pub fn check_archives(destination: &str, archive_dir: &Path) -> (u32, Vec<String>, Vec<String>) {
+    let lock = InstallArchiveLock::load(destination);
+    if archive_dir.as_os_str().is_empty() || lock.resolved.is_empty() {
+        return (0, Vec::new(), Vec::new());
+    }
+    let mut changed = Vec::new();
+    let mut missing = Vec::new();
+    for (name, hash) in &lock.resolved {
+        let stored = archive_dir.join(stored_filename(name, hash));
+        match hash_file(&stored) {
+            Ok(actual) if actual == *hash => {}
+            Ok(_) => changed.push(name.clone()),
+            Err(_) => missing.push(name.clone()),
+        }
+    }
+    let checked = u32::try_from(lock.resolved.len()).unwrap_or(u32::MAX);
+    (checked, changed, missing)
+}
+
+#[must_use]
+pub fn game_binary_present(entry: &ModlistEntry) -> bool {
+    let dir = game_launcher::game_dir(entry);
+    let configured = entry
+        .launch
+        .as_ref()
+        .filter(|config| matches!(config.kind, LaunchKind::Native | LaunchKind::Wine))
+        .map(|config| config.program.trim())
+        .filter(|program| !program.is_empty());
+    if let Some(program) = configured {
+        return dir.join(program).is_file();
+    }
+    [native_binary(entry.game), windows_binary(entry.game)]
+        .iter()
+        .any(|binary| dir.join(binary).is_file())
+}
+
+#[must_use]
+pub fn check(entry: &ModlistEntry, state: &WizardState) -> HealthReport {
+    let archive_dir = PathBuf::from(state.step1.mods_archive_folder.trim());
+    let destination = Path::new(entry.destination_folder.trim());
+    let mut report = HealthReport {
+        checked_at: Some(Utc::now()),
+        recorded_size_bytes: entry.total_size_bytes,
+        ..HealthReport::default()
+    };
+    if destination.as_os_str().is_empty() || !destination.is_dir() {
+        report.destination_missing = true;
+        return report;
+    }
+    report.game_binary_missing = !game_binary_present(entry);
+    report.log = check_log(state);
+    (
+        report.archives_checked,
+        report.archives_changed,
+        report.archives_missing,
+    ) = check_archives(&entry.destination_folder, &archive_dir);
+    report.size_bytes = Some(directory_size_bytes(destination));
+    report
+}
+
+pub fn start(orchestrator: &mut OrchestratorApp, modlist_id: &str) {
+    let Some(entry) = orchestrator.registry.find(modlist_id).cloned() else {
+        return;
+    };
+    if game_launcher::install_running_for(orchestrator, modlist_id) {
+        orchestrator.notification_manager.warn(format!(
+            "\"{}\" is being installed \u{2014} verify it once the install finishes",
+            entry.name
+        ));
+        return;
+    }
+    if orchestrator
+        .health_checks
+        .iter()
+        .any(|running| running.modlist_id == modlist_id)
+    {
+        return;
+    }
+    let state = settings_profiles_driver::modlist_wizard_state(orchestrator, modlist_id);
+    orchestrator
+        .notification_manager
+        .info(format!("Verifying \"{}\"\u{2026}", entry.name));
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let _ = tx.send(check(&entry, &state));
+    });
+    orchestrator.health_checks.push(HealthCheck {
+        modlist_id: modlist_id.to_string(),
+        rx,
+    });
+}
+
+fn modlist_name(orchestrator: &OrchestratorApp, modlist_id: &str) -> String {
+    orchestrator
+        .registry
+        .find(modlist_id)
+        .map_or_else(|| modlist_id.to_string(), |entry| entry.name.clone())
+}
+
+pub fn drain(orchestrator: &mut OrchestratorApp) {
+    let mut i = 0;
+    while i < orchestrator.health_checks.len() {
+        let report = match orchestrator.health_checks[i].rx.try_recv() {
+            Ok(report) => Some(report),
+            Err(TryRecvError::Disconnected) => None,
+            Err(TryRecvError::Empty) => {
+                i += 1;
+                continue;
+            }
+        };
+        let modlist_id = orchestrator.health_checks.swap_remove(i).modlist_id;
+        let name = modlist_name(orchestrator, &modlist_id);
+        let Some(report) = report else {
+            orchestrator
+                .notification_manager
+                .error(format!("Couldn't verify \"{name}\": worker disconnected"));
+            continue;
+        };
+        match report.summary() {
+            None => orchestrator
+                .notification_manager
+                .success(format!("\"{name}\" looks healthy")),
+            Some(summary) => orchestrator
+                .notification_manager
+                .warn(format!("\"{name}\": {summary}")),
+        }
+        if let Some(entry) = orchestrator.registry.find_mut(&modlist_id) {
+            entry.health = Some(report);
+            orchestrator
+                .persistence_cycle
+                .mark_registry_dirty(Instant::now());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::model::ModlistState;
+    use crate::test_support::temp_dir;
+
+    #[test]
+    fn missing_destination_short_circuits_and_tampered_archives_are_flagged() {
+        let root = temp_dir("health");
+        let destination = root.join("install");
+        let archive_dir = root.join("archives");
+        std::fs::create_dir_all(&destination).unwrap();
+        std::fs::create_dir_all(&archive_dir).unwrap();
+
+        let good = archive_dir.join("good.zip");
+        std::fs::write(&good, b"good").unwrap();
+        let good_hash = hash_file(&good).unwrap();
+        std::fs::rename(
+            &good,
+            archive_dir.join(stored_filename("good.zip", &good_hash)),
+        )
+        .unwrap();
+        std::fs::write(
+            archive_dir.join(stored_filename("bad.zip", "0123")),
+            b"tampered",
+        )
+        .unwrap();
+        let lock = serde_json::json!({
+            "resolved": {"good.zip": good_hash, "bad.zip": "0123", "gone.zip": "4567"}
+        });
+        std::fs::write(
+            destination.join(crate::install_runtime::archive_store::INSTALL_LOCK_FILENAME),
+            lock.to_string(),
+        )
+        .unwrap();
+        let dest = destination.to_string_lossy().to_string();
+        let (checked, changed, missing) = check_archives(&dest, &archive_dir);
+        assert_eq!(checked, 3);
+        assert_eq!(changed, vec!["bad.zip".to_string()]);
+        assert_eq!(missing, vec!["gone.zip".to_string()]);
+
+        let mut entry = ModlistEntry {
+            state: ModlistState::Installed,
+            destination_folder: dest,
+            total_size_bytes: Some(1),
+            ..ModlistEntry::default()
+        };
+        let state = WizardState {
+            step1: crate::app::state::Step1State {
+                mods_archive_folder: archive_dir.to_string_lossy().to_string(),
+                ..crate::app::state::Step1State::default()
+            },
+            ..WizardState::default()
+        };
+        let report = check(&entry, &state);
+        assert!(report.game_binary_missing);
+        assert_eq!(report.log, None);
+        let summary = report.summary().unwrap();
+        assert!(summary.contains("game binary missing"), "{summary}");
+        assert!(summary.contains("2 archive(s)"), "{summary}");
+
+        entry.destination_folder = root.join("gone").to_string_lossy().to_string();
+        let report = check(&entry, &state);
+        assert!(report.destination_missing);
+        assert_eq!(report.summary().as_deref(), Some("install folder missing"));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
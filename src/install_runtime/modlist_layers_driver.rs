@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::BTreeSet;
+use std::time::Instant;
+
+use tracing::warn;
+
+use crate::app::compat_rules::load_rules;
+use crate::app::layer_merge::{self, LayerCompose, OrderCycle};
+use crate::registry::model::Game;
+use crate::registry::modlist_layers::ModlistLayer;
+use crate::registry::store_workspace::WorkspaceStore;
+use crate::registry::workspace_model::ModlistWorkspaceState;
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::workspace::workspace_state_loader::apply_workspace_selection;
+
+#[must_use]
+pub fn workspace_for(orchestrator: &OrchestratorApp, modlist_id: &str) -> ModlistWorkspaceState {
+    orchestrator
+        .workspace_state
+        .get(modlist_id)
+        .cloned()
+        .or_else(|| WorkspaceStore::new_for_id(modlist_id).load().ok())
+        .unwrap_or_default()
+}
+
+pub fn commit_workspace(
+    orchestrator: &mut OrchestratorApp,
+    modlist_id: &str,
+    game: Game,
+    workspace: ModlistWorkspaceState,
+) {
+    if orchestrator.workspace_view.loaded_workspace_id.as_deref() == Some(modlist_id) {
+        apply_workspace_selection(&workspace, game, &mut orchestrator.wizard_state);
+    }
+    orchestrator
+        .workspace_stores
+        .entry(modlist_id.to_string())
+        .or_insert_with(|| WorkspaceStore::new_for_id(modlist_id));
+    orchestrator
+        .workspace_state
+        .insert(modlist_id.to_string(), workspace);
+    orchestrator
+        .persistence_cycle
+        .mark_workspace_dirty(modlist_id, Instant::now());
+}
+
+#[must_use]
+pub fn modlist_mods(orchestrator: &OrchestratorApp, modlist_id: &str) -> Vec<String> {
+    let workspace = workspace_for(orchestrator, modlist_id);
+    workspace
+        .order_bgee
+        .iter()
+        .chain(&workspace.order_bg2ee)
+        .chain(&workspace.order_iwdee)
+        .map(|component| component.tp2.to_ascii_uppercase())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+pub fn save_layer(
+    orchestrator: &mut OrchestratorApp,
+    modlist_id: &str,
+    name: &str,
+    mods: &BTreeSet<String>,
+) {
+    let Some(entry) = orchestrator.registry.find(modlist_id) else {
+        return;
+    };
+    let game = entry.game;
+    let workspace = workspace_for(orchestrator, modlist_id);
+    let layer = ModlistLayer::from_workspace(name, game, &workspace, |component| {
+        mods.contains(&component.tp2.to_ascii_uppercase())
+    });
+    if layer.name.is_empty() || layer.component_count() == 0 {
+        orchestrator
+            .notification_manager
+            .warn("A layer needs a name and at least one component".to_string());
+        return;
+    }
+    let saved = orchestrator.layer_library.save_layer(layer);
+    let message = if saved.revision > 1 {
+        format!(
+            "Updated layer \"{}\" to revision {} ({} components)",
+            saved.name,
+            saved.revision,
+            saved.component_count()
+        )
+    } else {
+        format!(
+            "Saved layer \"{}\" ({} components)",
+            saved.name,
+            saved.component_count()
+        )
+    };
+    persist(orchestrator);
+    orchestrator.notification_manager.success(message);
+}
+
+pub fn delete_layer(orchestrator: &mut OrchestratorApp, layer_id: &str) {
+    orchestrator.layer_library.remove(layer_id);
+    persist(orchestrator);
+}
+
+pub(crate) fn compose_new(
+    orchestrator: &mut OrchestratorApp,
+    name: &str,
+    layer_ids: &[String],
+) -> Option<LayerCompose> {
+    let layers: Vec<&ModlistLayer> = layer_ids
+        .iter()
+        .filter_map(|id| orchestrator.layer_library.find(id))
+        .collect();
+    if layers.is_empty() {
+        return None;
+    }
+    match layer_merge::compose_from_layers(&layers, &load_rules().rules, None) {
+        Ok(composed) => Some(composed),
+        Err(cycle) => {
+            notify_cycle(orchestrator, name, &cycle);
+            None
+        }
+    }
+}
+
+pub fn reapply_layers(orchestrator: &mut OrchestratorApp, modlist_id: &str) {
+    let Some(entry) = orchestrator.registry.find(modlist_id).cloned() else {
+        return;
+    };
+    let layers: Vec<&ModlistLayer> = entry
+        .layers
+        .iter()
+        .filter_map(|applied| orchestrator.layer_library.find(&applied.layer_id))
+        .collect();
+    if layers.is_empty() {
+        orchestrator.notification_manager.warn(format!(
+            "\"{}\" has no layers left in the library to re-apply",
+            entry.name
+        ));
+        return;
+    }
+    let current = workspace_for(orchestrator, modlist_id);
+    let composed = match layer_merge::compose_from_layers(
+        &layers,
+        &load_rules().rules,
+        Some((&current, &entry.layers)),
+    ) {
+        Ok(composed) => composed,
+        Err(cycle) => {
+            notify_cycle(orchestrator, &entry.name, &cycle);
+            return;
+        }
+    };
+
+    commit_workspace(
+        orchestrator,
+        modlist_id,
+        entry.game,
+        composed.workspace.clone(),
+    );
+    if let Some(target) = orchestrator.registry.find_mut(modlist_id) {
+        target.layers = composed.provenance.clone();
+    }
+    orchestrator
+        .persistence_cycle
+        .mark_registry_dirty(Instant::now());
+    notify_composed(orchestrator, &entry.name, &composed);
+}
+
+pub(crate) fn notify_composed(
+    orchestrator: &mut OrchestratorApp,
+    name: &str,
+    composed: &LayerCompose,
+) {
+    orchestrator.notification_manager.success(format!(
+        "Applied {} layer(s) to \"{name}\" ({} components)",
+        composed.provenance.len(),
+        composed.component_count()
+    ));
+}
+
+fn notify_cycle(orchestrator: &mut OrchestratorApp, name: &str, cycle: &OrderCycle) {
+    orchestrator.notification_manager.error(format!(
+        "Couldn't apply layers to \"{name}\": {cycle}. Adjust the layer order or the order rules."
+    ));
+}
+
+fn persist(orchestrator: &OrchestratorApp) {
+    if let Err(err) = orchestrator.layer_store.save(&orchestrator.layer_library) {
+        warn!(
+            target = "orchestrator",
+            "modlist layers write failed: {err} (library kept in memory)"
+        );
+    }
+}
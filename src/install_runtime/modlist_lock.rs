@@ -0,0 +1,715 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::app::app_step2_update_download::archive_file_name;
+use crate::app::mod_downloads::{
+    ModDownloadsLoad, active_modlist_dir, load_mod_download_sources_for_modlist,
+    normalize_mod_download_tp2, source_is_page_archive_url,
+};
+use crate::app::state::{Step2UpdateAsset, WizardState};
+use crate::install_runtime::archive_store::{hash_file, hash_tree};
+use crate::registry::store_workspace::modlist_data_dir;
+
+pub const MODLIST_LOCK_FILENAME: &str = "modlist.lock.toml";
+
+pub const PENDING_LOCK_FILENAME: &str = "modlist.lock.pending.toml";
+
+const LOCK_FORMAT_VERSION: u32 = 1;
+
+static LOCK_WRITES: Mutex<()> = Mutex::new(());
+
+fn lock_writes() -> MutexGuard<'static, ()> {
+    LOCK_WRITES.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModlistLock {
+    pub format_version: u32,
+
+    pub generated_at: Option<DateTime<Utc>>,
+
+    #[serde(rename = "mod")]
+    pub mods: Vec<LockedMod>,
+}
+
+impl Default for ModlistLock {
+    fn default() -> Self {
+        Self {
+            format_version: LOCK_FORMAT_VERSION,
+            generated_at: None,
+            mods: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockedSourceKind {
+    #[default]
+    ReleaseAsset,
+
+    PageArchive,
+
+    SourceSnapshot,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LockedMod {
+    pub tp2: String,
+
+    pub label: String,
+
+    pub source_id: String,
+
+    pub kind: LockedSourceKind,
+
+    pub resolved_ref: String,
+
+    pub asset_name: String,
+
+    pub asset_url: String,
+
+    pub archive_name: String,
+
+    pub archive_hash: String,
+
+    pub tree_hash: String,
+}
+
+impl LockedMod {
+    fn same_pin(&self, other: &Self) -> bool {
+        self.source_id == other.source_id
+            && self.resolved_ref == other.resolved_ref
+            && self.asset_url == other.asset_url
+            && self.archive_hash == other.archive_hash
+            && self.tree_hash == other.tree_hash
+    }
+}
+
+impl ModlistLock {
+    #[must_use]
+    pub fn find(&self, tp2: &str) -> Option<&LockedMod> {
+        let key = normalize_mod_download_tp2(tp2);
+        self.mods
+            .iter()
+            .find(|m| normalize_mod_download_tp2(&m.tp2) == key)
+    }
+
+    pub fn upsert(&mut self, mut locked: LockedMod) {
+        locked.tp2 = normalize_mod_download_tp2(&locked.tp2);
+        match self.mods.iter_mut().find(|m| m.tp2 == locked.tp2) {
+            Some(existing) => *existing = locked,
+            None => self.mods.push(locked),
+        }
+        self.mods.sort_by(|a, b| a.tp2.cmp(&b.tp2));
+    }
+
+    pub fn load(path: &Path) -> Result<Option<Self>, String> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(format!("read {}: {err}", path.display())),
+        };
+        toml::from_str::<Self>(&text)
+            .map(Some)
+            .map_err(|err| format!("parse {}: {err}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let _writes = lock_writes();
+        self.write_to(path)
+    }
+
+    fn write_to(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let text = toml::to_string_pretty(self).map_err(std::io::Error::other)?;
+        let tmp = path.with_extension("toml.tmp");
+        std::fs::write(&tmp, text)?;
+        std::fs::rename(&tmp, path)
+    }
+}
+
+#[must_use]
+pub fn lock_path_for_modlist(modlist_id: &str) -> PathBuf {
+    modlist_data_dir(modlist_id).join(MODLIST_LOCK_FILENAME)
+}
+
+#[must_use]
+pub fn pending_lock_path_for_modlist(modlist_id: &str) -> PathBuf {
+    modlist_data_dir(modlist_id).join(PENDING_LOCK_FILENAME)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LockChange {
+    Added(LockedMod),
+
+    Removed(LockedMod),
+
+    Changed {
+        from: Box<LockedMod>,
+        to: Box<LockedMod>,
+    },
+}
+
+impl LockChange {
+    #[must_use]
+    pub fn tp2(&self) -> &str {
+        match self {
+            Self::Added(m) | Self::Removed(m) => &m.tp2,
+            Self::Changed { to, .. } => &to.tp2,
+        }
+    }
+}
+
+#[must_use]
+pub fn diff_locks(old: &ModlistLock, new: &ModlistLock) -> Vec<LockChange> {
+    let old_by: BTreeMap<&str, &LockedMod> = old.mods.iter().map(|m| (m.tp2.as_str(), m)).collect();
+    let new_by: BTreeMap<&str, &LockedMod> = new.mods.iter().map(|m| (m.tp2.as_str(), m)).collect();
+    let mut out = Vec::new();
+    for (tp2, from) in &old_by {
+        match new_by.get(tp2) {
+            None => out.push(LockChange::Removed((*from).clone())),
+            Some(to) if !from.same_pin(to) => out.push(LockChange::Changed {
+                from: Box::new((*from).clone()),
+                to: Box::new((*to).clone()),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (tp2, to) in &new_by {
+        if !old_by.contains_key(tp2) {
+            out.push(LockChange::Added((*to).clone()));
+        }
+    }
+    out.sort_by(|a, b| a.tp2().cmp(b.tp2()));
+    out
+}
+
+#[must_use]
+pub fn render_lock_diff(changes: &[LockChange]) -> String {
+    if changes.is_empty() {
+        return "lock is up to date; no changes".to_string();
+    }
+    let mut out = String::new();
+    for change in changes {
+        match change {
+            LockChange::Added(m) => {
+                let _ = writeln!(out, "+ {} {} ({})", m.tp2, m.resolved_ref, m.source_id);
+            }
+            LockChange::Removed(m) => {
+                let _ = writeln!(out, "- {} {} ({})", m.tp2, m.resolved_ref, m.source_id);
+            }
+            LockChange::Changed { from, to } => {
+                let mut line = format!("~ {}", to.tp2);
+                if from.source_id != to.source_id {
+                    let _ = write!(line, " source {} -> {}", from.source_id, to.source_id);
+                }
+                if from.resolved_ref == to.resolved_ref {
+                    let _ = write!(line, " {} (content changed)", to.resolved_ref);
+                } else {
+                    let _ = write!(line, " {} -> {}", from.resolved_ref, to.resolved_ref);
+                }
+                let _ = writeln!(out, "{line}");
+            }
+        }
+    }
+    out
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LockMerge {
+    pub lock: ModlistLock,
+
+    pub pending: Option<ModlistLock>,
+}
+
+#[must_use]
+pub fn merge_observed(existing: Option<ModlistLock>, observed: Vec<LockedMod>) -> LockMerge {
+    let Some(mut lock) = existing else {
+        let mut lock = ModlistLock {
+            generated_at: Some(Utc::now()),
+            ..ModlistLock::default()
+        };
+        for m in observed {
+            lock.upsert(m);
+        }
+        return LockMerge {
+            lock,
+            pending: None,
+        };
+    };
+    let mut pending = lock.clone();
+    let mut pending_dirty = false;
+    for m in observed {
+        match lock.find(&m.tp2) {
+            None => {
+                lock.upsert(m.clone());
+                pending.upsert(m);
+            }
+            Some(current) if current.same_pin(&m) => {}
+            Some(_) => {
+                pending.upsert(m);
+                pending_dirty = true;
+            }
+        }
+    }
+    LockMerge {
+        lock,
+        pending: pending_dirty.then_some(pending),
+    }
+}
+
+#[must_use]
+pub fn drift_against_lock(
+    lock: &ModlistLock,
+    asset: &Step2UpdateAsset,
+    archive_hash: Option<&str>,
+    tree_hash: Option<&str>,
+) -> Option<String> {
+    let Some(locked) = lock.find(&asset.tp_file) else {
+        return Some(format!("{} is not in {MODLIST_LOCK_FILENAME}", asset.label));
+    };
+    if locked.source_id != asset.source_id {
+        return Some(format!(
+            "{}: source {} differs from locked {}",
+            asset.label, asset.source_id, locked.source_id
+        ));
+    }
+    let resolved = asset.installed_source_ref.as_deref().unwrap_or(&asset.tag);
+    if locked.resolved_ref != resolved {
+        return Some(format!(
+            "{}: resolved {resolved} differs from locked {}",
+            asset.label, locked.resolved_ref
+        ));
+    }
+    if locked.asset_url != asset.asset_url {
+        return Some(format!(
+            "{}: asset URL {} differs from locked {}",
+            asset.label, asset.asset_url, locked.asset_url
+        ));
+    }
+    if let Some(hash) = archive_hash
+        && hash != locked.archive_hash
+    {
+        return Some(format!(
+            "{}: archive hash {hash} differs from locked {}",
+            asset.label, locked.archive_hash
+        ));
+    }
+    if let Some(hash) = tree_hash
+        && hash != locked.tree_hash
+    {
+        return Some(format!(
+            "{}: extracted tree hash {hash} differs from locked {}",
+            asset.label, locked.tree_hash
+        ));
+    }
+    None
+}
+
+pub fn pin_assets_to_lock(state: &mut WizardState) {
+    if !state.install_from_lock {
+        return;
+    }
+    let Some(lock) = active_modlist_dir().and_then(|dir| {
+        ModlistLock::load(&dir.join(MODLIST_LOCK_FILENAME))
+            .ok()
+            .flatten()
+    }) else {
+        return;
+    };
+    for asset in &mut state.step2.update_selected_update_assets {
+        pin_asset(&lock, asset);
+    }
+}
+
+fn pin_asset(lock: &ModlistLock, asset: &mut Step2UpdateAsset) {
+    let Some(locked) = lock
+        .find(&asset.tp_file)
+        .filter(|locked| locked.source_id == asset.source_id)
+    else {
+        return;
+    };
+    match asset.installed_source_ref.as_mut() {
+        Some(source_ref) => source_ref.clone_from(&locked.resolved_ref),
+        None => asset.tag.clone_from(&locked.resolved_ref),
+    }
+    asset.asset_name.clone_from(&locked.asset_name);
+    asset.asset_url.clone_from(&locked.asset_url);
+}
+
+fn extracted_targets(state: &WizardState) -> Vec<(Step2UpdateAsset, PathBuf)> {
+    state
+        .step2
+        .update_selected_extracted_sources
+        .iter()
+        .filter_map(|entry| entry.split_once(" -> "))
+        .filter_map(|(label, target)| {
+            state
+                .step2
+                .update_selected_update_assets
+                .iter()
+                .find(|a| a.label == label.trim())
+                .map(|a| (a.clone(), PathBuf::from(target.trim())))
+        })
+        .collect()
+}
+
+fn locked_kind(asset: &Step2UpdateAsset, sources: &ModDownloadsLoad) -> LockedSourceKind {
+    if asset.installed_source_ref.is_some() {
+        LockedSourceKind::SourceSnapshot
+    } else if sources
+        .resolve_source(&asset.tp_file, Some(&asset.source_id))
+        .is_some_and(|source| source_is_page_archive_url(&source.url))
+    {
+        LockedSourceKind::PageArchive
+    } else {
+        LockedSourceKind::ReleaseAsset
+    }
+}
+
+fn observed_drift(
+    lock: &ModlistLock,
+    asset: &Step2UpdateAsset,
+    archive: &Path,
+    target: &Path,
+) -> Option<String> {
+    let archive_hash = match hash_file(archive) {
+        Ok(hash) => hash,
+        Err(err) => {
+            return Some(format!(
+                "{}: archive could not be hashed: {err}",
+                asset.label
+            ));
+        }
+    };
+    let tree_hash = match hash_tree(target) {
+        Ok(hash) => hash,
+        Err(err) => {
+            return Some(format!(
+                "{}: extracted tree could not be hashed: {err}",
+                asset.label
+            ));
+        }
+    };
+    drift_against_lock(lock, asset, Some(&archive_hash), Some(&tree_hash))
+}
+
+fn locked_mod_for(
+    asset: &Step2UpdateAsset,
+    kind: LockedSourceKind,
+    archive_dir: &Path,
+    target: &Path,
+) -> std::io::Result<LockedMod> {
+    let archive_name = archive_file_name(asset);
+    Ok(LockedMod {
+        tp2: normalize_mod_download_tp2(&asset.tp_file),
+        label: asset.label.clone(),
+        source_id: asset.source_id.clone(),
+        kind,
+        resolved_ref: asset
+            .installed_source_ref
+            .clone()
+            .unwrap_or_else(|| asset.tag.clone()),
+        asset_name: asset.asset_name.clone(),
+        asset_url: asset.asset_url.clone(),
+        archive_hash: hash_file(&archive_dir.join(&archive_name))?,
+        archive_name,
+        tree_hash: hash_tree(target)?,
+    })
+}
+
+pub fn record_extracted(state: &mut WizardState) {
+    let Some(dir) = active_modlist_dir() else {
+        return;
+    };
+    let targets = extracted_targets(state);
+    if targets.is_empty() {
+        return;
+    }
+    let archive_dir = PathBuf::from(state.step1.mods_archive_folder.trim());
+    let lock_path = dir.join(MODLIST_LOCK_FILENAME);
+
+    if state.install_from_lock {
+        let lock = match ModlistLock::load(&lock_path) {
+            Ok(Some(lock)) => lock,
+            Ok(None) => {
+                state
+                    .step2
+                    .update_selected_lock_drift_sources
+                    .push(format!("{MODLIST_LOCK_FILENAME} is missing"));
+                return;
+            }
+            Err(err) => {
+                state.step2.update_selected_lock_drift_sources.push(err);
+                return;
+            }
+        };
+        for (asset, target) in &targets {
+            let archive = archive_dir.join(archive_file_name(asset));
+            if let Some(drift) = observed_drift(&lock, asset, &archive, target) {
+                state.step2.update_selected_lock_drift_sources.push(drift);
+            }
+        }
+        return;
+    }
+
+    std::thread::spawn(move || {
+        let sources = load_mod_download_sources_for_modlist(&dir);
+        let observed: Vec<LockedMod> = targets
+            .iter()
+            .filter_map(|(asset, target)| {
+                let kind = locked_kind(asset, &sources);
+                match locked_mod_for(asset, kind, &archive_dir, target) {
+                    Ok(m) => Some(m),
+                    Err(err) => {
+                        warn!(
+                            target = "orchestrator",
+                            "modlist lock: could not fingerprint {}: {err}", asset.label
+                        );
+                        None
+                    }
+                }
+            })
+            .collect();
+        let _writes = lock_writes();
+        let existing = ModlistLock::load(&lock_path).unwrap_or_else(|err| {
+            warn!(target = "orchestrator", "modlist lock unreadable: {err}");
+            None
+        });
+        let merged = merge_observed(existing, observed);
+        if let Err(err) = merged.lock.write_to(&lock_path) {
+            warn!(
+                target = "orchestrator",
+                "persist modlist lock {}: {err}",
+                lock_path.display()
+            );
+        }
+        if let Some(pending) = merged.pending {
+            let pending_path = dir.join(PENDING_LOCK_FILENAME);
+            match pending.write_to(&pending_path) {
+                Ok(()) => info!(
+                    target = "orchestrator",
+                    "modlist lock: version changes staged in {}",
+                    pending_path.display()
+                ),
+                Err(err) => warn!(
+                    target = "orchestrator",
+                    "persist pending modlist lock {}: {err}",
+                    pending_path.display()
+                ),
+            }
+        }
+    });
+}
+
+pub fn apply_pending_update(modlist_id: &str) -> Result<Vec<LockChange>, String> {
+    let lock_path = lock_path_for_modlist(modlist_id);
+    let pending_path = pending_lock_path_for_modlist(modlist_id);
+    let _writes = lock_writes();
+    let Some(pending) = ModlistLock::load(&pending_path)? else {
+        return Ok(Vec::new());
+    };
+    let current = ModlistLock::load(&lock_path)?.unwrap_or_default();
+    let changes = diff_locks(&current, &pending);
+    let updated = ModlistLock {
+        generated_at: Some(Utc::now()),
+        ..pending
+    };
+    updated
+        .write_to(&lock_path)
+        .map_err(|err| format!("write {}: {err}", lock_path.display()))?;
+    std::fs::remove_file(&pending_path)
+        .map_err(|err| format!("remove {}: {err}", pending_path.display()))?;
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locked(tp2: &str, r: &str, hash: &str) -> LockedMod {
+        LockedMod {
+            tp2: tp2.to_string(),
+            label: tp2.to_string(),
+            source_id: "github".to_string(),
+            kind: LockedSourceKind::ReleaseAsset,
+            resolved_ref: r.to_string(),
+            asset_name: format!("{tp2}-{r}.zip"),
+            asset_url: format!("https://example/{tp2}-{r}.zip"),
+            archive_name: format!("{tp2}__github__{r}.zip"),
+            archive_hash: hash.to_string(),
+            tree_hash: format!("tree-{hash}"),
+        }
+    }
+
+    fn asset(tp2: &str, tag: &str) -> Step2UpdateAsset {
+        Step2UpdateAsset {
+            game_tab: "BGEE".to_string(),
+            tp_file: format!("{tp2}/setup-{tp2}.tp2"),
+            label: tp2.to_string(),
+            source_id: "github".to_string(),
+            tag: tag.to_string(),
+            asset_name: format!("{tp2}-{tag}.zip"),
+            asset_url: format!("https://example/{tp2}-{tag}.zip"),
+            installed_source_ref: None,
+        }
+    }
+
+    #[test]
+    fn lock_round_trips_through_toml() {
+        let mut lock = ModlistLock::default();
+        lock.upsert(locked("eet", "v13.4", "aa"));
+        lock.upsert(locked("bg1npc", "v26", "bb"));
+        let text = toml::to_string_pretty(&lock).expect("serialize");
+        assert!(
+            text.contains("[[mod]]"),
+            "human-readable array of tables: {text}"
+        );
+        let back: ModlistLock = toml::from_str(&text).expect("parse");
+        assert_eq!(back, lock);
+        assert_eq!(back.mods[0].tp2, "bg1npc", "entries are kept sorted by tp2");
+    }
+
+    #[test]
+    fn unhashable_archive_or_tree_is_reported_as_drift() {
+        let root = crate::test_support::temp_dir("modlist_lock_drift");
+        let mut lock = ModlistLock::default();
+        lock.upsert(locked("eet", "v1", "aa"));
+        let archive = root.join("eet-v1.zip");
+        let tree = root.join("eet");
+
+        let drift = observed_drift(&lock, &asset("eet", "v1"), &archive, &tree);
+        assert!(drift.is_some_and(|d| d.contains("archive could not be hashed")));
+
+        std::fs::write(&archive, "zip").expect("archive");
+        let drift = observed_drift(&lock, &asset("eet", "v1"), &archive, &tree);
+        assert!(drift.is_some_and(|d| d.contains("extracted tree could not be hashed")));
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn first_merge_writes_everything_without_pending() {
+        let merged = merge_observed(None, vec![locked("eet", "v1", "aa")]);
+        assert_eq!(merged.lock.mods.len(), 1);
+        assert!(merged.lock.generated_at.is_some());
+        assert!(merged.pending.is_none());
+    }
+
+    #[test]
+    fn new_mods_are_appended_but_version_changes_are_staged() {
+        let mut existing = ModlistLock::default();
+        existing.upsert(locked("eet", "v1", "aa"));
+        let merged = merge_observed(
+            Some(existing),
+            vec![locked("eet", "v2", "cc"), locked("bg1npc", "v26", "bb")],
+        );
+        assert_eq!(merged.lock.find("eet").unwrap().resolved_ref, "v1");
+        assert!(merged.lock.find("bg1npc").is_some());
+        let pending = merged.pending.expect("eet changed => pending lock");
+        assert_eq!(pending.find("eet").unwrap().resolved_ref, "v2");
+
+        let changes = diff_locks(&merged.lock, &pending);
+        assert_eq!(changes.len(), 1);
+        assert!(matches!(&changes[0], LockChange::Changed { from, to }
+            if from.resolved_ref == "v1" && to.resolved_ref == "v2"));
+    }
+
+    #[test]
+    fn identical_observation_is_a_no_op() {
+        let mut existing = ModlistLock::default();
+        existing.upsert(locked("eet", "v1", "aa"));
+        let merged = merge_observed(Some(existing.clone()), vec![locked("eet", "v1", "aa")]);
+        assert_eq!(merged.lock, existing);
+        assert!(merged.pending.is_none());
+    }
+
+    #[test]
+    fn diff_reports_added_removed_changed() {
+        let mut old = ModlistLock::default();
+        old.upsert(locked("a", "v1", "1"));
+        old.upsert(locked("b", "v1", "2"));
+        let mut new = ModlistLock::default();
+        new.upsert(locked("b", "v1", "3"));
+        new.upsert(locked("c", "v9", "4"));
+        let changes = diff_locks(&old, &new);
+        let text = render_lock_diff(&changes);
+        assert_eq!(changes.len(), 3);
+        assert!(text.contains("- a v1"), "got: {text}");
+        assert!(text.contains("~ b v1 (content changed)"), "got: {text}");
+        assert!(text.contains("+ c v9"), "got: {text}");
+        assert_eq!(render_lock_diff(&[]), "lock is up to date; no changes");
+    }
+
+    #[test]
+    fn drift_detects_ref_url_and_hash_mismatches() {
+        let mut lock = ModlistLock::default();
+        let mut pinned = locked("eet", "v1", "aa");
+        pinned.asset_url = "https://example/eet-v1.zip".to_string();
+        lock.upsert(pinned);
+
+        assert_eq!(
+            drift_against_lock(&lock, &asset("eet", "v1"), Some("aa"), Some("tree-aa")),
+            None
+        );
+        assert!(
+            drift_against_lock(&lock, &asset("eet", "v2"), None, None)
+                .is_some_and(|d| d.contains("v2") && d.contains("locked v1"))
+        );
+        assert!(
+            drift_against_lock(&lock, &asset("eet", "v1"), Some("zz"), None)
+                .is_some_and(|d| d.contains("archive hash"))
+        );
+        assert!(
+            drift_against_lock(&lock, &asset("eet", "v1"), Some("aa"), Some("tree-zz"))
+                .is_some_and(|d| d.contains("tree hash"))
+        );
+        assert!(
+            drift_against_lock(&lock, &asset("other", "v1"), None, None)
+                .is_some_and(|d| d.contains("not in"))
+        );
+        let mut moved = asset("eet", "v1");
+        moved.asset_url = "https://mirror/eet-v1.zip".to_string();
+        assert!(
+            drift_against_lock(&lock, &moved, None, None).is_some_and(|d| d.contains("asset URL"))
+        );
+    }
+
+    #[test]
+    fn upsert_and_find_share_the_tp2_key() {
+        let mut lock = ModlistLock::default();
+        lock.upsert(locked("EET/Setup-EET.tp2", "v1", "aa"));
+        lock.upsert(locked("eet", "v2", "bb"));
+        assert_eq!(lock.mods.len(), 1);
+        assert_eq!(lock.find("eet/setup-eet.tp2").unwrap().resolved_ref, "v2");
+    }
+
+    #[test]
+    fn assets_are_pinned_to_the_locked_ref_and_url() {
+        let mut lock = ModlistLock::default();
+        lock.upsert(locked("eet", "v1", "aa"));
+        let mut newer = asset("eet", "v2");
+        pin_asset(&lock, &mut newer);
+        assert_eq!(newer.tag, "v1");
+        assert_eq!(newer.asset_url, "https://example/eet-v1.zip");
+        assert_eq!(drift_against_lock(&lock, &newer, Some("aa"), None), None);
+
+        let mut other_source = asset("eet", "v2");
+        other_source.source_id = "weasel".to_string();
+        pin_asset(&lock, &mut other_source);
+        assert_eq!(other_source.tag, "v2");
+    }
+}
@@ -0,0 +1,728 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::app::app_step2_update_check::{
+    Step2PackageKind, Step2UpdateCheckOutcome, Step2UpdateCheckRequest,
+};
+use crate::app::app_step2_update_check_worker::{Step2UpdateCheckEvent, Step2UpdateCheckProgress};
+use crate::app::mod_downloads::{
+    active_modlist_dir, load_mod_download_sources_for_modlist, normalize_mod_download_tp2,
+};
+use crate::app::modlist_config_files::validate_relative_config_path;
+use crate::app::step5::prompt_memory;
+use crate::install_runtime::archive_store::{hash_file, stored_filename};
+use crate::install_runtime::modlist_lock::{
+    LockedSourceKind, MODLIST_LOCK_FILENAME, ModlistLock, lock_path_for_modlist,
+};
+use crate::platform_defaults::compose_component_key;
+use crate::registry::model::ModlistEntry;
+use crate::registry::share_export::{
+    ArchiveMeta, ModConfigBytes, bake_archive_meta_into_code, bake_mod_configs_into_code,
+    build_archive_meta_from_install_lock, decode_archive_meta,
+};
+use crate::registry::store_workspace::{WorkspaceStore, modlist_data_dir};
+
+pub const BUNDLE_EXTENSION: &str = "bio-bundle.zip";
+
+const BUNDLE_FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_ENTRY: &str = "bundle.toml";
+
+const PROMPT_ANSWERS_ENTRY: &str = "prompt_answers.json";
+
+const ARCHIVES_PREFIX: &str = "archives/";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BundleManifest {
+    pub format_version: u32,
+
+    pub bio_version: String,
+
+    pub created_at: Option<DateTime<Utc>>,
+
+    pub name: String,
+
+    pub game: String,
+
+    pub share_code: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BundleContents {
+    pub name: String,
+
+    pub game: String,
+
+    pub share_code: String,
+
+    pub lock: Option<ModlistLock>,
+
+    pub prompt_answers: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BundleExportReport {
+    pub output: PathBuf,
+
+    pub archives: usize,
+
+    pub bytes: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UnpackedBundle {
+    pub manifest: BundleManifest,
+
+    pub lock: Option<ModlistLock>,
+
+    pub prompt_answers: Option<String>,
+
+    pub archives_written: usize,
+
+    pub archives_present: usize,
+}
+
+pub fn contents_for_entry(entry: &ModlistEntry) -> Result<BundleContents, String> {
+    let share_code = entry
+        .latest_share_code
+        .clone()
+        .filter(|code| !code.trim().is_empty())
+        .ok_or_else(|| format!("\"{}\" has no import code yet", entry.name))?;
+    let lock_path = lock_path_for_modlist(&entry.id);
+    let lock = ModlistLock::load(&lock_path)?.ok_or_else(|| {
+        format!(
+            "\"{}\" has no {MODLIST_LOCK_FILENAME}; reinstall it once to pin its archives",
+            entry.name
+        )
+    })?;
+    let share_code = bake_mod_configs_into_code(&share_code, &collect_mod_configs(entry, &lock)?)?;
+    let component_keys: HashSet<String> = WorkspaceStore::new_for_id(&entry.id)
+        .load()
+        .map(|workspace| {
+            workspace
+                .order_bgee
+                .iter()
+                .chain(&workspace.order_bg2ee)
+                .chain(&workspace.order_iwdee)
+                .map(|component| compose_component_key(&component.tp2, &component.id.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    let prompt_answers = (!component_keys.is_empty())
+        .then(|| prompt_memory::export_string_for_components(&component_keys))
+        .transpose()
+        .map_err(|err| warn!(target = "orchestrator", "bundle: prompt answers: {err}"))
+        .ok()
+        .flatten();
+    Ok(BundleContents {
+        name: entry.name.clone(),
+        game: entry.game.to_legacy_string().to_string(),
+        share_code,
+        lock: Some(lock),
+        prompt_answers,
+    })
+}
+
+fn collect_mod_configs(
+    entry: &ModlistEntry,
+    lock: &ModlistLock,
+) -> Result<Vec<ModConfigBytes>, String> {
+    let sources = load_mod_download_sources_for_modlist(&modlist_data_dir(&entry.id));
+    let roots = installed_mod_roots(Path::new(&entry.destination_folder));
+    let mut files = Vec::new();
+    for locked in &lock.mods {
+        let Some(root) = roots.get(&normalize_mod_download_tp2(&locked.tp2)) else {
+            continue;
+        };
+        let Some(source) = sources.resolve_source(&locked.tp2, Some(&locked.source_id)) else {
+            continue;
+        };
+        for relative_path in &source.config_files {
+            let relative_path = validate_relative_config_path(relative_path)?;
+            let Ok(bytes) = std::fs::read(root.join(&relative_path)) else {
+                continue;
+            };
+            files.push(ModConfigBytes {
+                tp2: normalize_mod_download_tp2(&locked.tp2),
+                source_id: locked.source_id.clone(),
+                relative_path: relative_path.to_string_lossy().replace('\\', "/"),
+                bytes,
+            });
+        }
+    }
+    Ok(files)
+}
+
+fn installed_mod_roots(destination: &Path) -> HashMap<String, PathBuf> {
+    let mut roots = HashMap::new();
+    if destination.as_os_str().is_empty() {
+        return roots;
+    }
+    let mut game_dirs = vec![destination.to_path_buf()];
+    if let Ok(children) = std::fs::read_dir(destination) {
+        game_dirs.extend(
+            children
+                .flatten()
+                .map(|child| child.path())
+                .filter(|path| path.is_dir()),
+        );
+    }
+    for game_dir in game_dirs {
+        let Ok(text) = std::fs::read_to_string(game_dir.join("weidu.log")) else {
+            continue;
+        };
+        for line in text.lines() {
+            let Some(tp2_path) = line
+                .trim_start()
+                .strip_prefix('~')
+                .and_then(|rest| rest.split('~').next())
+            else {
+                continue;
+            };
+            let tp2_path = tp2_path.replace('\\', "/");
+            let Some(mod_dir) = Path::new(&tp2_path).parent() else {
+                continue;
+            };
+            roots
+                .entry(normalize_mod_download_tp2(&tp2_path))
+                .or_insert_with(|| game_dir.join(mod_dir));
+        }
+    }
+    roots
+}
+
+#[must_use]
+pub fn collect_archive_meta(
+    contents: &BundleContents,
+    destination: &str,
+    archive_dir: &Path,
+) -> Vec<ArchiveMeta> {
+    let mut by_name: BTreeMap<String, ArchiveMeta> = BTreeMap::new();
+    for meta in decode_archive_meta(&contents.share_code).unwrap_or_default() {
+        by_name.insert(meta.name.clone(), meta);
+    }
+    for meta in build_archive_meta_from_install_lock(destination, archive_dir) {
+        by_name.entry(meta.name.clone()).or_insert(meta);
+    }
+    for locked in contents.lock.iter().flat_map(|lock| &lock.mods) {
+        if locked.archive_name.is_empty() || by_name.contains_key(&locked.archive_name) {
+            continue;
+        }
+        let size = locate_archive(archive_dir, &locked.archive_name, &locked.archive_hash)
+            .and_then(|path| std::fs::metadata(path).ok())
+            .map(|m| m.len());
+        if let Some(size) = size {
+            by_name.insert(
+                locked.archive_name.clone(),
+                ArchiveMeta {
+                    name: locked.archive_name.clone(),
+                    size,
+                    hash: locked.archive_hash.clone(),
+                },
+            );
+        }
+    }
+    by_name.into_values().collect()
+}
+
+fn locate_archive(archive_dir: &Path, name: &str, hash: &str) -> Option<PathBuf> {
+    [
+        archive_dir.join(stored_filename(name, hash)),
+        archive_dir.join(name),
+    ]
+    .into_iter()
+    .find(|path| path.is_file() && hash_file(path).is_ok_and(|h| h == hash))
+}
+
+pub fn export_bundle(
+    contents: &BundleContents,
+    archives: &[ArchiveMeta],
+    archive_dir: &Path,
+    output: &Path,
+) -> Result<BundleExportReport, String> {
+    if archives.is_empty() {
+        return Err("no archives with known hashes to bundle".to_string());
+    }
+    let mut sources = Vec::with_capacity(archives.len());
+    let mut missing = Vec::new();
+    for meta in archives {
+        match locate_archive(archive_dir, &meta.name, &meta.hash) {
+            Some(path) => sources.push((meta, path)),
+            None => missing.push(meta.name.as_str()),
+        }
+    }
+    if !missing.is_empty() {
+        return Err(format!(
+            "{} archive(s) missing or changed in {}: {}",
+            missing.len(),
+            archive_dir.display(),
+            missing.join(", ")
+        ));
+    }
+
+    let manifest = BundleManifest {
+        format_version: BUNDLE_FORMAT_VERSION,
+        bio_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: Some(Utc::now()),
+        name: contents.name.clone(),
+        game: contents.game.clone(),
+        share_code: bake_archive_meta_into_code(&contents.share_code, archives)?,
+    };
+
+    if let Some(parent) = output.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+    }
+    let tmp = output.with_extension("zip.tmp");
+    let written = write_bundle(&tmp, &manifest, contents, &sources).and_then(|bytes| {
+        std::fs::rename(&tmp, output)
+            .map(|()| bytes)
+            .map_err(|err| err.to_string())
+    });
+    if written.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    Ok(BundleExportReport {
+        output: output.to_path_buf(),
+        archives: sources.len(),
+        bytes: written?,
+    })
+}
+
+fn write_bundle(
+    path: &Path,
+    manifest: &BundleManifest,
+    contents: &BundleContents,
+    sources: &[(&ArchiveMeta, PathBuf)],
+) -> Result<u64, String> {
+    let file = File::create(path).map_err(|err| err.to_string())?;
+    let mut zip = ZipWriter::new(file);
+    let text = SimpleFileOptions::default();
+    let err = |e: zip::result::ZipError| e.to_string();
+
+    let manifest_text = toml::to_string_pretty(manifest).map_err(|e| e.to_string())?;
+    zip.start_file(MANIFEST_ENTRY, text).map_err(err)?;
+    zip.write_all(manifest_text.as_bytes())
+        .map_err(|e| e.to_string())?;
+    if let Some(lock) = contents.lock.as_ref() {
+        let lock_text = toml::to_string_pretty(lock).map_err(|e| e.to_string())?;
+        zip.start_file(MODLIST_LOCK_FILENAME, text).map_err(err)?;
+        zip.write_all(lock_text.as_bytes())
+            .map_err(|e| e.to_string())?;
+    }
+    if let Some(answers) = contents.prompt_answers.as_deref() {
+        zip.start_file(PROMPT_ANSWERS_ENTRY, text).map_err(err)?;
+        zip.write_all(answers.as_bytes())
+            .map_err(|e| e.to_string())?;
+    }
+    for (meta, source) in sources {
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Stored)
+            .large_file(meta.size > u64::from(u32::MAX));
+        zip.start_file(format!("{ARCHIVES_PREFIX}{}", meta.name), options)
+            .map_err(err)?;
+        let mut input = File::open(source).map_err(|e| e.to_string())?;
+        io::copy(&mut input, &mut zip).map_err(|e| e.to_string())?;
+    }
+    let file = zip.finish().map_err(err)?;
+    file.metadata().map(|m| m.len()).map_err(|e| e.to_string())
+}
+
+fn open_bundle(bundle: &Path) -> Result<ZipArchive<File>, String> {
+    let file = File::open(bundle).map_err(|err| format!("open {}: {err}", bundle.display()))?;
+    ZipArchive::new(file).map_err(|err| format!("{} is not a BIO bundle: {err}", bundle.display()))
+}
+
+fn read_text_entry(zip: &mut ZipArchive<File>, name: &str) -> Result<Option<String>, String> {
+    let mut entry = match zip.by_name(name) {
+        Ok(entry) => entry,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+        Err(err) => return Err(format!("bundle entry {name}: {err}")),
+    };
+    let mut text = String::new();
+    entry
+        .read_to_string(&mut text)
+        .map_err(|err| format!("bundle entry {name}: {err}"))?;
+    Ok(Some(text))
+}
+
+fn parse_manifest(zip: &mut ZipArchive<File>) -> Result<BundleManifest, String> {
+    let text = read_text_entry(zip, MANIFEST_ENTRY)?
+        .ok_or_else(|| format!("bundle has no {MANIFEST_ENTRY}"))?;
+    let manifest: BundleManifest =
+        toml::from_str(&text).map_err(|err| format!("{MANIFEST_ENTRY}: {err}"))?;
+    if manifest.format_version > BUNDLE_FORMAT_VERSION {
+        return Err(format!(
+            "bundle was written by a newer BIO (format {}); this build reads up to format \
+             {BUNDLE_FORMAT_VERSION}",
+            manifest.format_version
+        ));
+    }
+    if manifest.share_code.trim().is_empty() {
+        return Err("bundle manifest has no import code".to_string());
+    }
+    Ok(manifest)
+}
+
+pub fn read_manifest(bundle: &Path) -> Result<BundleManifest, String> {
+    parse_manifest(&mut open_bundle(bundle)?)
+}
+
+fn is_plain_file_name(name: &str) -> bool {
+    !name.is_empty()
+        && name != "."
+        && name != ".."
+        && !name.contains(['/', '\\', ':'])
+        && !name.starts_with('.')
+}
+
+pub fn unpack_bundle(bundle: &Path, archive_dir: &Path) -> Result<UnpackedBundle, String> {
+    if archive_dir.as_os_str().is_empty() {
+        return Err("set a mods archive folder in Settings before importing a bundle".to_string());
+    }
+    let mut zip = open_bundle(bundle)?;
+    let manifest = parse_manifest(&mut zip)?;
+    let expected = decode_archive_meta(&manifest.share_code)?;
+    if expected.is_empty() {
+        return Err("bundle import code carries no archive hashes to verify".to_string());
+    }
+    if let Some(bad) = expected.iter().find(|m| !is_plain_file_name(&m.name)) {
+        return Err(format!(
+            "bundle lists an unsafe archive name: {:?}",
+            bad.name
+        ));
+    }
+    let lock = read_text_entry(&mut zip, MODLIST_LOCK_FILENAME)?
+        .map(|text| toml::from_str::<ModlistLock>(&text))
+        .transpose()
+        .map_err(|err| format!("{MODLIST_LOCK_FILENAME}: {err}"))?;
+    let prompt_answers = read_text_entry(&mut zip, PROMPT_ANSWERS_ENTRY)?;
+
+    std::fs::create_dir_all(archive_dir)
+        .map_err(|err| format!("create {}: {err}", archive_dir.display()))?;
+    let mut unpacked = UnpackedBundle {
+        manifest,
+        lock,
+        prompt_answers,
+        ..UnpackedBundle::default()
+    };
+    for meta in &expected {
+        let target = archive_dir.join(&meta.name);
+        if target.is_file() && hash_file(&target).is_ok_and(|h| h == meta.hash) {
+            unpacked.archives_present += 1;
+            continue;
+        }
+        extract_verified(&mut zip, meta, &target)?;
+        unpacked.archives_written += 1;
+    }
+    Ok(unpacked)
+}
+
+fn extract_verified(
+    zip: &mut ZipArchive<File>,
+    meta: &ArchiveMeta,
+    target: &Path,
+) -> Result<(), String> {
+    let entry_name = format!("{ARCHIVES_PREFIX}{}", meta.name);
+    let mut entry = zip
+        .by_name(&entry_name)
+        .map_err(|err| format!("bundle is missing {}: {err}", meta.name))?;
+    if entry.size() != meta.size {
+        return Err(format!(
+            "{} in bundle is {} bytes; the import code expects {}",
+            meta.name,
+            entry.size(),
+            meta.size
+        ));
+    }
+    let tmp = target.with_extension("bundle.tmp");
+    let copied = File::create(&tmp)
+        .and_then(|mut out| io::copy(&mut entry, &mut out))
+        .map_err(|err| format!("extract {}: {err}", meta.name));
+    let verified = copied.and_then(|_| {
+        let hash = hash_file(&tmp).map_err(|err| format!("hash {}: {err}", meta.name))?;
+        if hash == meta.hash {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} in bundle has hash {hash}; the import code expects {}",
+                meta.name, meta.hash
+            ))
+        }
+    });
+    match verified.and_then(|()| {
+        std::fs::rename(&tmp, target).map_err(|err| format!("place {}: {err}", meta.name))
+    }) {
+        Ok(()) => Ok(()),
+        Err(err) => {
+            let _ = std::fs::remove_file(&tmp);
+            Err(err)
+        }
+    }
+}
+
+pub(crate) fn resolve_from_lock(
+    lock: Option<&ModlistLock>,
+    requests: Vec<Step2UpdateCheckRequest>,
+) -> Vec<Step2UpdateCheckOutcome> {
+    requests
+        .into_iter()
+        .map(|request| {
+            let Some(locked) = lock.and_then(|lock| lock.find(&request.tp_file)) else {
+                return crate::app::app_step2_update_check::failed_outcome(
+                    request,
+                    "not pinned in the offline bundle",
+                );
+            };
+            Step2UpdateCheckOutcome {
+                game_tab: request.game_tab,
+                modlist_id: request.modlist_id,
+                tp_file: request.tp_file,
+                label: request.label,
+                source_id: locked.source_id.clone(),
+                tag: Some(locked.resolved_ref.clone()),
+                source_ref: Some(locked.resolved_ref.clone()),
+                asset_name: Some(locked.asset_name.clone()),
+                asset_url: Some(locked.asset_url.clone()),
+                error: None,
+                package_kind: match locked.kind {
+                    LockedSourceKind::ReleaseAsset => Step2PackageKind::ReleaseAsset,
+                    LockedSourceKind::PageArchive => Step2PackageKind::PageArchive,
+                    LockedSourceKind::SourceSnapshot => Step2PackageKind::SourceSnapshot,
+                },
+                version_pin_overridden: None,
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn spawn_offline_update_check(
+    requests: Vec<Step2UpdateCheckRequest>,
+) -> mpsc::Receiver<Step2UpdateCheckEvent> {
+    let (tx, rx) = mpsc::channel();
+    let lock = active_modlist_dir().and_then(|dir| {
+        ModlistLock::load(&dir.join(MODLIST_LOCK_FILENAME)).unwrap_or_else(|err| {
+            warn!(target = "orchestrator", "offline resolve: {err}");
+            None
+        })
+    });
+    let total = requests.len();
+    let outcomes = resolve_from_lock(lock.as_ref(), requests);
+    let _ = tx.send(Step2UpdateCheckEvent::Progress(Step2UpdateCheckProgress {
+        completed: total,
+        total,
+    }));
+    let _ = tx.send(Step2UpdateCheckEvent::Finished(outcomes));
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::install_runtime::modlist_lock::LockedMod;
+    use crate::test_support::temp_dir;
+
+    fn share_code() -> String {
+        "BIO-MODLIST-V1:eJyrVkrLL8pNLIkvSy0qzszPU7Iy1FFKT8xNjc_MKy5JzMlRslJydQ1RqgUAPDgOUg"
+            .to_string()
+    }
+
+    fn meta_for(path: &Path) -> ArchiveMeta {
+        ArchiveMeta {
+            name: path.file_name().unwrap().to_string_lossy().into_owned(),
+            size: std::fs::metadata(path).unwrap().len(),
+            hash: hash_file(path).unwrap(),
+        }
+    }
+
+    fn contents() -> BundleContents {
+        BundleContents {
+            name: "Offline".to_string(),
+            game: "EET".to_string(),
+            share_code: share_code(),
+            lock: Some(ModlistLock {
+                mods: vec![LockedMod {
+                    tp2: "stratagems".to_string(),
+                    source_id: "github".to_string(),
+                    resolved_ref: "v35.0".to_string(),
+                    asset_name: "stratagems-v35.0.zip".to_string(),
+                    asset_url: "https://example/stratagems-v35.0.zip".to_string(),
+                    archive_name: "stratagems__github__v35.0.zip".to_string(),
+                    ..LockedMod::default()
+                }],
+                ..ModlistLock::default()
+            }),
+            prompt_answers: Some("{\"entries\":[]}".to_string()),
+        }
+    }
+
+    #[test]
+    fn export_then_unpack_round_trips_archives_and_lock() {
+        let src = temp_dir("offline_bundle");
+        let archive = src.join("stratagems__github__v35.0.zip");
+        std::fs::write(&archive, b"stratagems archive bytes").unwrap();
+        let metas = vec![meta_for(&archive)];
+        let bundle = src.join(format!("offline.{BUNDLE_EXTENSION}"));
+
+        let report = export_bundle(&contents(), &metas, &src, &bundle).expect("export");
+        assert_eq!(report.archives, 1);
+        assert!(!bundle.with_extension("zip.tmp").exists());
+
+        let manifest = read_manifest(&bundle).expect("manifest");
+        assert_eq!(manifest.name, "Offline");
+        assert_eq!(decode_archive_meta(&manifest.share_code).unwrap(), metas);
+
+        let dst = temp_dir("offline_bundle");
+        let unpacked = unpack_bundle(&bundle, &dst).expect("unpack");
+        assert_eq!(
+            (unpacked.archives_written, unpacked.archives_present),
+            (1, 0)
+        );
+        assert_eq!(
+            std::fs::read(dst.join("stratagems__github__v35.0.zip")).unwrap(),
+            b"stratagems archive bytes"
+        );
+        assert_eq!(unpacked.lock, contents().lock);
+        assert!(unpacked.prompt_answers.is_some());
+
+        let again = unpack_bundle(&bundle, &dst).expect("second unpack");
+        assert_eq!((again.archives_written, again.archives_present), (0, 1));
+        let _ = std::fs::remove_dir_all(&src);
+        let _ = std::fs::remove_dir_all(&dst);
+    }
+
+    #[test]
+    fn export_refuses_archives_whose_hash_changed() {
+        let src = temp_dir("offline_bundle");
+        let archive = src.join("a__github__1.zip");
+        std::fs::write(&archive, b"original").unwrap();
+        let metas = vec![meta_for(&archive)];
+        std::fs::write(&archive, b"tampered").unwrap();
+        let err = export_bundle(&contents(), &metas, &src, &src.join("x.zip")).unwrap_err();
+        assert!(err.contains("a__github__1.zip"), "{err}");
+        let _ = std::fs::remove_dir_all(&src);
+    }
+
+    #[test]
+    fn unpack_rejects_archive_that_does_not_match_embedded_hash() {
+        let src = temp_dir("offline_bundle");
+        let archive = src.join("a__github__1.zip");
+        std::fs::write(&archive, b"original").unwrap();
+        let mut metas = vec![meta_for(&archive)];
+        metas[0].hash = "0".repeat(32);
+        let bundle = src.join("bad.zip");
+        let file = File::create(&bundle).unwrap();
+        let mut zip = ZipWriter::new(file);
+        let manifest = BundleManifest {
+            format_version: BUNDLE_FORMAT_VERSION,
+            share_code: bake_archive_meta_into_code(&share_code(), &metas).unwrap(),
+            ..BundleManifest::default()
+        };
+        zip.start_file(MANIFEST_ENTRY, SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(toml::to_string(&manifest).unwrap().as_bytes())
+            .unwrap();
+        zip.start_file("archives/a__github__1.zip", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"original").unwrap();
+        zip.finish().unwrap();
+
+        let dst = temp_dir("offline_bundle");
+        let err = unpack_bundle(&bundle, &dst).unwrap_err();
+        assert!(err.contains("expects"), "{err}");
+        assert_eq!(std::fs::read_dir(&dst).unwrap().count(), 0);
+        let _ = std::fs::remove_dir_all(&src);
+        let _ = std::fs::remove_dir_all(&dst);
+    }
+
+    #[test]
+    fn mod_roots_come_from_the_installed_weidu_logs() {
+        let dest = temp_dir("offline_bundle");
+        std::fs::create_dir_all(dest.join("bg2")).unwrap();
+        std::fs::write(
+            dest.join("bg2").join("weidu.log"),
+            "~STRATAGEMS\\SETUP-STRATAGEMS.TP2~ #0 #0 // Core\n~EET/EET.TP2~ #0 #0 // EET\n",
+        )
+        .unwrap();
+        let roots = installed_mod_roots(&dest);
+        assert_eq!(
+            roots.get("stratagems"),
+            Some(&dest.join("bg2").join("STRATAGEMS"))
+        );
+        assert_eq!(roots.get("eet"), Some(&dest.join("bg2").join("EET")));
+        assert!(installed_mod_roots(Path::new("")).is_empty());
+        let _ = std::fs::remove_dir_all(&dest);
+    }
+
+    #[test]
+    fn archive_names_must_be_plain_file_names() {
+        assert!(is_plain_file_name("mod__github__v1.zip"));
+        for bad in [
+            "",
+            "..",
+            "../evil.zip",
+            "sub/a.zip",
+            "a\\b.zip",
+            "C:x.zip",
+            ".hidden",
+        ] {
+            assert!(!is_plain_file_name(bad), "{bad}");
+        }
+    }
+
+    #[test]
+    fn offline_resolve_answers_from_lock_and_fails_unpinned() {
+        let request = |tp: &str| Step2UpdateCheckRequest {
+            game_tab: "BGEE".to_string(),
+            modlist_id: String::new(),
+            tp_file: tp.to_string(),
+            label: tp.to_string(),
+            source_id: "github".to_string(),
+            repo: String::new(),
+            exact_github: Vec::new(),
+            source_url: String::new(),
+            channel: None,
+            tag: None,
+            commit: None,
+            branch: None,
+            asset: None,
+            pkg: None,
+            requested_version: None,
+        };
+        let mut lock = contents().lock;
+        if let Some(lock) = lock.as_mut() {
+            lock.mods[0].kind = LockedSourceKind::SourceSnapshot;
+        }
+        let outcomes = resolve_from_lock(
+            lock.as_ref(),
+            vec![
+                request("STRATAGEMS/SETUP-STRATAGEMS.TP2"),
+                request("other.tp2"),
+            ],
+        );
+        assert_eq!(outcomes[0].tag.as_deref(), Some("v35.0"));
+        assert_eq!(
+            outcomes[0].asset_url.as_deref(),
+            Some("https://example/stratagems-v35.0.zip")
+        );
+        assert!(outcomes[0].error.is_none());
+        assert!(matches!(
+            outcomes[0].package_kind,
+            Step2PackageKind::SourceSnapshot
+        ));
+        assert!(outcomes[1].tag.is_none());
+        assert!(outcomes[1].error.is_some());
+    }
+}
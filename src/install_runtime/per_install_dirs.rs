@@ -101,6 +101,122 @@ pub fn resolve(destination: &str, game: Game) -> PerInstallDirs {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallSlot {
+    Bgee,
+    Bg2ee,
+}
+
+impl InstallSlot {
+    #[must_use]
+    pub const fn for_game(game: Game) -> Self {
+        match game {
+            Game::BG2EE | Game::EET => Self::Bg2ee,
+            Game::BGEE | Game::IWDEE => Self::Bgee,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstalledGameDir {
+    pub slot: InstallSlot,
+    pub dir: PathBuf,
+}
+
+#[must_use]
+pub fn installed_game_dirs(destination: &str, game: Game) -> Vec<InstalledGameDir> {
+    let dest = Path::new(destination.trim());
+    let existing = |dir: PathBuf| {
+        if dir.is_dir() {
+            dir
+        } else {
+            dest.to_path_buf()
+        }
+    };
+    let dirs = resolve(destination, game);
+    match (dirs.eet_clone_dirs, dirs.single_game_clone_dir) {
+        (Some((pre, fin)), _) => vec![
+            InstalledGameDir {
+                slot: InstallSlot::Bgee,
+                dir: existing(pre),
+            },
+            InstalledGameDir {
+                slot: InstallSlot::Bg2ee,
+                dir: existing(fin),
+            },
+        ],
+        (None, single) => vec![InstalledGameDir {
+            slot: InstallSlot::for_game(game),
+            dir: single.map_or_else(|| dest.to_path_buf(), existing),
+        }],
+    }
+}
+
+#[must_use]
+pub fn installed_game_dirs_for_step1(step1: &Step1State) -> Vec<InstalledGameDir> {
+    let chosen = |enabled: bool, over: &str, fallback: &str| {
+        let dir = if enabled && !over.trim().is_empty() {
+            over
+        } else {
+            fallback
+        };
+        PathBuf::from(dir.trim())
+    };
+    match Game::from_legacy_string(&step1.game_install) {
+        Game::EET => vec![
+            InstalledGameDir {
+                slot: InstallSlot::Bgee,
+                dir: chosen(
+                    step1.new_pre_eet_dir_enabled,
+                    &step1.eet_pre_dir,
+                    source_game_folder(step1, Game::BGEE),
+                ),
+            },
+            InstalledGameDir {
+                slot: InstallSlot::Bg2ee,
+                dir: chosen(
+                    step1.new_eet_dir_enabled,
+                    &step1.eet_new_dir,
+                    source_game_folder(step1, Game::BG2EE),
+                ),
+            },
+        ],
+        game => vec![InstalledGameDir {
+            slot: InstallSlot::for_game(game),
+            dir: chosen(
+                step1.generate_directory_enabled,
+                &step1.generate_directory,
+                source_game_folder(step1, game),
+            ),
+        }],
+    }
+}
+
+#[must_use]
+pub fn source_game_folder(step1: &Step1State, game: Game) -> &str {
+    let eet = step1.game_install == "EET";
+    match game {
+        Game::BGEE if eet && !step1.new_pre_eet_dir_enabled => {
+            pick(&step1.eet_bgee_game_folder, &step1.bgee_game_folder)
+        }
+        Game::BG2EE if eet && !step1.new_eet_dir_enabled => {
+            pick(&step1.eet_bg2ee_game_folder, &step1.bg2ee_game_folder)
+        }
+        Game::BGEE => pick(&step1.bgee_game_folder, &step1.eet_bgee_game_folder),
+        Game::BG2EE => pick(&step1.bg2ee_game_folder, &step1.eet_bg2ee_game_folder),
+        Game::IWDEE => pick(&step1.iwdee_game_folder, &step1.bgee_game_folder),
+        Game::EET => pick(&step1.eet_bg2ee_game_folder, &step1.bg2ee_game_folder),
+    }
+}
+
+fn pick<'a>(primary: &'a str, fallback: &'a str) -> &'a str {
+    if primary.trim().is_empty() {
+        fallback.trim()
+    } else {
+        primary.trim()
+    }
+}
+
 pub fn derive_per_install_dirs(
     wizard_state_step1: &mut Step1State,
     destination: &str,
@@ -330,6 +446,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn installed_dirs_agree_for_registry_and_derived_step1() {
+        for game in [Game::BGEE, Game::BG2EE, Game::IWDEE, Game::EET] {
+            let dest = td();
+            let dest_s = dest.to_string_lossy().into_owned();
+            let mut step1 = Step1State {
+                game_install: game.to_legacy_string().to_string(),
+                ..Step1State::default()
+            };
+            derive_per_install_dirs(&mut step1, &dest_s, game).expect("derive");
+            let from_entry = installed_game_dirs(&dest_s, game);
+            assert_eq!(
+                from_entry,
+                installed_game_dirs_for_step1(&step1),
+                "{game:?}"
+            );
+            let _ = std::fs::remove_dir_all(&dest);
+        }
+        let iwdee = installed_game_dirs(r"C:\games\iwd", Game::IWDEE);
+        assert_eq!(iwdee.len(), 1);
+        assert_eq!(iwdee[0].slot, InstallSlot::Bgee);
+    }
+
     #[test]
     fn empty_destination_is_an_error() {
         let mut step1 = Step1State::default();
@@ -8,7 +8,7 @@ use chrono::Utc;
 use tracing::warn;
 
 use crate::app::state::WizardState;
-use crate::install_runtime::import_code_writer;
+use crate::install_runtime::{import_code_writer, install_verification};
 use crate::registry::model::{ModlistEntry, ModlistRegistry, ModlistState};
 use crate::registry::share_export::{self, ArchiveMeta, ShareMeta};
 use crate::registry::store::RegistryStore;
@@ -57,6 +57,7 @@ pub fn flip_to_installed(
     share_code_override: Option<&str>,
 ) -> Option<SizeWorkerReceiver> {
     let (mod_count, component_count) = count_mods_and_components(wizard_state);
+    let verdict = install_verification::verify_install(wizard_state);
 
     let Some(entry_ref) = registry.find(id) else {
         warn!(
@@ -92,6 +93,8 @@ pub fn flip_to_installed(
     entry.install_date = Some(Utc::now());
     entry.mod_count = mod_count;
     entry.component_count = component_count;
+    entry.install_verdict = (verdict.planned > 0).then_some(verdict);
+    entry.health = None;
 
     let verified_code = new_code.clone();
     entry.latest_share_code = Some(new_code);
@@ -206,7 +209,7 @@ fn spawn_size_worker(id: &str, destination: String) -> Option<SizeWorkerReceiver
     Some(rx)
 }
 
-fn directory_size_bytes(root: &Path) -> u64 {
+pub(crate) fn directory_size_bytes(root: &Path) -> u64 {
     if !root.is_dir() {
         return 0;
     }
@@ -4,18 +4,33 @@
 use tracing::warn;
 
 use crate::app::modlist_share::preview_modlist_share_code;
+use crate::install_runtime::modlist_lock::lock_path_for_modlist;
 use crate::registry::model::ModlistEntry;
 use crate::ui::install::state_install::{DestChoice, InstallStage, PreviewTab};
 use crate::ui::orchestrator::nav_destination::NavDestination;
 use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
 
 pub fn start_reinstall(modlist: &ModlistEntry, orchestrator: &mut OrchestratorApp) {
+    stage_entry_install(modlist, orchestrator, DestChoice::Clear);
+
+    orchestrator.pending_reinstall_id = Some(modlist.id.clone());
+
+    orchestrator.install_screen_state.stage = InstallStage::Preview;
+    orchestrator.nav = NavDestination::Install;
+}
+
+pub fn stage_entry_install(
+    modlist: &ModlistEntry,
+    orchestrator: &mut OrchestratorApp,
+    choice: DestChoice,
+) {
     let st = &mut orchestrator.install_screen_state;
 
     st.destination.clone_from(&modlist.destination_folder);
     st.import_code = modlist.latest_share_code.clone().unwrap_or_default();
 
-    st.destination_choice = Some(DestChoice::Clear);
+    st.destination_choice = Some(choice);
+    st.install_from_lock = lock_path_for_modlist(&modlist.id).exists().then_some(true);
 
     st.clear_preview();
     match preview_modlist_share_code(st.import_code.trim()) {
@@ -35,7 +50,7 @@ pub fn start_reinstall(modlist: &ModlistEntry, orchestrator: &mut OrchestratorAp
         }
     }
 
-    let dest_flags = DestChoice::Clear.to_flags();
+    let dest_flags = choice.to_flags();
     orchestrator
         .wizard_state
         .step1
@@ -44,11 +59,6 @@ pub fn start_reinstall(modlist: &ModlistEntry, orchestrator: &mut OrchestratorAp
         .wizard_state
         .step1
         .backup_targets_before_eet_copy = dest_flags.backup_targets_before_eet_copy;
-
-    orchestrator.pending_reinstall_id = Some(modlist.id.clone());
-
-    orchestrator.install_screen_state.stage = InstallStage::Preview;
-    orchestrator.nav = NavDestination::Install;
 }
 
 #[cfg(test)]
@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::time::Instant;
+
+use tracing::warn;
+
+use crate::app::state::{Step1State, WizardState};
+use crate::install_runtime::modlist_layers_driver::workspace_for;
+use crate::settings::layers::{self, LayerInputs, ResolvedSettings};
+use crate::settings::model::Step1Settings;
+use crate::settings::profiles::{ProfilesStore, SettingsOverrides, SettingsProfiles};
+use crate::ui::orchestrator::nav_destination::NavDestination;
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::workspace::workspace_state_loader::populate_wizard_state_from_workspace;
+
+#[derive(Debug, Clone)]
+pub struct AppliedLayers {
+    pub modlist_id: Option<String>,
+    pub resolved: ResolvedSettings,
+}
+
+#[must_use]
+pub fn load_profiles(store: &ProfilesStore) -> SettingsProfiles {
+    store.load().unwrap_or_else(|err| {
+        warn!(
+            target = "orchestrator",
+            "settings profiles load failed: {err}; starting without profiles"
+        );
+        SettingsProfiles::default()
+    })
+}
+
+pub fn persist(orchestrator: &OrchestratorApp) {
+    if let Err(err) = orchestrator
+        .settings_profiles_store
+        .save(&orchestrator.settings_profiles)
+    {
+        warn!(
+            target = "orchestrator",
+            "settings profiles write failed: {err} (profiles kept in memory)"
+        );
+    }
+}
+
+fn resolve_for(
+    orchestrator: &OrchestratorApp,
+    base: &Step1State,
+    modlist_id: Option<&str>,
+) -> ResolvedSettings {
+    let entry = modlist_id.and_then(|id| orchestrator.registry.find(id));
+    let launch = layers::launch_overrides();
+    let env = layers::env_overrides();
+    let profile_name = launch
+        .profile
+        .as_deref()
+        .or_else(|| entry.and_then(|e| e.settings_profile.as_deref()))
+        .or(orchestrator.settings_profiles.active.as_deref());
+    let base: Step1Settings = base.clone().into();
+    layers::resolve(
+        &base,
+        &orchestrator.settings_profiles,
+        LayerInputs {
+            profile_name,
+            modlist: entry.map(|e| &e.settings_overrides),
+            env: Some(&env),
+            cli: Some(&launch.settings),
+            explicit: &[],
+        },
+    )
+}
+
+pub fn apply(orchestrator: &mut OrchestratorApp, modlist_id: Option<&str>) {
+    let resolved = resolve_for(orchestrator, &orchestrator.wizard_state.step1, modlist_id);
+    for err in &resolved.errors {
+        warn!(target = "orchestrator", "settings layer skipped: {err}");
+    }
+    orchestrator.settings_layers = Some(AppliedLayers {
+        modlist_id: modlist_id.map(str::to_string),
+        resolved,
+    });
+}
+
+#[must_use]
+pub fn effective_step1(
+    orchestrator: &OrchestratorApp,
+    base: &Step1State,
+    modlist_id: Option<&str>,
+) -> Step1State {
+    let resolved = resolve_for(orchestrator, base, modlist_id);
+    if resolved.applied.is_empty() {
+        base.clone()
+    } else {
+        resolved.settings.into()
+    }
+}
+
+#[must_use]
+pub fn modlist_wizard_state(orchestrator: &OrchestratorApp, modlist_id: &str) -> WizardState {
+    let mut state =
+        if orchestrator.workspace_view.loaded_workspace_id.as_deref() == Some(modlist_id) {
+            orchestrator.wizard_state.clone()
+        } else {
+            let mut state = WizardState::default();
+            if let Ok(settings) = orchestrator.settings_store.load() {
+                state.step1 = settings.step1.into();
+            }
+            if let Some(entry) = orchestrator.registry.find(modlist_id) {
+                populate_wizard_state_from_workspace(
+                    &workspace_for(orchestrator, modlist_id),
+                    entry,
+                    &orchestrator.settings_store,
+                    &mut state,
+                );
+            }
+            state
+        };
+    state.step1 = effective_step1(orchestrator, &state.step1, Some(modlist_id));
+    state
+}
+
+#[must_use]
+pub fn modlist_step1(orchestrator: &OrchestratorApp, modlist_id: &str) -> Step1State {
+    modlist_wizard_state(orchestrator, modlist_id).step1
+}
+
+pub fn with_effective_step1<R>(
+    orchestrator: &mut OrchestratorApp,
+    run: impl FnOnce(&mut OrchestratorApp) -> R,
+) -> R {
+    let scope = orchestrator
+        .settings_layers
+        .as_ref()
+        .and_then(|layers| layers.modlist_id.clone());
+    let effective = effective_step1(
+        orchestrator,
+        &orchestrator.wizard_state.step1,
+        scope.as_deref(),
+    );
+    let persisted = std::mem::replace(&mut orchestrator.wizard_state.step1, effective);
+    let result = run(orchestrator);
+    orchestrator.wizard_state.step1 = persisted;
+    result
+}
+
+pub fn reapply(orchestrator: &mut OrchestratorApp) {
+    let modlist_id = orchestrator
+        .settings_layers
+        .as_ref()
+        .and_then(|layers| layers.modlist_id.clone());
+    apply(orchestrator, modlist_id.as_deref());
+}
+
+pub fn leave_modlist_on_nav(orchestrator: &mut OrchestratorApp) {
+    let scoped = orchestrator
+        .settings_layers
+        .as_ref()
+        .is_some_and(|layers| layers.modlist_id.is_some());
+    if scoped
+        && matches!(
+            orchestrator.nav,
+            NavDestination::Home | NavDestination::Create
+        )
+    {
+        apply(orchestrator, None);
+    }
+}
+
+pub fn set_modlist_layer(
+    orchestrator: &mut OrchestratorApp,
+    modlist_id: &str,
+    profile: Option<String>,
+    overrides: SettingsOverrides,
+) {
+    let Some(entry) = orchestrator.registry.find_mut(modlist_id) else {
+        return;
+    };
+    entry.settings_profile = profile;
+    entry.settings_overrides = overrides;
+    orchestrator
+        .persistence_cycle
+        .mark_registry_dirty(Instant::now());
+    if orchestrator
+        .settings_layers
+        .as_ref()
+        .is_some_and(|layers| layers.modlist_id.as_deref() == Some(modlist_id))
+    {
+        apply(orchestrator, Some(modlist_id));
+    }
+}
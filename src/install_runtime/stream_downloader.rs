@@ -13,6 +13,7 @@ use std::time::Duration;
 
 use crate::app::app_step2_update_download::archive_file_name;
 use crate::app::state::{Step2UpdateAsset, WizardState};
+use crate::install_runtime::modlist_lock::pin_assets_to_lock;
 
 pub const POOL_SIZE: usize = 10;
 
@@ -65,6 +66,7 @@ where
     }
 
     let archive_dir = state.step1.mods_archive_folder.trim().to_string();
+    pin_assets_to_lock(state);
     let assets = state.step2.update_selected_update_assets.clone();
 
     state.step2.update_selected_download_running = true;
@@ -73,6 +75,7 @@ where
     state.step2.update_selected_download_failed_sources.clear();
     state.step2.update_selected_extracted_sources.clear();
     state.step2.update_selected_extract_failed_sources.clear();
+    state.step2.update_selected_lock_drift_sources.clear();
     state.step2.scan_status = format!("Downloading updates: 0/{}", assets.len());
 
     let (tx, rx) = mpsc::channel::<StreamDownloadEvent>();
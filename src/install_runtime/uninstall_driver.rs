@@ -0,0 +1,255 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use crate::install::uninstall_plan::{UninstallPlan, plan_uninstall};
+use crate::install_runtime::game_launcher;
+use crate::install_runtime::modlist_layers_driver::{commit_workspace, workspace_for};
+use crate::install_runtime::per_install_dirs::WEIDU_LOG_FILENAME;
+use crate::install_runtime::weidu_step_run::WeiduStepRun;
+use crate::install_runtime::{registry_transition, settings_profiles_driver};
+use crate::mods::component::Component;
+use crate::mods::log_file::LogFile;
+use crate::registry::model::Game;
+use crate::registry::workspace_model::{ComponentRef, ModlistWorkspaceState};
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+
+pub struct UninstallSession {
+    pub modlist_id: String,
+    pub modlist_name: String,
+    pub game: Game,
+    pub game_dir: PathBuf,
+    pub installed: Vec<Component>,
+    pub selected: BTreeSet<usize>,
+    removed: Vec<Component>,
+    pub run: Option<WeiduStepRun>,
+}
+
+impl UninstallSession {
+    #[must_use]
+    pub fn plan(&self) -> UninstallPlan {
+        let targets: Vec<usize> = self.selected.iter().copied().collect();
+        plan_uninstall(&self.installed, &targets)
+    }
+}
+
+fn installed_from_log(game_dir: &std::path::Path) -> Result<Vec<Component>, String> {
+    LogFile::from_path(&game_dir.join(WEIDU_LOG_FILENAME))
+        .map(|log| log.components().to_vec())
+        .map_err(|err| format!("{err:#}"))
+}
+
+fn tp2_file(tp2: &str) -> &str {
+    tp2.rsplit(['/', '\\']).next().unwrap_or(tp2)
+}
+
+fn same_component(reference: &ComponentRef, component: &Component) -> bool {
+    tp2_file(&reference.tp2).eq_ignore_ascii_case(tp2_file(&component.tp_file))
+        && component.component.trim().parse() == Ok(reference.id)
+}
+
+fn order_for(workspace: &mut ModlistWorkspaceState, game: Game) -> &mut Vec<ComponentRef> {
+    match game {
+        Game::BGEE => &mut workspace.order_bgee,
+        Game::IWDEE => &mut workspace.order_iwdee,
+        Game::BG2EE | Game::EET => &mut workspace.order_bg2ee,
+    }
+}
+
+pub fn open(orchestrator: &mut OrchestratorApp, modlist_id: &str) {
+    let Some(entry) = orchestrator.registry.find(modlist_id).cloned() else {
+        return;
+    };
+    if game_launcher::install_running_for(orchestrator, modlist_id) {
+        orchestrator.notification_manager.warn(format!(
+            "\"{}\" is being installed \u{2014} wait for the install to finish",
+            entry.name
+        ));
+        return;
+    }
+    let game_dir = game_launcher::game_dir(&entry);
+    let installed = match installed_from_log(&game_dir) {
+        Ok(installed) => installed,
+        Err(err) => {
+            orchestrator.notification_manager.error(format!(
+                "Couldn't read weidu.log for \"{}\": {err}",
+                entry.name
+            ));
+            return;
+        }
+    };
+    orchestrator.uninstall = Some(UninstallSession {
+        modlist_id: entry.id,
+        modlist_name: entry.name,
+        game: entry.game,
+        game_dir,
+        installed,
+        selected: BTreeSet::new(),
+        removed: Vec::new(),
+        run: None,
+    });
+}
+
+pub fn start(orchestrator: &mut OrchestratorApp) {
+    let Some((modlist_id, game, game_dir, plan)) = orchestrator.uninstall.as_ref().map(|session| {
+        (
+            session.modlist_id.clone(),
+            session.game,
+            session.game_dir.clone(),
+            session.plan(),
+        )
+    }) else {
+        return;
+    };
+    if plan.is_empty() {
+        return;
+    }
+    let step1 = settings_profiles_driver::modlist_step1(orchestrator, &modlist_id);
+    let workspace = workspace_for(orchestrator, &modlist_id);
+    let planned = match game {
+        Game::BGEE => &workspace.order_bgee,
+        Game::IWDEE => &workspace.order_iwdee,
+        Game::BG2EE | Game::EET => &workspace.order_bg2ee,
+    };
+    let mut steps = plan.steps();
+    for step in &mut steps {
+        if step.component.wlb_inputs.is_none() {
+            step.component.wlb_inputs = planned
+                .iter()
+                .find(|reference| same_component(reference, &step.component))
+                .and_then(|reference| reference.wlb_inputs.clone());
+        }
+    }
+    let run = match WeiduStepRun::start(&step1, game_dir, steps) {
+        Ok(run) => run,
+        Err(err) => {
+            orchestrator.notification_manager.error(err);
+            return;
+        }
+    };
+    if let Some(session) = orchestrator.uninstall.as_mut() {
+        session.removed = plan.removed;
+        session.run = Some(run);
+    }
+}
+
+pub fn tick(orchestrator: &mut OrchestratorApp) -> bool {
+    let Some(run) = orchestrator
+        .uninstall
+        .as_mut()
+        .and_then(|session| session.run.as_mut())
+    else {
+        return false;
+    };
+    if !run.is_running() {
+        return false;
+    }
+    run.tick();
+    match run.outcome.clone() {
+        Some(Ok(())) => finish(orchestrator, None),
+        Some(Err(err)) => finish(orchestrator, Some(err)),
+        None => {}
+    }
+    true
+}
+
+fn finish(orchestrator: &mut OrchestratorApp, failure: Option<String>) {
+    let Some(session) = orchestrator.uninstall.as_mut() else {
+        return;
+    };
+    let removed = std::mem::take(&mut session.removed);
+    let (modlist_id, name, game) = (
+        session.modlist_id.clone(),
+        session.modlist_name.clone(),
+        session.game,
+    );
+    let installed = match installed_from_log(&session.game_dir) {
+        Ok(installed) => installed,
+        Err(err) => {
+            orchestrator.notification_manager.error(format!(
+                "Uninstall of \"{name}\" ended, but weidu.log couldn't be re-read: {err}"
+            ));
+            return;
+        }
+    };
+    let before = std::mem::replace(&mut session.installed, installed.clone());
+    session.selected.clear();
+    let gone: Vec<Component> = before
+        .into_iter()
+        .filter(|old| !installed.iter().any(|now| same_logged(old, now)))
+        .collect();
+
+    let mut workspace = workspace_for(orchestrator, &modlist_id);
+    order_for(&mut workspace, game).retain(|reference| {
+        !removed
+            .iter()
+            .any(|c| same_component(reference, c) && gone.iter().any(|g| same_logged(g, c)))
+    });
+    commit_workspace(orchestrator, &modlist_id, game, workspace);
+
+    let state = settings_profiles_driver::modlist_wizard_state(orchestrator, &modlist_id);
+    let rx = registry_transition::flip_to_installed(
+        &modlist_id,
+        &mut orchestrator.registry,
+        &orchestrator.registry_store,
+        &state,
+        None,
+    );
+    if rx.is_some() {
+        orchestrator.install_size_worker_rx = rx;
+    }
+
+    match failure {
+        None => orchestrator.notification_manager.success(format!(
+            "Removed {} component(s) from \"{name}\"",
+            gone.len()
+        )),
+        Some(err) => orchestrator.notification_manager.error(format!(
+            "Uninstall stopped: {err} \u{2014} \"{name}\" now has {} component(s) installed; \
+             {} removed before it stopped",
+            installed.len(),
+            gone.len()
+        )),
+    }
+}
+
+fn same_logged(a: &Component, b: &Component) -> bool {
+    tp2_file(&a.tp_file).eq_ignore_ascii_case(tp2_file(&b.tp_file))
+        && a.component.trim() == b.component.trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn workspace_refs_match_log_components_by_file_and_id() {
+        let component =
+            Component::parse_weidu_line("~SCS/SETUP-SCS.TP2~ #0 #4000 // Smarter mages: v35")
+                .expect("fixture");
+        let reference = |tp2: &str, id| ComponentRef {
+            tp2: tp2.to_string(),
+            id,
+            language: 0,
+            wlb_inputs: None,
+        };
+        assert!(same_component(
+            &reference("setup-scs.tp2", 4000),
+            &component
+        ));
+        assert!(same_component(
+            &reference("SCS\\SETUP-SCS.TP2", 4000),
+            &component
+        ));
+        assert!(!same_component(
+            &reference("setup-scs.tp2", 4010),
+            &component
+        ));
+        assert!(!same_component(
+            &reference("setup-tactics.tp2", 4000),
+            &component
+        ));
+    }
+}
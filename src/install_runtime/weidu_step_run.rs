@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::app::state::Step1State;
+use crate::app::terminal::EmbeddedTerminal;
+use crate::install::uninstall_plan::{WeiduAction, WeiduStep};
+use crate::platform_defaults::resolve_weidu_binary;
+
+pub const STEP_POLL: Duration = Duration::from_millis(100);
+
+pub struct WeiduStepRun {
+    terminal: EmbeddedTerminal,
+    weidu: String,
+    language: String,
+    weidu_log_mode: String,
+    pub steps: Vec<WeiduStep>,
+    pub current: usize,
+    inputs: VecDeque<String>,
+    pub awaiting_input: bool,
+    pub input_line: String,
+    pub outcome: Option<Result<(), String>>,
+    pub started_at: DateTime<Utc>,
+}
+
+impl WeiduStepRun {
+    pub fn start(
+        step1: &Step1State,
+        game_dir: PathBuf,
+        steps: Vec<WeiduStep>,
+    ) -> Result<Self, String> {
+        let mut terminal =
+            EmbeddedTerminal::new().map_err(|err| format!("Couldn't open the console: {err:#}"))?;
+        terminal.configure_from_step1(step1, None);
+        terminal.set_working_dir(Some(game_dir));
+        let mut run = Self {
+            terminal,
+            weidu: resolve_weidu_binary(&step1.weidu_binary),
+            language: step1.language.clone(),
+            weidu_log_mode: if step1.weidu_log_mode_enabled {
+                step1.weidu_log_mode.clone()
+            } else {
+                String::new()
+            },
+            steps,
+            current: 0,
+            inputs: VecDeque::new(),
+            awaiting_input: false,
+            input_line: String::new(),
+            outcome: None,
+            started_at: Utc::now(),
+        };
+        run.start_current();
+        Ok(run)
+    }
+
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.outcome.is_none()
+    }
+
+    #[must_use]
+    pub fn current_label(&self) -> Option<String> {
+        self.steps.get(self.current).map(WeiduStep::label)
+    }
+
+    #[must_use]
+    pub fn console_excerpt(&self, max_chars: usize) -> String {
+        self.terminal.console_excerpt(max_chars)
+    }
+
+    #[must_use]
+    pub fn console_text(&self) -> String {
+        self.terminal.console_text()
+    }
+
+    pub fn send_input(&mut self) {
+        let line = std::mem::take(&mut self.input_line);
+        self.terminal.send_line(line.trim());
+        self.terminal.echo_sent(line.trim());
+        self.awaiting_input = false;
+    }
+
+    pub fn cancel(&mut self) {
+        self.terminal.force_terminate();
+        self.outcome = Some(Err(format!(
+            "cancelled during step {} of {}",
+            self.current + 1,
+            self.steps.len()
+        )));
+    }
+
+    fn start_current(&mut self) {
+        let Some(step) = self.steps.get(self.current) else {
+            self.outcome = Some(Ok(()));
+            return;
+        };
+        self.inputs = match step.action {
+            WeiduAction::Install => step
+                .component
+                .wlb_inputs
+                .as_deref()
+                .map(|spec| spec.split(',').map(|p| p.trim().to_string()).collect())
+                .unwrap_or_default(),
+            WeiduAction::Uninstall => VecDeque::new(),
+        };
+        self.awaiting_input = false;
+        let label = step.label();
+        let args = step.args(&self.language, &self.weidu_log_mode);
+        self.terminal.append_marker(&format!(
+            "[{}/{}] {label}",
+            self.current + 1,
+            self.steps.len()
+        ));
+        if let Err(err) = self.terminal.start_process(&self.weidu, &args) {
+            self.outcome = Some(Err(format!("{label}: {err:#}")));
+        }
+    }
+
+    pub fn tick(&mut self) {
+        if !self.is_running() {
+            return;
+        }
+        self.terminal.poll_output();
+        if self.terminal.take_exit_event() {
+            match self.terminal.take_exit_code() {
+                Some(0) => {
+                    self.current += 1;
+                    self.start_current();
+                }
+                code => {
+                    let label = self.current_label().unwrap_or_default();
+                    self.outcome = Some(Err(format!(
+                        "{label} failed (exit {})",
+                        code.map_or_else(|| "?".to_string(), |c| c.to_string())
+                    )));
+                }
+            }
+            return;
+        }
+        if self.terminal.has_new_data()
+            && self.terminal.likely_input_needed_visible()
+            && self.terminal.prompt_headers_ready()
+        {
+            if let Some(answer) = self.inputs.pop_front() {
+                self.terminal.send_line(&answer);
+                self.terminal.echo_sent(&answer);
+            } else {
+                self.awaiting_input = true;
+            }
+        }
+    }
+}
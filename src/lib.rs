@@ -7,6 +7,10 @@ pub mod app;
 pub mod cli;
 #[path = "core/config/mod.rs"]
 pub mod config;
+#[path = "core/game_discovery.rs"]
+pub mod game_discovery;
+#[path = "core/game_fingerprint.rs"]
+pub mod game_fingerprint;
 #[path = "core/install/mod.rs"]
 pub mod install;
 #[path = "core/logging/mod.rs"]
@@ -18,6 +22,9 @@ pub mod parser;
 #[path = "core/platform_defaults.rs"]
 pub mod platform_defaults;
 pub mod settings;
+#[cfg(test)]
+#[path = "core/test_support.rs"]
+pub(crate) mod test_support;
 pub mod ui;
 
 pub mod registry;
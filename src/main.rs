@@ -4,17 +4,27 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use anyhow::Result;
-use bio::cli::args::{Cli, Command};
+use bio::cli::args::{self, Cli, Command};
 use bio::config::options;
 use bio::logging::setup;
-use clap::Parser;
+use bio::settings::layers::{self, LaunchOverrides};
+use bio::settings::profiles::parse_override_lines;
+use clap::{CommandFactory, FromArgMatches};
 
 fn main() -> Result<()> {
-    let mut cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let mut cli = Cli::from_arg_matches(&matches)
+        .map_err(|err| err.format(&mut Cli::command()))
+        .unwrap_or_else(|err| err.exit());
     if cli.command.is_none() && cli.help.is_none() && cli.version.is_none() {
         cli.command = Some(Command::Gui);
     }
     setup::init(&cli.log_level)?;
+    layers::set_launch_overrides(LaunchOverrides {
+        profile: cli.profile.clone(),
+        settings: parse_override_lines(&cli.set.join("\n")).map_err(anyhow::Error::msg)?,
+        explicit_args: args::explicit_args(&matches),
+    });
     if let Some(command) = options::from_cli(&cli) {
         match &command {
             options::AppCommandConfig::Gui { dev_mode } => bio::ui::run(*dev_mode)?,
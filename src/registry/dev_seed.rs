@@ -7,10 +7,11 @@ use chrono::Utc;
 
 use crate::registry::errors::RegistryError;
 use crate::registry::ids::new_modlist_id;
-use crate::registry::model::{Game, ModlistEntry, ModlistRegistry, ModlistState};
+use crate::registry::model::{Game, ModlistEntry, ModlistRegistry, ModlistState, PlayStats};
 use crate::registry::store::RegistryStore;
 use crate::registry::store_workspace::WorkspaceStore;
 use crate::registry::workspace_model::ModlistWorkspaceState;
+use crate::settings::profiles::SettingsOverrides;
 
 pub fn seed_demo_entry(
     registry: &mut ModlistRegistry,
@@ -40,7 +41,19 @@ pub fn seed_demo_entry(
         latest_share_code: None,
 
         author: None,
+        tags: Vec::new(),
+        notes: String::new(),
+        pinned: false,
         forked_from: Vec::new(),
+        upstream_share_code: None,
+        install_verdict: None,
+        health: None,
+        update_status: None,
+        layers: Vec::new(),
+        launch: None,
+        play_stats: PlayStats::default(),
+        settings_profile: None,
+        settings_overrides: SettingsOverrides::new(),
         workspace_file_relpath: PathBuf::from(format!("modlists/{id}/workspace.json")),
     };
     registry.entries.push(entry.clone());
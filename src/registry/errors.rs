@@ -12,7 +12,16 @@ pub enum RegistryError {
 
     Parse(serde_json::Error),
 
-    Corrupt { path: PathBuf, message: String },
+    Corrupt {
+        path: PathBuf,
+        message: String,
+    },
+
+    UnsupportedVersion {
+        path: PathBuf,
+        found: u32,
+        supported: u32,
+    },
 }
 
 impl fmt::Display for RegistryError {
@@ -27,6 +36,15 @@ impl fmt::Display for RegistryError {
                     path.display()
                 )
             }
+            Self::UnsupportedVersion {
+                path,
+                found,
+                supported,
+            } => write!(
+                f,
+                "{} was written by a newer BIO (format {found}); this build reads up to format {supported}",
+                path.display()
+            ),
         }
     }
 }
@@ -36,7 +54,7 @@ impl Error for RegistryError {
         match self {
             Self::Io(err) => Some(err),
             Self::Parse(err) => Some(err),
-            Self::Corrupt { .. } => None,
+            Self::Corrupt { .. } | Self::UnsupportedVersion { .. } => None,
         }
     }
 }
@@ -0,0 +1,301 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::registry::errors::RegistryError;
+use crate::registry::store_workspace::modlist_data_dir;
+
+const INSTALL_HISTORY_DIR: &str = "install_history";
+const INDEX_FILE_NAME: &str = "index.json";
+pub const CONSOLE_LOG_FILE_NAME: &str = "console.log";
+pub const TRIAGE_FILE_NAME: &str = "triage.txt";
+
+pub const MAX_RECORDED_RUNS: usize = 100;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InstallRunRecord {
+    pub run_id: String,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub attempted: u32,
+    pub succeeded: u32,
+    pub failed: u32,
+    pub warnings: u32,
+    pub exit_code: Option<i32>,
+    pub status: String,
+    pub failed_components: Vec<String>,
+    pub answer_sources: BTreeMap<String, u32>,
+}
+
+impl InstallRunRecord {
+    #[must_use]
+    pub fn outcome(&self) -> RunOutcome {
+        if self.failed > 0 || self.exit_code.is_some_and(|code| code != 0) {
+            RunOutcome::Failed
+        } else if self.exit_code.is_none() {
+            RunOutcome::Interrupted
+        } else if self.warnings > 0 {
+            RunOutcome::Warnings
+        } else {
+            RunOutcome::Clean
+        }
+    }
+
+    #[must_use]
+    pub fn matches_query(&self, query: &str) -> bool {
+        let query = query.trim().to_ascii_lowercase();
+        if query.is_empty() {
+            return true;
+        }
+        self.run_id.to_ascii_lowercase().contains(&query)
+            || self.status.to_ascii_lowercase().contains(&query)
+            || self
+                .failed_components
+                .iter()
+                .any(|c| c.to_ascii_lowercase().contains(&query))
+            || self
+                .answer_sources
+                .keys()
+                .any(|k| k.to_ascii_lowercase().contains(&query))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunOutcome {
+    Clean,
+    Warnings,
+    Failed,
+    Interrupted,
+}
+
+impl RunOutcome {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Clean => "clean",
+            Self::Warnings => "warnings",
+            Self::Failed => "failed",
+            Self::Interrupted => "interrupted",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RunFilter {
+    #[default]
+    All,
+    Clean,
+    Warnings,
+    Failed,
+}
+
+impl RunFilter {
+    pub const ALL: [Self; 4] = [Self::All, Self::Clean, Self::Warnings, Self::Failed];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Clean => "clean",
+            Self::Warnings => "warnings",
+            Self::Failed => "failed",
+        }
+    }
+
+    #[must_use]
+    pub fn accepts(self, record: &InstallRunRecord) -> bool {
+        match self {
+            Self::All => true,
+            Self::Clean => record.outcome() == RunOutcome::Clean,
+            Self::Warnings => record.outcome() == RunOutcome::Warnings,
+            Self::Failed => matches!(
+                record.outcome(),
+                RunOutcome::Failed | RunOutcome::Interrupted
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InstallHistory {
+    pub runs: Vec<InstallRunRecord>,
+}
+
+impl InstallHistory {
+    #[must_use]
+    pub fn search(&self, query: &str, filter: RunFilter) -> Vec<&InstallRunRecord> {
+        self.runs
+            .iter()
+            .rev()
+            .filter(|run| filter.accepts(run) && run.matches_query(query))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn seconds_per_component(&self) -> Option<f64> {
+        self.runs.iter().rev().find_map(|run| {
+            let elapsed = run.finished_at? - run.started_at?;
+            (run.attempted > 0 && elapsed.num_seconds() > 0)
+                .then(|| elapsed.num_seconds() as f64 / f64::from(run.attempted))
+        })
+    }
+
+    fn push(&mut self, record: InstallRunRecord) -> Vec<String> {
+        self.runs.retain(|run| run.run_id != record.run_id);
+        self.runs.push(record);
+        let excess = self.runs.len().saturating_sub(MAX_RECORDED_RUNS);
+        self.runs.drain(..excess).map(|run| run.run_id).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InstallHistoryStore {
+    root: PathBuf,
+}
+
+impl InstallHistoryStore {
+    #[must_use]
+    pub fn new_for_id(modlist_id: &str) -> Self {
+        Self {
+            root: modlist_data_dir(modlist_id).join(INSTALL_HISTORY_DIR),
+        }
+    }
+
+    pub fn new_with_root(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    #[must_use]
+    pub fn run_dir(&self, run_id: &str) -> PathBuf {
+        self.root.join(format!("run_{run_id}"))
+    }
+
+    pub fn load(&self) -> Result<InstallHistory, RegistryError> {
+        let path = self.root.join(INDEX_FILE_NAME);
+        let raw = match std::fs::read_to_string(&path) {
+            Ok(s) => s,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(InstallHistory::default());
+            }
+            Err(err) => return Err(RegistryError::Io(err)),
+        };
+        serde_json::from_str(&raw).map_err(|err| RegistryError::corrupt(path, err.to_string()))
+    }
+
+    fn save(&self, history: &InstallHistory) -> Result<(), RegistryError> {
+        std::fs::create_dir_all(&self.root)?;
+        let raw = serde_json::to_string_pretty(history)?;
+        let path = self.root.join(INDEX_FILE_NAME);
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, raw.as_bytes())?;
+        std::fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+
+    pub fn record(
+        &self,
+        record: InstallRunRecord,
+        console_log: &str,
+        triage: &str,
+    ) -> Result<(), RegistryError> {
+        let run_dir = self.run_dir(&record.run_id);
+        std::fs::create_dir_all(&run_dir)?;
+        std::fs::write(run_dir.join(CONSOLE_LOG_FILE_NAME), console_log)?;
+        std::fs::write(run_dir.join(TRIAGE_FILE_NAME), triage)?;
+        let mut history = self.load()?;
+        let dropped = history.push(record);
+        self.save(&history)?;
+        for run_id in dropped {
+            let _ = std::fs::remove_dir_all(self.run_dir(&run_id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    fn run(id: &str, failed: u32, warnings: u32, exit_code: Option<i32>) -> InstallRunRecord {
+        InstallRunRecord {
+            run_id: id.to_string(),
+            attempted: 3,
+            succeeded: 3 - failed,
+            failed,
+            warnings,
+            exit_code,
+            status: "Install finished".to_string(),
+            failed_components: if failed > 0 {
+                vec!["SETUP-SCS.TP2 #4000 Smarter mages".to_string()]
+            } else {
+                Vec::new()
+            },
+            ..InstallRunRecord::default()
+        }
+    }
+
+    #[test]
+    fn search_filters_by_outcome_and_text_newest_first() {
+        let history = InstallHistory {
+            runs: vec![
+                run("2026-01-01", 0, 0, Some(0)),
+                run("2026-01-02", 1, 0, Some(1)),
+                run("2026-01-03", 0, 2, Some(0)),
+                run("2026-01-04", 0, 0, None),
+            ],
+        };
+        let ids = |runs: Vec<&InstallRunRecord>| {
+            runs.iter().map(|r| r.run_id.clone()).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            ids(history.search("", RunFilter::All)),
+            vec!["2026-01-04", "2026-01-03", "2026-01-02", "2026-01-01"]
+        );
+        assert_eq!(
+            ids(history.search("", RunFilter::Failed)),
+            vec!["2026-01-04", "2026-01-02"]
+        );
+        assert_eq!(
+            ids(history.search("", RunFilter::Warnings)),
+            vec!["2026-01-03"]
+        );
+        assert_eq!(
+            ids(history.search("scs", RunFilter::All)),
+            vec!["2026-01-02"]
+        );
+    }
+
+    #[test]
+    fn record_writes_files_and_caps_the_index() {
+        let root = temp_dir("install_history");
+        let store = InstallHistoryStore::new_with_root(&root);
+        for i in 0..=MAX_RECORDED_RUNS {
+            store
+                .record(run(&format!("{i:04}"), 0, 0, Some(0)), "console", "triage")
+                .expect("record run");
+        }
+        let history = store.load().expect("load index");
+        assert_eq!(history.runs.len(), MAX_RECORDED_RUNS);
+        assert_eq!(history.runs[0].run_id, "0001");
+        assert!(!store.run_dir("0000").exists());
+        let latest = store.run_dir(&format!("{MAX_RECORDED_RUNS:04}"));
+        assert_eq!(
+            std::fs::read_to_string(latest.join(TRIAGE_FILE_NAME)).expect("triage"),
+            "triage"
+        );
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
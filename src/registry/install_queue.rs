@@ -0,0 +1,333 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::platform_defaults::app_config_file;
+use crate::registry::errors::RegistryError;
+use crate::registry::model::{ModlistEntry, ModlistState};
+
+const INSTALL_QUEUE_FILE_NAME: &str = "install_queue.json";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueueWorkflow {
+    #[default]
+    Fresh,
+    Reinstall,
+    ContinuePartial,
+}
+
+impl QueueWorkflow {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Fresh => "fresh",
+            Self::Reinstall => "reinstall",
+            Self::ContinuePartial => "continue",
+        }
+    }
+
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::Fresh => Self::Reinstall,
+            Self::Reinstall => Self::ContinuePartial,
+            Self::ContinuePartial => Self::Fresh,
+        }
+    }
+
+    #[must_use]
+    pub const fn default_for(entry: &ModlistEntry) -> Self {
+        match entry.state {
+            ModlistState::Installed => Self::Reinstall,
+            ModlistState::InProgress => Self::ContinuePartial,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueueItemStatus {
+    #[default]
+    Pending,
+    Running,
+    Done,
+    Failed,
+    Skipped,
+}
+
+impl QueueItemStatus {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Done => "done",
+            Self::Failed => "failed",
+            Self::Skipped => "skipped",
+        }
+    }
+
+    #[must_use]
+    pub const fn is_finished(self) -> bool {
+        matches!(self, Self::Done | Self::Failed | Self::Skipped)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueuePromptPolicy {
+    #[default]
+    FailItem,
+    PauseQueue,
+}
+
+impl QueuePromptPolicy {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::FailItem => "fail item",
+            Self::PauseQueue => "pause queue",
+        }
+    }
+
+    #[must_use]
+    pub const fn next(self) -> Self {
+        match self {
+            Self::FailItem => Self::PauseQueue,
+            Self::PauseQueue => Self::FailItem,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InstallQueueItem {
+    pub modlist_id: String,
+    pub workflow: QueueWorkflow,
+    pub status: QueueItemStatus,
+    pub message: Option<String>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InstallQueue {
+    pub items: Vec<InstallQueueItem>,
+    pub paused: bool,
+    pub prompt_policy: QueuePromptPolicy,
+}
+
+impl InstallQueue {
+    pub fn push(&mut self, modlist_id: &str, workflow: QueueWorkflow) -> bool {
+        if self
+            .items
+            .iter()
+            .any(|item| item.modlist_id == modlist_id && !item.status.is_finished())
+        {
+            return false;
+        }
+        self.items.push(InstallQueueItem {
+            modlist_id: modlist_id.to_string(),
+            workflow,
+            ..InstallQueueItem::default()
+        });
+        true
+    }
+
+    #[must_use]
+    pub fn running(&self) -> Option<usize> {
+        self.items
+            .iter()
+            .position(|item| item.status == QueueItemStatus::Running)
+    }
+
+    #[must_use]
+    pub fn next_pending(&self) -> Option<usize> {
+        self.items
+            .iter()
+            .position(|item| item.status == QueueItemStatus::Pending)
+    }
+
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.items
+            .iter()
+            .filter(|item| item.status == QueueItemStatus::Pending)
+            .count()
+    }
+
+    pub fn finish(&mut self, index: usize, status: QueueItemStatus, message: Option<String>) {
+        if let Some(item) = self.items.get_mut(index) {
+            item.status = status;
+            item.message = message;
+            item.finished_at = Some(Utc::now());
+        }
+    }
+
+    pub fn move_item(&mut self, index: usize, up: bool) -> bool {
+        let Some(target) = (if up {
+            index.checked_sub(1)
+        } else {
+            Some(index + 1)
+        }) else {
+            return false;
+        };
+        let movable = |item: Option<&InstallQueueItem>| {
+            item.is_some_and(|item| item.status == QueueItemStatus::Pending)
+        };
+        if !movable(self.items.get(index)) || !movable(self.items.get(target)) {
+            return false;
+        }
+        self.items.swap(index, target);
+        true
+    }
+
+    pub fn skip(&mut self, index: usize) {
+        if self
+            .items
+            .get(index)
+            .is_some_and(|item| item.status == QueueItemStatus::Pending)
+        {
+            self.finish(index, QueueItemStatus::Skipped, None);
+        }
+    }
+
+    pub fn retry(&mut self, index: usize) {
+        if let Some(item) = self.items.get_mut(index).filter(|item| {
+            matches!(
+                item.status,
+                QueueItemStatus::Failed | QueueItemStatus::Skipped
+            )
+        }) {
+            item.status = QueueItemStatus::Pending;
+            item.message = None;
+            item.finished_at = None;
+        }
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        if self
+            .items
+            .get(index)
+            .is_some_and(|item| item.status != QueueItemStatus::Running)
+        {
+            self.items.remove(index);
+        }
+    }
+
+    pub fn clear_finished(&mut self) {
+        self.items.retain(|item| !item.status.is_finished());
+    }
+
+    pub fn recover_interrupted(&mut self) -> bool {
+        let mut changed = false;
+        for item in &mut self.items {
+            if item.status == QueueItemStatus::Running {
+                item.status = QueueItemStatus::Failed;
+                item.message = Some("interrupted when BIO closed".to_string());
+                changed = true;
+            }
+        }
+        changed
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InstallQueueStore {
+    path: PathBuf,
+}
+
+impl InstallQueueStore {
+    #[must_use]
+    pub fn new_default() -> Self {
+        Self {
+            path: app_config_file(INSTALL_QUEUE_FILE_NAME, "."),
+        }
+    }
+
+    pub fn new_with_path(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn load(&self) -> Result<InstallQueue, RegistryError> {
+        let raw = match std::fs::read_to_string(&self.path) {
+            Ok(s) => s,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(InstallQueue::default());
+            }
+            Err(err) => return Err(RegistryError::Io(err)),
+        };
+        serde_json::from_str(&raw)
+            .map_err(|err| RegistryError::corrupt(self.path.clone(), err.to_string()))
+    }
+
+    pub fn save(&self, queue: &InstallQueue) -> Result<(), RegistryError> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let raw = serde_json::to_string_pretty(queue)?;
+        let tmp_path = self.path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, raw.as_bytes())?;
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    #[test]
+    fn queue_orders_skips_recovers_and_round_trips() {
+        let mut queue = InstallQueue::default();
+        assert!(queue.push("A", QueueWorkflow::Fresh));
+        assert!(queue.push("B", QueueWorkflow::Reinstall));
+        assert!(queue.push("C", QueueWorkflow::ContinuePartial));
+        assert!(
+            !queue.push("B", QueueWorkflow::Fresh),
+            "no duplicate pending"
+        );
+
+        assert!(queue.move_item(2, true));
+        let order: Vec<&str> = queue.items.iter().map(|i| i.modlist_id.as_str()).collect();
+        assert_eq!(order, vec!["A", "C", "B"]);
+        assert!(!queue.move_item(0, true));
+
+        queue.items[0].status = QueueItemStatus::Running;
+        assert!(!queue.move_item(1, true), "running items stay put");
+        queue.skip(1);
+        assert_eq!(queue.items[1].status, QueueItemStatus::Skipped);
+        assert_eq!(queue.next_pending(), Some(2));
+        queue.remove(0);
+        assert_eq!(queue.items.len(), 3, "running items cannot be removed");
+
+        assert!(queue.recover_interrupted());
+        assert_eq!(queue.items[0].status, QueueItemStatus::Failed);
+        assert_eq!(queue.running(), None);
+        queue.retry(0);
+        assert_eq!(queue.items[0].status, QueueItemStatus::Pending);
+        assert_eq!(queue.pending_count(), 2);
+        queue.clear_finished();
+        assert_eq!(queue.items.len(), 2);
+        assert_eq!(queue.prompt_policy, QueuePromptPolicy::FailItem);
+        queue.prompt_policy = queue.prompt_policy.next();
+
+        let dir = temp_dir("install_queue");
+        let path = dir.join("queue.json");
+        let store = InstallQueueStore::new_with_path(&path);
+        store.save(&queue).expect("save");
+        assert_eq!(store.load().expect("load"), queue);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
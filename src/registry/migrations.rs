@@ -0,0 +1,415 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{Map, Value};
+
+use crate::registry::errors::RegistryError;
+
+/// Bumped whenever entries gain fields: a build that predates them refuses the
+/// newer file instead of loading it and dropping those fields on its next save.
+pub const REGISTRY_FORMAT_VERSION: u32 = 3;
+
+pub const WORKSPACE_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatKind {
+    Registry,
+    Workspace,
+}
+
+struct MigrationStep {
+    from: u32,
+    summary: &'static str,
+    apply: fn(&mut Map<String, Value>),
+}
+
+const REGISTRY_STEPS: &[MigrationStep] = &[
+    MigrationStep {
+        from: 1,
+        summary: "make author/forked_from/install_started_at explicit",
+        apply: registry_v1_to_v2,
+    },
+    MigrationStep {
+        from: 2,
+        summary: "add library, update, health, launch and settings fields to every entry",
+        apply: registry_v2_to_v3,
+    },
+];
+
+const WORKSPACE_STEPS: &[MigrationStep] = &[MigrationStep {
+    from: 0,
+    summary: "stamp format_version on unversioned workspace file",
+    apply: workspace_v0_to_v1,
+}];
+
+impl FormatKind {
+    #[must_use]
+    pub const fn current_version(self) -> u32 {
+        match self {
+            Self::Registry => REGISTRY_FORMAT_VERSION,
+            Self::Workspace => WORKSPACE_FORMAT_VERSION,
+        }
+    }
+
+    const fn unversioned(self) -> u32 {
+        match self {
+            Self::Registry => 1,
+            Self::Workspace => 0,
+        }
+    }
+
+    const fn steps(self) -> &'static [MigrationStep] {
+        match self {
+            Self::Registry => REGISTRY_STEPS,
+            Self::Workspace => WORKSPACE_STEPS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Migration {
+    pub from: u32,
+    pub to: u32,
+    pub steps: Vec<&'static str>,
+    pub value: Value,
+}
+
+impl Migration {
+    #[must_use]
+    pub const fn is_noop(&self) -> bool {
+        self.from == self.to
+    }
+}
+
+#[must_use]
+pub fn detect_version(kind: FormatKind, value: &Value) -> u32 {
+    value
+        .get("format_version")
+        .and_then(Value::as_u64)
+        .and_then(|v| u32::try_from(v).ok())
+        .unwrap_or_else(|| kind.unversioned())
+}
+
+pub fn migrate(
+    kind: FormatKind,
+    path: &Path,
+    mut value: Value,
+) -> Result<Migration, RegistryError> {
+    let from = detect_version(kind, &value);
+    let supported = kind.current_version();
+    if from > supported {
+        return Err(RegistryError::UnsupportedVersion {
+            path: path.to_path_buf(),
+            found: from,
+            supported,
+        });
+    }
+    let Some(object) = value.as_object_mut() else {
+        return Err(RegistryError::corrupt(path, "expected a JSON object"));
+    };
+    let mut steps = Vec::new();
+    let mut version = from;
+    while version < supported {
+        let Some(step) = kind.steps().iter().find(|step| step.from == version) else {
+            return Err(RegistryError::corrupt(
+                path,
+                format!("no migration step from format {version}"),
+            ));
+        };
+        (step.apply)(object);
+        version += 1;
+        object.insert("format_version".to_string(), Value::from(version));
+        steps.push(step.summary);
+    }
+    Ok(Migration {
+        from,
+        to: version,
+        steps,
+        value,
+    })
+}
+
+pub fn migrate_file(
+    kind: FormatKind,
+    path: &Path,
+    dry_run: bool,
+) -> Result<Migration, RegistryError> {
+    let raw = std::fs::read_to_string(path)?;
+    let value = serde_json::from_str::<Value>(&raw)
+        .map_err(|err| RegistryError::corrupt(path, err.to_string()))?;
+    let migration = migrate(kind, path, value)?;
+    if !dry_run && !migration.is_noop() {
+        write_migrated(path, &migration)?;
+    }
+    Ok(migration)
+}
+
+pub fn write_migrated(path: &Path, migration: &Migration) -> Result<PathBuf, RegistryError> {
+    let backup = backup_path(path, migration.from);
+    std::fs::copy(path, &backup)?;
+    let raw = serde_json::to_string_pretty(&migration.value)?;
+    let tmp_path = path.with_extension("json.tmp");
+    std::fs::write(&tmp_path, raw.as_bytes())?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(backup)
+}
+
+fn backup_path(path: &Path, from: u32) -> PathBuf {
+    let ts = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    path.with_extension(format!("json.v{from}-backup-{ts}"))
+}
+
+fn registry_v1_to_v2(registry: &mut Map<String, Value>) {
+    let Some(entries) = registry.get_mut("entries").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for entry in entries.iter_mut().filter_map(Value::as_object_mut) {
+        entry.entry("author").or_insert(Value::Null);
+        entry
+            .entry("forked_from")
+            .or_insert_with(|| Value::Array(Vec::new()));
+        entry.entry("install_started_at").or_insert(Value::Null);
+    }
+}
+
+fn registry_v2_to_v3(registry: &mut Map<String, Value>) {
+    let Some(entries) = registry.get_mut("entries").and_then(Value::as_array_mut) else {
+        return;
+    };
+    let play_stats = serde_json::to_value(crate::registry::model::PlayStats::default())
+        .unwrap_or_else(|_| Value::Object(Map::new()));
+    for entry in entries.iter_mut().filter_map(Value::as_object_mut) {
+        for key in ["tags", "layers"] {
+            entry.entry(key).or_insert_with(|| Value::Array(Vec::new()));
+        }
+        entry
+            .entry("notes")
+            .or_insert_with(|| Value::String(String::new()));
+        entry.entry("pinned").or_insert(Value::Bool(false));
+        for key in [
+            "upstream_share_code",
+            "install_verdict",
+            "health",
+            "update_status",
+            "launch",
+            "settings_profile",
+        ] {
+            entry.entry(key).or_insert(Value::Null);
+        }
+        entry
+            .entry("play_stats")
+            .or_insert_with(|| play_stats.clone());
+        entry
+            .entry("settings_overrides")
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+}
+
+fn workspace_v0_to_v1(_workspace: &mut Map<String, Value>) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::model::{ModlistRegistry, ModlistState};
+    use crate::registry::workspace_model::ModlistWorkspaceState;
+    use crate::test_support::temp_dir;
+
+    const REGISTRY_V1_MINIMAL: &str = r#"{
+        "format_version": 1,
+        "entries": [{
+            "id": "0123456789AB",
+            "name": "old",
+            "game": "EET",
+            "destination_folder": "/games/eet",
+            "state": "installed",
+            "creation_date": "2025-01-01T00:00:00Z",
+            "last_touched_date": "2025-02-01T00:00:00Z",
+            "install_date": null,
+            "last_played_date": null,
+            "mod_count": 3,
+            "component_count": 9,
+            "paused_at_step": null,
+            "total_size_bytes": null,
+            "latest_share_code": null,
+            "workspace_file_relpath": "modlists/0123456789AB/workspace.json"
+        }]
+    }"#;
+
+    const REGISTRY_V1_WITH_OPTIONAL: &str = r#"{
+        "format_version": 1,
+        "entries": [{
+            "id": "BA9876543210",
+            "name": "forked",
+            "game": "BG2EE",
+            "state": "in_progress",
+            "creation_date": "2025-03-01T00:00:00Z",
+            "last_touched_date": "2025-03-02T00:00:00Z",
+            "install_started_at": "2025-03-02T00:00:00Z",
+            "author": "someone",
+            "forked_from": [],
+            "workspace_file_relpath": "modlists/BA9876543210/workspace.json"
+        }]
+    }"#;
+
+    const REGISTRY_V2: &str = r#"{
+        "format_version": 2,
+        "entries": [{
+            "id": "C0FFEE123456",
+            "name": "tagged",
+            "game": "BGEE",
+            "state": "installed",
+            "creation_date": "2025-04-01T00:00:00Z",
+            "last_touched_date": "2025-04-02T00:00:00Z",
+            "install_started_at": null,
+            "author": null,
+            "forked_from": [],
+            "tags": ["tactics"],
+            "pinned": true,
+            "workspace_file_relpath": "modlists/C0FFEE123456/workspace.json"
+        }]
+    }"#;
+
+    const REGISTRY_UNVERSIONED: &str = r#"{ "entries": [] }"#;
+
+    const WORKSPACE_V0: &str = r#"{ "last_share_code": "BIO-OLD" }"#;
+
+    fn temp_file(label: &str, contents: &str) -> PathBuf {
+        let dir = temp_dir(&format!("migrations_{label}"));
+        let path = dir.join("file.json");
+        std::fs::write(&path, contents).expect("write fixture");
+        path
+    }
+
+    fn parse(raw: &str) -> Value {
+        serde_json::from_str(raw).expect("fixture json")
+    }
+
+    #[test]
+    fn v1_registry_without_optional_fields_upgrades() {
+        let m = migrate(
+            FormatKind::Registry,
+            Path::new("x"),
+            parse(REGISTRY_V1_MINIMAL),
+        )
+        .expect("migrate");
+        assert_eq!((m.from, m.to, m.steps.len()), (1, 3, 2));
+        let registry: ModlistRegistry = serde_json::from_value(m.value).expect("typed");
+        assert_eq!(registry.format_version, REGISTRY_FORMAT_VERSION);
+        let entry = &registry.entries[0];
+        assert_eq!(entry.state, ModlistState::Installed);
+        assert!(entry.install_date.is_none());
+        assert!(entry.author.is_none());
+        assert!(entry.forked_from.is_empty());
+        assert!(entry.tags.is_empty() && entry.notes.is_empty() && !entry.pinned);
+        assert!(entry.layers.is_empty() && entry.update_status.is_none());
+        assert!(entry.settings_profile.is_none() && entry.settings_overrides.is_empty());
+        assert_eq!(
+            entry.play_stats,
+            crate::registry::model::PlayStats::default()
+        );
+    }
+
+    #[test]
+    fn v2_registry_backfills_v3_fields_and_keeps_existing_ones() {
+        let m = migrate(FormatKind::Registry, Path::new("x"), parse(REGISTRY_V2)).expect("migrate");
+        assert_eq!((m.from, m.to, m.steps.len()), (2, 3, 1));
+        let registry: ModlistRegistry = serde_json::from_value(m.value).expect("typed");
+        assert_eq!(registry.format_version, 3);
+        let entry = &registry.entries[0];
+        assert_eq!(entry.tags, vec!["tactics".to_string()]);
+        assert!(entry.pinned && entry.notes.is_empty() && entry.layers.is_empty());
+        assert!(entry.upstream_share_code.is_none() && entry.install_verdict.is_none());
+        assert!(entry.health.is_none() && entry.launch.is_none());
+        assert!(entry.settings_profile.is_none() && entry.settings_overrides.is_empty());
+        assert_eq!(
+            entry.play_stats,
+            crate::registry::model::PlayStats::default()
+        );
+    }
+
+    #[test]
+    fn v1_registry_with_optional_fields_keeps_them() {
+        let m = migrate(
+            FormatKind::Registry,
+            Path::new("x"),
+            parse(REGISTRY_V1_WITH_OPTIONAL),
+        )
+        .expect("migrate");
+        let registry: ModlistRegistry = serde_json::from_value(m.value).expect("typed");
+        let entry = &registry.entries[0];
+        assert_eq!(entry.author.as_deref(), Some("someone"));
+        assert!(entry.install_started_at.is_some());
+        assert!(entry.install_date.is_none());
+    }
+
+    #[test]
+    fn unversioned_files_use_oldest_format() {
+        let registry = parse(REGISTRY_UNVERSIONED);
+        assert_eq!(detect_version(FormatKind::Registry, &registry), 1);
+        let workspace = parse(WORKSPACE_V0);
+        assert_eq!(detect_version(FormatKind::Workspace, &workspace), 0);
+        let m = migrate(FormatKind::Workspace, Path::new("w"), workspace).expect("migrate");
+        assert_eq!(m.value["format_version"], 1);
+        let state: ModlistWorkspaceState = serde_json::from_value(m.value).expect("typed");
+        assert_eq!(state.last_share_code.as_deref(), Some("BIO-OLD"));
+    }
+
+    #[test]
+    fn current_format_is_noop() {
+        let raw = serde_json::to_value(ModlistRegistry::default()).expect("value");
+        let m = migrate(FormatKind::Registry, Path::new("x"), raw).expect("migrate");
+        assert!(m.is_noop());
+        assert!(m.steps.is_empty());
+    }
+
+    #[test]
+    fn newer_format_is_refused() {
+        let raw = parse(r#"{ "format_version": 99, "entries": [] }"#);
+        match migrate(FormatKind::Registry, Path::new("new.json"), raw) {
+            Err(RegistryError::UnsupportedVersion {
+                found, supported, ..
+            }) => {
+                assert_eq!(found, 99);
+                assert_eq!(supported, REGISTRY_FORMAT_VERSION);
+            }
+            other => panic!("expected UnsupportedVersion, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn dry_run_leaves_file_untouched() {
+        let path = temp_file("dry", REGISTRY_V1_MINIMAL);
+        let m = migrate_file(FormatKind::Registry, &path, true).expect("dry run");
+        assert_eq!((m.from, m.to), (1, REGISTRY_FORMAT_VERSION));
+        assert_eq!(
+            std::fs::read_to_string(&path).expect("read"),
+            REGISTRY_V1_MINIMAL
+        );
+        let siblings = std::fs::read_dir(path.parent().unwrap()).unwrap().count();
+        assert_eq!(siblings, 1);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn migrate_file_writes_backup_first() {
+        let path = temp_file("write", WORKSPACE_V0);
+        migrate_file(FormatKind::Workspace, &path, false).expect("migrate");
+        let migrated = parse(&std::fs::read_to_string(&path).expect("read"));
+        assert_eq!(migrated["format_version"], 1);
+        let backup = std::fs::read_dir(path.parent().unwrap())
+            .unwrap()
+            .filter_map(Result::ok)
+            .find(|e| e.file_name().to_string_lossy().contains(".v0-backup-"))
+            .expect("backup written");
+        assert_eq!(
+            std::fs::read_to_string(backup.path()).expect("read backup"),
+            WORKSPACE_V0
+        );
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
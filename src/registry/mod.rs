@@ -4,7 +4,11 @@
 pub mod dev_seed;
 pub mod errors;
 pub mod ids;
+pub mod install_history;
+pub mod install_queue;
+pub mod migrations;
 pub mod model;
+pub mod modlist_layers;
 pub mod operations;
 pub mod operations_create;
 pub mod operations_rename;
@@ -12,6 +16,7 @@ pub mod persistence_cycle;
 pub mod share_export;
 pub mod store;
 pub mod store_workspace;
+pub mod workspace_history;
 pub mod workspace_model;
 
 pub use errors::RegistryError;
@@ -17,7 +17,7 @@ pub struct ModlistRegistry {
 impl Default for ModlistRegistry {
     fn default() -> Self {
         Self {
-            format_version: 1,
+            format_version: crate::registry::migrations::REGISTRY_FORMAT_VERSION,
             entries: Vec::new(),
         }
     }
@@ -76,9 +76,45 @@ pub struct ModlistEntry {
     #[serde(default)]
     pub author: Option<String>,
 
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    #[serde(default)]
+    pub notes: String,
+
+    #[serde(default)]
+    pub pinned: bool,
+
     #[serde(default)]
     pub(crate) forked_from: Vec<crate::app::modlist_share::ForkAncestor>,
 
+    #[serde(default)]
+    pub upstream_share_code: Option<String>,
+
+    #[serde(default)]
+    pub install_verdict: Option<InstallVerdict>,
+
+    #[serde(default)]
+    pub health: Option<HealthReport>,
+
+    #[serde(default)]
+    pub update_status: Option<ModlistUpdateStatus>,
+
+    #[serde(default)]
+    pub layers: Vec<LayerProvenance>,
+
+    #[serde(default)]
+    pub launch: Option<LaunchConfig>,
+
+    #[serde(default)]
+    pub play_stats: PlayStats,
+
+    #[serde(default)]
+    pub settings_profile: Option<String>,
+
+    #[serde(default)]
+    pub settings_overrides: crate::settings::profiles::SettingsOverrides,
+
     pub workspace_file_relpath: PathBuf,
 }
 
@@ -102,12 +138,296 @@ impl Default for ModlistEntry {
             total_size_bytes: None,
             latest_share_code: None,
             author: None,
+            tags: Vec::new(),
+            notes: String::new(),
+            pinned: false,
             forked_from: Vec::new(),
+            upstream_share_code: None,
+            install_verdict: None,
+            health: None,
+            update_status: None,
+            layers: Vec::new(),
+            launch: None,
+            play_stats: PlayStats::default(),
+            settings_profile: None,
+            settings_overrides: crate::settings::profiles::SettingsOverrides::new(),
             workspace_file_relpath: PathBuf::new(),
         }
     }
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InstallVerdict {
+    pub checked_at: Option<DateTime<Utc>>,
+
+    pub planned: u32,
+
+    pub installed: u32,
+
+    pub missing: Vec<String>,
+
+    pub extra: Vec<String>,
+
+    pub reordered: Vec<String>,
+
+    pub language_mismatch: Vec<String>,
+
+    pub changed: Vec<String>,
+
+    pub error: Option<String>,
+}
+
+impl InstallVerdict {
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.error.is_none()
+            && self.missing.is_empty()
+            && self.extra.is_empty()
+            && self.reordered.is_empty()
+            && self.language_mismatch.is_empty()
+            && self.changed.is_empty()
+    }
+
+    #[must_use]
+    pub fn summary(&self) -> Option<String> {
+        if let Some(err) = self.error.as_deref() {
+            return Some(format!("not verified ({err})"));
+        }
+        let plural =
+            |n: usize, one: &str, many: &str| format!("{n} {}", if n == 1 { one } else { many });
+        let mut parts = Vec::new();
+        if !self.missing.is_empty() {
+            parts.push(format!(
+                "{} skipped",
+                plural(self.missing.len(), "component", "components")
+            ));
+        }
+        if !self.extra.is_empty() {
+            parts.push(format!("{} extra", self.extra.len()));
+        }
+        if !self.reordered.is_empty() {
+            parts.push(format!("{} out of order", self.reordered.len()));
+        }
+        if !self.language_mismatch.is_empty() {
+            parts.push(format!(
+                "{} in another language",
+                self.language_mismatch.len()
+            ));
+        }
+        if !self.changed.is_empty() {
+            parts.push(format!("{} changed", self.changed.len()));
+        }
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+}
+
+pub const HEALTH_SIZE_DRIFT_PERCENT: i64 = 10;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HealthReport {
+    pub checked_at: Option<DateTime<Utc>>,
+
+    pub destination_missing: bool,
+
+    pub game_binary_missing: bool,
+
+    pub log: Option<InstallVerdict>,
+
+    pub archives_checked: u32,
+
+    pub archives_changed: Vec<String>,
+
+    pub archives_missing: Vec<String>,
+
+    pub size_bytes: Option<u64>,
+
+    pub recorded_size_bytes: Option<u64>,
+}
+
+impl HealthReport {
+    #[must_use]
+    pub fn size_drift_percent(&self) -> Option<i64> {
+        let now = i128::from(self.size_bytes?);
+        let recorded = i128::from(self.recorded_size_bytes.filter(|&bytes| bytes > 0)?);
+        i64::try_from((now - recorded) * 100 / recorded).ok()
+    }
+
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.summary().is_none()
+    }
+
+    #[must_use]
+    pub fn summary(&self) -> Option<String> {
+        if self.destination_missing {
+            return Some("install folder missing".to_string());
+        }
+        let mut parts = Vec::new();
+        if self.game_binary_missing {
+            parts.push("game binary missing".to_string());
+        }
+        if let Some(summary) = self.log.as_ref().and_then(InstallVerdict::summary) {
+            parts.push(format!("weidu.log {summary}"));
+        }
+        let archives = self.archives_changed.len() + self.archives_missing.len();
+        if archives > 0 {
+            parts.push(format!("{archives} archive(s) changed or missing"));
+        }
+        if let Some(drift) = self
+            .size_drift_percent()
+            .filter(|drift| drift.abs() >= HEALTH_SIZE_DRIFT_PERCENT)
+        {
+            parts.push(format!("size {drift:+}%"));
+        }
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
+    #[must_use]
+    pub fn details(&self) -> String {
+        let mut lines = Vec::new();
+        if let Some(log) = self.log.as_ref() {
+            lines.extend(log.missing.iter().map(|c| format!("skipped: {c}")));
+            lines.extend(log.extra.iter().map(|c| format!("extra: {c}")));
+            lines.extend(log.reordered.iter().map(|c| format!("out of order: {c}")));
+            lines.extend(
+                log.language_mismatch
+                    .iter()
+                    .map(|c| format!("other language: {c}")),
+            );
+            lines.extend(log.changed.iter().map(|c| format!("changed: {c}")));
+        }
+        lines.extend(
+            self.archives_changed
+                .iter()
+                .map(|name| format!("archive changed: {name}")),
+        );
+        lines.extend(
+            self.archives_missing
+                .iter()
+                .map(|name| format!("archive missing: {name}")),
+        );
+        lines.join("\n")
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModlistUpdateStatus {
+    pub checked_at: Option<DateTime<Utc>>,
+
+    pub updates: Vec<ModUpdateDelta>,
+
+    pub failed: Vec<String>,
+
+    pub skipped_locked: u32,
+
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModUpdateDelta {
+    pub tp2: String,
+
+    pub current: Option<String>,
+
+    pub latest: String,
+}
+
+impl ModlistUpdateStatus {
+    #[must_use]
+    pub fn summary(&self) -> Option<String> {
+        match self.updates.len() {
+            0 if self.error.is_some() => Some("Update check failed".to_string()),
+            0 => None,
+            1 => Some("1 update available".to_string()),
+            n => Some(format!("{n} updates available")),
+        }
+    }
+
+    #[must_use]
+    pub fn details(&self) -> String {
+        self.error
+            .iter()
+            .cloned()
+            .chain(self.updates.iter().map(|delta| {
+                format!(
+                    "{}: {} \u{2192} {}",
+                    delta.tp2,
+                    delta.current.as_deref().unwrap_or("?"),
+                    delta.latest
+                )
+            }))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LayerProvenance {
+    pub layer_id: String,
+
+    pub name: String,
+
+    pub revision: u32,
+
+    pub components: Vec<crate::registry::workspace_model::ComponentRef>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchKind {
+    #[default]
+    Native,
+
+    Steam,
+
+    Wine,
+
+    Custom,
+}
+
+impl LaunchKind {
+    pub const ALL: [Self; 4] = [Self::Native, Self::Steam, Self::Wine, Self::Custom];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Native => "Game binary",
+            Self::Steam => "Steam",
+            Self::Wine => "Wine / Proton",
+            Self::Custom => "Custom script",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LaunchConfig {
+    pub kind: LaunchKind,
+
+    pub program: String,
+
+    pub wrapper: String,
+
+    pub args: String,
+
+    pub env: std::collections::BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlayStats {
+    pub sessions: u32,
+
+    pub last_session_secs: Option<u64>,
+
+    pub total_secs: u64,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum ModlistState {
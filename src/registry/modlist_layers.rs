@@ -0,0 +1,270 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::platform_defaults::app_config_file;
+use crate::registry::errors::RegistryError;
+use crate::registry::ids::new_modlist_id;
+use crate::registry::model::{Game, LayerProvenance, ModlistEntry};
+use crate::registry::workspace_model::{ComponentRef, ModlistWorkspaceState};
+
+const MODLIST_LAYERS_FILE_NAME: &str = "modlist_layers.json";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModlistLayer {
+    pub id: String,
+
+    pub name: String,
+
+    pub game: Game,
+
+    pub revision: u32,
+
+    pub updated_at: DateTime<Utc>,
+
+    pub order_bgee: Vec<ComponentRef>,
+
+    pub order_bg2ee: Vec<ComponentRef>,
+
+    pub order_iwdee: Vec<ComponentRef>,
+}
+
+impl Default for ModlistLayer {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            name: String::new(),
+            game: Game::default(),
+            revision: 1,
+            updated_at: Utc::now(),
+            order_bgee: Vec::new(),
+            order_bg2ee: Vec::new(),
+            order_iwdee: Vec::new(),
+        }
+    }
+}
+
+impl ModlistLayer {
+    #[must_use]
+    pub fn from_workspace(
+        name: &str,
+        game: Game,
+        workspace: &ModlistWorkspaceState,
+        mut keep: impl FnMut(&ComponentRef) -> bool,
+    ) -> Self {
+        let mut pick = |order: &[ComponentRef]| -> Vec<ComponentRef> {
+            order.iter().filter(|c| keep(c)).cloned().collect()
+        };
+        Self {
+            name: name.trim().to_string(),
+            game,
+            order_bgee: pick(&workspace.order_bgee),
+            order_bg2ee: pick(&workspace.order_bg2ee),
+            order_iwdee: pick(&workspace.order_iwdee),
+            ..Self::default()
+        }
+    }
+
+    pub fn components(&self) -> impl Iterator<Item = &ComponentRef> {
+        self.order_bgee
+            .iter()
+            .chain(&self.order_bg2ee)
+            .chain(&self.order_iwdee)
+    }
+
+    #[must_use]
+    pub fn component_count(&self) -> usize {
+        self.components().count()
+    }
+
+    #[must_use]
+    pub fn mod_names(&self) -> Vec<String> {
+        self.components()
+            .map(|component| component.tp2.to_ascii_uppercase())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    #[must_use]
+    pub fn provenance(&self) -> LayerProvenance {
+        LayerProvenance {
+            layer_id: self.id.clone(),
+            name: self.name.clone(),
+            revision: self.revision,
+            components: self.components().cloned().collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LayerLibrary {
+    pub layers: Vec<ModlistLayer>,
+}
+
+impl LayerLibrary {
+    #[must_use]
+    pub fn find(&self, id: &str) -> Option<&ModlistLayer> {
+        self.layers.iter().find(|layer| layer.id == id)
+    }
+
+    #[must_use]
+    pub fn for_game(&self, game: Game) -> Vec<&ModlistLayer> {
+        self.layers
+            .iter()
+            .filter(|layer| layer.game == game)
+            .collect()
+    }
+
+    pub fn save_layer(&mut self, mut layer: ModlistLayer) -> &ModlistLayer {
+        let existing = self.layers.iter().position(|current| {
+            current.game == layer.game && current.name.eq_ignore_ascii_case(&layer.name)
+        });
+        layer.updated_at = Utc::now();
+        let index = if let Some(index) = existing {
+            let current = &self.layers[index];
+            layer.id.clone_from(&current.id);
+            layer.revision = current.revision + 1;
+            self.layers[index] = layer;
+            index
+        } else {
+            layer.id = new_modlist_id();
+            layer.revision = 1;
+            self.layers.push(layer);
+            self.layers.len() - 1
+        };
+        &self.layers[index]
+    }
+
+    pub fn remove(&mut self, id: &str) {
+        self.layers.retain(|layer| layer.id != id);
+    }
+
+    #[must_use]
+    pub fn stale_layers<'a>(&self, entry: &'a ModlistEntry) -> Vec<&'a LayerProvenance> {
+        entry
+            .layers
+            .iter()
+            .filter(|applied| {
+                self.find(&applied.layer_id)
+                    .is_some_and(|layer| layer.revision > applied.revision)
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LayerStore {
+    path: PathBuf,
+}
+
+impl LayerStore {
+    #[must_use]
+    pub fn new_default() -> Self {
+        Self {
+            path: app_config_file(MODLIST_LAYERS_FILE_NAME, "."),
+        }
+    }
+
+    pub fn new_with_path(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn load(&self) -> Result<LayerLibrary, RegistryError> {
+        let raw = match std::fs::read_to_string(&self.path) {
+            Ok(s) => s,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(LayerLibrary::default());
+            }
+            Err(err) => return Err(RegistryError::Io(err)),
+        };
+        serde_json::from_str(&raw)
+            .map_err(|err| RegistryError::corrupt(self.path.clone(), err.to_string()))
+    }
+
+    pub fn save(&self, library: &LayerLibrary) -> Result<(), RegistryError> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let raw = serde_json::to_string_pretty(library)?;
+        let tmp_path = self.path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, raw.as_bytes())?;
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::temp_dir;
+
+    fn component(tp2: &str, id: i64) -> ComponentRef {
+        ComponentRef {
+            tp2: tp2.to_string(),
+            id,
+            ..ComponentRef::default()
+        }
+    }
+
+    #[test]
+    fn saving_same_name_bumps_revision_and_flags_stale_entries() {
+        let workspace = ModlistWorkspaceState {
+            order_bg2ee: vec![
+                component("bg2fixpack/setup-bg2fixpack.tp2", 0),
+                component("scs/setup-scs.tp2", 1000),
+            ],
+            ..ModlistWorkspaceState::default()
+        };
+        let mut library = LayerLibrary::default();
+        let first = library
+            .save_layer(ModlistLayer::from_workspace(
+                "Fixpacks",
+                Game::BG2EE,
+                &workspace,
+                |c| c.tp2.starts_with("bg2fixpack"),
+            ))
+            .clone();
+        assert_eq!(first.revision, 1);
+        assert_eq!(first.component_count(), 1);
+
+        let entry = ModlistEntry {
+            layers: vec![first.provenance()],
+            ..ModlistEntry::default()
+        };
+        assert!(library.stale_layers(&entry).is_empty());
+
+        let second = library
+            .save_layer(ModlistLayer::from_workspace(
+                "fixpacks",
+                Game::BG2EE,
+                &workspace,
+                |_| true,
+            ))
+            .clone();
+        assert_eq!(second.id, first.id);
+        assert_eq!(second.revision, 2);
+        assert_eq!(library.layers.len(), 1);
+        assert_eq!(library.stale_layers(&entry).len(), 1);
+        assert_eq!(library.for_game(Game::EET).len(), 0);
+
+        let dir = temp_dir("modlist_layers");
+        let path = dir.join("layers.json");
+        let store = LayerStore::new_with_path(&path);
+        store.save(&library).expect("save");
+        assert_eq!(store.load().expect("load"), library);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
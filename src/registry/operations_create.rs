@@ -20,6 +20,7 @@ pub(crate) struct ForkedModlistInput<'a> {
     pub(crate) parent_name: &'a str,
     pub(crate) parent_author: &'a str,
     pub(crate) parent_forked_from: &'a [ForkAncestor],
+    pub(crate) parent_share_code: &'a str,
     pub(crate) parent_mod_count: u32,
     pub(crate) parent_component_count: u32,
 }
@@ -112,6 +113,8 @@ pub(crate) fn create_forked_modlist(
         last_touched_date: now,
         author,
         forked_from,
+        upstream_share_code: Some(input.parent_share_code.trim().to_string())
+            .filter(|code| !code.is_empty()),
         mod_count: input.parent_mod_count,
         component_count: input.parent_component_count,
         workspace_file_relpath: PathBuf::from("modlists").join(&id).join("workspace.json"),
@@ -144,6 +147,7 @@ mod tests {
             parent_name,
             parent_author,
             parent_forked_from,
+            parent_share_code: "",
             parent_mod_count: 0,
             parent_component_count: 0,
         }
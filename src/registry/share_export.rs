@@ -16,6 +16,8 @@ const SHARE_CODE_PREFIX: &str = "BIO-MODLIST-V1:";
 
 const ARCHIVE_META_KEY: &str = "archive_meta";
 
+const MOD_CONFIGS_KEY: &str = "mod_configs";
+
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct ArchiveMeta {
     pub name: String,
@@ -25,6 +27,17 @@ pub struct ArchiveMeta {
     pub hash: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModConfigBytes {
+    pub tp2: String,
+
+    pub source_id: String,
+
+    pub relative_path: String,
+
+    pub bytes: Vec<u8>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct ShareMeta {
     pub allow_auto_install: bool,
@@ -162,6 +175,46 @@ fn insert_archive_meta(obj: &mut serde_json::Map<String, Value>, archive_meta: &
 pub fn bake_archive_meta_into_code(
     code: &str,
     archive_meta: &[ArchiveMeta],
+) -> Result<String, String> {
+    edit_share_payload(code, |obj| insert_archive_meta(obj, archive_meta))
+}
+
+pub fn bake_mod_configs_into_code(code: &str, files: &[ModConfigBytes]) -> Result<String, String> {
+    if files.is_empty() {
+        return Ok(code.trim().to_string());
+    }
+    edit_share_payload(code, |obj| insert_mod_configs(obj, files))
+}
+
+fn insert_mod_configs(obj: &mut serde_json::Map<String, Value>, files: &[ModConfigBytes]) {
+    let mut list = obj
+        .get(MOD_CONFIGS_KEY)
+        .and_then(|configs| configs.get("files"))
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    for file in files {
+        list.retain(|existing| {
+            existing.get("tp2").and_then(Value::as_str) != Some(file.tp2.as_str())
+                || existing.get("relative_path").and_then(Value::as_str)
+                    != Some(file.relative_path.as_str())
+        });
+        list.push(serde_json::json!({
+            "tp2": file.tp2,
+            "source_id": file.source_id,
+            "relative_path": file.relative_path,
+            "base64_data": base64url_encode(&file.bytes),
+        }));
+    }
+    obj.insert(
+        MOD_CONFIGS_KEY.to_string(),
+        serde_json::json!({ "files": list }),
+    );
+}
+
+fn edit_share_payload(
+    code: &str,
+    edit: impl FnOnce(&mut serde_json::Map<String, Value>),
 ) -> Result<String, String> {
     let encoded = code
         .trim()
@@ -175,7 +228,7 @@ pub fn bake_archive_meta_into_code(
         .as_object_mut()
         .ok_or_else(|| "share payload was not a JSON object".to_string())?;
 
-    insert_archive_meta(obj, archive_meta);
+    edit(obj);
     let out_bytes =
         serde_json::to_vec(&payload).map_err(|err| format!("re-serialize failed: {err}"))?;
     let recompressed = zlib_compress(&out_bytes)?;
@@ -438,6 +491,32 @@ mod tests {
         serde_json::from_slice(&bytes).unwrap()
     }
 
+    #[test]
+    fn bake_mod_configs_replaces_matching_files_and_keeps_others() {
+        let base = make_base(&json!({
+            "format_version": 1,
+            "mod_configs": { "files": [
+                { "tp2": "scs", "source_id": "github", "relative_path": "stratagems.ini", "base64_data": "b2xk" },
+                { "tp2": "eet", "source_id": "github", "relative_path": "eet.ini", "base64_data": "ZWV0" },
+            ]},
+        }));
+        let code = bake_mod_configs_into_code(
+            &base,
+            &[ModConfigBytes {
+                tp2: "scs".to_string(),
+                source_id: "github".to_string(),
+                relative_path: "stratagems.ini".to_string(),
+                bytes: b"new".to_vec(),
+            }],
+        )
+        .expect("bake");
+        let files = decode_payload(&code)["mod_configs"]["files"].clone();
+        assert_eq!(files.as_array().unwrap().len(), 2);
+        assert_eq!(files[0]["tp2"], json!("eet"));
+        assert_eq!(files[1]["base64_data"], json!(base64url_encode(b"new")));
+        assert_eq!(bake_mod_configs_into_code(&base, &[]).unwrap(), base);
+    }
+
     #[test]
     fn pack_meta_injects_false_bit_and_omits_absent_provenance() {
         let base = make_base(&json!({
@@ -4,8 +4,11 @@
 use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use tracing::{info, warn};
+
 use crate::platform_defaults::app_config_file;
 use crate::registry::errors::RegistryError;
+use crate::registry::migrations::{FormatKind, migrate, write_migrated};
 use crate::registry::model::ModlistRegistry;
 
 const REGISTRY_FILE_NAME: &str = "modlists.json";
@@ -39,13 +42,29 @@ impl RegistryStore {
             }
             Err(err) => return Err(RegistryError::Io(err)),
         };
-        match serde_json::from_str::<ModlistRegistry>(&raw) {
-            Ok(registry) => Ok(registry),
-            Err(parse_err) => Err(RegistryError::corrupt(
-                self.path.clone(),
-                parse_err.to_string(),
-            )),
+        let value = serde_json::from_str::<serde_json::Value>(&raw)
+            .map_err(|err| RegistryError::corrupt(self.path.clone(), err.to_string()))?;
+        let migration = migrate(FormatKind::Registry, &self.path, value)?;
+        let registry = serde_json::from_value::<ModlistRegistry>(migration.value.clone())
+            .map_err(|err| RegistryError::corrupt(self.path.clone(), err.to_string()))?;
+        if !migration.is_noop() {
+            match write_migrated(&self.path, &migration) {
+                Ok(backup) => info!(
+                    target = "orchestrator",
+                    "migrated {} from format {} to {} (backup {})",
+                    self.path.display(),
+                    migration.from,
+                    migration.to,
+                    backup.display()
+                ),
+                Err(err) => warn!(
+                    target = "orchestrator",
+                    "could not persist migrated {}: {err}",
+                    self.path.display()
+                ),
+            }
         }
+        Ok(registry)
     }
 
     pub fn save(&self, registry: &ModlistRegistry) -> Result<(), RegistryError> {
@@ -183,4 +202,52 @@ mod tests {
         assert!(!path.with_extension("json.tmp").exists());
         let _ = std::fs::remove_file(&path);
     }
+
+    #[test]
+    fn newer_format_is_refused_and_left_in_place() {
+        let path = temp_path("newer");
+        let raw = r#"{"format_version":99,"entries":[]}"#;
+        std::fs::write(&path, raw).expect("write");
+        let store = RegistryStore::new_with_path(&path);
+        match store.load() {
+            Err(RegistryError::UnsupportedVersion { found: 99, .. }) => {}
+            other => panic!("expected UnsupportedVersion, got {other:?}"),
+        }
+        assert_eq!(std::fs::read_to_string(&path).expect("read"), raw);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn v1_file_is_migrated_on_load() {
+        let path = temp_path("v1");
+        std::fs::write(&path, r#"{"format_version":1,"entries":[]}"#).expect("write");
+        let store = RegistryStore::new_with_path(&path);
+        let registry = store.load().expect("load");
+        assert_eq!(
+            registry.format_version,
+            crate::registry::migrations::REGISTRY_FORMAT_VERSION
+        );
+        let on_disk: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).expect("read")).expect("json");
+        assert_eq!(
+            on_disk["format_version"],
+            crate::registry::migrations::REGISTRY_FORMAT_VERSION
+        );
+        let backups: Vec<_> = std::fs::read_dir(path.parent().unwrap())
+            .unwrap()
+            .filter_map(Result::ok)
+            .map(|e| e.path())
+            .filter(|p| {
+                p.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with(path.file_stem().unwrap().to_str().unwrap()))
+                    && p != &path
+            })
+            .collect();
+        assert_eq!(backups.len(), 1, "one backup: {backups:?}");
+        for p in backups {
+            let _ = std::fs::remove_file(p);
+        }
+        let _ = std::fs::remove_file(&path);
+    }
 }
@@ -3,8 +3,11 @@
 
 use std::path::{Path, PathBuf};
 
+use tracing::warn;
+
 use crate::platform_defaults::app_config_dir;
 use crate::registry::errors::RegistryError;
+use crate::registry::migrations::{FormatKind, WORKSPACE_FORMAT_VERSION, migrate, write_migrated};
 use crate::registry::workspace_model::ModlistWorkspaceState;
 
 const MODLISTS_DIR: &str = "modlists";
@@ -51,17 +54,32 @@ impl WorkspaceStore {
             }
             Err(err) => return Err(RegistryError::Io(err)),
         };
-        match serde_json::from_str::<ModlistWorkspaceState>(&raw) {
-            Ok(state) => Ok(state),
-            Err(parse_err) => Err(RegistryError::corrupt(
-                self.path.clone(),
-                parse_err.to_string(),
-            )),
+        let value = serde_json::from_str::<serde_json::Value>(&raw)
+            .map_err(|err| RegistryError::corrupt(self.path.clone(), err.to_string()))?;
+        let migration = migrate(FormatKind::Workspace, &self.path, value)?;
+        let state = serde_json::from_value::<ModlistWorkspaceState>(migration.value.clone())
+            .map_err(|err| RegistryError::corrupt(self.path.clone(), err.to_string()))?;
+        if !migration.is_noop()
+            && let Err(err) = write_migrated(&self.path, &migration)
+        {
+            warn!(
+                target = "orchestrator",
+                "could not persist migrated {}: {err}",
+                self.path.display()
+            );
         }
+        Ok(state)
     }
 
     pub fn save(&self, state: &ModlistWorkspaceState) -> Result<(), RegistryError> {
-        let raw = serde_json::to_string_pretty(state)?;
+        let mut value = serde_json::to_value(state)?;
+        if let Some(object) = value.as_object_mut() {
+            object.insert(
+                "format_version".to_string(),
+                serde_json::Value::from(WORKSPACE_FORMAT_VERSION),
+            );
+        }
+        let raw = serde_json::to_string_pretty(&value)?;
 
         if let Some(parent) = self
             .path
@@ -109,6 +127,9 @@ mod tests {
         assert!(path.parent().unwrap().exists(), "modlists subdir created");
         let loaded = store.load().expect("load");
         assert_eq!(loaded.last_share_code.as_deref(), Some("X"));
+        let raw: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(raw["format_version"], WORKSPACE_FORMAT_VERSION);
         let _ = std::fs::remove_dir_all(&root);
     }
 
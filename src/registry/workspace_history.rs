@@ -0,0 +1,493 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::registry::errors::RegistryError;
+use crate::registry::store_workspace::modlist_data_dir;
+use crate::registry::workspace_model::{ComponentRef, ModlistWorkspaceState};
+
+const HISTORY_FILE_NAME: &str = "history.json";
+
+pub const MAX_AUTO_REVISIONS: usize = 50;
+
+pub const AUTO_COALESCE_SECS: i64 = 120;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkspaceRevision {
+    pub id: u64,
+
+    pub created_at: DateTime<Utc>,
+
+    pub auto: bool,
+
+    pub name: Option<String>,
+
+    pub state: ModlistWorkspaceState,
+}
+
+impl Default for WorkspaceRevision {
+    fn default() -> Self {
+        Self {
+            id: 0,
+            created_at: Utc::now(),
+            auto: true,
+            name: None,
+            state: ModlistWorkspaceState::default(),
+        }
+    }
+}
+
+impl WorkspaceRevision {
+    #[must_use]
+    pub fn title(&self) -> String {
+        match (&self.name, self.auto) {
+            (Some(name), _) => name.clone(),
+            (None, true) => "auto snapshot".to_string(),
+            (None, false) => format!("revision #{}", self.id),
+        }
+    }
+
+    #[must_use]
+    pub fn component_count(&self) -> usize {
+        self.state.order_bgee.len() + self.state.order_bg2ee.len() + self.state.order_iwdee.len()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkspaceHistory {
+    pub next_id: u64,
+
+    pub revisions: Vec<WorkspaceRevision>,
+}
+
+impl WorkspaceHistory {
+    #[must_use]
+    pub fn find(&self, id: u64) -> Option<&WorkspaceRevision> {
+        self.revisions.iter().find(|r| r.id == id)
+    }
+
+    #[must_use]
+    pub fn latest(&self) -> Option<&WorkspaceRevision> {
+        self.revisions.last()
+    }
+
+    pub fn record_auto(&mut self, state: &ModlistWorkspaceState, now: DateTime<Utc>) -> bool {
+        if self
+            .latest()
+            .is_some_and(|latest| same_content(&latest.state, state))
+        {
+            return false;
+        }
+        let has_baseline = self.revisions.len() > 1;
+        if let Some(latest) = self.revisions.last_mut()
+            && has_baseline
+            && latest.auto
+            && latest.name.is_none()
+            && (now - latest.created_at).num_seconds() < AUTO_COALESCE_SECS
+        {
+            latest.state = state.clone();
+            latest.created_at = now;
+            return true;
+        }
+        self.push(state, true, None, now);
+        self.prune_auto();
+        true
+    }
+
+    pub fn record_named(
+        &mut self,
+        name: &str,
+        state: &ModlistWorkspaceState,
+        now: DateTime<Utc>,
+    ) -> u64 {
+        self.push(state, false, Some(name.trim().to_string()), now)
+    }
+
+    pub fn record_before_restore(
+        &mut self,
+        restoring: u64,
+        state: &ModlistWorkspaceState,
+        now: DateTime<Utc>,
+    ) {
+        if self
+            .latest()
+            .is_some_and(|latest| same_content(&latest.state, state))
+        {
+            return;
+        }
+        self.push(
+            state,
+            true,
+            Some(format!("before restoring #{restoring}")),
+            now,
+        );
+        self.prune_auto();
+    }
+
+    fn push(
+        &mut self,
+        state: &ModlistWorkspaceState,
+        auto: bool,
+        name: Option<String>,
+        now: DateTime<Utc>,
+    ) -> u64 {
+        self.next_id += 1;
+        self.revisions.push(WorkspaceRevision {
+            id: self.next_id,
+            created_at: now,
+            auto,
+            name,
+            state: state.clone(),
+        });
+        self.next_id
+    }
+
+    fn prune_auto(&mut self) {
+        let autos = self.revisions.iter().filter(|r| r.auto).count();
+        let mut excess = autos.saturating_sub(MAX_AUTO_REVISIONS);
+        self.revisions.retain(|r| {
+            if excess > 0 && r.auto {
+                excess -= 1;
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+#[must_use]
+pub fn same_content(a: &ModlistWorkspaceState, b: &ModlistWorkspaceState) -> bool {
+    a.order_bgee == b.order_bgee
+        && a.order_bg2ee == b.order_bg2ee
+        && a.order_iwdee == b.order_iwdee
+        && a.prompt_overrides == b.prompt_overrides
+}
+
+#[must_use]
+pub fn restored_state(
+    current: &ModlistWorkspaceState,
+    revision: &WorkspaceRevision,
+) -> ModlistWorkspaceState {
+    ModlistWorkspaceState {
+        order_bgee: revision.state.order_bgee.clone(),
+        order_bg2ee: revision.state.order_bg2ee.clone(),
+        order_iwdee: revision.state.order_iwdee.clone(),
+        prompt_overrides: revision.state.prompt_overrides.clone(),
+        step3_group_collapse: revision.state.step3_group_collapse.clone(),
+        ..current.clone()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrderDiff {
+    pub tab: &'static str,
+    pub added: Vec<ComponentRef>,
+    pub removed: Vec<ComponentRef>,
+    pub moved: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkspaceDiff {
+    pub orders: Vec<OrderDiff>,
+    pub prompts_added: Vec<String>,
+    pub prompts_removed: Vec<String>,
+    pub prompts_changed: Vec<String>,
+}
+
+impl WorkspaceDiff {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+            && self.prompts_added.is_empty()
+            && self.prompts_removed.is_empty()
+            && self.prompts_changed.is_empty()
+    }
+
+    #[must_use]
+    pub fn lines(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        for order in &self.orders {
+            for c in &order.added {
+                out.push(format!("+ {} {} #{}", order.tab, c.tp2, c.id));
+            }
+            for c in &order.removed {
+                out.push(format!("- {} {} #{}", order.tab, c.tp2, c.id));
+            }
+            if order.moved > 0 {
+                out.push(format!(
+                    "~ {} {} component(s) moved",
+                    order.tab, order.moved
+                ));
+            }
+        }
+        for key in &self.prompts_added {
+            out.push(format!("+ prompt answer {key}"));
+        }
+        for key in &self.prompts_removed {
+            out.push(format!("- prompt answer {key}"));
+        }
+        for key in &self.prompts_changed {
+            out.push(format!("~ prompt answer {key}"));
+        }
+        if out.is_empty() {
+            out.push("no differences".to_string());
+        }
+        out
+    }
+}
+
+#[must_use]
+pub fn diff_workspaces(from: &ModlistWorkspaceState, to: &ModlistWorkspaceState) -> WorkspaceDiff {
+    let orders = [
+        ("BGEE", &from.order_bgee, &to.order_bgee),
+        ("BG2EE", &from.order_bg2ee, &to.order_bg2ee),
+        ("IWDEE", &from.order_iwdee, &to.order_iwdee),
+    ]
+    .into_iter()
+    .map(|(tab, a, b)| diff_order(tab, a, b))
+    .filter(|d| !d.added.is_empty() || !d.removed.is_empty() || d.moved > 0)
+    .collect();
+
+    let from_keys: BTreeSet<&String> = from.prompt_overrides.keys().collect();
+    let to_keys: BTreeSet<&String> = to.prompt_overrides.keys().collect();
+    WorkspaceDiff {
+        orders,
+        prompts_added: to_keys
+            .difference(&from_keys)
+            .map(|k| (*k).clone())
+            .collect(),
+        prompts_removed: from_keys
+            .difference(&to_keys)
+            .map(|k| (*k).clone())
+            .collect(),
+        prompts_changed: from_keys
+            .intersection(&to_keys)
+            .filter(|k| from.prompt_overrides.get(**k) != to.prompt_overrides.get(**k))
+            .map(|k| (*k).clone())
+            .collect(),
+    }
+}
+
+fn diff_order(tab: &'static str, from: &[ComponentRef], to: &[ComponentRef]) -> OrderDiff {
+    let key = |c: &ComponentRef| (c.tp2.to_ascii_uppercase(), c.id);
+    let from_keys: Vec<_> = from.iter().map(key).collect();
+    let to_keys: Vec<_> = to.iter().map(key).collect();
+    let added = to
+        .iter()
+        .filter(|c| !from_keys.contains(&key(c)))
+        .cloned()
+        .collect();
+    let removed = from
+        .iter()
+        .filter(|c| !to_keys.contains(&key(c)))
+        .cloned()
+        .collect();
+    let kept_from: Vec<_> = from_keys.iter().filter(|k| to_keys.contains(k)).collect();
+    let kept_to: Vec<_> = to_keys.iter().filter(|k| from_keys.contains(k)).collect();
+    let moved = kept_from
+        .iter()
+        .zip(kept_to.iter())
+        .filter(|(a, b)| a != b)
+        .count();
+    OrderDiff {
+        tab,
+        added,
+        removed,
+        moved,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WorkspaceHistoryStore {
+    path: PathBuf,
+}
+
+impl WorkspaceHistoryStore {
+    #[must_use]
+    pub fn new_for_id(modlist_id: &str) -> Self {
+        Self {
+            path: modlist_data_dir(modlist_id).join(HISTORY_FILE_NAME),
+        }
+    }
+
+    pub fn new_with_path(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    #[must_use]
+    pub fn beside_workspace(workspace_path: &Path) -> Self {
+        Self {
+            path: workspace_path.with_file_name(HISTORY_FILE_NAME),
+        }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn load(&self) -> Result<WorkspaceHistory, RegistryError> {
+        let raw = match std::fs::read_to_string(&self.path) {
+            Ok(s) => s,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(WorkspaceHistory::default());
+            }
+            Err(err) => return Err(RegistryError::Io(err)),
+        };
+        serde_json::from_str(&raw)
+            .map_err(|err| RegistryError::corrupt(self.path.clone(), err.to_string()))
+    }
+
+    pub fn save(&self, history: &WorkspaceHistory) -> Result<(), RegistryError> {
+        let raw = serde_json::to_string_pretty(history)?;
+        if let Some(parent) = self
+            .path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = self.path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, raw.as_bytes())?;
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+
+    pub fn record_auto(&self, state: &ModlistWorkspaceState) -> Result<bool, RegistryError> {
+        let mut history = self.load()?;
+        if !history.record_auto(state, Utc::now()) {
+            return Ok(false);
+        }
+        self.save(&history)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::workspace_model::PromptOverride;
+    use crate::test_support::temp_dir;
+    use chrono::Duration;
+
+    fn comp(tp2: &str, id: i64) -> ComponentRef {
+        ComponentRef {
+            tp2: tp2.to_string(),
+            id,
+            ..Default::default()
+        }
+    }
+
+    fn state(order: &[(&str, i64)]) -> ModlistWorkspaceState {
+        ModlistWorkspaceState {
+            order_bg2ee: order.iter().map(|(t, i)| comp(t, *i)).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn auto_snapshots_skip_unchanged_and_coalesce_bursts() {
+        let t0 = Utc::now();
+        let mut h = WorkspaceHistory::default();
+        assert!(h.record_auto(&state(&[("A.TP2", 0)]), t0));
+        assert!(!h.record_auto(&state(&[("A.TP2", 0)]), t0));
+        assert!(h.record_auto(&state(&[("A.TP2", 0), ("B.TP2", 1)]), t0));
+        assert!(h.record_auto(&state(&[("B.TP2", 1)]), t0 + Duration::seconds(5)));
+        assert_eq!(h.revisions.len(), 2, "second edit coalesced into the first");
+        assert!(h.record_auto(&state(&[("C.TP2", 2)]), t0 + Duration::seconds(600)));
+        assert_eq!(h.revisions.len(), 3);
+    }
+
+    #[test]
+    fn component_count_includes_every_game_order() {
+        let mut revision = WorkspaceRevision {
+            state: state(&[("A.TP2", 0)]),
+            ..WorkspaceRevision::default()
+        };
+        revision.state.order_iwdee = vec![comp("B.TP2", 1), comp("C.TP2", 2)];
+        assert_eq!(revision.component_count(), 3);
+    }
+
+    #[test]
+    fn expand_state_only_is_not_a_meaningful_change() {
+        let mut h = WorkspaceHistory::default();
+        let a = state(&[("A.TP2", 0)]);
+        let mut b = a.clone();
+        b.expand_state.insert("x".to_string(), true);
+        h.record_auto(&a, Utc::now());
+        assert!(!h.record_auto(&b, Utc::now()));
+    }
+
+    #[test]
+    fn named_revisions_survive_auto_pruning() {
+        let mut h = WorkspaceHistory::default();
+        let t0 = Utc::now();
+        let named = h.record_named(" release ", &state(&[("N.TP2", 0)]), t0);
+        for i in 0..(MAX_AUTO_REVISIONS as i64 + 10) {
+            h.record_auto(&state(&[("A.TP2", i)]), t0 + Duration::hours(i + 1));
+        }
+        assert_eq!(
+            h.revisions.iter().filter(|r| r.auto).count(),
+            MAX_AUTO_REVISIONS
+        );
+        assert_eq!(h.find(named).unwrap().name.as_deref(), Some("release"));
+    }
+
+    #[test]
+    fn diff_reports_added_removed_moved_and_prompts() {
+        let mut from = state(&[("A.TP2", 0), ("B.TP2", 1), ("C.TP2", 2)]);
+        let mut to = state(&[("C.TP2", 2), ("A.TP2", 0), ("D.TP2", 3)]);
+        from.prompt_overrides
+            .insert("k1".to_string(), PromptOverride::default());
+        to.prompt_overrides.insert(
+            "k1".to_string(),
+            PromptOverride {
+                answer: "2".to_string(),
+                ..Default::default()
+            },
+        );
+        to.prompt_overrides
+            .insert("k2".to_string(), PromptOverride::default());
+        let d = diff_workspaces(&from, &to);
+        assert_eq!(d.orders.len(), 1);
+        assert_eq!(d.orders[0].added, vec![comp("D.TP2", 3)]);
+        assert_eq!(d.orders[0].removed, vec![comp("B.TP2", 1)]);
+        assert_eq!(d.orders[0].moved, 2);
+        assert_eq!(d.prompts_added, vec!["k2".to_string()]);
+        assert_eq!(d.prompts_changed, vec!["k1".to_string()]);
+        assert!(diff_workspaces(&from, &from).is_empty());
+    }
+
+    #[test]
+    fn restore_keeps_current_non_content_fields() {
+        let mut current = state(&[("A.TP2", 0)]);
+        current.last_share_code = Some("BIO-NOW".to_string());
+        let revision = WorkspaceRevision {
+            id: 3,
+            state: state(&[("B.TP2", 1)]),
+            ..Default::default()
+        };
+        let restored = restored_state(&current, &revision);
+        assert_eq!(restored.order_bg2ee, vec![comp("B.TP2", 1)]);
+        assert_eq!(restored.last_share_code.as_deref(), Some("BIO-NOW"));
+    }
+
+    #[test]
+    fn store_round_trips_and_missing_file_is_empty() {
+        let path = temp_dir("history").join("history.json");
+        let store = WorkspaceHistoryStore::new_with_path(&path);
+        assert!(store.load().expect("missing ok").revisions.is_empty());
+        assert!(store.record_auto(&state(&[("A.TP2", 0)])).expect("record"));
+        assert!(!store.record_auto(&state(&[("A.TP2", 0)])).expect("dedup"));
+        assert_eq!(store.load().expect("load").revisions.len(), 1);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -0,0 +1,369 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use serde_json::{Map, Value};
+
+use crate::config::options::CoreOptions;
+use crate::settings::model::Step1Settings;
+use crate::settings::profiles::{SettingsOverrides, SettingsProfiles};
+
+pub const ENV_PREFIX: &str = "BIO_SETTING_";
+const CORE_OPTION_KEYS: &[(&str, &str)] = &[
+    ("weidu_binary", "weidu_binary"),
+    ("mod_directories", "mods_folder"),
+    ("language", "language"),
+    ("depth", "depth"),
+    ("skip_installed", "skip_installed"),
+    ("timeout", "timeout"),
+    ("weidu_log_mode", "weidu_log_mode"),
+    ("strict_matching", "strict_matching"),
+    ("overwrite", "overwrite"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsSource {
+    Default,
+    Saved,
+    Profile,
+    Modlist,
+    Env,
+    Cli,
+}
+
+impl SettingsSource {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Saved => "saved",
+            Self::Profile => "profile",
+            Self::Modlist => "modlist",
+            Self::Env => "environment",
+            Self::Cli => "command line",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LaunchOverrides {
+    pub profile: Option<String>,
+    pub settings: SettingsOverrides,
+    pub explicit_args: Vec<String>,
+}
+
+static LAUNCH_OVERRIDES: OnceLock<LaunchOverrides> = OnceLock::new();
+
+pub fn set_launch_overrides(overrides: LaunchOverrides) {
+    let _ = LAUNCH_OVERRIDES.set(overrides);
+}
+
+#[must_use]
+pub fn launch_overrides() -> LaunchOverrides {
+    LAUNCH_OVERRIDES.get().cloned().unwrap_or_default()
+}
+
+#[must_use]
+pub fn env_overrides() -> SettingsOverrides {
+    std::env::vars()
+        .filter_map(|(key, value)| {
+            key.strip_prefix(ENV_PREFIX)
+                .map(|name| (name.to_ascii_lowercase(), value))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LayerInputs<'a> {
+    pub profile_name: Option<&'a str>,
+    pub modlist: Option<&'a SettingsOverrides>,
+    pub env: Option<&'a SettingsOverrides>,
+    pub cli: Option<&'a SettingsOverrides>,
+    pub explicit: &'a [String],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectiveValue {
+    pub key: String,
+    pub value: String,
+    pub source: SettingsSource,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResolvedSettings {
+    pub settings: Step1Settings,
+    pub profile: Option<String>,
+    pub values: Vec<EffectiveValue>,
+    pub applied: SettingsOverrides,
+    pub errors: Vec<String>,
+}
+
+#[must_use]
+pub fn resolve(
+    saved: &Step1Settings,
+    profiles: &SettingsProfiles,
+    inputs: LayerInputs<'_>,
+) -> ResolvedSettings {
+    let defaults = to_map(&Step1Settings::default());
+    let mut map = to_map(saved);
+    let mut values: Vec<EffectiveValue> = map
+        .iter()
+        .map(|(key, value)| EffectiveValue {
+            key: key.clone(),
+            value: value_text(value),
+            source: if inputs.explicit.contains(key) {
+                SettingsSource::Cli
+            } else if defaults.get(key) == Some(value) {
+                SettingsSource::Default
+            } else {
+                SettingsSource::Saved
+            },
+        })
+        .collect();
+    let mut applied = SettingsOverrides::new();
+    let mut errors = Vec::new();
+
+    let mut profile = None;
+    if let Some(name) = inputs.profile_name.map(str::trim).filter(|n| !n.is_empty()) {
+        match profiles.find(name) {
+            Some(found) => profile = Some(found),
+            None => errors.push(format!("profile '{name}' not found")),
+        }
+    }
+    let layers = [
+        (SettingsSource::Profile, profile.map(|p| &p.overrides)),
+        (SettingsSource::Modlist, inputs.modlist),
+        (SettingsSource::Env, inputs.env),
+        (SettingsSource::Cli, inputs.cli),
+    ];
+    for (source, overrides) in layers {
+        for (key, text) in overrides.into_iter().flatten() {
+            if source != SettingsSource::Cli && inputs.explicit.contains(key) {
+                continue;
+            }
+            match map.get(key).map(|current| convert(current, text)) {
+                Some(Ok(value)) => {
+                    if let Some(entry) = values.iter_mut().find(|v| &v.key == key) {
+                        entry.value = value_text(&value);
+                        entry.source = source;
+                        applied.insert(key.clone(), entry.value.clone());
+                    }
+                    map.insert(key.clone(), value);
+                }
+                Some(Err(err)) => errors.push(format!("{} {key}: {err}", source.label())),
+                None => errors.push(format!("{} {key}: unknown setting", source.label())),
+            }
+        }
+    }
+
+    ResolvedSettings {
+        settings: from_map(map).unwrap_or_else(|| saved.clone()),
+        profile: profile.map(|p| p.name.clone()),
+        values,
+        applied,
+        errors,
+    }
+}
+
+#[must_use]
+pub fn validate(overrides: &SettingsOverrides) -> Vec<String> {
+    let defaults = to_map(&Step1Settings::default());
+    overrides
+        .iter()
+        .filter_map(|(key, text)| match defaults.get(key) {
+            Some(current) => convert(current, text)
+                .err()
+                .map(|err| format!("{key}: {err}")),
+            None => Some(format!("{key}: unknown setting")),
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn layer_core_options(
+    options: &CoreOptions,
+    explicit_args: &[String],
+    profiles: &SettingsProfiles,
+    inputs: LayerInputs<'_>,
+) -> (CoreOptions, Vec<String>) {
+    let explicit: Vec<String> = CORE_OPTION_KEYS
+        .iter()
+        .filter(|(arg, _)| explicit_args.iter().any(|id| id == arg))
+        .map(|(_, key)| (*key).to_string())
+        .collect();
+    let base = Step1Settings {
+        weidu_binary: options.weidu_binary.to_string_lossy().into_owned(),
+        mods_folder: options.mod_directories.to_string_lossy().into_owned(),
+        language: options.language.clone(),
+        depth: options.depth,
+        skip_installed: options.skip_installed,
+        timeout: options.timeout,
+        weidu_log_mode: options.weidu_log_mode.clone(),
+        strict_matching: options.strict_matching,
+        overwrite: options.overwrite,
+        ..Step1Settings::default()
+    };
+    let resolved = resolve(
+        &base,
+        profiles,
+        LayerInputs {
+            explicit: &explicit,
+            ..inputs
+        },
+    );
+    let settings = resolved.settings;
+    let layered = CoreOptions {
+        weidu_binary: PathBuf::from(settings.weidu_binary),
+        mod_directories: PathBuf::from(settings.mods_folder),
+        language: settings.language,
+        depth: settings.depth,
+        skip_installed: settings.skip_installed,
+        timeout: settings.timeout,
+        weidu_log_mode: settings.weidu_log_mode,
+        strict_matching: settings.strict_matching,
+        overwrite: settings.overwrite,
+    };
+    (layered, resolved.errors)
+}
+
+#[must_use]
+pub fn field_texts(settings: &Step1Settings) -> SettingsOverrides {
+    to_map(settings)
+        .iter()
+        .map(|(key, value)| (key.clone(), value_text(value)))
+        .collect()
+}
+
+fn to_map(settings: &Step1Settings) -> Map<String, Value> {
+    match serde_json::to_value(settings) {
+        Ok(Value::Object(map)) => map,
+        _ => Map::new(),
+    }
+}
+
+fn from_map(map: Map<String, Value>) -> Option<Step1Settings> {
+    serde_json::from_value(Value::Object(map)).ok()
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn convert(current: &Value, text: &str) -> Result<Value, String> {
+    let trimmed = text.trim();
+    match current {
+        Value::Bool(_) => match trimmed.to_ascii_lowercase().as_str() {
+            "true" | "yes" | "on" | "1" => Ok(Value::Bool(true)),
+            "false" | "no" | "off" | "0" => Ok(Value::Bool(false)),
+            _ => Err(format!("expected true/false, got '{trimmed}'")),
+        },
+        Value::Number(_) => trimmed
+            .parse::<u64>()
+            .map(Value::from)
+            .map_err(|_| format!("expected a whole number, got '{trimmed}'")),
+        Value::String(_) => Ok(Value::String(text.to_string())),
+        _ => Err("not overridable".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::profiles::SettingsProfile;
+
+    #[test]
+    fn layers_apply_in_order() {
+        let saved = Step1Settings {
+            timeout: 1200,
+            ..Step1Settings::default()
+        };
+        let mut profiles = SettingsProfiles::default();
+        profiles.add(SettingsProfile {
+            name: "CI".to_string(),
+            overrides: [("timeout", "600"), ("casefold", "yes"), ("depth", "7")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        });
+        let modlist: SettingsOverrides = [("depth".to_string(), "9".to_string())].into();
+        let cli: SettingsOverrides = [
+            ("timeout".to_string(), "900".to_string()),
+            ("lookback".to_string(), "many".to_string()),
+        ]
+        .into();
+
+        let resolved = resolve(
+            &saved,
+            &profiles,
+            LayerInputs {
+                profile_name: Some("ci"),
+                modlist: Some(&modlist),
+                cli: Some(&cli),
+                ..LayerInputs::default()
+            },
+        );
+        assert_eq!(resolved.profile.as_deref(), Some("CI"));
+        assert_eq!(resolved.settings.timeout, 900);
+        assert_eq!(resolved.settings.depth, 9);
+        assert!(resolved.settings.casefold);
+        assert_eq!(resolved.errors.len(), 1);
+        let source = |key: &str| {
+            resolved
+                .values
+                .iter()
+                .find(|v| v.key == key)
+                .map(|v| v.source)
+        };
+        assert_eq!(source("timeout"), Some(SettingsSource::Cli));
+        assert_eq!(source("depth"), Some(SettingsSource::Modlist));
+        assert_eq!(source("casefold"), Some(SettingsSource::Profile));
+        assert_eq!(source("tick"), Some(SettingsSource::Default));
+    }
+
+    #[test]
+    fn headless_options_keep_explicit_flags_over_profile_and_env() {
+        let options = CoreOptions {
+            weidu_binary: PathBuf::from("/bin/weidu"),
+            mod_directories: PathBuf::from("/mods"),
+            language: "en_US".to_string(),
+            depth: 5,
+            skip_installed: true,
+            timeout: 3600,
+            weidu_log_mode: "autolog".to_string(),
+            strict_matching: false,
+            overwrite: false,
+        };
+        let mut profiles = SettingsProfiles::default();
+        profiles.add(SettingsProfile {
+            name: "CI".to_string(),
+            overrides: [("timeout".to_string(), "600".to_string())].into(),
+        });
+        let env: SettingsOverrides = [
+            ("strict_matching".to_string(), "on".to_string()),
+            ("language".to_string(), "fr_FR".to_string()),
+        ]
+        .into();
+        let cli: SettingsOverrides = [("depth".to_string(), "2".to_string())].into();
+        let (layered, errors) = layer_core_options(
+            &options,
+            &["timeout".to_string(), "language".to_string()],
+            &profiles,
+            LayerInputs {
+                profile_name: Some("CI"),
+                env: Some(&env),
+                cli: Some(&cli),
+                ..LayerInputs::default()
+            },
+        );
+        assert!(errors.is_empty());
+        assert_eq!((layered.timeout, layered.depth), (3600, 2));
+        assert_eq!(layered.language, "en_US");
+        assert!(layered.strict_matching && layered.skip_installed);
+        assert_eq!(layered.mod_directories, PathBuf::from("/mods"));
+    }
+}
@@ -1,7 +1,9 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Copyright (c) 2026 Born2BSalty
 
+pub mod layers;
 pub mod model;
+pub mod profiles;
 pub mod store;
 
 pub mod redesign_fields;
@@ -71,6 +71,7 @@ pub struct Step1Settings<Flag = bool> {
     pub lookback: usize,
     pub casefold: Flag,
     pub backup_targets_before_eet_copy: Flag,
+    pub clean_game_policy: String,
 }
 
 impl Default for Step1Settings {
@@ -139,6 +140,7 @@ impl Default for Step1Settings {
             lookback: 10,
             casefold: false,
             backup_targets_before_eet_copy: false,
+            clean_game_policy: "warn".to_string(),
         }
     }
 }
@@ -0,0 +1,267 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::app::step5::diagnostics_bundle::Redactor;
+use crate::platform_defaults::app_config_file;
+use crate::settings::layers;
+use crate::settings::redesign_fields::DiagnosticsRedaction;
+
+const PROFILES_FILE_NAME: &str = "bio_settings_profiles.json";
+const EXPORT_FORMAT: &str = "bio-settings-profile/1";
+const SECRET_KEY_MARKERS: [&str; 6] = [
+    "token",
+    "secret",
+    "password",
+    "passwd",
+    "api_key",
+    "credential",
+];
+
+pub type SettingsOverrides = BTreeMap<String, String>;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SettingsProfile {
+    pub name: String,
+
+    pub overrides: SettingsOverrides,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SettingsProfiles {
+    pub active: Option<String>,
+
+    pub profiles: Vec<SettingsProfile>,
+}
+
+impl SettingsProfiles {
+    #[must_use]
+    pub fn find(&self, name: &str) -> Option<&SettingsProfile> {
+        let name = name.trim();
+        self.profiles
+            .iter()
+            .find(|profile| profile.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn find_mut(&mut self, name: &str) -> Option<&mut SettingsProfile> {
+        let name = name.trim();
+        self.profiles
+            .iter_mut()
+            .find(|profile| profile.name.eq_ignore_ascii_case(name))
+    }
+
+    #[must_use]
+    pub fn unique_name(&self, base: &str) -> String {
+        let base = match base.trim() {
+            "" => "profile",
+            trimmed => trimmed,
+        };
+        if self.find(base).is_none() {
+            return base.to_string();
+        }
+        (2..)
+            .map(|n| format!("{base} ({n})"))
+            .find(|candidate| self.find(candidate).is_none())
+            .unwrap_or_else(|| base.to_string())
+    }
+
+    pub fn add(&mut self, mut profile: SettingsProfile) -> String {
+        profile.name = self.unique_name(&profile.name);
+        let name = profile.name.clone();
+        self.profiles.push(profile);
+        name
+    }
+
+    pub fn remove(&mut self, name: &str) -> bool {
+        let before = self.profiles.len();
+        self.profiles
+            .retain(|profile| !profile.name.eq_ignore_ascii_case(name.trim()));
+        if self
+            .active
+            .as_deref()
+            .is_some_and(|active| active.eq_ignore_ascii_case(name.trim()))
+        {
+            self.active = None;
+        }
+        self.profiles.len() != before
+    }
+}
+
+#[must_use]
+pub fn is_secret_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    SECRET_KEY_MARKERS.iter().any(|marker| key.contains(marker))
+}
+
+pub fn parse_override_lines(text: &str) -> Result<SettingsOverrides, String> {
+    let mut overrides = SettingsOverrides::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(format!("line {}: expected key = value", index + 1));
+        };
+        let key = key.trim().to_ascii_lowercase();
+        if key.is_empty() {
+            return Err(format!("line {}: missing key", index + 1));
+        }
+        overrides.insert(key, value.trim().to_string());
+    }
+    Ok(overrides)
+}
+
+#[must_use]
+pub fn override_lines(overrides: &SettingsOverrides) -> String {
+    overrides
+        .iter()
+        .map(|(key, value)| format!("{key} = {value}\n"))
+        .collect()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ProfileExport {
+    format: String,
+    name: String,
+    overrides: SettingsOverrides,
+}
+
+#[must_use]
+pub fn export_profile(profile: &SettingsProfile) -> (String, u32) {
+    let redactor = Redactor::with_identity(
+        DiagnosticsRedaction {
+            user_paths: false,
+            usernames: false,
+            tokens: true,
+            max_file_kib: 0,
+        },
+        None,
+        None,
+    );
+    let mut stripped = 0;
+    let mut overrides = SettingsOverrides::new();
+    for (key, value) in &profile.overrides {
+        if is_secret_key(key) {
+            stripped += 1;
+            continue;
+        }
+        let (value, redacted) = redactor.redact(value);
+        stripped += redacted;
+        overrides.insert(key.clone(), value);
+    }
+    let export = ProfileExport {
+        format: EXPORT_FORMAT.to_string(),
+        name: profile.name.clone(),
+        overrides,
+    };
+    (
+        serde_json::to_string_pretty(&export).unwrap_or_default(),
+        stripped,
+    )
+}
+
+pub fn import_profile(text: &str) -> Result<SettingsProfile> {
+    let parsed = serde_json::from_str::<ProfileExport>(text.trim())
+        .context("not a settings profile export")?;
+    if parsed.format != EXPORT_FORMAT {
+        bail!("unsupported profile format '{}'", parsed.format);
+    }
+    let overrides: SettingsOverrides = parsed
+        .overrides
+        .into_iter()
+        .filter(|(key, _)| !is_secret_key(key))
+        .map(|(key, value)| (key.trim().to_ascii_lowercase(), value))
+        .collect();
+    let errors = layers::validate(&overrides);
+    if !errors.is_empty() {
+        bail!("{}", errors.join("; "));
+    }
+    Ok(SettingsProfile {
+        name: parsed.name.trim().to_string(),
+        overrides,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct ProfilesStore {
+    path: PathBuf,
+}
+
+impl ProfilesStore {
+    #[must_use]
+    pub fn new_default() -> Self {
+        let path = app_config_file(PROFILES_FILE_NAME, ".");
+        Self { path }
+    }
+
+    pub fn new_with_path(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn load(&self) -> Result<SettingsProfiles> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(raw) => serde_json::from_str::<SettingsProfiles>(&raw).with_context(|| {
+                format!("failed parsing settings profiles {}", self.path.display())
+            }),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                Ok(SettingsProfiles::default())
+            }
+            Err(err) => Err(err).with_context(|| {
+                format!("failed reading settings profiles {}", self.path.display())
+            }),
+        }
+    }
+
+    pub fn save(&self, profiles: &SettingsProfiles) -> Result<()> {
+        let raw = serde_json::to_string_pretty(profiles)
+            .context("failed serializing settings profiles json")?;
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!("failed creating settings directory {}", parent.display())
+            })?;
+        }
+        std::fs::write(&self.path, raw)
+            .with_context(|| format!("failed writing settings profiles {}", self.path.display()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_drops_secrets_and_import_round_trips() {
+        let mut profiles = SettingsProfiles::default();
+        let name = profiles.add(SettingsProfile {
+            name: "Steam Deck".to_string(),
+            overrides: parse_override_lines(
+                "# handheld\ntimeout = 600\ncasefold = true\ngithub_token = ghp_abc\n",
+            )
+            .expect("valid lines"),
+        });
+        assert_eq!(profiles.unique_name("steam deck"), "steam deck (2)");
+
+        let profile = profiles.find(&name).expect("added profile");
+        let (json, stripped) = export_profile(profile);
+        assert_eq!(stripped, 1);
+        assert!(!json.contains("ghp_abc"));
+
+        let imported = import_profile(&json).expect("import");
+        assert_eq!(imported.name, "Steam Deck");
+        assert_eq!(
+            override_lines(&imported.overrides),
+            "casefold = true\ntimeout = 600\n"
+        );
+        assert!(import_profile(&json.replace("casefold", "no_such_setting")).is_err());
+        assert!(parse_override_lines("timeout 600").is_err());
+    }
+}
@@ -62,6 +62,46 @@ impl UiLanguage {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DiagnosticsRedaction {
+    pub user_paths: bool,
+
+    pub usernames: bool,
+
+    pub tokens: bool,
+
+    pub max_file_kib: u32,
+}
+
+impl Default for DiagnosticsRedaction {
+    fn default() -> Self {
+        Self {
+            user_paths: true,
+            usernames: true,
+            tokens: true,
+            max_file_kib: 2048,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackgroundUpdateChecks {
+    pub enabled: bool,
+
+    pub interval_hours: u32,
+}
+
+impl Default for BackgroundUpdateChecks {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_hours: 24,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct RedesignSettings {
@@ -75,6 +115,10 @@ pub struct RedesignSettings {
 
     #[serde(default = "default_true")]
     pub validate_paths_on_startup: bool,
+
+    pub diagnostics_redaction: DiagnosticsRedaction,
+
+    pub background_update_checks: BackgroundUpdateChecks,
 }
 
 const fn default_true() -> bool {
@@ -89,6 +133,8 @@ impl Default for RedesignSettings {
             language: UiLanguage::default(),
             diagnostic_mode: false,
             validate_paths_on_startup: true,
+            diagnostics_redaction: DiagnosticsRedaction::default(),
+            background_update_checks: BackgroundUpdateChecks::default(),
         }
     }
 }
@@ -114,6 +160,8 @@ mod tests {
         assert_eq!(s.language, UiLanguage::English);
         assert!(!s.diagnostic_mode);
         assert!(s.validate_paths_on_startup);
+        assert_eq!(s.diagnostics_redaction, DiagnosticsRedaction::default());
+        assert!(!s.background_update_checks.enabled);
     }
 
     #[test]
@@ -124,6 +172,15 @@ mod tests {
             language: UiLanguage::French,
             diagnostic_mode: true,
             validate_paths_on_startup: false,
+            diagnostics_redaction: DiagnosticsRedaction {
+                usernames: false,
+                max_file_kib: 0,
+                ..DiagnosticsRedaction::default()
+            },
+            background_update_checks: BackgroundUpdateChecks {
+                enabled: true,
+                interval_hours: 6,
+            },
         };
         let raw = serde_json::to_string_pretty(&s).expect("serialize");
         let s2: RedesignSettings = serde_json::from_str(&raw).expect("deserialize");
@@ -63,6 +63,8 @@ mod dispatch {
                         dev_mode: app.dev_mode,
                         exe_fingerprint: app.exe_fingerprint.as_str(),
                         palette: crate::ui::shared::redesign_tokens::ThemePalette::Dark,
+                        redaction: crate::settings::redesign_fields::DiagnosticsRedaction::default(
+                        ),
                     },
                 ) {
                     match action {
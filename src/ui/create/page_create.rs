@@ -9,7 +9,10 @@ use eframe::egui;
 use tracing::warn;
 
 use crate::app::modlist_share::preview_modlist_share_code;
-use crate::install_runtime::{destination_prep, fork_pipeline_arm, per_install_dirs};
+use crate::install_runtime::modlist_lock::lock_path_for_modlist;
+use crate::install_runtime::{
+    destination_prep, fork_pipeline_arm, modlist_layers_driver, offline_bundle, per_install_dirs,
+};
 use crate::registry::model::Game;
 use crate::registry::operations;
 use crate::registry::operations_create::create_modlist_with_author;
@@ -39,6 +42,7 @@ enum CreateRequest {
     GoForkPaste,
     ForkPasteBack,
     ForkPastePreview,
+    ForkOpenBundle,
     ForkPreviewBack,
     ForkBeginImport,
     ForkDownloadCancel,
@@ -54,6 +58,7 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, ctx: &egui:
     let palette = orchestrator.theme_palette;
 
     poll_create_destination_prep(orchestrator);
+    poll_offline_bundle_unpack(orchestrator);
 
     if let Some(deadline) = orchestrator.create_screen_state.load_draft_copied_until
         && Instant::now() >= deadline
@@ -83,10 +88,14 @@ fn collect_stage_request(
 ) -> Option<CreateRequest> {
     match orchestrator.create_screen_state.stage {
         CreateStage::Choose => {
+            let layers = orchestrator
+                .layer_library
+                .for_game(orchestrator.create_screen_state.game);
             match stage_choose::render(
                 ui,
                 palette,
                 &mut orchestrator.create_screen_state,
+                &layers,
                 orchestrator.create_destination_prep_rx.is_some(),
             ) {
                 ChooseOutcome::StartScratch => Some(CreateRequest::StartScratch),
@@ -99,6 +108,7 @@ fn collect_stage_request(
             match stage_fork_paste::render(ui, palette, &mut orchestrator.create_screen_state) {
                 ForkPasteOutcome::Back => Some(CreateRequest::ForkPasteBack),
                 ForkPasteOutcome::Preview => Some(CreateRequest::ForkPastePreview),
+                ForkPasteOutcome::OpenBundle => Some(CreateRequest::ForkOpenBundle),
                 ForkPasteOutcome::Stay => None,
             }
         }
@@ -150,6 +160,7 @@ fn handle_create_request(
         CreateRequest::StartScratch => start_scratch(orchestrator),
         CreateRequest::GoForkPaste => {
             orchestrator.create_screen_state.fork_code.clear();
+            orchestrator.create_screen_state.offline_bundle = None;
             orchestrator.create_screen_state.clear_fork_preview();
             orchestrator.create_screen_state.stage = CreateStage::ForkPaste;
         }
@@ -158,24 +169,22 @@ fn handle_create_request(
             orchestrator.create_screen_state.stage = CreateStage::Choose;
         }
         CreateRequest::ForkPastePreview => {
+            orchestrator.create_screen_state.offline_bundle = None;
             run_fork_preview_parse(&mut orchestrator.create_screen_state);
             orchestrator.create_screen_state.stage = CreateStage::ForkPreview;
         }
+        CreateRequest::ForkOpenBundle => open_offline_bundle(orchestrator),
         CreateRequest::ForkPreviewBack => {
             orchestrator.create_screen_state.clear_fork_preview();
             orchestrator.create_screen_state.stage = CreateStage::ForkPaste;
         }
-        CreateRequest::ForkBeginImport => match fork_pipeline_arm::mint_and_arm(orchestrator) {
-            Ok(_) => {
-                orchestrator.create_screen_state.stage = CreateStage::ForkDownload;
-            }
-            Err(err) => {
-                warn!(
-                    target = "orchestrator",
-                    "Create fork: mint_and_arm failed: {err}"
-                );
+        CreateRequest::ForkBeginImport => {
+            if orchestrator.create_screen_state.offline_bundle.is_some() {
+                start_offline_bundle_unpack(orchestrator);
+            } else {
+                begin_fork_import(orchestrator);
             }
-        },
+        }
         CreateRequest::ForkDownloadCancel => fork_download_cancel(orchestrator),
         CreateRequest::ForkExtractCompleteRouteToWorkspace(id) => {
             fork_extract_complete_route_to_workspace(orchestrator, id);
@@ -453,12 +462,30 @@ fn finish_start_scratch(orchestrator: &mut OrchestratorApp, name: &str, game: Ga
         .is_some_and(|s| !s.step1.effective_global_mods_folder().trim().is_empty());
     let source = default_scratch_mods_source(global_non_empty);
 
+    let layer_ids: Vec<String> = orchestrator
+        .layer_library
+        .for_game(game)
+        .iter()
+        .filter(|layer| {
+            orchestrator
+                .create_screen_state
+                .selected_layers
+                .contains(&layer.id)
+        })
+        .map(|layer| layer.id.clone())
+        .collect();
+    let composed = modlist_layers_driver::compose_new(orchestrator, &entry.name, &layer_ids);
+
     let canonical_store = WorkspaceStore::new_for_id(&entry.id);
+    let base = composed
+        .as_ref()
+        .map(|composed| composed.workspace.clone())
+        .unwrap_or_default();
     let workspace_state = ModlistWorkspaceState {
         scratch_mods_folder: Some(scratch_mods_folder),
         mods_source: source,
         last_rescanned_mods_source: source,
-        ..Default::default()
+        ..base
     };
     if let Err(err) = canonical_store.save(&workspace_state) {
         warn!(
@@ -474,6 +501,12 @@ fn finish_start_scratch(orchestrator: &mut OrchestratorApp, name: &str, game: Ga
     orchestrator
         .workspace_stores
         .insert(entry.id.clone(), canonical_store);
+    if let Some(composed) = &composed {
+        if let Some(target) = orchestrator.registry.find_mut(&entry.id) {
+            target.layers = composed.provenance.clone();
+        }
+        modlist_layers_driver::notify_composed(orchestrator, &entry.name, composed);
+    }
 
     if let Err(err) = orchestrator.registry_store.save(&orchestrator.registry) {
         warn!(
@@ -490,6 +523,7 @@ fn finish_start_scratch(orchestrator: &mut OrchestratorApp, name: &str, game: Ga
     orchestrator.create_screen_state.modlist_name.clear();
     orchestrator.create_screen_state.destination.clear();
     orchestrator.create_screen_state.destination_choice = None;
+    orchestrator.create_screen_state.selected_layers.clear();
     orchestrator.create_screen_state.resumed_build_id = Some(new_id.clone());
     orchestrator.nav = NavDestination::Workspace {
         modlist_id: Some(new_id),
@@ -547,6 +581,128 @@ fn run_fork_preview_parse(state: &mut crate::ui::create::state_create::CreateScr
     }
 }
 
+fn begin_fork_import(orchestrator: &mut OrchestratorApp) -> bool {
+    match fork_pipeline_arm::mint_and_arm(orchestrator) {
+        Ok(_) => {
+            orchestrator.create_screen_state.stage = CreateStage::ForkDownload;
+            true
+        }
+        Err(err) => {
+            warn!(
+                target = "orchestrator",
+                "Create fork: mint_and_arm failed: {err}"
+            );
+            false
+        }
+    }
+}
+
+fn open_offline_bundle(orchestrator: &mut OrchestratorApp) {
+    let Some(path) = rfd::FileDialog::new()
+        .add_filter("BIO offline bundle", &["zip"])
+        .pick_file()
+    else {
+        return;
+    };
+    match offline_bundle::read_manifest(&path) {
+        Ok(manifest) => {
+            let state = &mut orchestrator.create_screen_state;
+            state.fork_code = manifest.share_code;
+            state.offline_bundle = Some(path);
+            run_fork_preview_parse(state);
+            state.stage = CreateStage::ForkPreview;
+        }
+        Err(err) => {
+            orchestrator
+                .notification_manager
+                .error(format!("Couldn't open bundle: {err}"));
+        }
+    }
+}
+
+fn start_offline_bundle_unpack(orchestrator: &mut OrchestratorApp) {
+    if orchestrator.offline_bundle_unpack_rx.is_some() {
+        return;
+    }
+    let Some(bundle) = orchestrator.create_screen_state.offline_bundle.clone() else {
+        return;
+    };
+    let archive_dir = orchestrator
+        .settings_store
+        .load()
+        .map(|settings| {
+            let from: crate::app::state::Step1State = settings.step1.into();
+            PathBuf::from(from.mods_archive_folder.trim())
+        })
+        .unwrap_or_default();
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let _ = tx.send(offline_bundle::unpack_bundle(&bundle, &archive_dir));
+    });
+    orchestrator.offline_bundle_unpack_rx = Some(rx);
+    orchestrator
+        .notification_manager
+        .info("Unpacking offline bundle\u{2026}".to_string());
+}
+
+fn poll_offline_bundle_unpack(orchestrator: &mut OrchestratorApp) {
+    let Some(rx) = orchestrator.offline_bundle_unpack_rx.as_ref() else {
+        return;
+    };
+    let result = match rx.try_recv() {
+        Ok(result) => result,
+        Err(TryRecvError::Empty) => return,
+        Err(TryRecvError::Disconnected) => Err("unpack worker disconnected".to_string()),
+    };
+    orchestrator.offline_bundle_unpack_rx = None;
+    let unpacked = match result {
+        Ok(unpacked) => unpacked,
+        Err(err) => {
+            orchestrator
+                .notification_manager
+                .error(format!("Couldn't import bundle: {err}"));
+            return;
+        }
+    };
+    if orchestrator.create_screen_state.stage != CreateStage::ForkPreview
+        || !begin_fork_import(orchestrator)
+    {
+        return;
+    }
+    if let Some(id) = orchestrator.active_install_modlist_id.clone() {
+        if let Some(lock) = unpacked.lock.as_ref()
+            && let Err(err) = lock.save(&lock_path_for_modlist(&id))
+        {
+            warn!(
+                target = "orchestrator",
+                "offline bundle: writing lock for {id} failed: {err}"
+            );
+        }
+        orchestrator.install_screen_state.install_from_lock = Some(unpacked.lock.is_some());
+    }
+    if let Some(answers) = unpacked.prompt_answers.as_deref() {
+        match crate::app::step5::prompt_memory::merge_json_str(answers) {
+            Ok(merged) if !merged.conflicts.is_empty() => {
+                orchestrator.notification_manager.warn(format!(
+                    "{} prompt answer(s) in the bundle differ from yours and were not imported: {}",
+                    merged.conflicts.len(),
+                    merged.conflicts.join(", ")
+                ));
+            }
+            Ok(_) => {}
+            Err(err) => warn!(
+                target = "orchestrator",
+                "offline bundle: merging prompt answers failed: {err}"
+            ),
+        }
+    }
+    orchestrator.install_screen_state.offline_bundle = true;
+    orchestrator.notification_manager.success(format!(
+        "Bundle verified: {} archive(s) unpacked, {} already present",
+        unpacked.archives_written, unpacked.archives_present
+    ));
+}
+
 fn fork_download_cancel(orchestrator: &mut OrchestratorApp) {
     orchestrator.reset_install_screen_to_paste();
     orchestrator.create_screen_state.fork_download_progress =
@@ -565,6 +721,7 @@ fn fork_extract_complete_route_to_workspace(orchestrator: &mut OrchestratorApp,
 
     orchestrator.reset_install_screen_to_paste();
     orchestrator.create_screen_state.fork_code.clear();
+    orchestrator.create_screen_state.offline_bundle = None;
     orchestrator.create_screen_state.clear_fork_preview();
     orchestrator.create_screen_state.fork_download_progress =
         crate::ui::install::stage_downloading::DownloadProgress::default();
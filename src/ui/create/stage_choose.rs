@@ -4,6 +4,7 @@
 use eframe::egui;
 
 use crate::registry::model::Game;
+use crate::registry::modlist_layers::ModlistLayer;
 use crate::ui::create::state_create::{CreateScreenState, StartingPoint};
 use crate::ui::install::destination_not_empty;
 use crate::ui::install::sub_flow_footer::{self, PrimaryBtn};
@@ -48,11 +49,15 @@ pub fn render(
     ui: &mut egui::Ui,
     palette: ThemePalette,
     state: &mut CreateScreenState,
+    layers: &[&ModlistLayer],
     destination_prep_running: bool,
 ) -> ChooseOutcome {
     let mut outcome = ChooseOutcome::Stay;
 
     render_body(ui, palette, state, &mut outcome);
+    if state.starting_point == StartingPoint::Scratch && !layers.is_empty() {
+        render_layers_box(ui, palette, state, layers);
+    }
 
     let spacer = (ui.available_height() - sub_flow_footer::FOOTER_HEIGHT_PX).max(0.0);
     if spacer > 0.0 {
@@ -302,6 +307,50 @@ fn render_starting_point_boxes(
     }
 }
 
+fn render_layers_box(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    state: &mut CreateScreenState,
+    layers: &[&ModlistLayer],
+) {
+    ui.add_space(14.0);
+    redesign_box(ui, palette, Some("start from layers"), |ui| {
+        ui.label(
+            egui::RichText::new(
+                "Ticked layers are merged into the new modlist in this order; compat order rules win over layer order.",
+            )
+            .size(13.0)
+            .color(redesign_text_muted(palette)),
+        );
+        ui.add_space(6.0);
+        for layer in layers {
+            let mut checked = state.selected_layers.contains(&layer.id);
+            let label = format!(
+                "{} \u{00B7} {} components \u{00B7} rev {}",
+                layer.name,
+                layer.component_count(),
+                layer.revision
+            );
+            if ui
+                .checkbox(
+                    &mut checked,
+                    egui::RichText::new(label)
+                        .size(13.0)
+                        .color(redesign_text_primary(palette)),
+                )
+                .on_hover_text(layer.mod_names().join("\n"))
+                .changed()
+            {
+                if checked {
+                    state.selected_layers.push(layer.id.clone());
+                } else {
+                    state.selected_layers.retain(|id| id != &layer.id);
+                }
+            }
+        }
+    });
+}
+
 fn field_label(ui: &mut egui::Ui, palette: ThemePalette, text: &str) {
     ui.label(
         egui::RichText::new(text)
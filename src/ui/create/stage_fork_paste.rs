@@ -4,7 +4,7 @@
 use eframe::egui;
 
 use crate::ui::create::state_create::CreateScreenState;
-use crate::ui::install::sub_flow_footer::{self, BackBtn, PrimaryBtn};
+use crate::ui::install::sub_flow_footer::{self, BackBtn, PrimaryBtn, SecondaryBtn};
 use crate::ui::orchestrator::widgets::{redesign_box, render_screen_title};
 use crate::ui::shared::redesign_tokens::{
     REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_border_strong,
@@ -20,6 +20,7 @@ pub enum ForkPasteOutcome {
     Stay,
     Back,
     Preview,
+    OpenBundle,
 }
 
 pub fn render(
@@ -47,7 +48,9 @@ pub fn render(
         ui,
         palette,
         Some(BackBtn { label: "Back" }),
-        None::<sub_flow_footer::SecondaryBtn<'_>>,
+        Some(SecondaryBtn {
+            label: "Open offline bundle",
+        }),
         Some(if code_empty {
             "paste a BIO share code to preview"
         } else {
@@ -61,6 +64,8 @@ pub fn render(
 
     if footer.back_clicked {
         ForkPasteOutcome::Back
+    } else if footer.secondary_clicked {
+        ForkPasteOutcome::OpenBundle
     } else if footer.primary_clicked {
         ForkPasteOutcome::Preview
     } else {
@@ -30,6 +30,7 @@ pub struct CreateScreenState {
     pub destination: String,
     pub destination_choice: Option<DestChoice>,
     pub starting_point: StartingPoint,
+    pub selected_layers: Vec<String>,
     pub load_draft_open: bool,
 
     pub fork_code: String,
@@ -38,6 +39,7 @@ pub struct CreateScreenState {
     pub fork_active_preview_tab: PreviewTab,
     pub fork_info_open: bool,
     pub fork_download_progress: DownloadProgress,
+    pub offline_bundle: Option<std::path::PathBuf>,
 
     pub resumed_build_id: Option<String>,
 
@@ -84,6 +86,7 @@ mod tests {
         assert!(s.fork_preview_parse_error.is_none());
         assert_eq!(s.fork_active_preview_tab, PreviewTab::Summary);
         assert!(!s.fork_info_open);
+        assert!(s.offline_bundle.is_none());
     }
 
     #[test]
@@ -32,6 +32,16 @@ pub fn reinstall_dialog_text(entry: &ModlistEntry) -> (String, String) {
     (title, body)
 }
 
+#[must_use]
+pub fn reapply_layers_dialog_text(entry: &ModlistEntry) -> (String, String) {
+    let title = format!("Re-apply layers to \"{}\"?", entry.name);
+    let body = "This modlist is installed. Re-applying its layers rewrites the \
+                component selection and order; the installed game keeps the old \
+                order until you apply the changes or reinstall."
+        .to_string();
+    (title, body)
+}
+
 fn destination_display(entry: &ModlistEntry) -> String {
     let d = entry.destination_folder.trim();
     if d.is_empty() {
@@ -71,6 +81,21 @@ pub const fn reinstall_confirm<'a>(
     }
 }
 
+#[must_use]
+pub const fn reapply_layers_confirm<'a>(
+    id_salt: &'a str,
+    title: &'a str,
+    body: &'a str,
+) -> ConfirmDialog<'a> {
+    ConfirmDialog {
+        id_salt,
+        title,
+        body,
+        confirm_label: "Re-apply",
+        danger: false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,4 +153,11 @@ mod tests {
         assert!(r.danger);
         assert_eq!(r.confirm_label, "Reinstall");
     }
+
+    #[test]
+    fn reapply_layers_title_and_body() {
+        let (t, b) = reapply_layers_dialog_text(&e("EET Mega", "/games/eet"));
+        assert_eq!(t, "Re-apply layers to \"EET Mega\"?");
+        assert!(b.contains("installed game keeps the old order"));
+    }
 }
@@ -3,6 +3,7 @@
 
 use eframe::egui;
 
+use crate::game_discovery::{GameKind, best_for};
 use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
 use crate::ui::settings::state_settings::PathStatus;
 use crate::ui::settings::validate_now::{
@@ -12,21 +13,32 @@ use crate::ui::shared::redesign_tokens::{
     ThemePalette, redesign_text_faint, redesign_text_primary,
 };
 
-const GAME_ROWS: [(&str, &str); 3] = [
-    ("BGEE", FIELD_BGEE_GAME_FOLDER),
-    ("BG2EE", FIELD_BG2EE_GAME_FOLDER),
-    ("IWDEE", FIELD_IWDEE_GAME_FOLDER),
+const GAME_ROWS: [(&str, &str, GameKind); 3] = [
+    ("BGEE", FIELD_BGEE_GAME_FOLDER, GameKind::Bgee),
+    ("BG2EE", FIELD_BG2EE_GAME_FOLDER, GameKind::Bg2ee),
+    ("IWDEE", FIELD_IWDEE_GAME_FOLDER, GameKind::Iwdee),
 ];
 
 pub fn render(ui: &mut egui::Ui, palette: ThemePalette, orchestrator: &OrchestratorApp) {
     let report = &orchestrator.settings_screen_state.path_validation_results;
+    let discovered = &orchestrator.settings_screen_state.discovered_installs;
 
     ui.vertical(|ui| {
         ui.spacing_mut().item_spacing.y = 4.0;
-        for (name, field) in &GAME_ROWS {
+        for (name, field, kind) in &GAME_ROWS {
             let found = matches!(report.fields.get(*field), Some(PathStatus::Ok { .. }));
             let (marker, text, color) = if found {
                 ("\u{2713}", name.to_string(), redesign_text_primary(palette))
+            } else if let Some(install) = best_for(discovered, *kind) {
+                (
+                    "+",
+                    format!(
+                        "{} \u{00B7} detected via {}, set in Settings",
+                        install.kind.label(),
+                        install.source
+                    ),
+                    redesign_text_primary(palette),
+                )
             } else {
                 (
                     "?",
@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use eframe::egui;
+
+use crate::install_runtime::install_queue_driver;
+use crate::registry::install_queue::QueueItemStatus;
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_box, redesign_btn};
+use crate::ui::shared::redesign_tokens::{
+    ThemePalette, redesign_accent, redesign_error, redesign_text_faint, redesign_text_primary,
+};
+
+const EMPTY_HINT: &str = "Add modlists from a card's menu to install them back-to-back. The queue runs while BIO stays open.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QueueAction {
+    MoveUp(usize),
+    MoveDown(usize),
+    CycleWorkflow(usize),
+    Skip(usize),
+    Retry(usize),
+    Remove(usize),
+    TogglePause,
+    CyclePromptPolicy,
+    ClearFinished,
+}
+
+pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp) {
+    let palette = orchestrator.theme_palette;
+    let mut action: Option<QueueAction> = None;
+
+    redesign_box(ui, palette, Some("install queue"), |ui| {
+        let queue = &orchestrator.install_queue;
+        if queue.items.is_empty() {
+            ui.label(
+                egui::RichText::new(EMPTY_HINT)
+                    .size(13.0)
+                    .color(redesign_text_faint(palette)),
+            );
+            return;
+        }
+        ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = 6.0;
+            for (index, item) in queue.items.iter().enumerate() {
+                let name = orchestrator
+                    .registry
+                    .find(&item.modlist_id)
+                    .map_or(item.modlist_id.as_str(), |entry| entry.name.as_str());
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 5.0;
+                    ui.label(
+                        egui::RichText::new(format!("{}. {name}", index + 1))
+                            .size(13.0)
+                            .family(egui::FontFamily::Name("poppins_medium".into()))
+                            .color(redesign_text_primary(palette)),
+                    );
+                    let pending = item.status == QueueItemStatus::Pending;
+                    let workflow = ui.add_enabled(
+                        pending,
+                        egui::Button::new(
+                            egui::RichText::new(item.workflow.label())
+                                .size(12.0)
+                                .color(redesign_accent(palette)),
+                        )
+                        .frame(false),
+                    );
+                    if workflow.on_hover_text("click to change workflow").clicked() {
+                        action = Some(QueueAction::CycleWorkflow(index));
+                    }
+                    let status_color = match item.status {
+                        QueueItemStatus::Failed => redesign_error(palette),
+                        QueueItemStatus::Running => redesign_accent(palette),
+                        _ => redesign_text_faint(palette),
+                    };
+                    let status = ui.label(
+                        egui::RichText::new(item.status.label())
+                            .size(12.0)
+                            .color(status_color),
+                    );
+                    if let Some(message) = item.message.as_deref() {
+                        status.on_hover_text(message);
+                    }
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if let Some(picked) = render_item_buttons(ui, palette, item.status, index) {
+                            action = Some(picked);
+                        }
+                    });
+                });
+            }
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                let pause_label = if queue.paused { "resume" } else { "pause" };
+                if small_btn(ui, palette, pause_label) {
+                    action = Some(QueueAction::TogglePause);
+                }
+                if small_btn(
+                    ui,
+                    palette,
+                    &format!("on prompt: {}", queue.prompt_policy.label()),
+                ) {
+                    action = Some(QueueAction::CyclePromptPolicy);
+                }
+                if queue.items.iter().any(|item| item.status.is_finished())
+                    && small_btn(ui, palette, "clear finished")
+                {
+                    action = Some(QueueAction::ClearFinished);
+                }
+            });
+        });
+    });
+
+    if let Some(action) = action {
+        apply_action(orchestrator, action);
+    }
+}
+
+fn render_item_buttons(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    status: QueueItemStatus,
+    index: usize,
+) -> Option<QueueAction> {
+    let mut picked = None;
+    match status {
+        QueueItemStatus::Pending => {
+            if small_btn(ui, palette, "\u{2715}") {
+                picked = Some(QueueAction::Remove(index));
+            }
+            if small_btn(ui, palette, "skip") {
+                picked = Some(QueueAction::Skip(index));
+            }
+            if small_btn(ui, palette, "\u{2193}") {
+                picked = Some(QueueAction::MoveDown(index));
+            }
+            if small_btn(ui, palette, "\u{2191}") {
+                picked = Some(QueueAction::MoveUp(index));
+            }
+        }
+        QueueItemStatus::Failed | QueueItemStatus::Skipped => {
+            if small_btn(ui, palette, "\u{2715}") {
+                picked = Some(QueueAction::Remove(index));
+            }
+            if small_btn(ui, palette, "retry") {
+                picked = Some(QueueAction::Retry(index));
+            }
+        }
+        QueueItemStatus::Done => {
+            if small_btn(ui, palette, "\u{2715}") {
+                picked = Some(QueueAction::Remove(index));
+            }
+        }
+        QueueItemStatus::Running => {}
+    }
+    picked
+}
+
+fn small_btn(ui: &mut egui::Ui, palette: ThemePalette, label: &str) -> bool {
+    redesign_btn(
+        ui,
+        palette,
+        label,
+        BtnOpts {
+            small: true,
+            no_shadow: true,
+            ..Default::default()
+        },
+    )
+    .clicked()
+}
+
+fn apply_action(orchestrator: &mut OrchestratorApp, action: QueueAction) {
+    let queue = &mut orchestrator.install_queue;
+    match action {
+        QueueAction::MoveUp(index) => {
+            queue.move_item(index, true);
+        }
+        QueueAction::MoveDown(index) => {
+            queue.move_item(index, false);
+        }
+        QueueAction::CycleWorkflow(index) => {
+            if let Some(item) = queue.items.get_mut(index) {
+                item.workflow = item.workflow.next();
+            }
+        }
+        QueueAction::Skip(index) => queue.skip(index),
+        QueueAction::Retry(index) => queue.retry(index),
+        QueueAction::Remove(index) => queue.remove(index),
+        QueueAction::TogglePause => queue.paused = !queue.paused,
+        QueueAction::CyclePromptPolicy => queue.prompt_policy = queue.prompt_policy.next(),
+        QueueAction::ClearFinished => queue.clear_finished(),
+    }
+    install_queue_driver::save(orchestrator);
+}
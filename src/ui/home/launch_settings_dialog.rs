@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use eframe::egui;
+
+use crate::install_runtime::game_launcher::{native_binary, steam_app_id, windows_binary};
+use crate::registry::model::LaunchKind;
+use crate::ui::home::state_home::LaunchDraft;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
+use crate::ui::shared::redesign_tokens::{
+    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_border_strong,
+    redesign_error, redesign_input_bg, redesign_shell_bg, redesign_text_muted,
+    redesign_text_primary,
+};
+
+const MAX_WIDTH_PX: f32 = 480.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LaunchSettingsOutcome {
+    #[default]
+    Pending,
+    Save,
+    Cancelled,
+}
+
+pub fn render(
+    ctx: &egui::Context,
+    palette: ThemePalette,
+    draft: &mut LaunchDraft,
+) -> LaunchSettingsOutcome {
+    let mut outcome = LaunchSettingsOutcome::Pending;
+
+    let frame = egui::Frame::default()
+        .fill(redesign_shell_bg(palette))
+        .stroke(egui::Stroke::new(
+            REDESIGN_BORDER_WIDTH_PX,
+            redesign_border_strong(palette),
+        ))
+        .corner_radius(egui::CornerRadius::same(REDESIGN_BORDER_RADIUS_U8))
+        .inner_margin(egui::Margin::same(18));
+
+    egui::Window::new("Launch settings")
+        .id(egui::Id::new(("home_launch_settings", &draft.modlist_id)))
+        .title_bar(false)
+        .resizable(false)
+        .collapsible(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .frame(frame)
+        .show(ctx, |ui| {
+            ui.set_max_width(MAX_WIDTH_PX);
+            ui.label(
+                egui::RichText::new("Launch settings")
+                    .size(15.0)
+                    .family(egui::FontFamily::Name("poppins_medium".into()))
+                    .color(redesign_text_primary(palette)),
+            );
+            ui.add_space(8.0);
+
+            ui.horizontal(|ui| {
+                for kind in LaunchKind::ALL {
+                    if ui
+                        .selectable_label(
+                            draft.kind == kind,
+                            egui::RichText::new(kind.label()).size(12.0),
+                        )
+                        .clicked()
+                    {
+                        draft.kind = kind;
+                    }
+                }
+            });
+            ui.add_space(8.0);
+
+            let program_hint = match draft.kind {
+                LaunchKind::Native => {
+                    format!("game binary (default {})", native_binary(draft.game))
+                }
+                LaunchKind::Steam => format!(
+                    "Steam app id (default {}); runs {} from the modded folder",
+                    steam_app_id(draft.game),
+                    native_binary(draft.game)
+                ),
+                LaunchKind::Wine => {
+                    format!("Windows binary (default {})", windows_binary(draft.game))
+                }
+                LaunchKind::Custom => "script or program to run".to_string(),
+            };
+            field(ui, palette, "program", &mut draft.program, &program_hint);
+            if draft.kind == LaunchKind::Wine {
+                field(
+                    ui,
+                    palette,
+                    "wrapper",
+                    &mut draft.wrapper,
+                    "wine, or /path/to/proton run",
+                );
+            }
+            field(ui, palette, "arguments", &mut draft.args, "extra arguments");
+            ui.label(
+                egui::RichText::new("environment (one KEY=VALUE per line)")
+                    .size(12.0)
+                    .color(redesign_text_muted(palette)),
+            );
+            ui.add_sized(
+                egui::vec2(ui.available_width(), 60.0),
+                egui::TextEdit::multiline(&mut draft.env_text)
+                    .hint_text("WINEPREFIX=/home/me/.wine-bg")
+                    .text_color(redesign_text_primary(palette))
+                    .background_color(redesign_input_bg(palette)),
+            );
+            if let Some(err) = draft.error.as_deref() {
+                ui.add_space(4.0);
+                ui.label(
+                    egui::RichText::new(err)
+                        .size(12.0)
+                        .color(redesign_error(palette)),
+                );
+            }
+            ui.add_space(12.0);
+
+            ui.allocate_ui_with_layout(
+                egui::vec2(ui.available_width(), 30.0),
+                egui::Layout::right_to_left(egui::Align::Center),
+                |ui| {
+                    ui.spacing_mut().item_spacing.x = 8.0;
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        "Save",
+                        BtnOpts {
+                            small: true,
+                            primary: true,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        outcome = LaunchSettingsOutcome::Save;
+                    }
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        "Cancel",
+                        BtnOpts {
+                            small: true,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        outcome = LaunchSettingsOutcome::Cancelled;
+                    }
+                },
+            );
+        });
+
+    outcome
+}
+
+fn field(ui: &mut egui::Ui, palette: ThemePalette, label: &str, value: &mut String, hint: &str) {
+    ui.label(
+        egui::RichText::new(label)
+            .size(12.0)
+            .color(redesign_text_muted(palette)),
+    );
+    ui.add_sized(
+        egui::vec2(ui.available_width(), 26.0),
+        egui::TextEdit::singleline(value)
+            .hint_text(hint)
+            .text_color(redesign_text_primary(palette))
+            .background_color(redesign_input_bg(palette))
+            .margin(egui::Margin::symmetric(8, 4)),
+    );
+    ui.add_space(6.0);
+}
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use eframe::egui;
+
+use crate::install_runtime::modlist_layers_driver;
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_box, redesign_btn};
+use crate::ui::shared::redesign_tokens::{
+    redesign_text_faint, redesign_text_muted, redesign_text_primary,
+};
+
+const EMPTY_HINT: &str = "Save part of a modlist as a layer from its card's menu, then compose new modlists from layers in Create.";
+
+pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp) {
+    let palette = orchestrator.theme_palette;
+    let mut delete: Option<String> = None;
+
+    redesign_box(ui, palette, Some("layers"), |ui| {
+        let layers = &orchestrator.layer_library.layers;
+        if layers.is_empty() {
+            ui.label(
+                egui::RichText::new(EMPTY_HINT)
+                    .size(13.0)
+                    .color(redesign_text_faint(palette)),
+            );
+            return;
+        }
+        ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = 6.0;
+            for layer in layers {
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 5.0;
+                    ui.label(
+                        egui::RichText::new(&layer.name)
+                            .size(13.0)
+                            .family(egui::FontFamily::Name("poppins_medium".into()))
+                            .color(redesign_text_primary(palette)),
+                    )
+                    .on_hover_text(layer.mod_names().join("\n"));
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{} \u{00B7} {} components \u{00B7} rev {}",
+                            layer.game.to_legacy_string(),
+                            layer.component_count(),
+                            layer.revision
+                        ))
+                        .size(12.0)
+                        .color(redesign_text_muted(palette)),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if redesign_btn(
+                            ui,
+                            palette,
+                            "\u{2715}",
+                            BtnOpts {
+                                small: true,
+                                no_shadow: true,
+                                ..Default::default()
+                            },
+                        )
+                        .on_hover_text("delete layer")
+                        .clicked()
+                        {
+                            delete = Some(layer.id.clone());
+                        }
+                    });
+                });
+            }
+        });
+    });
+
+    if let Some(id) = delete {
+        modlist_layers_driver::delete_layer(orchestrator, &id);
+    }
+}
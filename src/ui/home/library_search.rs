@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::collections::BTreeMap;
+
+use crate::registry::model::ModlistEntry;
+use crate::ui::home::state_home::HomeSort;
+
+#[must_use]
+pub fn parse_tags(text: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in text.split(',').map(|tag| tag.trim().to_lowercase()) {
+        if !tag.is_empty() && !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+#[must_use]
+pub fn tag_counts<'a>(entries: impl IntoIterator<Item = &'a ModlistEntry>) -> Vec<(String, usize)> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for tag in entries.into_iter().flat_map(|entry| &entry.tags) {
+        *counts.entry(tag).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .map(|(tag, count)| (tag.to_string(), count))
+        .collect()
+}
+
+#[must_use]
+pub fn query_has_tag(query: &str, tag: &str) -> bool {
+    query
+        .split_whitespace()
+        .any(|token| token.strip_prefix("tag:") == Some(tag))
+}
+
+#[must_use]
+pub fn toggle_tag(query: &str, tag: &str) -> String {
+    let token = format!("tag:{tag}");
+    let mut tokens: Vec<&str> = query.split_whitespace().collect();
+    if let Some(pos) = tokens.iter().position(|t| *t == token) {
+        tokens.remove(pos);
+    } else {
+        tokens.push(&token);
+    }
+    tokens.join(" ")
+}
+
+#[must_use]
+pub fn needs_mod_index(query: &str) -> bool {
+    query
+        .split_whitespace()
+        .any(|token| !token.starts_with("tag:") && !token.starts_with("author:"))
+}
+
+#[must_use]
+pub fn matches(entry: &ModlistEntry, mods: &[String], query: &str) -> bool {
+    let contains = |haystack: &str, needle: &str| haystack.to_lowercase().contains(needle);
+    let author = entry.author.as_deref().unwrap_or_default();
+    query.split_whitespace().all(|token| {
+        let token = token.to_lowercase();
+        if let Some(tag) = token.strip_prefix("tag:") {
+            return entry.tags.iter().any(|t| t == tag);
+        }
+        if let Some(name) = token.strip_prefix("author:") {
+            return contains(author, name);
+        }
+        if let Some(name) = token.strip_prefix("mod:") {
+            return mods.iter().any(|m| contains(m, name));
+        }
+        contains(&entry.name, &token)
+            || contains(author, &token)
+            || contains(&entry.notes, &token)
+            || entry.tags.iter().any(|t| t.contains(&token))
+            || mods.iter().any(|m| contains(m, &token))
+    })
+}
+
+pub fn sort_entries(entries: &mut [&ModlistEntry], sort: HomeSort) {
+    entries.sort_by(|a, b| {
+        b.pinned.cmp(&a.pinned).then_with(|| match sort {
+            HomeSort::LastTouched => b.last_touched_date.cmp(&a.last_touched_date),
+            HomeSort::Size => b.total_size_bytes.cmp(&a.total_size_bytes),
+            HomeSort::Components => b.component_count.cmp(&a.component_count),
+            HomeSort::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        })
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, tags: &[&str], components: u32, pinned: bool) -> ModlistEntry {
+        ModlistEntry {
+            id: name.to_string(),
+            name: name.to_string(),
+            tags: tags.iter().map(|t| (*t).to_string()).collect(),
+            component_count: components,
+            pinned,
+            ..ModlistEntry::default()
+        }
+    }
+
+    #[test]
+    fn search_tokens_and_sorting_with_pins() {
+        assert_eq!(
+            parse_tags(" Tactical, eet,,tactical "),
+            vec!["tactical", "eet"]
+        );
+        assert_eq!(toggle_tag("scs", "eet"), "scs tag:eet");
+        assert_eq!(toggle_tag("scs tag:eet", "eet"), "scs");
+
+        let tactical = entry("Tactical EET", &["tactical", "eet"], 300, false);
+        let mods = vec!["SCS/SETUP-SCS.TP2".to_string()];
+        assert!(matches(&tactical, &mods, "tag:eet scs"));
+        assert!(matches(&tactical, &mods, "mod:scs TACT"));
+        assert!(!matches(&tactical, &mods, "tag:test"));
+        assert!(!matches(&tactical, &[], "mod:scs"));
+
+        let small = entry("small", &[], 10, true);
+        let medium = entry("medium", &["test"], 50, false);
+        let mut list = vec![&tactical, &small, &medium];
+        sort_entries(&mut list, HomeSort::Components);
+        let names: Vec<&str> = list.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["small", "Tactical EET", "medium"]);
+        assert_eq!(
+            tag_counts([&tactical, &medium]),
+            vec![
+                ("eet".to_string(), 1),
+                ("tactical".to_string(), 1),
+                ("test".to_string(), 1)
+            ]
+        );
+    }
+}
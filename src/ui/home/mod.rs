@@ -6,7 +6,15 @@ pub mod confirm_delete;
 pub mod filter_chip;
 pub mod first_launch_setup_card;
 pub mod game_installs_detected;
+pub mod install_queue_panel;
+pub mod launch_settings_dialog;
+pub mod layers_panel;
+pub mod library_search;
 pub mod modlist_card;
 pub mod page_home;
 pub mod reinstall_route_wire;
+pub mod save_layer_dialog;
+pub mod settings_profile_dialog;
 pub mod state_home;
+pub mod tags_notes_dialog;
+pub mod uninstall_dialog;
@@ -11,8 +11,9 @@ use crate::ui::orchestrator::widgets::{
 };
 use crate::ui::shared::format_relative::relative_time;
 use crate::ui::shared::redesign_tokens::{
-    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_border_strong,
-    redesign_input_bg, redesign_shell_bg, redesign_text_faint, redesign_text_primary,
+    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_accent,
+    redesign_border_strong, redesign_error, redesign_input_bg, redesign_shell_bg,
+    redesign_text_faint, redesign_text_primary,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -23,9 +24,20 @@ pub enum ModlistCardActions {
     Open,
     CopyImportCode,
     OpenInstallFolder,
+    ExportBundle,
     Reinstall,
+    Enqueue,
+    Play,
+    LaunchSettings,
+    Verify,
+    Uninstall,
+    SaveAsLayer,
+    ReapplyLayers,
     Delete,
     Rename,
+    TogglePin,
+    EditTagsNotes,
+    SettingsProfile,
     SaveRename,
     CancelRename,
 }
@@ -63,12 +75,29 @@ pub fn render(
                     egui::Layout::top_down(egui::Align::LEFT),
                     |ui| {
                         ui.spacing_mut().item_spacing.y = 2.0;
-                        ui.label(
-                            egui::RichText::new(&entry.name)
-                                .size(13.0)
-                                .family(egui::FontFamily::Name("poppins_medium".into()))
-                                .color(redesign_text_primary(palette)),
-                        );
+                        ui.horizontal(|ui| {
+                            if render_pin(ui, palette, entry.pinned).clicked() {
+                                action = ModlistCardActions::TogglePin;
+                            }
+                            let name = ui.label(
+                                egui::RichText::new(&entry.name)
+                                    .size(13.0)
+                                    .family(egui::FontFamily::Name("poppins_medium".into()))
+                                    .color(redesign_text_primary(palette)),
+                            );
+                            if !entry.notes.trim().is_empty() {
+                                name.on_hover_text(entry.notes.trim());
+                            }
+                            render_update_badge(ui, palette, entry);
+                            render_health_badge(ui, palette, entry);
+                            for tag in &entry.tags {
+                                ui.label(
+                                    egui::RichText::new(format!("#{tag}"))
+                                        .size(12.0)
+                                        .color(redesign_text_faint(palette)),
+                                );
+                            }
+                        });
                         ui.label(
                             egui::RichText::new(meta_line(entry))
                                 .size(14.0)
@@ -88,6 +117,63 @@ pub fn render(
     action
 }
 
+fn render_pin(ui: &mut egui::Ui, palette: ThemePalette, pinned: bool) -> egui::Response {
+    let (glyph, color, hover) = if pinned {
+        ("\u{2605}", redesign_accent(palette), "unpin")
+    } else {
+        ("\u{2606}", redesign_text_faint(palette), "pin to top")
+    };
+    ui.add(
+        egui::Label::new(egui::RichText::new(glyph).size(13.0).color(color))
+            .sense(egui::Sense::click()),
+    )
+    .on_hover_text(hover)
+}
+
+fn render_update_badge(ui: &mut egui::Ui, palette: ThemePalette, entry: &ModlistEntry) {
+    let Some(status) = entry.update_status.as_ref() else {
+        return;
+    };
+    let Some(summary) = status.summary() else {
+        return;
+    };
+    ui.label(
+        egui::RichText::new(format!("· {summary}"))
+            .size(12.0)
+            .family(egui::FontFamily::Name("poppins_medium".into()))
+            .color(redesign_accent(palette)),
+    )
+    .on_hover_text(status.details());
+}
+
+fn render_health_badge(ui: &mut egui::Ui, palette: ThemePalette, entry: &ModlistEntry) {
+    let Some(report) = entry.health.as_ref() else {
+        return;
+    };
+    let checked = report
+        .checked_at
+        .map_or_else(String::new, |at| format!("checked {}", relative_time(at)));
+    let (text, color, hover) = match report.summary() {
+        Some(summary) => (
+            format!("\u{00B7} {summary}"),
+            redesign_error(palette),
+            format!("{checked}\n{}", report.details()),
+        ),
+        None => (
+            "\u{00B7} healthy".to_string(),
+            redesign_text_faint(palette),
+            checked,
+        ),
+    };
+    ui.label(
+        egui::RichText::new(text)
+            .size(12.0)
+            .family(egui::FontFamily::Name("poppins_medium".into()))
+            .color(color),
+    )
+    .on_hover_text(hover.trim());
+}
+
 fn render_rename_row(
     ui: &mut egui::Ui,
     palette: ThemePalette,
@@ -195,12 +281,19 @@ pub fn meta_line(entry: &ModlistEntry) -> String {
                 .total_size_bytes
                 .map_or_else(|| "\u{2014}".to_string(), human_size);
             let when = entry.install_date.unwrap_or(entry.last_touched_date);
-            format!(
+            let mut s = format!(
                 "{} mods \u{00B7} {} \u{00B7} installed {}",
                 entry.mod_count,
                 size,
                 relative_time(when),
-            )
+            );
+            if let Some(summary) = entry.install_verdict.as_ref().and_then(|v| v.summary()) {
+                let _ = write!(s, ", {summary}");
+            }
+            if let Some(played) = entry.last_played_date {
+                let _ = write!(s, " \u{00B7} played {}", relative_time(played));
+            }
+            s
         }
     }
 }
@@ -224,8 +317,27 @@ fn render_action_cluster(
                 KebabItem::new("Rename", || {
                     picked.set(ModlistCardActions::Rename);
                 }),
-                KebabItem::danger("Delete", || picked.set(ModlistCardActions::Delete)),
+                KebabItem::new("Tags & notes\u{2026}", || {
+                    picked.set(ModlistCardActions::EditTagsNotes);
+                }),
+                KebabItem::new("Settings profile\u{2026}", || {
+                    picked.set(ModlistCardActions::SettingsProfile);
+                }),
+                KebabItem::new("Add to install queue", || {
+                    picked.set(ModlistCardActions::Enqueue);
+                }),
+                KebabItem::new("Save as layer\u{2026}", || {
+                    picked.set(ModlistCardActions::SaveAsLayer);
+                }),
             ];
+            if !entry.layers.is_empty() {
+                items.push(KebabItem::new("Re-apply layers", || {
+                    picked.set(ModlistCardActions::ReapplyLayers);
+                }));
+            }
+            items.push(KebabItem::danger("Delete", || {
+                picked.set(ModlistCardActions::Delete);
+            }));
             let kebab_h = redesign_btn_height(ui, true);
             render_kebab(ui, palette, &entry.id, &mut items, kebab_h);
             drop(items);
@@ -254,12 +366,43 @@ fn render_action_cluster(
                 KebabItem::new("Open install folder", || {
                     picked.set(ModlistCardActions::OpenInstallFolder);
                 }),
+                KebabItem::new("Launch settings\u{2026}", || {
+                    picked.set(ModlistCardActions::LaunchSettings);
+                }),
+                KebabItem::new("Verify install", || {
+                    picked.set(ModlistCardActions::Verify);
+                }),
+                KebabItem::new("Uninstall components\u{2026}", || {
+                    picked.set(ModlistCardActions::Uninstall);
+                }),
+                KebabItem::new("Export offline bundle", || {
+                    picked.set(ModlistCardActions::ExportBundle);
+                }),
                 KebabItem::new("Rename", || {
                     picked.set(ModlistCardActions::Rename);
                 }),
+                KebabItem::new("Tags & notes\u{2026}", || {
+                    picked.set(ModlistCardActions::EditTagsNotes);
+                }),
+                KebabItem::new("Settings profile\u{2026}", || {
+                    picked.set(ModlistCardActions::SettingsProfile);
+                }),
                 KebabItem::new("Reinstall", || picked.set(ModlistCardActions::Reinstall)),
-                KebabItem::danger("Delete", || picked.set(ModlistCardActions::Delete)),
+                KebabItem::new("Add to install queue", || {
+                    picked.set(ModlistCardActions::Enqueue);
+                }),
+                KebabItem::new("Save as layer\u{2026}", || {
+                    picked.set(ModlistCardActions::SaveAsLayer);
+                }),
             ];
+            if !entry.layers.is_empty() {
+                items.push(KebabItem::new("Re-apply layers", || {
+                    picked.set(ModlistCardActions::ReapplyLayers);
+                }));
+            }
+            items.push(KebabItem::danger("Delete", || {
+                picked.set(ModlistCardActions::Delete);
+            }));
             let kebab_h = redesign_btn_height(ui, true);
             render_kebab(ui, palette, &entry.id, &mut items, kebab_h);
             drop(items);
@@ -278,13 +421,28 @@ fn render_action_cluster(
             {
                 picked.set(ModlistCardActions::Open);
             }
+            if redesign_btn(
+                ui,
+                palette,
+                "play",
+                BtnOpts {
+                    small: true,
+                    primary: true,
+                    no_shadow: true,
+                    ..Default::default()
+                },
+            )
+            .clicked()
+            {
+                picked.set(ModlistCardActions::Play);
+            }
         }
     }
 
     picked.into_inner()
 }
 
-fn human_size(bytes: u64) -> String {
+pub(crate) fn human_size(bytes: u64) -> String {
     const KB: u64 = 1024;
     const MB: u64 = KB * 1024;
     const GB: u64 = MB * 1024;
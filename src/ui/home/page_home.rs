@@ -3,24 +3,42 @@
 
 use eframe::egui;
 
-use crate::registry::model::{ModlistEntry, ModlistState};
+use crate::install_runtime::{
+    game_launcher, install_queue_driver, modlist_health, modlist_layers_driver, offline_bundle,
+    settings_profiles_driver, uninstall_driver,
+};
+use crate::registry::model::{LaunchConfig, ModlistEntry, ModlistState};
 use crate::registry::operations::{self, remove_entry_and_save, spawn_delete_folder_worker};
 use crate::registry::operations_rename;
+use crate::settings::{layers, profiles};
 use crate::ui::home::add_a_modlist::{self, AddAModlistAction};
 use crate::ui::home::confirm_delete;
+use crate::ui::home::install_queue_panel;
+use crate::ui::home::launch_settings_dialog::{self, LaunchSettingsOutcome};
+use crate::ui::home::layers_panel;
+use crate::ui::home::library_search;
 use crate::ui::home::modlist_card::ModlistCardActions;
 use crate::ui::home::reinstall_route_wire;
-use crate::ui::home::state_home::{HomeFilter, empty_filter_message};
+use crate::ui::home::save_layer_dialog::{self, SaveLayerOutcome};
+use crate::ui::home::settings_profile_dialog::{self, SettingsProfileOutcome};
+use crate::ui::home::state_home::{
+    HomeFilter, HomeScreenState, HomeSort, LaunchDraft, SaveLayerDraft, SettingsProfileDraft,
+    TagsNotesDraft, empty_filter_message,
+};
+use crate::ui::home::tags_notes_dialog::{self, TagsNotesOutcome};
+use crate::ui::home::uninstall_dialog::{self, UninstallOutcome};
 use crate::ui::home::{filter_chip, first_launch_setup_card, modlist_card};
 use crate::ui::orchestrator::nav_destination::NavDestination;
 use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
-use crate::ui::orchestrator::orchestrator_app::PendingFolderDelete;
+use crate::ui::orchestrator::orchestrator_app::{PendingBundleExport, PendingFolderDelete};
 use crate::ui::orchestrator::widgets::clipboard;
 use crate::ui::orchestrator::widgets::dialogs::confirm_dialog::{self, ConfirmOutcome};
 use crate::ui::orchestrator::widgets::{redesign_box, render_screen_title};
 use crate::ui::settings::state_settings::SettingsTab;
 use crate::ui::shared::format_relative::relative_time;
-use crate::ui::shared::redesign_tokens::{ThemePalette, redesign_text_faint};
+use crate::ui::shared::redesign_tokens::{
+    ThemePalette, redesign_input_bg, redesign_text_faint, redesign_text_primary,
+};
 
 const COLUMN_GAP_PX: f32 = 20.0;
 const LEFT_COLUMN_FRACTION: f32 = 2.0 / 3.0;
@@ -35,9 +53,20 @@ enum NavRequest {
 enum CardIntent {
     CopyImportCode(String),
     OpenInstallFolder(String),
+    ExportBundle(String),
     RequestDelete(String),
     RequestReinstall(String),
+    Enqueue(String),
+    Play(String),
+    EditLaunch(String),
+    Verify(String),
+    Uninstall(String),
+    SaveAsLayer(String),
+    ReapplyLayers(String),
     RequestRename(String),
+    TogglePin(String),
+    EditTagsNotes(String),
+    EditSettingsProfile(String),
     SaveRename(String),
     CancelRename,
 }
@@ -60,6 +89,7 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, ctx: &egui:
         .home_screen_state
         .effective_filter(installed_count, in_progress_count);
     let mut new_filter: Option<HomeFilter> = None;
+    refresh_mod_index(orchestrator);
 
     let row_width = ui.available_width();
     let left_w = ((row_width - COLUMN_GAP_PX) * LEFT_COLUMN_FRACTION).max(0.0);
@@ -89,6 +119,13 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, ctx: &egui:
                             effective_filter = picked;
                         }
 
+                        ui.add_space(8.0);
+                        render_search_row(
+                            ui,
+                            palette,
+                            &mut orchestrator.home_screen_state,
+                            &orchestrator.registry.entries,
+                        );
                         ui.add_space(12.0);
 
                         let (nav, intent) = render_card_list(
@@ -97,8 +134,7 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, ctx: &egui:
                             effective_filter,
                             &installed,
                             &in_progress,
-                            orchestrator.home_screen_state.rename_target.as_ref(),
-                            &mut orchestrator.home_screen_state.rename_temp,
+                            &mut orchestrator.home_screen_state,
                         );
                         if let Some(act) = nav {
                             nav_request = Some(act);
@@ -127,6 +163,10 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, ctx: &egui:
                     }
                     AddAModlistAction::None => {}
                 }
+                ui.add_space(COLUMN_GAP_PX);
+                install_queue_panel::render(ui, orchestrator);
+                ui.add_space(COLUMN_GAP_PX);
+                layers_panel::render(ui, orchestrator);
             },
         );
     });
@@ -146,6 +186,12 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, ctx: &egui:
 
     render_delete_confirm(orchestrator, ctx);
     render_reinstall_confirm(orchestrator, ctx);
+    render_reapply_layers_confirm(orchestrator, ctx);
+    render_save_layer_dialog(orchestrator, ctx);
+    render_launch_settings_dialog(orchestrator, ctx);
+    render_tags_notes_dialog(orchestrator, ctx);
+    render_settings_profile_dialog(orchestrator, ctx);
+    render_uninstall_dialog(orchestrator, ctx);
 }
 
 fn apply_nav_request(orchestrator: &mut OrchestratorApp, req: NavRequest) {
@@ -195,12 +241,84 @@ fn apply_card_intent(orchestrator: &mut OrchestratorApp, ctx: &egui::Context, in
             }
         }
         CardIntent::OpenInstallFolder(id) => open_install_folder_for(orchestrator, &id),
+        CardIntent::ExportBundle(id) => export_bundle_for(orchestrator, &id),
         CardIntent::RequestDelete(id) => {
             orchestrator.home_screen_state.delete_target = Some(id);
         }
         CardIntent::RequestReinstall(id) => {
             orchestrator.home_screen_state.reinstall_target = Some(id);
         }
+        CardIntent::Enqueue(id) => install_queue_driver::enqueue(orchestrator, &id),
+        CardIntent::Play(id) => game_launcher::launch(orchestrator, &id),
+        CardIntent::Verify(id) => modlist_health::start(orchestrator, &id),
+        CardIntent::Uninstall(id) => uninstall_driver::open(orchestrator, &id),
+        CardIntent::EditLaunch(id) => {
+            let Some(entry) = orchestrator.registry.find(&id) else {
+                return;
+            };
+            let config = entry.launch.clone().unwrap_or_default();
+            orchestrator.home_screen_state.launch_settings = Some(LaunchDraft {
+                modlist_id: id,
+                game: entry.game,
+                kind: config.kind,
+                program: config.program,
+                wrapper: config.wrapper,
+                args: config.args,
+                env_text: game_launcher::env_lines(&config.env),
+                error: None,
+            });
+        }
+        CardIntent::TogglePin(id) => {
+            if let Some(entry) = orchestrator.registry.find_mut(&id) {
+                entry.pinned = !entry.pinned;
+                orchestrator
+                    .persistence_cycle
+                    .mark_registry_dirty(std::time::Instant::now());
+            }
+        }
+        CardIntent::EditTagsNotes(id) => {
+            let Some(entry) = orchestrator.registry.find(&id) else {
+                return;
+            };
+            orchestrator.home_screen_state.tags_notes = Some(TagsNotesDraft {
+                modlist_id: id,
+                tags: entry.tags.join(", "),
+                notes: entry.notes.clone(),
+            });
+        }
+        CardIntent::EditSettingsProfile(id) => {
+            let Some(entry) = orchestrator.registry.find(&id) else {
+                return;
+            };
+            orchestrator.home_screen_state.settings_profile = Some(SettingsProfileDraft {
+                modlist_id: id,
+                profile: entry.settings_profile.clone(),
+                overrides: profiles::override_lines(&entry.settings_overrides),
+                error: None,
+            });
+        }
+        CardIntent::SaveAsLayer(id) => {
+            let mods = modlist_layers_driver::modlist_mods(orchestrator, &id)
+                .into_iter()
+                .map(|tp2| (tp2, true))
+                .collect();
+            orchestrator.home_screen_state.save_layer = Some(SaveLayerDraft {
+                modlist_id: id,
+                name: String::new(),
+                mods,
+            });
+        }
+        CardIntent::ReapplyLayers(id) => {
+            let installed = orchestrator
+                .registry
+                .find(&id)
+                .is_some_and(|entry| entry.state == ModlistState::Installed);
+            if installed {
+                orchestrator.home_screen_state.reapply_layers_target = Some(id);
+            } else {
+                modlist_layers_driver::reapply_layers(orchestrator, &id);
+            }
+        }
         CardIntent::RequestRename(id) => {
             let name = modlist_name(orchestrator, &id);
             orchestrator.home_screen_state.rename_temp = name;
@@ -250,6 +368,62 @@ fn open_install_folder_for(orchestrator: &mut OrchestratorApp, id: &str) {
     }
 }
 
+fn export_bundle_for(orchestrator: &mut OrchestratorApp, id: &str) {
+    let Some(entry) = orchestrator.registry.find(id).cloned() else {
+        return;
+    };
+    let contents = match offline_bundle::contents_for_entry(&entry) {
+        Ok(contents) => contents,
+        Err(err) => {
+            orchestrator
+                .notification_manager
+                .error(format!("Couldn't export bundle: {err}"));
+            return;
+        }
+    };
+    let Some(output) = rfd::FileDialog::new()
+        .set_file_name(format!(
+            "{}.{}",
+            entry.name,
+            offline_bundle::BUNDLE_EXTENSION
+        ))
+        .save_file()
+    else {
+        return;
+    };
+    let archive_dir = orchestrator
+        .settings_store
+        .load()
+        .map(|settings| {
+            let from: crate::app::state::Step1State = settings.step1.into();
+            std::path::PathBuf::from(from.mods_archive_folder.trim())
+        })
+        .unwrap_or_default();
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let archives = offline_bundle::collect_archive_meta(
+            &contents,
+            &entry.destination_folder,
+            &archive_dir,
+        );
+        let _ = tx.send(offline_bundle::export_bundle(
+            &contents,
+            &archives,
+            &archive_dir,
+            &output,
+        ));
+    });
+    orchestrator
+        .notification_manager
+        .info(format!("Exporting \"{}\"\u{2026}", entry.name));
+    orchestrator
+        .pending_bundle_exports
+        .push(PendingBundleExport {
+            modlist_name: entry.name,
+            rx,
+        });
+}
+
 fn modlist_name(orchestrator: &OrchestratorApp, id: &str) -> String {
     orchestrator
         .registry
@@ -339,6 +513,238 @@ fn render_reinstall_confirm(orchestrator: &mut OrchestratorApp, ctx: &egui::Cont
     }
 }
 
+fn render_reapply_layers_confirm(orchestrator: &mut OrchestratorApp, ctx: &egui::Context) {
+    let Some(id) = orchestrator.home_screen_state.reapply_layers_target.clone() else {
+        return;
+    };
+    let Some(entry) = orchestrator.registry.find(&id).cloned() else {
+        orchestrator.home_screen_state.reapply_layers_target = None;
+        return;
+    };
+
+    let (title, body) = confirm_delete::reapply_layers_dialog_text(&entry);
+    let dialog = confirm_delete::reapply_layers_confirm(&id, &title, &body);
+    match confirm_dialog::render(ctx, orchestrator.theme_palette, &dialog) {
+        ConfirmOutcome::Confirmed => {
+            orchestrator.home_screen_state.reapply_layers_target = None;
+            modlist_layers_driver::reapply_layers(orchestrator, &id);
+        }
+        ConfirmOutcome::Cancelled => {
+            orchestrator.home_screen_state.reapply_layers_target = None;
+        }
+        ConfirmOutcome::Pending => {}
+    }
+}
+
+fn render_save_layer_dialog(orchestrator: &mut OrchestratorApp, ctx: &egui::Context) {
+    let palette = orchestrator.theme_palette;
+    let Some(draft) = orchestrator.home_screen_state.save_layer.as_mut() else {
+        return;
+    };
+    match save_layer_dialog::render(ctx, palette, draft) {
+        SaveLayerOutcome::Save => {
+            let Some(draft) = orchestrator.home_screen_state.save_layer.take() else {
+                return;
+            };
+            let mods = draft
+                .mods
+                .into_iter()
+                .filter(|(_, keep)| *keep)
+                .map(|(tp2, _)| tp2)
+                .collect();
+            modlist_layers_driver::save_layer(orchestrator, &draft.modlist_id, &draft.name, &mods);
+        }
+        SaveLayerOutcome::Cancelled => {
+            orchestrator.home_screen_state.save_layer = None;
+        }
+        SaveLayerOutcome::Pending => {}
+    }
+}
+
+fn render_launch_settings_dialog(orchestrator: &mut OrchestratorApp, ctx: &egui::Context) {
+    let palette = orchestrator.theme_palette;
+    let Some(draft) = orchestrator.home_screen_state.launch_settings.as_mut() else {
+        return;
+    };
+    match launch_settings_dialog::render(ctx, palette, draft) {
+        LaunchSettingsOutcome::Save => {
+            let env = match game_launcher::parse_env_lines(&draft.env_text) {
+                Ok(env) => env,
+                Err(err) => {
+                    draft.error = Some(err);
+                    return;
+                }
+            };
+            let config = LaunchConfig {
+                kind: draft.kind,
+                program: draft.program.trim().to_string(),
+                wrapper: draft.wrapper.trim().to_string(),
+                args: draft.args.trim().to_string(),
+                env,
+            };
+            let id = draft.modlist_id.clone();
+            orchestrator.home_screen_state.launch_settings = None;
+            if let Some(entry) = orchestrator.registry.find_mut(&id) {
+                entry.launch = (config != LaunchConfig::default()).then_some(config);
+            }
+            orchestrator
+                .persistence_cycle
+                .mark_registry_dirty(std::time::Instant::now());
+        }
+        LaunchSettingsOutcome::Cancelled => {
+            orchestrator.home_screen_state.launch_settings = None;
+        }
+        LaunchSettingsOutcome::Pending => {}
+    }
+}
+
+fn render_tags_notes_dialog(orchestrator: &mut OrchestratorApp, ctx: &egui::Context) {
+    let palette = orchestrator.theme_palette;
+    let Some(draft) = orchestrator.home_screen_state.tags_notes.as_mut() else {
+        return;
+    };
+    match tags_notes_dialog::render(ctx, palette, draft) {
+        TagsNotesOutcome::Save => {
+            let Some(draft) = orchestrator.home_screen_state.tags_notes.take() else {
+                return;
+            };
+            if let Some(entry) = orchestrator.registry.find_mut(&draft.modlist_id) {
+                entry.tags = library_search::parse_tags(&draft.tags);
+                entry.notes = draft.notes.trim().to_string();
+            }
+            orchestrator
+                .persistence_cycle
+                .mark_registry_dirty(std::time::Instant::now());
+        }
+        TagsNotesOutcome::Cancelled => {
+            orchestrator.home_screen_state.tags_notes = None;
+        }
+        TagsNotesOutcome::Pending => {}
+    }
+}
+
+fn render_settings_profile_dialog(orchestrator: &mut OrchestratorApp, ctx: &egui::Context) {
+    let palette = orchestrator.theme_palette;
+    let names: Vec<String> = orchestrator
+        .settings_profiles
+        .profiles
+        .iter()
+        .map(|profile| profile.name.clone())
+        .collect();
+    let Some(draft) = orchestrator.home_screen_state.settings_profile.as_mut() else {
+        return;
+    };
+    match settings_profile_dialog::render(ctx, palette, draft, &names) {
+        SettingsProfileOutcome::Save => {
+            let overrides = match profiles::parse_override_lines(&draft.overrides) {
+                Ok(overrides) => overrides,
+                Err(err) => {
+                    draft.error = Some(err);
+                    return;
+                }
+            };
+            let errors = layers::validate(&overrides);
+            if !errors.is_empty() {
+                draft.error = Some(errors.join("; "));
+                return;
+            }
+            let Some(draft) = orchestrator.home_screen_state.settings_profile.take() else {
+                return;
+            };
+            settings_profiles_driver::set_modlist_layer(
+                orchestrator,
+                &draft.modlist_id,
+                draft.profile,
+                overrides,
+            );
+        }
+        SettingsProfileOutcome::Cancelled => {
+            orchestrator.home_screen_state.settings_profile = None;
+        }
+        SettingsProfileOutcome::Pending => {}
+    }
+}
+
+fn render_uninstall_dialog(orchestrator: &mut OrchestratorApp, ctx: &egui::Context) {
+    let palette = orchestrator.theme_palette;
+    let Some(session) = orchestrator.uninstall.as_mut() else {
+        return;
+    };
+    match uninstall_dialog::render(ctx, palette, session) {
+        UninstallOutcome::Run => uninstall_driver::start(orchestrator),
+        UninstallOutcome::Close => orchestrator.uninstall = None,
+        UninstallOutcome::Pending => {}
+    }
+}
+
+fn refresh_mod_index(orchestrator: &mut OrchestratorApp) {
+    if !library_search::needs_mod_index(&orchestrator.home_screen_state.search) {
+        return;
+    }
+    let stale: Vec<(String, chrono::DateTime<chrono::Utc>)> = orchestrator
+        .registry
+        .entries
+        .iter()
+        .filter(|entry| {
+            orchestrator
+                .home_screen_state
+                .mod_index
+                .get(&entry.id)
+                .is_none_or(|(stamp, _)| *stamp != entry.last_touched_date)
+        })
+        .map(|entry| (entry.id.clone(), entry.last_touched_date))
+        .collect();
+    for (id, stamp) in stale {
+        let mods = modlist_layers_driver::modlist_mods(orchestrator, &id);
+        orchestrator
+            .home_screen_state
+            .mod_index
+            .insert(id, (stamp, mods));
+    }
+}
+
+fn render_search_row(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    state: &mut HomeScreenState,
+    entries: &[ModlistEntry],
+) {
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 8.0;
+        let sort_w = 150.0;
+        ui.add_sized(
+            egui::vec2((ui.available_width() - sort_w - 8.0).max(80.0), 26.0),
+            egui::TextEdit::singleline(&mut state.search)
+                .hint_text("search name, author, mods, tags \u{2014} or tag:x mod:x author:x")
+                .text_color(redesign_text_primary(palette))
+                .background_color(redesign_input_bg(palette))
+                .margin(egui::Margin::symmetric(8, 4)),
+        );
+        egui::ComboBox::from_id_salt("home_sort")
+            .width(sort_w - 20.0)
+            .selected_text(format!("sort: {}", state.sort.label()))
+            .show_ui(ui, |ui| {
+                for sort in HomeSort::ALL {
+                    ui.selectable_value(&mut state.sort, sort, sort.label());
+                }
+            });
+    });
+    let tags = library_search::tag_counts(entries);
+    if tags.is_empty() {
+        return;
+    }
+    ui.add_space(6.0);
+    ui.horizontal_wrapped(|ui| {
+        ui.spacing_mut().item_spacing.x = 8.0;
+        for (tag, count) in tags {
+            let active = library_search::query_has_tag(&state.search, &tag);
+            if filter_chip::render(ui, palette, &format!("#{tag}"), count, active).clicked() {
+                state.search = library_search::toggle_tag(&state.search, &tag);
+            }
+        }
+    });
+}
+
 fn build_subtitle(installed: &[ModlistEntry], in_progress_count: usize) -> String {
     let mut segments: Vec<String> = Vec::new();
 
@@ -425,18 +831,32 @@ fn render_card_list(
     filter: HomeFilter,
     installed: &[ModlistEntry],
     in_progress: &[ModlistEntry],
-    rename_target: Option<&String>,
-    rename_temp: &mut String,
+    state: &mut HomeScreenState,
 ) -> (Option<NavRequest>, Option<CardIntent>) {
-    let visible: Vec<&ModlistEntry> = match filter {
+    let query = state.search.trim().to_string();
+    let mut visible: Vec<&ModlistEntry> = match filter {
         HomeFilter::Installed => installed.iter().collect(),
         HomeFilter::InProgress => in_progress.iter().collect(),
         HomeFilter::All => installed.iter().chain(in_progress.iter()).collect(),
     };
+    let unfiltered = visible.len();
+    visible.retain(|entry| {
+        let mods = state
+            .mod_index
+            .get(&entry.id)
+            .map_or(&[][..], |(_, mods)| mods.as_slice());
+        library_search::matches(entry, mods, &query)
+    });
+    library_search::sort_entries(&mut visible, state.sort);
 
     if visible.is_empty() {
+        let message = if unfiltered > 0 {
+            format!("No modlists match \"{query}\".")
+        } else {
+            empty_filter_message(filter).to_string()
+        };
         ui.label(
-            egui::RichText::new(empty_filter_message(filter))
+            egui::RichText::new(message)
                 .size(13.0)
                 .family(egui::FontFamily::Proportional)
                 .color(redesign_text_faint(palette)),
@@ -449,9 +869,12 @@ fn render_card_list(
     ui.vertical(|ui| {
         ui.spacing_mut().item_spacing.y = 10.0;
         for entry in visible {
-            let is_renaming = rename_target.is_some_and(|id| id.as_str() == entry.id.as_str());
+            let is_renaming = state
+                .rename_target
+                .as_ref()
+                .is_some_and(|id| id.as_str() == entry.id.as_str());
             let buf = if is_renaming {
-                Some(rename_temp as &mut String)
+                Some(&mut state.rename_temp)
             } else {
                 None
             };
@@ -467,15 +890,48 @@ fn render_card_list(
                 ModlistCardActions::CopyImportCode => {
                     intent = Some(CardIntent::CopyImportCode(entry.id.clone()));
                 }
+                ModlistCardActions::ExportBundle => {
+                    intent = Some(CardIntent::ExportBundle(entry.id.clone()));
+                }
                 ModlistCardActions::Reinstall => {
                     intent = Some(CardIntent::RequestReinstall(entry.id.clone()));
                 }
+                ModlistCardActions::Enqueue => {
+                    intent = Some(CardIntent::Enqueue(entry.id.clone()));
+                }
+                ModlistCardActions::Play => {
+                    intent = Some(CardIntent::Play(entry.id.clone()));
+                }
+                ModlistCardActions::LaunchSettings => {
+                    intent = Some(CardIntent::EditLaunch(entry.id.clone()));
+                }
+                ModlistCardActions::Verify => {
+                    intent = Some(CardIntent::Verify(entry.id.clone()));
+                }
+                ModlistCardActions::Uninstall => {
+                    intent = Some(CardIntent::Uninstall(entry.id.clone()));
+                }
+                ModlistCardActions::SaveAsLayer => {
+                    intent = Some(CardIntent::SaveAsLayer(entry.id.clone()));
+                }
+                ModlistCardActions::ReapplyLayers => {
+                    intent = Some(CardIntent::ReapplyLayers(entry.id.clone()));
+                }
                 ModlistCardActions::Delete => {
                     intent = Some(CardIntent::RequestDelete(entry.id.clone()));
                 }
                 ModlistCardActions::Rename => {
                     intent = Some(CardIntent::RequestRename(entry.id.clone()));
                 }
+                ModlistCardActions::TogglePin => {
+                    intent = Some(CardIntent::TogglePin(entry.id.clone()));
+                }
+                ModlistCardActions::EditTagsNotes => {
+                    intent = Some(CardIntent::EditTagsNotes(entry.id.clone()));
+                }
+                ModlistCardActions::SettingsProfile => {
+                    intent = Some(CardIntent::EditSettingsProfile(entry.id.clone()));
+                }
                 ModlistCardActions::SaveRename => {
                     intent = Some(CardIntent::SaveRename(entry.id.clone()));
                 }
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use eframe::egui;
+
+use crate::ui::home::state_home::SaveLayerDraft;
+use crate::ui::orchestrator::widgets::{BtnOpts, InputOpts, redesign_btn, redesign_text_input};
+use crate::ui::shared::redesign_tokens::{
+    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_border_strong,
+    redesign_input_bg, redesign_shell_bg, redesign_text_faint, redesign_text_muted,
+    redesign_text_primary,
+};
+
+const MAX_WIDTH_PX: f32 = 460.0;
+const MOD_LIST_MAX_H_PX: f32 = 260.0;
+const INPUT_MARGIN: egui::Margin = egui::Margin {
+    left: 10,
+    right: 10,
+    top: 6,
+    bottom: 6,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SaveLayerOutcome {
+    #[default]
+    Pending,
+    Save,
+    Cancelled,
+}
+
+pub fn render(
+    ctx: &egui::Context,
+    palette: ThemePalette,
+    draft: &mut SaveLayerDraft,
+) -> SaveLayerOutcome {
+    let mut outcome = SaveLayerOutcome::Pending;
+
+    let frame = egui::Frame::default()
+        .fill(redesign_shell_bg(palette))
+        .stroke(egui::Stroke::new(
+            REDESIGN_BORDER_WIDTH_PX,
+            redesign_border_strong(palette),
+        ))
+        .corner_radius(egui::CornerRadius::same(REDESIGN_BORDER_RADIUS_U8))
+        .inner_margin(egui::Margin::same(18));
+
+    egui::Window::new("Save as layer")
+        .id(egui::Id::new(("home_save_layer_dialog", &draft.modlist_id)))
+        .title_bar(false)
+        .resizable(false)
+        .collapsible(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .frame(frame)
+        .show(ctx, |ui| {
+            ui.set_max_width(MAX_WIDTH_PX);
+            ui.label(
+                egui::RichText::new("Save as layer")
+                    .size(15.0)
+                    .family(egui::FontFamily::Name("poppins_medium".into()))
+                    .color(redesign_text_primary(palette)),
+            );
+            ui.add_space(4.0);
+            ui.label(
+                egui::RichText::new(
+                    "Pick the mods this layer carries. Saving under an existing layer name \
+                     publishes a new revision that composed modlists can re-apply.",
+                )
+                .size(13.0)
+                .family(egui::FontFamily::Name("poppins_light".into()))
+                .color(redesign_text_muted(palette)),
+            );
+            ui.add_space(10.0);
+
+            redesign_text_input(
+                ui,
+                palette,
+                InputOpts {
+                    edit: egui::TextEdit::singleline(&mut draft.name)
+                        .hint_text(
+                            egui::RichText::new("layer name, e.g. Fixpacks + UI")
+                                .color(redesign_text_faint(palette)),
+                        )
+                        .text_color(redesign_text_primary(palette))
+                        .background_color(redesign_input_bg(palette))
+                        .margin(INPUT_MARGIN),
+                    margin: INPUT_MARGIN,
+                    size: egui::vec2(ui.available_width(), 28.0),
+                    border: None,
+                },
+            );
+            ui.add_space(10.0);
+
+            egui::ScrollArea::vertical()
+                .max_height(MOD_LIST_MAX_H_PX)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    for (tp2, keep) in &mut draft.mods {
+                        ui.checkbox(
+                            keep,
+                            egui::RichText::new(tp2.as_str())
+                                .size(12.0)
+                                .color(redesign_text_primary(palette)),
+                        );
+                    }
+                });
+            ui.add_space(14.0);
+
+            let can_save =
+                !draft.name.trim().is_empty() && draft.mods.iter().any(|(_, keep)| *keep);
+            ui.allocate_ui_with_layout(
+                egui::vec2(ui.available_width(), 30.0),
+                egui::Layout::right_to_left(egui::Align::Center),
+                |ui| {
+                    ui.spacing_mut().item_spacing.x = 8.0;
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        "Save layer",
+                        BtnOpts {
+                            small: true,
+                            primary: true,
+                            disabled: !can_save,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        outcome = SaveLayerOutcome::Save;
+                    }
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        "Cancel",
+                        BtnOpts {
+                            small: true,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        outcome = SaveLayerOutcome::Cancelled;
+                    }
+                },
+            );
+        });
+
+    outcome
+}
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use eframe::egui;
+
+use crate::ui::home::state_home::SettingsProfileDraft;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
+use crate::ui::shared::redesign_tokens::{
+    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_border_strong,
+    redesign_error, redesign_input_bg, redesign_shell_bg, redesign_text_faint, redesign_text_muted,
+    redesign_text_primary,
+};
+
+const MAX_WIDTH_PX: f32 = 440.0;
+const NO_PROFILE_LABEL: &str = "global default";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SettingsProfileOutcome {
+    #[default]
+    Pending,
+    Save,
+    Cancelled,
+}
+
+pub fn render(
+    ctx: &egui::Context,
+    palette: ThemePalette,
+    draft: &mut SettingsProfileDraft,
+    profile_names: &[String],
+) -> SettingsProfileOutcome {
+    let mut outcome = SettingsProfileOutcome::Pending;
+
+    let frame = egui::Frame::default()
+        .fill(redesign_shell_bg(palette))
+        .stroke(egui::Stroke::new(
+            REDESIGN_BORDER_WIDTH_PX,
+            redesign_border_strong(palette),
+        ))
+        .corner_radius(egui::CornerRadius::same(REDESIGN_BORDER_RADIUS_U8))
+        .inner_margin(egui::Margin::same(18));
+
+    egui::Window::new("Settings profile")
+        .id(egui::Id::new(("home_settings_profile", &draft.modlist_id)))
+        .title_bar(false)
+        .resizable(false)
+        .collapsible(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .frame(frame)
+        .show(ctx, |ui| {
+            ui.set_max_width(MAX_WIDTH_PX);
+            ui.label(
+                egui::RichText::new("Settings profile")
+                    .size(15.0)
+                    .family(egui::FontFamily::Name("poppins_medium".into()))
+                    .color(redesign_text_primary(palette)),
+            );
+            ui.add_space(8.0);
+
+            ui.label(
+                egui::RichText::new("profile")
+                    .size(12.0)
+                    .color(redesign_text_muted(palette)),
+            );
+            egui::ComboBox::from_id_salt(("home_settings_profile_pick", &draft.modlist_id))
+                .width(ui.available_width())
+                .selected_text(draft.profile.as_deref().unwrap_or(NO_PROFILE_LABEL))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut draft.profile, None, NO_PROFILE_LABEL);
+                    for name in profile_names {
+                        ui.selectable_value(&mut draft.profile, Some(name.clone()), name);
+                    }
+                });
+            ui.add_space(8.0);
+
+            ui.label(
+                egui::RichText::new("overrides for this modlist (one key = value per line)")
+                    .size(12.0)
+                    .color(redesign_text_muted(palette)),
+            );
+            ui.add_sized(
+                egui::vec2(ui.available_width(), 90.0),
+                egui::TextEdit::multiline(&mut draft.overrides)
+                    .font(egui::FontId::new(
+                        12.0,
+                        egui::FontFamily::Name("firacode_nerd".into()),
+                    ))
+                    .hint_text("timeout = 7200")
+                    .text_color(redesign_text_primary(palette))
+                    .background_color(redesign_input_bg(palette)),
+            );
+            ui.label(
+                egui::RichText::new(
+                    "Applied over the profile whenever this modlist is open; \
+                     --set and BIO_SETTING_* still win.",
+                )
+                .size(11.0)
+                .color(redesign_text_faint(palette)),
+            );
+            if let Some(err) = draft.error.as_deref() {
+                ui.add_space(4.0);
+                ui.label(
+                    egui::RichText::new(err)
+                        .size(12.0)
+                        .color(redesign_error(palette)),
+                );
+            }
+            ui.add_space(12.0);
+
+            ui.allocate_ui_with_layout(
+                egui::vec2(ui.available_width(), 30.0),
+                egui::Layout::right_to_left(egui::Align::Center),
+                |ui| {
+                    ui.spacing_mut().item_spacing.x = 8.0;
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        "Save",
+                        BtnOpts {
+                            small: true,
+                            primary: true,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        outcome = SettingsProfileOutcome::Save;
+                    }
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        "Cancel",
+                        BtnOpts {
+                            small: true,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        outcome = SettingsProfileOutcome::Cancelled;
+                    }
+                },
+            );
+        });
+
+    outcome
+}
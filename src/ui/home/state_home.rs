@@ -1,6 +1,12 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Copyright (c) 2026 Born2BSalty
 
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::registry::model::{Game, LaunchKind};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum HomeFilter {
     #[default]
@@ -9,6 +15,29 @@ pub enum HomeFilter {
     All,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HomeSort {
+    #[default]
+    LastTouched,
+    Size,
+    Components,
+    Name,
+}
+
+impl HomeSort {
+    pub const ALL: [Self; 4] = [Self::LastTouched, Self::Size, Self::Components, Self::Name];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::LastTouched => "last touched",
+            Self::Size => "size",
+            Self::Components => "components",
+            Self::Name => "name",
+        }
+    }
+}
+
 #[must_use]
 pub const fn resolve_default_filter(
     installed_count: usize,
@@ -42,9 +71,56 @@ pub struct HomeScreenState {
 
     pub delete_target: Option<String>,
     pub reinstall_target: Option<String>,
+    pub reapply_layers_target: Option<String>,
 
     pub rename_target: Option<String>,
     pub rename_temp: String,
+
+    pub save_layer: Option<SaveLayerDraft>,
+
+    pub launch_settings: Option<LaunchDraft>,
+
+    pub search: String,
+    pub sort: HomeSort,
+    pub mod_index: BTreeMap<String, (DateTime<Utc>, Vec<String>)>,
+
+    pub tags_notes: Option<TagsNotesDraft>,
+
+    pub settings_profile: Option<SettingsProfileDraft>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TagsNotesDraft {
+    pub modlist_id: String,
+    pub tags: String,
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SettingsProfileDraft {
+    pub modlist_id: String,
+    pub profile: Option<String>,
+    pub overrides: String,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SaveLayerDraft {
+    pub modlist_id: String,
+    pub name: String,
+    pub mods: Vec<(String, bool)>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LaunchDraft {
+    pub modlist_id: String,
+    pub game: Game,
+    pub kind: LaunchKind,
+    pub program: String,
+    pub wrapper: String,
+    pub args: String,
+    pub env_text: String,
+    pub error: Option<String>,
 }
 
 impl HomeScreenState {
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use eframe::egui;
+
+use crate::ui::home::state_home::TagsNotesDraft;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
+use crate::ui::shared::redesign_tokens::{
+    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_border_strong,
+    redesign_input_bg, redesign_shell_bg, redesign_text_muted, redesign_text_primary,
+};
+
+const MAX_WIDTH_PX: f32 = 480.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TagsNotesOutcome {
+    #[default]
+    Pending,
+    Save,
+    Cancelled,
+}
+
+pub fn render(
+    ctx: &egui::Context,
+    palette: ThemePalette,
+    draft: &mut TagsNotesDraft,
+) -> TagsNotesOutcome {
+    let mut outcome = TagsNotesOutcome::Pending;
+
+    let frame = egui::Frame::default()
+        .fill(redesign_shell_bg(palette))
+        .stroke(egui::Stroke::new(
+            REDESIGN_BORDER_WIDTH_PX,
+            redesign_border_strong(palette),
+        ))
+        .corner_radius(egui::CornerRadius::same(REDESIGN_BORDER_RADIUS_U8))
+        .inner_margin(egui::Margin::same(18));
+
+    egui::Window::new("Tags and notes")
+        .id(egui::Id::new(("home_tags_notes", &draft.modlist_id)))
+        .title_bar(false)
+        .resizable(false)
+        .collapsible(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .frame(frame)
+        .show(ctx, |ui| {
+            ui.set_max_width(MAX_WIDTH_PX);
+            ui.label(
+                egui::RichText::new("Tags and notes")
+                    .size(15.0)
+                    .family(egui::FontFamily::Name("poppins_medium".into()))
+                    .color(redesign_text_primary(palette)),
+            );
+            ui.add_space(8.0);
+
+            ui.label(
+                egui::RichText::new("tags (comma separated)")
+                    .size(12.0)
+                    .color(redesign_text_muted(palette)),
+            );
+            ui.add_sized(
+                egui::vec2(ui.available_width(), 26.0),
+                egui::TextEdit::singleline(&mut draft.tags)
+                    .hint_text("tactical, test, eet")
+                    .text_color(redesign_text_primary(palette))
+                    .background_color(redesign_input_bg(palette))
+                    .margin(egui::Margin::symmetric(8, 4)),
+            );
+            ui.add_space(6.0);
+            ui.label(
+                egui::RichText::new("notes")
+                    .size(12.0)
+                    .color(redesign_text_muted(palette)),
+            );
+            ui.add_sized(
+                egui::vec2(ui.available_width(), 90.0),
+                egui::TextEdit::multiline(&mut draft.notes)
+                    .hint_text("what this modlist is for")
+                    .text_color(redesign_text_primary(palette))
+                    .background_color(redesign_input_bg(palette)),
+            );
+            ui.add_space(12.0);
+
+            ui.allocate_ui_with_layout(
+                egui::vec2(ui.available_width(), 30.0),
+                egui::Layout::right_to_left(egui::Align::Center),
+                |ui| {
+                    ui.spacing_mut().item_spacing.x = 8.0;
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        "Save",
+                        BtnOpts {
+                            small: true,
+                            primary: true,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        outcome = TagsNotesOutcome::Save;
+                    }
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        "Cancel",
+                        BtnOpts {
+                            small: true,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        outcome = TagsNotesOutcome::Cancelled;
+                    }
+                },
+            );
+        });
+
+    outcome
+}
@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use eframe::egui;
+
+use crate::install::uninstall_plan::component_label;
+use crate::install_runtime::uninstall_driver::UninstallSession;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
+use crate::ui::shared::redesign_tokens::{
+    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_border_strong,
+    redesign_shell_bg, redesign_text_muted, redesign_text_primary,
+};
+use crate::ui::shared::weidu_step_console;
+
+const MAX_WIDTH_PX: f32 = 560.0;
+const LIST_MAX_H_PX: f32 = 260.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UninstallOutcome {
+    #[default]
+    Pending,
+    Run,
+    Close,
+}
+
+fn small_btn(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    label: &str,
+    primary: bool,
+    disabled: bool,
+) -> bool {
+    redesign_btn(
+        ui,
+        palette,
+        label,
+        BtnOpts {
+            small: true,
+            primary,
+            disabled,
+            ..Default::default()
+        },
+    )
+    .clicked()
+}
+
+fn render_planning(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    session: &mut UninstallSession,
+) -> UninstallOutcome {
+    let mut outcome = UninstallOutcome::Pending;
+    ui.label(
+        egui::RichText::new(
+            "Pick the components to remove. Anything installed after them is \
+             uninstalled first and put back afterwards.",
+        )
+        .size(12.0)
+        .color(redesign_text_muted(palette)),
+    );
+    ui.add_space(8.0);
+    egui::ScrollArea::vertical()
+        .max_height(LIST_MAX_H_PX)
+        .auto_shrink([false, true])
+        .show(ui, |ui| {
+            for (index, component) in session.installed.iter().enumerate() {
+                let mut checked = session.selected.contains(&index);
+                if ui
+                    .checkbox(
+                        &mut checked,
+                        egui::RichText::new(component_label(component))
+                            .size(12.0)
+                            .color(redesign_text_primary(palette)),
+                    )
+                    .changed()
+                {
+                    if checked {
+                        session.selected.insert(index);
+                    } else {
+                        session.selected.remove(&index);
+                    }
+                }
+            }
+        });
+    ui.add_space(8.0);
+    let plan = session.plan();
+    ui.label(
+        egui::RichText::new(format!(
+            "removes {} \u{b7} uninstalls {} \u{b7} reinstalls {}",
+            plan.removed.len(),
+            plan.uninstall.len(),
+            plan.reinstall.len()
+        ))
+        .size(12.0)
+        .color(redesign_text_muted(palette)),
+    );
+    ui.add_space(12.0);
+    ui.allocate_ui_with_layout(
+        egui::vec2(ui.available_width(), 30.0),
+        egui::Layout::right_to_left(egui::Align::Center),
+        |ui| {
+            ui.spacing_mut().item_spacing.x = 8.0;
+            if small_btn(ui, palette, "Run", true, plan.is_empty()) {
+                outcome = UninstallOutcome::Run;
+            }
+            if small_btn(ui, palette, "Close", false, false) {
+                outcome = UninstallOutcome::Close;
+            }
+        },
+    );
+    outcome
+}
+
+pub fn render(
+    ctx: &egui::Context,
+    palette: ThemePalette,
+    session: &mut UninstallSession,
+) -> UninstallOutcome {
+    let mut outcome = UninstallOutcome::Pending;
+
+    let frame = egui::Frame::default()
+        .fill(redesign_shell_bg(palette))
+        .stroke(egui::Stroke::new(
+            REDESIGN_BORDER_WIDTH_PX,
+            redesign_border_strong(palette),
+        ))
+        .corner_radius(egui::CornerRadius::same(REDESIGN_BORDER_RADIUS_U8))
+        .inner_margin(egui::Margin::same(18));
+
+    egui::Window::new("Uninstall components")
+        .id(egui::Id::new(("home_uninstall", &session.modlist_id)))
+        .title_bar(false)
+        .resizable(false)
+        .collapsible(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .frame(frame)
+        .show(ctx, |ui| {
+            ui.set_max_width(MAX_WIDTH_PX);
+            ui.label(
+                egui::RichText::new(format!("Uninstall from \"{}\"", session.modlist_name))
+                    .size(15.0)
+                    .family(egui::FontFamily::Name("poppins_medium".into()))
+                    .color(redesign_text_primary(palette)),
+            );
+            ui.add_space(8.0);
+            outcome = match session.run.as_mut() {
+                Some(run) => {
+                    if weidu_step_console::render(ui, palette, run) {
+                        UninstallOutcome::Close
+                    } else {
+                        UninstallOutcome::Pending
+                    }
+                }
+                None => render_planning(ui, palette, session),
+            };
+        });
+
+    outcome
+}
@@ -1,10 +1,16 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Copyright (c) 2026 Born2BSalty
 
+use std::sync::Arc;
+use std::time::Duration;
+
 use eframe::egui;
 
 use crate::app::modlist_share::preview_modlist_share_code;
+use crate::install_runtime::disk_preflight::{self, DiskPreflight};
 use crate::install_runtime::start_hooks;
+use crate::registry::model::Game;
+use crate::registry::share_export::decode_archive_meta;
 use crate::ui::install::stage_downloading::{self, DownloadScreenCopy, DownloadingOutcome};
 use crate::ui::install::stage_installing::{self, StageInstallingOutcome};
 use crate::ui::install::stage_paste::{self, PasteOutcome};
@@ -13,6 +19,8 @@ use crate::ui::install::state_install::InstallStage;
 use crate::ui::orchestrator::nav_destination::NavDestination;
 use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
 
+const PREFLIGHT_POLL: Duration = Duration::from_millis(250);
+
 enum InstallRequest {
     Stage(InstallStage),
     Nav(NavDestination),
@@ -37,7 +45,23 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, ctx: &egui:
             }
         }
         InstallStage::Preview => {
-            match stage_preview::render(ui, palette, ctx, &mut orchestrator.install_screen_state) {
+            let preflight = preview_preflight(orchestrator);
+            if preflight.is_none()
+                && !orchestrator
+                    .install_screen_state
+                    .destination
+                    .trim()
+                    .is_empty()
+            {
+                ctx.request_repaint_after(PREFLIGHT_POLL);
+            }
+            match stage_preview::render(
+                ui,
+                palette,
+                ctx,
+                &mut orchestrator.install_screen_state,
+                preflight.as_deref(),
+            ) {
                 PreviewOutcome::Back => {
                     orchestrator.install_screen_state.clear_preview();
                     request = Some(InstallRequest::Stage(InstallStage::Paste));
@@ -45,25 +69,15 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, ctx: &egui:
                 PreviewOutcome::OpenInCreate => {
                     request = Some(InstallRequest::Nav(NavDestination::Create));
                 }
-                PreviewOutcome::Advance => {
-                    if let Some(reinstall_id) = orchestrator.pending_reinstall_id.clone() {
-                        let OrchestratorApp {
-                            wizard_state,
-                            registry,
-                            registry_store,
-                            pending_reinstall_id,
-                            ..
-                        } = &mut *orchestrator;
-                        start_hooks::reinstall_flip_at_install_click(
-                            &reinstall_id,
-                            wizard_state,
-                            registry,
-                            registry_store,
-                            pending_reinstall_id,
-                        );
+                PreviewOutcome::Advance => match advance_from_preview(orchestrator) {
+                    PreviewStart::Started => {
+                        request = Some(InstallRequest::Stage(InstallStage::Downloading));
                     }
-                    request = Some(InstallRequest::Stage(InstallStage::Downloading));
-                }
+                    PreviewStart::Pending => ctx.request_repaint_after(PREFLIGHT_POLL),
+                    PreviewStart::Blocked(reason) => {
+                        orchestrator.notification_manager.error(reason);
+                    }
+                },
                 PreviewOutcome::Stay => {}
             }
         }
@@ -103,10 +117,69 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, ctx: &egui:
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PreviewStart {
+    Started,
+    Pending,
+    Blocked(String),
+}
+
+pub(crate) fn advance_from_preview(orchestrator: &mut OrchestratorApp) -> PreviewStart {
+    let state = &orchestrator.install_screen_state;
+    if let Some(err) = state.preview_parse_error.as_deref() {
+        return PreviewStart::Blocked(err.to_string());
+    }
+    if state.destination.trim().is_empty() {
+        return PreviewStart::Blocked("no destination folder is set".to_string());
+    }
+    match preview_preflight(orchestrator) {
+        None => return PreviewStart::Pending,
+        Some(preflight) => {
+            if let Some(reason) = preflight.block_reason() {
+                return PreviewStart::Blocked(reason);
+            }
+        }
+    }
+    if let Some(reinstall_id) = orchestrator.pending_reinstall_id.clone() {
+        let OrchestratorApp {
+            wizard_state,
+            registry,
+            registry_store,
+            pending_reinstall_id,
+            ..
+        } = &mut *orchestrator;
+        start_hooks::reinstall_flip_at_install_click(
+            &reinstall_id,
+            wizard_state,
+            registry,
+            registry_store,
+            pending_reinstall_id,
+        );
+    }
+    PreviewStart::Started
+}
+
+fn preview_preflight(orchestrator: &OrchestratorApp) -> Option<Arc<DiskPreflight>> {
+    let state = &orchestrator.install_screen_state;
+    let game = Game::from_legacy_string(&state.parsed_preview.as_ref()?.game_install);
+    let input = disk_preflight::input_for_install(
+        &orchestrator.wizard_state.step1,
+        &state.destination,
+        game,
+        state.preview_archive_bytes,
+    );
+    disk_preflight::preflight_cached(&input)
+}
+
 fn run_preview_parse(state: &mut crate::ui::install::state_install::InstallScreenState) {
     state.clear_preview();
     match preview_modlist_share_code(state.import_code.trim()) {
         Ok(preview) => {
+            state.preview_archive_bytes = decode_archive_meta(state.import_code.trim())
+                .unwrap_or_default()
+                .iter()
+                .map(|meta| meta.size)
+                .fold(0u64, u64::saturating_add);
             state.parsed_preview = Some(preview);
             state.preview_cached = true;
             state.active_preview_tab = crate::ui::install::state_install::PreviewTab::default();
@@ -718,6 +718,10 @@ fn finish_pipeline_arm_after_destination_prep(
         &inputs.code,
     ) {
         Ok(_) => {
+            orchestrator.wizard_state.install_from_lock =
+                orchestrator.install_screen_state.install_from_lock == Some(true);
+            orchestrator.wizard_state.install_offline =
+                orchestrator.install_screen_state.offline_bundle;
             let settings: crate::settings::model::Step1Settings =
                 orchestrator.wizard_state.step1.clone().into();
             crate::install_runtime::flag_policies::apply_flags(
@@ -100,6 +100,7 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp) -> StageIns
                 dev_mode: orchestrator.dev_mode,
                 exe_fingerprint: &exe_fingerprint,
                 palette,
+                redaction: orchestrator.redesign_settings.diagnostics_redaction,
             },
         );
     });
@@ -4,12 +4,15 @@
 use eframe::egui;
 
 use crate::app::modlist_share::ModlistSharePreview;
+use crate::install_runtime::disk_preflight::DiskPreflight;
+use crate::ui::home::modlist_card::human_size;
 use crate::ui::install::preview_counts;
 use crate::ui::install::preview_tabs;
 use crate::ui::install::state_install::InstallScreenState;
 use crate::ui::install::sub_flow_footer::{self, BackBtn, PrimaryBtn, SecondaryBtn};
 use crate::ui::orchestrator::widgets::dialogs::fork_info_popup::{self, SelfNode};
 use crate::ui::orchestrator::widgets::{redesign_box, render_screen_title};
+use crate::ui::settings::widgets::toggle_row;
 use crate::ui::shared::redesign_tokens::{
     REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_border_strong,
     redesign_pill_danger, redesign_shell_bg, redesign_text_faint, redesign_text_muted,
@@ -24,6 +27,9 @@ Review and customize the components in Create \u{2192} Import and modify before
 const DISABLED_IMPORT_TIP: &str =
     "Auto-install disabled for draft codes \u{2014} open in Create to review";
 
+const DISK_BLOCKED_TIP: &str =
+    "Not enough free disk space \u{2014} free up space or pick another destination";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub(crate) enum PreviewOutcome {
     #[default]
@@ -38,6 +44,7 @@ pub(crate) fn render(
     palette: ThemePalette,
     ctx: &egui::Context,
     state: &mut InstallScreenState,
+    preflight: Option<&DiskPreflight>,
 ) -> PreviewOutcome {
     if let Some(err) = state.preview_parse_error.clone() {
         return render_parse_error(ui, palette, &err);
@@ -86,8 +93,9 @@ pub(crate) fn render(
         ui.add_space(12.0);
     }
 
-    render_preview_body(ui, palette, state, &preview);
-    let outcome = render_preview_footer(ui, palette, auto_install);
+    render_preview_body(ui, palette, state, &preview, preflight);
+    let disk_blocked = preflight.is_some_and(DiskPreflight::is_blocking);
+    let outcome = render_preview_footer(ui, palette, auto_install, disk_blocked);
     render_fork_popup(ctx, palette, state, &preview);
 
     outcome
@@ -106,9 +114,26 @@ fn render_preview_body(
     palette: ThemePalette,
     state: &mut InstallScreenState,
     preview: &ModlistSharePreview,
+    preflight: Option<&DiskPreflight>,
 ) {
     overview_box(ui, palette, preview);
     ui.add_space(12.0);
+    if !state.destination.trim().is_empty() {
+        disk_box(ui, palette, preflight);
+        ui.add_space(12.0);
+    }
+
+    if let Some(from_lock) = state.install_from_lock.as_mut() {
+        toggle_row::render(
+            ui,
+            palette,
+            "Install from lock",
+            from_lock,
+            Some("use modlist.lock.toml and stop if any mod's version or archive drifted"),
+            || {},
+        );
+        ui.add_space(8.0);
+    }
 
     let active_tab_rect =
         preview_tabs::render_tab_strip(ui, palette, &mut state.active_preview_tab);
@@ -146,6 +171,7 @@ fn render_preview_footer(
     ui: &mut egui::Ui,
     palette: ThemePalette,
     auto_install: bool,
+    disk_blocked: bool,
 ) -> PreviewOutcome {
     let footer = sub_flow_footer::render(
         ui,
@@ -158,14 +184,16 @@ fn render_preview_footer(
                 label: "Open in Create",
             })
         },
-        Some(if auto_install {
-            "downloads, extracts, then runs install \u{2014} no review step"
-        } else {
+        Some(if !auto_install {
             DISABLED_IMPORT_TIP
+        } else if disk_blocked {
+            DISK_BLOCKED_TIP
+        } else {
+            "downloads, extracts, then runs install \u{2014} no review step"
         }),
         PrimaryBtn {
             label: "Import Modlist",
-            disabled: !auto_install,
+            disabled: !auto_install || disk_blocked,
         },
     );
 
@@ -237,6 +265,56 @@ fn overview_box(ui: &mut egui::Ui, palette: ThemePalette, p: &ModlistSharePrevie
     });
 }
 
+fn disk_box(ui: &mut egui::Ui, palette: ThemePalette, preflight: Option<&DiskPreflight>) {
+    redesign_box(ui, palette, Some("disk space"), |ui| {
+        let Some(preflight) = preflight else {
+            ui.label(
+                egui::RichText::new("checking free space\u{2026}")
+                    .size(13.0)
+                    .color(redesign_text_faint(palette)),
+            );
+            return;
+        };
+        ui.label(
+            egui::RichText::new(format!(
+                "archives {} \u{00B7} extracted ~{} \u{00B7} game copy {}",
+                human_size(preflight.archive_bytes),
+                human_size(preflight.extracted_bytes),
+                human_size(preflight.game_copy_bytes),
+            ))
+            .size(13.0)
+            .color(redesign_text_muted(palette)),
+        );
+        for volume in &preflight.volumes {
+            let free = volume
+                .available_bytes
+                .map_or_else(|| "unknown".to_string(), human_size);
+            let color = if volume.is_short() {
+                redesign_pill_danger(palette)
+            } else {
+                redesign_text_primary(palette)
+            };
+            ui.label(
+                egui::RichText::new(format!(
+                    "{}: needs {} ({}), {free} free",
+                    volume.mount,
+                    human_size(volume.required_bytes),
+                    volume.purposes.join(", "),
+                ))
+                .size(13.0)
+                .color(color),
+            );
+        }
+        for warning in preflight.warnings() {
+            ui.label(
+                egui::RichText::new(format!("\u{26A0} {warning}"))
+                    .size(12.0)
+                    .color(redesign_text_faint(palette)),
+            );
+        }
+    });
+}
+
 fn overview_cell(ui: &mut egui::Ui, palette: ThemePalette, width: f32, label: &str, value: &str) {
     ui.allocate_ui_with_layout(
         egui::vec2(width, 22.0),
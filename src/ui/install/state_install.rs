@@ -216,6 +216,8 @@ pub struct InstallScreenState {
     pub stage: InstallStage,
     pub destination: String,
     pub destination_choice: Option<DestChoice>,
+    pub install_from_lock: Option<bool>,
+    pub offline_bundle: bool,
     pub import_code: String,
     pub(crate) parsed_preview: Option<ModlistSharePreview>,
     pub preview_parse_error: Option<String>,
@@ -232,6 +234,7 @@ pub struct InstallScreenState {
     pub expected_archive_sizes: std::collections::BTreeMap<usize, u64>,
     pub skip_indices: std::collections::HashSet<usize>,
     pub hashed_indices: std::collections::HashSet<usize>,
+    pub preview_archive_bytes: u64,
 }
 
 impl InstallScreenState {
@@ -282,6 +285,8 @@ impl InstallScreenState {
         self.expected_archive_sizes = std::collections::BTreeMap::new();
         self.skip_indices = std::collections::HashSet::new();
         self.hashed_indices = std::collections::HashSet::new();
+        self.offline_bundle = false;
+        self.preview_archive_bytes = 0;
     }
 }
 
@@ -12,6 +12,9 @@ use tracing::warn;
 
 use crate::app::app_bootstrap_init;
 use crate::app::app_step1_github_oauth::GitHubOAuthFlowResult;
+use crate::app::modlist_update_schedule::{
+    ScheduledUpdateReport, UPDATE_SCHEDULE_POLL, due_targets, spawn_scheduled_update_check,
+};
 use crate::app::state::WizardState;
 use crate::app::step2_worker::Step2ScanEvent;
 use crate::app::step5::install_flow::PendingInstallStart;
@@ -26,14 +29,22 @@ use crate::install_runtime::flag_policies::InstallWorkflow;
 use crate::install_runtime::install_concurrency;
 use crate::install_runtime::rail_lock_reason::RailLockReason;
 use crate::install_runtime::registry_transition;
+use crate::install_runtime::{
+    apply_changes_driver, game_launcher, install_queue_driver, modlist_health,
+    settings_profiles_driver, uninstall_driver, weidu_step_run,
+};
 use crate::registry::errors::RegistryError;
+use crate::registry::install_queue::{InstallQueue, InstallQueueStore};
 use crate::registry::model::Game;
 use crate::registry::model::ModlistRegistry;
+use crate::registry::modlist_layers::{LayerLibrary, LayerStore};
 use crate::registry::persistence_cycle::RegistryPersistenceCycle;
 use crate::registry::store::RegistryStore;
 use crate::registry::store_workspace::WorkspaceStore;
+use crate::registry::workspace_history::WorkspaceHistoryStore;
 use crate::registry::workspace_model::ModlistWorkspaceState;
 use crate::settings::model::AppSettings;
+use crate::settings::profiles::{ProfilesStore, SettingsProfiles};
 use crate::settings::redesign_fields::{RedesignSettings, ThemeChoice};
 use crate::settings::redesign_store::RedesignSettingsStore;
 use crate::settings::store::SettingsStore;
@@ -104,6 +115,12 @@ pub(crate) struct PendingFolderDelete {
     pub(crate) rx: crate::registry::operations::FolderDeleteReceiver,
 }
 
+pub(crate) struct PendingBundleExport {
+    pub(crate) modlist_name: String,
+    pub(crate) rx:
+        Receiver<Result<crate::install_runtime::offline_bundle::BundleExportReport, String>>,
+}
+
 pub(crate) struct PendingCreateStart {
     pub(crate) token: DestinationPrepToken,
     pub(crate) name: String,
@@ -245,6 +262,7 @@ pub struct OrchestratorApp {
     pub persistence_cycle: RegistryPersistenceCycle,
     pub workspace_state: HashMap<String, ModlistWorkspaceState>,
     pub workspace_stores: HashMap<String, WorkspaceStore>,
+    pub(crate) workspace_load_errors: HashMap<String, String>,
     pub home_stub_state: HomeStubState,
 
     pub home_screen_state: HomeScreenState,
@@ -280,6 +298,17 @@ pub struct OrchestratorApp {
     pub(crate) install_size_worker_rx:
         Option<crate::install_runtime::registry_transition::SizeWorkerReceiver>,
 
+    pub(crate) install_queue: InstallQueue,
+    pub(crate) install_queue_store: InstallQueueStore,
+    pub(crate) install_queue_prompt: install_queue_driver::PromptWatch,
+    pub(crate) layer_library: LayerLibrary,
+    pub(crate) layer_store: LayerStore,
+    pub(crate) game_sessions: Vec<crate::install_runtime::game_launcher::GameSession>,
+
+    pub(crate) update_schedule_rx: Option<Receiver<ScheduledUpdateReport>>,
+    pub(crate) update_schedule_next_poll: Option<Instant>,
+    pub(crate) update_schedule_paused_until: Option<chrono::DateTime<chrono::Utc>>,
+
     pub step5_terminal: Option<EmbeddedTerminal>,
     pub step5_terminal_error: Option<String>,
     pub step5_console_view: Step5ConsoleViewState,
@@ -314,6 +343,23 @@ pub struct OrchestratorApp {
     pub(crate) hash_progress: Arc<std::sync::Mutex<Option<(usize, usize)>>>,
 
     pub(crate) pending_folder_deletes: Vec<PendingFolderDelete>,
+
+    pub(crate) offline_bundle_unpack_rx:
+        Option<Receiver<Result<crate::install_runtime::offline_bundle::UnpackedBundle, String>>>,
+
+    pub(crate) pending_bundle_exports: Vec<PendingBundleExport>,
+
+    pub(crate) health_checks: Vec<modlist_health::HealthCheck>,
+
+    pub(crate) uninstall: Option<uninstall_driver::UninstallSession>,
+
+    pub(crate) apply_changes: Option<apply_changes_driver::ApplyChangesSession>,
+
+    pub(crate) settings_profiles: SettingsProfiles,
+    pub(crate) settings_profiles_store: ProfilesStore,
+    pub(crate) settings_layers: Option<settings_profiles_driver::AppliedLayers>,
+
+    pub(crate) game_discovery_rx: Option<Receiver<Vec<crate::game_discovery::DiscoveredInstall>>>,
 }
 
 fn load_registry(registry_store: &RegistryStore) -> RegistryLoad {
@@ -328,15 +374,18 @@ fn load_registry(registry_store: &RegistryStore) -> RegistryLoad {
                 target = "orchestrator",
                 "modlists.json load failed: {err}; backing up and entering terminal-error state"
             );
-            let registry_backup_path = match registry_store.backup_corrupt_file() {
-                Ok(new_path) => Some(new_path),
-                Err(backup_err) => {
-                    warn!(
-                        target = "orchestrator",
-                        "backup_corrupt_file failed: {backup_err}"
-                    );
-                    None
-                }
+            let registry_backup_path = match &err {
+                RegistryError::UnsupportedVersion { .. } => None,
+                _ => match registry_store.backup_corrupt_file() {
+                    Ok(new_path) => Some(new_path),
+                    Err(backup_err) => {
+                        warn!(
+                            target = "orchestrator",
+                            "backup_corrupt_file failed: {backup_err}"
+                        );
+                        None
+                    }
+                },
             };
             RegistryLoad {
                 registry: ModlistRegistry::default(),
@@ -347,6 +396,35 @@ fn load_registry(registry_store: &RegistryStore) -> RegistryLoad {
     }
 }
 
+fn load_install_queue(store: &InstallQueueStore) -> InstallQueue {
+    let mut queue = store.load().unwrap_or_else(|err| {
+        warn!(
+            target = "orchestrator",
+            "install queue load failed: {err}; starting with an empty queue"
+        );
+        InstallQueue::default()
+    });
+    if queue.recover_interrupted()
+        && let Err(err) = store.save(&queue)
+    {
+        warn!(
+            target = "orchestrator",
+            "install queue write after recovery failed: {err}"
+        );
+    }
+    queue
+}
+
+fn load_layer_library(store: &LayerStore) -> LayerLibrary {
+    store.load().unwrap_or_else(|err| {
+        warn!(
+            target = "orchestrator",
+            "modlist layers load failed: {err}; starting with an empty library"
+        );
+        LayerLibrary::default()
+    })
+}
+
 fn load_redesign_settings(store: &RedesignSettingsStore) -> RedesignSettings {
     match store.load() {
         Ok(settings) => settings,
@@ -393,6 +471,13 @@ impl OrchestratorApp {
 
         let persistence_cycle = RegistryPersistenceCycle::new_with_baseline(registry.clone());
 
+        let install_queue_store = InstallQueueStore::new_default();
+        let install_queue = load_install_queue(&install_queue_store);
+        let layer_store = LayerStore::new_default();
+        let layer_library = load_layer_library(&layer_store);
+        let settings_profiles_store = ProfilesStore::new_default();
+        let settings_profiles = settings_profiles_driver::load_profiles(&settings_profiles_store);
+
         let redesign_settings_store = RedesignSettingsStore::new_default();
         let redesign_settings = load_redesign_settings(&redesign_settings_store);
         let theme_palette = match redesign_settings.theme_palette {
@@ -423,6 +508,7 @@ impl OrchestratorApp {
             persistence_cycle,
             workspace_state: HashMap::new(),
             workspace_stores: HashMap::new(),
+            workspace_load_errors: HashMap::new(),
             home_stub_state: HomeStubState::default(),
             home_screen_state: HomeScreenState::default(),
             notification_manager: NotificationManager::new(),
@@ -450,6 +536,15 @@ impl OrchestratorApp {
             post_install_reset_gate: PostInstallResetGate::Idle,
             install_running_since: None,
             install_size_worker_rx: None,
+            install_queue,
+            install_queue_store,
+            install_queue_prompt: install_queue_driver::PromptWatch::default(),
+            layer_library,
+            layer_store,
+            game_sessions: Vec::new(),
+            update_schedule_rx: None,
+            update_schedule_next_poll: None,
+            update_schedule_paused_until: None,
             step5_terminal: None,
             step5_terminal_error: None,
             step5_console_view: Step5ConsoleViewState::default(),
@@ -473,7 +568,20 @@ impl OrchestratorApp {
             destination_prep_generation: 0,
             hash_progress: Arc::new(std::sync::Mutex::new(None)),
             pending_folder_deletes: Vec::new(),
+            offline_bundle_unpack_rx: None,
+            pending_bundle_exports: Vec::new(),
+            health_checks: Vec::new(),
+            uninstall: None,
+            apply_changes: None,
+            settings_profiles,
+            settings_profiles_store,
+            settings_layers: None,
+            game_discovery_rx: None,
         };
+        settings_profiles_driver::apply(&mut app, None);
+        if no_game_paths_configured(&app.wizard_state.step1) {
+            app.start_game_discovery();
+        }
 
         if app.redesign_settings.validate_paths_on_startup {
             app.settings_screen_state.path_validation_results =
@@ -577,6 +685,33 @@ impl OrchestratorApp {
         self.step5_console_view = Step5ConsoleViewState::default();
     }
 
+    fn record_install_history(&mut self) {
+        let Some(id) = self
+            .workspace_view
+            .loaded_workspace_id
+            .clone()
+            .or_else(|| self.active_install_modlist_id.clone())
+        else {
+            return;
+        };
+        let console = self
+            .step5_terminal
+            .as_ref()
+            .map(crate::app::terminal::EmbeddedTerminal::console_text)
+            .unwrap_or_default();
+        if let Err(err) = crate::install_runtime::install_history_record::record_finished_run(
+            &id,
+            &self.wizard_state,
+            &console,
+        ) {
+            warn!(
+                target = "orchestrator",
+                "recording install history for {id} failed: {err}"
+            );
+        }
+        self.workspace_view.install_history = None;
+    }
+
     fn maybe_flip_to_installed_on_clean_exit(&mut self) {
         if !crate::ui::workspace::step5::success_banner::clean_exit(&self.wizard_state) {
             return;
@@ -648,13 +783,94 @@ impl OrchestratorApp {
     }
 
     const fn slow_workers_active(&self) -> bool {
-        self.install_size_worker_rx.is_some() || !self.pending_folder_deletes.is_empty()
+        self.install_size_worker_rx.is_some()
+            || !self.pending_folder_deletes.is_empty()
+            || !self.pending_bundle_exports.is_empty()
+            || !self.health_checks.is_empty()
+            || self.offline_bundle_unpack_rx.is_some()
+            || self.game_discovery_rx.is_some()
+            || self.update_schedule_rx.is_some()
     }
 
     fn drain_background_workers(&mut self) {
         self.drain_size_worker_result();
         self.drain_folder_deletes();
+        self.drain_bundle_exports();
+        modlist_health::drain(self);
+        self.drain_game_discovery();
         self.drain_finished_destination_prep_workers();
+        self.tick_update_schedule();
+    }
+
+    fn tick_update_schedule(&mut self) {
+        use std::sync::mpsc::TryRecvError;
+
+        if let Some(rx) = self.update_schedule_rx.as_ref() {
+            match rx.try_recv() {
+                Ok(report) => {
+                    self.update_schedule_rx = None;
+                    self.apply_scheduled_update_report(report);
+                }
+                Err(TryRecvError::Empty) => {}
+                Err(TryRecvError::Disconnected) => {
+                    warn!(
+                        target = "orchestrator",
+                        "scheduled update check worker disconnected without a result"
+                    );
+                    self.update_schedule_rx = None;
+                }
+            }
+            return;
+        }
+
+        let checks = self.redesign_settings.background_update_checks;
+        let now = Instant::now();
+        if !checks.enabled
+            || self.wizard_state.install_offline
+            || self.wizard_state.step5.install_running
+            || self
+                .update_schedule_next_poll
+                .is_some_and(|next_poll| now < next_poll)
+        {
+            return;
+        }
+        self.update_schedule_next_poll = Some(now + UPDATE_SCHEDULE_POLL);
+        let utc_now = chrono::Utc::now();
+        if self
+            .update_schedule_paused_until
+            .is_some_and(|until| utc_now < until)
+        {
+            return;
+        }
+        let targets = due_targets(&self.registry, checks.interval_hours, utc_now);
+        if !targets.is_empty() {
+            self.update_schedule_rx = Some(spawn_scheduled_update_check(targets));
+        }
+    }
+
+    fn apply_scheduled_update_report(&mut self, report: ScheduledUpdateReport) {
+        self.update_schedule_paused_until = report.rate_limited_until;
+        if report.statuses.is_empty() {
+            return;
+        }
+        for (modlist_id, status) in report.statuses {
+            let Some(entry) = self.registry.find_mut(&modlist_id) else {
+                continue;
+            };
+            let previous = entry
+                .update_status
+                .as_ref()
+                .map_or(0, |previous| previous.updates.len());
+            if status.updates.len() > previous {
+                self.notification_manager.info(format!(
+                    "Updates available for \"{}\": {} mod(s)",
+                    entry.name,
+                    status.updates.len()
+                ));
+            }
+            entry.update_status = Some(status);
+        }
+        self.persistence_cycle.mark_registry_dirty(Instant::now());
     }
 
     pub(crate) fn drain_folder_deletes(&mut self) {
@@ -687,6 +903,67 @@ impl OrchestratorApp {
         }
     }
 
+    fn drain_bundle_exports(&mut self) {
+        use std::sync::mpsc::TryRecvError;
+
+        let mut i = 0;
+        while i < self.pending_bundle_exports.len() {
+            let result = match self.pending_bundle_exports[i].rx.try_recv() {
+                Ok(result) => result,
+                Err(TryRecvError::Disconnected) => Err("worker disconnected".to_string()),
+                Err(TryRecvError::Empty) => {
+                    i += 1;
+                    continue;
+                }
+            };
+            let name = self.pending_bundle_exports.swap_remove(i).modlist_name;
+            match result {
+                Ok(report) => self.notification_manager.success(format!(
+                    "Exported \"{name}\" with {} archive(s) to {}",
+                    report.archives,
+                    report.output.display()
+                )),
+                Err(err) => self
+                    .notification_manager
+                    .error(format!("Couldn't export bundle for \"{name}\": {err}")),
+            }
+        }
+    }
+
+    pub(crate) fn start_game_discovery(&mut self) {
+        if self.game_discovery_rx.is_some() {
+            return;
+        }
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = tx.send(crate::game_discovery::discover());
+        });
+        self.game_discovery_rx = Some(rx);
+    }
+
+    fn drain_game_discovery(&mut self) {
+        use std::sync::mpsc::TryRecvError;
+
+        let Some(rx) = self.game_discovery_rx.as_ref() else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok(found) => {
+                self.settings_screen_state.discovered_installs = found;
+                self.settings_screen_state.discovery_scanned = true;
+                self.game_discovery_rx = None;
+            }
+            Err(TryRecvError::Empty) => {}
+            Err(TryRecvError::Disconnected) => {
+                warn!(
+                    target = "orchestrator",
+                    "game discovery worker disconnected without a result"
+                );
+                self.game_discovery_rx = None;
+            }
+        }
+    }
+
     fn drain_size_worker_result(&mut self) {
         use std::sync::mpsc::TryRecvError;
 
@@ -962,6 +1239,7 @@ impl OrchestratorApp {
         *extract_parallel_rx = None;
         wizard_state.step2.update_selected_extract_running = false;
         wizard_state.step2.update_selected_extracted_sources = result.extracted;
+        crate::install_runtime::modlist_lock::record_extracted(wizard_state);
 
         let extracted_labels: HashSet<String> = wizard_state
             .step2
@@ -1149,24 +1427,35 @@ impl OrchestratorApp {
             &mut self.step5_terminal,
             &mut self.step5_terminal_error,
         );
-        step5_requested_repaint |= app_step5_flow::poll_step5_prep(
-            &mut self.wizard_state,
-            &mut self.step5_prep_rx,
-            &mut self.step5_terminal,
-            &mut self.step5_terminal_error,
-            &mut self.step5_pending_start,
-        );
+        if self.step5_prep_rx.is_some() {
+            step5_requested_repaint |=
+                settings_profiles_driver::with_effective_step1(self, |app| {
+                    app_step5_flow::poll_step5_prep(
+                        &mut app.wizard_state,
+                        &mut app.step5_prep_rx,
+                        &mut app.step5_terminal,
+                        &mut app.step5_terminal_error,
+                        &mut app.step5_pending_start,
+                    )
+                });
+        }
         step5_requested_repaint
     }
 
     fn start_step5_after_render(&mut self) -> bool {
-        app_step5_flow::start_if_requested(
-            &mut self.wizard_state,
-            &mut self.step5_terminal,
-            &mut self.step5_terminal_error,
-            &mut self.step5_prep_rx,
-            &mut self.step5_pending_start,
-        )
+        if !self.wizard_state.step5.start_install_requested || self.wizard_state.step5.prep_running
+        {
+            return false;
+        }
+        settings_profiles_driver::with_effective_step1(self, |app| {
+            app_step5_flow::start_if_requested(
+                &mut app.wizard_state,
+                &mut app.step5_terminal,
+                &mut app.step5_terminal_error,
+                &mut app.step5_prep_rx,
+                &mut app.step5_pending_start,
+            )
+        })
     }
 
     fn step5_needs_repaint(&self) -> bool {
@@ -1246,14 +1535,27 @@ impl OrchestratorApp {
             let Some(store) = self.workspace_stores.get(id) else {
                 continue;
             };
-            if let Err(err) = self
+            match self
                 .persistence_cycle
                 .persist_workspace_if_needed(id, ws, store, now)
             {
-                warn!(
-                    target = "orchestrator",
-                    "persist_workspace_if_needed({id}) failed: {err}"
-                );
+                Ok(true) => {
+                    if let Err(err) =
+                        WorkspaceHistoryStore::beside_workspace(store.path()).record_auto(ws)
+                    {
+                        warn!(
+                            target = "orchestrator",
+                            "workspace history snapshot for {id} failed: {err}"
+                        );
+                    }
+                }
+                Ok(false) => {}
+                Err(err) => {
+                    warn!(
+                        target = "orchestrator",
+                        "persist_workspace_if_needed({id}) failed: {err}"
+                    );
+                }
             }
         }
 
@@ -1460,7 +1762,9 @@ impl eframe::App for OrchestratorApp {
         }
         if install_was_running && !self.wizard_state.step5.install_running {
             self.install_running_since = None;
+            self.record_install_history();
             self.maybe_flip_to_installed_on_clean_exit();
+            install_queue_driver::on_install_finished(self);
         }
 
         self.refresh_path_validation_status();
@@ -1540,7 +1844,19 @@ impl eframe::App for OrchestratorApp {
             self.slow_workers_active(),
         );
 
+        if self.redesign_settings.background_update_checks.enabled {
+            ctx.request_repaint_after(UPDATE_SCHEDULE_POLL);
+        }
         self.drain_background_workers();
+        if game_launcher::drain_sessions(self) {
+            ctx.request_repaint_after(game_launcher::SESSION_POLL);
+        }
+        if uninstall_driver::tick(self) | apply_changes_driver::tick(self) {
+            ctx.request_repaint_after(weidu_step_run::STEP_POLL);
+        }
+        if install_queue_driver::tick(self) {
+            ctx.request_repaint();
+        }
 
         self.sync_active_workspace_if_dirty();
 
@@ -1606,6 +1922,18 @@ fn next_debounce_due_in(app: &OrchestratorApp) -> Option<std::time::Duration> {
         .min()
 }
 
+fn no_game_paths_configured(step1: &crate::app::state::Step1State) -> bool {
+    [
+        &step1.bgee_game_folder,
+        &step1.bg2ee_game_folder,
+        &step1.iwdee_game_folder,
+        &step1.eet_bgee_game_folder,
+        &step1.eet_bg2ee_game_folder,
+    ]
+    .iter()
+    .all(|folder| folder.trim().is_empty())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
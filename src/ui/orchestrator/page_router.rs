@@ -4,8 +4,10 @@
 use eframe::egui;
 use tracing::warn;
 
+use crate::install_runtime::settings_profiles_driver;
 use crate::registry::model::ModlistEntry;
 use crate::registry::store_workspace::WorkspaceStore;
+use crate::registry::workspace_history::WorkspaceHistoryStore;
 use crate::registry::workspace_model::ModlistWorkspaceState;
 use crate::ui::create::state_create::CreateStage;
 use crate::ui::home::page_home;
@@ -17,6 +19,9 @@ use crate::ui::orchestrator::orchestrator_app::{
 };
 use crate::ui::orchestrator::registry_error_panel;
 use crate::ui::orchestrator::stubs;
+use crate::ui::orchestrator::widgets::{
+    BtnOpts, redesign_box, redesign_btn, redesign_label, render_screen_title,
+};
 use crate::ui::settings::page_settings;
 use crate::ui::shared::redesign_tokens::{ThemePalette, redesign_text_faint};
 use crate::ui::workspace::state_workspace::{ForkMeta, WorkspaceStep, WorkspaceStep2State};
@@ -39,6 +44,7 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, ctx: &egui:
     }
 
     flush_workspace_on_nav_away(orchestrator);
+    settings_profiles_driver::leave_modlist_on_nav(orchestrator);
     invalidate_destination_prep_on_route_change(orchestrator);
 
     reset_completed_install_route_on_nav_away(orchestrator);
@@ -102,6 +108,11 @@ fn render_workspace(
         return;
     }
 
+    if orchestrator.workspace_load_errors.contains_key(id) {
+        render_workspace_load_error(ui, orchestrator, id, &entry.name);
+        return;
+    }
+
     if orchestrator.workspace_view.loaded_workspace_id.as_deref() != Some(id) {
         let has_pending =
             orchestrator.step5_prep_rx.is_some() || orchestrator.step5_pending_start.is_some();
@@ -117,16 +128,33 @@ fn render_workspace(
 
         if !orchestrator.workspace_state.contains_key(id) {
             let store = WorkspaceStore::new_for_id(id);
-            let loaded = match store.load() {
-                Ok(ws) => ws,
-                Err(err) => {
-                    warn!(
-                        target = "orchestrator",
-                        "workspace.json for {id} not loadable ({err}); using empty state \
-                         (per-workspace terminal-error UI deferred to the persistence run)"
-                    );
-                    ModlistWorkspaceState::default()
+            let loaded = if store.path().exists() {
+                match store.load() {
+                    Ok(ws) => {
+                        if let Err(err) =
+                            WorkspaceHistoryStore::beside_workspace(store.path()).record_auto(&ws)
+                        {
+                            warn!(
+                                target = "orchestrator",
+                                "workspace history baseline for {id} failed: {err}"
+                            );
+                        }
+                        ws
+                    }
+                    Err(err) => {
+                        warn!(
+                            target = "orchestrator",
+                            "workspace.json for {id} not loadable ({err}); opening read-only"
+                        );
+                        orchestrator
+                            .workspace_load_errors
+                            .insert(id.to_string(), err.to_string());
+                        render_workspace_load_error(ui, orchestrator, id, &entry.name);
+                        return;
+                    }
                 }
+            } else {
+                ModlistWorkspaceState::default()
             };
             orchestrator.workspace_state.insert(id.to_string(), loaded);
             orchestrator.workspace_stores.insert(id.to_string(), store);
@@ -144,6 +172,7 @@ fn render_workspace(
             &orchestrator.settings_store,
             &mut orchestrator.wizard_state,
         );
+        settings_profiles_driver::apply(orchestrator, Some(id));
 
         orchestrator.workspace_view.modlist_id = id.to_string();
         orchestrator
@@ -156,6 +185,7 @@ fn render_workspace(
         orchestrator.workspace_view.step2 =
             crate::ui::workspace::state_workspace::WorkspaceStep2State::default();
         orchestrator.workspace_view.loaded_workspace_id = Some(id.to_string());
+        orchestrator.workspace_view.prompt_dry_run = None;
         orchestrator.workspace_view.fork_meta = fork_meta_from_entry(&entry);
         orchestrator.workspace_step5.reset_for_modlist();
 
@@ -346,7 +376,7 @@ fn reset_completed_install_route_on_enter_install(
     reset_completed_install_runtime(orchestrator);
 }
 
-fn reset_completed_install_runtime(orchestrator: &mut OrchestratorApp) {
+pub(crate) fn reset_completed_install_runtime(orchestrator: &mut OrchestratorApp) {
     orchestrator.post_install_reset_gate =
         crate::ui::orchestrator::orchestrator_app::PostInstallResetGate::Idle;
     if let Some(term) = orchestrator.step5_terminal.as_mut() {
@@ -443,6 +473,71 @@ fn sync_share_provenance_from_entry(orchestrator: &mut OrchestratorApp, entry: &
     );
 }
 
+fn render_workspace_load_error(
+    ui: &mut egui::Ui,
+    orchestrator: &mut OrchestratorApp,
+    id: &str,
+    name: &str,
+) {
+    let palette = orchestrator.theme_palette;
+    let err = orchestrator
+        .workspace_load_errors
+        .get(id)
+        .cloned()
+        .unwrap_or_default();
+    render_screen_title(
+        ui,
+        palette,
+        name,
+        Some("This workspace could not be read and is open read-only."),
+    );
+    let mut retry = false;
+    let mut home = false;
+    redesign_box(ui, palette, Some("workspace error"), |ui| {
+        let _ = redesign_label(ui, palette, &err);
+        ui.add_space(8.0);
+        ui.label(
+            egui::RichText::new(
+                "The file was left untouched and nothing will be saved for this modlist \
+                 until it loads. Restore it from a backup or open it with a newer build.",
+            )
+            .size(12.0)
+            .family(egui::FontFamily::Proportional)
+            .color(redesign_text_faint(palette)),
+        );
+        ui.add_space(10.0);
+        ui.horizontal(|ui| {
+            retry = redesign_btn(
+                ui,
+                palette,
+                "Retry",
+                BtnOpts {
+                    small: true,
+                    primary: true,
+                    ..Default::default()
+                },
+            )
+            .clicked();
+            home = redesign_btn(
+                ui,
+                palette,
+                "Back to Home",
+                BtnOpts {
+                    small: true,
+                    ..Default::default()
+                },
+            )
+            .clicked();
+        });
+    });
+    if retry {
+        orchestrator.workspace_load_errors.remove(id);
+    }
+    if home {
+        orchestrator.nav = NavDestination::Home;
+    }
+}
+
 fn render_missing_modlist(ui: &mut egui::Ui, palette: ThemePalette, id: &str) {
     ui.add_space(8.0);
     ui.label(
@@ -88,5 +88,16 @@ fn error_summary(err: &RegistryError) -> (String, String) {
             String::from("(see log)"),
             format!("parse error: {parse_err}"),
         ),
+        RegistryError::UnsupportedVersion {
+            path,
+            found,
+            supported,
+        } => (
+            path.display().to_string(),
+            format!(
+                "written by a newer BIO (format {found}); this build reads up to format {supported}. \
+                 Update BIO to open it; the file was left untouched."
+            ),
+        ),
     }
 }
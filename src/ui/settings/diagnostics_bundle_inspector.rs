@@ -0,0 +1,227 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use chrono::Local;
+use eframe::egui;
+
+use crate::app::step5::diagnostics_bundle::{InspectedBundle, inspect_bundle};
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
+use crate::ui::shared::redesign_tokens::{
+    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_accent_deep,
+    redesign_border_strong, redesign_input_bg, redesign_shell_bg, redesign_text_faint,
+    redesign_text_muted, redesign_text_primary,
+};
+use crate::ui::shared::redesign_visuals::redesign_overlay_shadow;
+
+const WINDOW_SIZE: egui::Vec2 = egui::vec2(860.0, 520.0);
+
+const FILE_LIST_WIDTH_PX: f32 = 260.0;
+
+pub fn pick_and_open(orchestrator: &mut OrchestratorApp) {
+    let Some(path) = rfd::FileDialog::new()
+        .add_filter("BIO diagnostics bundle", &["zip"])
+        .pick_file()
+    else {
+        return;
+    };
+    match inspect_bundle(&path) {
+        Ok(bundle) => {
+            orchestrator.settings_screen_state.inspected_bundle = Some(bundle);
+            orchestrator.settings_screen_state.inspected_bundle_file = 0;
+        }
+        Err(err) => {
+            orchestrator
+                .notification_manager
+                .error(format!("Couldn't open diagnostics bundle: {err:#}"));
+        }
+    }
+}
+
+pub fn render(orchestrator: &mut OrchestratorApp, palette: ThemePalette, ctx: &egui::Context) {
+    let state = &mut orchestrator.settings_screen_state;
+    let Some(bundle) = state.inspected_bundle.as_ref() else {
+        return;
+    };
+    let mut selected = state.inspected_bundle_file;
+    let mut close = false;
+
+    let frame = egui::Frame::default()
+        .fill(redesign_shell_bg(palette))
+        .stroke(egui::Stroke::new(
+            REDESIGN_BORDER_WIDTH_PX,
+            redesign_border_strong(palette),
+        ))
+        .corner_radius(egui::CornerRadius::same(REDESIGN_BORDER_RADIUS_U8))
+        .shadow(redesign_overlay_shadow(palette))
+        .inner_margin(egui::Margin::same(18));
+
+    egui::Window::new("settings_diagnostics_bundle_inspector")
+        .id(egui::Id::new("settings_diagnostics_bundle_inspector"))
+        .title_bar(false)
+        .resizable(false)
+        .collapsible(false)
+        .fixed_size(WINDOW_SIZE)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .frame(frame)
+        .show(ctx, |ui| {
+            render_header(ui, palette, bundle);
+            ui.add_space(10.0);
+            let body_height = WINDOW_SIZE.y - 120.0;
+            ui.horizontal_top(|ui| {
+                ui.allocate_ui(egui::vec2(FILE_LIST_WIDTH_PX, body_height), |ui| {
+                    render_file_list(ui, palette, bundle, &mut selected);
+                });
+                ui.separator();
+                ui.allocate_ui(egui::vec2(ui.available_width(), body_height), |ui| {
+                    render_file_view(ui, palette, bundle, selected);
+                });
+            });
+            ui.add_space(10.0);
+            ui.allocate_ui_with_layout(
+                egui::vec2(ui.available_width(), 30.0),
+                egui::Layout::right_to_left(egui::Align::Center),
+                |ui| {
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        "Close",
+                        BtnOpts {
+                            small: true,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        close = true;
+                    }
+                },
+            );
+        });
+
+    state.inspected_bundle_file = selected;
+    if close {
+        state.inspected_bundle = None;
+        state.inspected_bundle_file = 0;
+    }
+}
+
+fn render_header(ui: &mut egui::Ui, palette: ThemePalette, bundle: &InspectedBundle) {
+    let manifest = &bundle.manifest;
+    ui.label(
+        egui::RichText::new(format!("Inspect run {}", manifest.run_id))
+            .size(15.0)
+            .family(egui::FontFamily::Name("poppins_medium".into()))
+            .color(redesign_text_primary(palette)),
+    );
+    let created = manifest.created_at.map_or_else(
+        || "unknown time".to_string(),
+        |t| t.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string(),
+    );
+    let redaction = manifest.redaction;
+    let redacted: Vec<&str> = [
+        (redaction.user_paths, "paths"),
+        (redaction.usernames, "usernames"),
+        (redaction.tokens, "tokens"),
+    ]
+    .into_iter()
+    .filter_map(|(on, label)| on.then_some(label))
+    .collect();
+    ui.label(
+        egui::RichText::new(format!(
+            "{} \u{00B7} BIO {} \u{00B7} {created} \u{00B7} redacted: {} \u{00B7} read-only",
+            bundle.source.display(),
+            manifest.bio_version,
+            if redacted.is_empty() {
+                "nothing".to_string()
+            } else {
+                redacted.join(", ")
+            }
+        ))
+        .size(11.0)
+        .family(egui::FontFamily::Proportional)
+        .color(redesign_text_muted(palette)),
+    );
+}
+
+fn render_file_list(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    bundle: &InspectedBundle,
+    selected: &mut usize,
+) {
+    egui::ScrollArea::vertical()
+        .id_salt("diagnostics_bundle_files")
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            for (index, file) in bundle.files.iter().enumerate() {
+                let truncated = bundle
+                    .manifest
+                    .files
+                    .iter()
+                    .any(|f| f.path == file.path && f.truncated);
+                let color = if index == *selected {
+                    redesign_accent_deep(palette)
+                } else {
+                    redesign_text_primary(palette)
+                };
+                let label = if truncated {
+                    format!("{} (trimmed)", file.path)
+                } else {
+                    file.path.clone()
+                };
+                let text = egui::RichText::new(label)
+                    .size(12.0)
+                    .family(egui::FontFamily::Monospace)
+                    .color(color);
+                if ui.selectable_label(index == *selected, text).clicked() {
+                    *selected = index;
+                }
+            }
+            for file in bundle.manifest.files.iter().filter(|f| f.omitted) {
+                ui.label(
+                    egui::RichText::new(format!("{} (omitted)", file.path))
+                        .size(12.0)
+                        .family(egui::FontFamily::Monospace)
+                        .color(redesign_text_faint(palette)),
+                );
+            }
+        });
+}
+
+fn render_file_view(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    bundle: &InspectedBundle,
+    selected: usize,
+) {
+    let Some(file) = bundle.files.get(selected) else {
+        ui.label(
+            egui::RichText::new("This bundle has no files.")
+                .size(13.0)
+                .color(redesign_text_faint(palette)),
+        );
+        return;
+    };
+    let Some(text) = file.text.as_deref() else {
+        ui.label(
+            egui::RichText::new(format!("binary file, {} bytes", file.bytes))
+                .size(13.0)
+                .color(redesign_text_faint(palette)),
+        );
+        return;
+    };
+    egui::ScrollArea::both()
+        .id_salt("diagnostics_bundle_view")
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            let mut view = text;
+            ui.add(
+                egui::TextEdit::multiline(&mut view)
+                    .font(egui::TextStyle::Monospace)
+                    .text_color(redesign_text_primary(palette))
+                    .background_color(redesign_input_bg(palette))
+                    .desired_width(f32::INFINITY),
+            );
+        });
+}
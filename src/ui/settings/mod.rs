@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Copyright (c) 2026 Born2BSalty
 
+pub mod diagnostics_bundle_inspector;
 pub mod oauth_glue;
 pub mod page_settings;
 pub mod state_settings;
@@ -8,6 +9,7 @@ pub mod tab_accounts;
 pub mod tab_advanced;
 pub mod tab_general;
 pub mod tab_paths;
+pub mod tab_profiles;
 pub mod tab_tools;
 pub mod validate_debounce;
 pub mod validate_now;
@@ -5,11 +5,14 @@ use eframe::egui;
 
 use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
 use crate::ui::orchestrator::widgets::render_screen_title;
+use crate::ui::settings::diagnostics_bundle_inspector;
 use crate::ui::settings::state_settings::SettingsTab;
 use crate::ui::settings::widgets::tab_strip;
-use crate::ui::settings::{tab_accounts, tab_advanced, tab_general, tab_paths, tab_tools};
+use crate::ui::settings::{
+    tab_accounts, tab_advanced, tab_general, tab_paths, tab_profiles, tab_tools,
+};
 
-pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, _ctx: &egui::Context) {
+pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, ctx: &egui::Context) {
     let palette = orchestrator.theme_palette;
     render_screen_title(ui, palette, "Settings", None);
 
@@ -25,8 +28,10 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, _ctx: &egui
             SettingsTab::Paths => tab_paths::render(ui, orchestrator),
             SettingsTab::Tools => tab_tools::render(ui, orchestrator),
             SettingsTab::Accounts => tab_accounts::render(ui, orchestrator),
+            SettingsTab::Profiles => tab_profiles::render(ui, orchestrator),
             SettingsTab::Advanced => tab_advanced::render(ui, orchestrator),
         },
     );
     orchestrator.settings_screen_state.active_tab = current;
+    diagnostics_bundle_inspector::render(orchestrator, palette, ctx);
 }
@@ -4,6 +4,8 @@
 use std::collections::HashMap;
 use std::time::Instant;
 
+use crate::app::step5::diagnostics_bundle::InspectedBundle;
+use crate::game_discovery::DiscoveredInstall;
 use crate::ui::settings::widgets::tab_strip::TabLabel;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -13,6 +15,7 @@ pub enum SettingsTab {
     Paths,
     Tools,
     Accounts,
+    Profiles,
     Advanced,
 }
 
@@ -23,6 +26,7 @@ impl TabLabel for SettingsTab {
             Self::Paths => "Paths",
             Self::Tools => "Tools",
             Self::Accounts => "Accounts",
+            Self::Profiles => "Profiles",
             Self::Advanced => "Advanced",
         }
     }
@@ -31,11 +35,12 @@ impl TabLabel for SettingsTab {
 impl SettingsTab {
     #[must_use]
     pub const fn all() -> &'static [Self] {
-        const ALL: [SettingsTab; 5] = [
+        const ALL: [SettingsTab; 6] = [
             SettingsTab::General,
             SettingsTab::Paths,
             SettingsTab::Tools,
             SettingsTab::Accounts,
+            SettingsTab::Profiles,
             SettingsTab::Advanced,
         ];
         &ALL
@@ -97,4 +102,14 @@ pub struct SettingsScreenState {
     pub validate_now_in_flight: bool,
     pub path_edit_debounce: HashMap<&'static str, Instant>,
     pub path_validation_results: ValidationReport,
+    pub discovered_installs: Vec<DiscoveredInstall>,
+    pub discovery_scanned: bool,
+    pub inspected_bundle: Option<InspectedBundle>,
+    pub inspected_bundle_file: usize,
+    pub profile_selected: Option<String>,
+    pub profile_overrides_buffer: String,
+    pub profile_new_name: String,
+    pub profile_import_buffer: String,
+    pub profile_error: Option<String>,
+    pub profile_show_all: bool,
 }
@@ -5,6 +5,8 @@ use eframe::egui;
 
 use crate::settings::redesign_fields::{ThemeChoice, UiLanguage};
 use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
+use crate::ui::settings::diagnostics_bundle_inspector;
 use crate::ui::settings::widgets::{name_row, segmented_toggle, toggle_row};
 use crate::ui::shared::redesign_tokens::{
     ThemePalette, redesign_text_faint, redesign_text_muted, redesign_text_primary,
@@ -17,6 +19,8 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp) {
     ui.add_space(12.0);
     render_theme_language_rows(ui, palette, orchestrator);
     render_mode_rows(ui, palette, orchestrator);
+    render_update_check_rows(ui, palette, orchestrator);
+    render_diagnostics_bundle_rows(ui, palette, orchestrator);
 }
 
 fn render_name_row(ui: &mut egui::Ui, palette: ThemePalette, orchestrator: &mut OrchestratorApp) {
@@ -179,6 +183,127 @@ fn render_diagnostic_mode_row(
     );
 }
 
+const UPDATE_CHECK_INTERVALS: [(&str, u32); 3] = [("6h", 6), ("daily", 24), ("weekly", 168)];
+
+fn render_update_check_rows(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    orchestrator: &mut OrchestratorApp,
+) {
+    let mut checks = orchestrator.redesign_settings.background_update_checks;
+    let mut changed = false;
+    ui.columns(2, |cols| {
+        settings_row(
+            &mut cols[0],
+            palette,
+            "Background update checks",
+            "check every modlist's mod sources; locked mods are skipped",
+            |ui| {
+                toggle_row::render(ui, palette, "", &mut checks.enabled, None, || {
+                    changed = true
+                })
+            },
+        );
+        settings_row(
+            &mut cols[1],
+            palette,
+            "Check interval",
+            "how often each modlist is checked",
+            |ui| {
+                let clicked = segmented_toggle::render(
+                    ui,
+                    palette,
+                    UPDATE_CHECK_INTERVALS
+                        .map(|(label, hours)| (label, checks.interval_hours == hours)),
+                );
+                if let Some(i) = clicked
+                    && checks.interval_hours != UPDATE_CHECK_INTERVALS[i].1
+                {
+                    checks.interval_hours = UPDATE_CHECK_INTERVALS[i].1;
+                    changed = true;
+                }
+            },
+        );
+    });
+    if changed {
+        orchestrator.redesign_settings.background_update_checks = checks;
+        orchestrator.redesign_settings_dirty = true;
+    }
+}
+
+fn render_diagnostics_bundle_rows(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    orchestrator: &mut OrchestratorApp,
+) {
+    let mut redaction = orchestrator.redesign_settings.diagnostics_redaction;
+    let mut changed = false;
+    ui.columns(2, |cols| {
+        settings_row(
+            &mut cols[0],
+            palette,
+            "Redact home paths",
+            "diagnostics bundles replace your home folder with <HOME>",
+            |ui| {
+                toggle_row::render(ui, palette, "", &mut redaction.user_paths, None, || {
+                    changed = true
+                })
+            },
+        );
+        settings_row(
+            &mut cols[1],
+            palette,
+            "Redact usernames",
+            "diagnostics bundles replace your login name with <USER>",
+            |ui| {
+                toggle_row::render(ui, palette, "", &mut redaction.usernames, None, || {
+                    changed = true
+                })
+            },
+        );
+    });
+    let limit_hint = if redaction.max_file_kib == 0 {
+        "access tokens are replaced with <TOKEN>".to_string()
+    } else {
+        format!(
+            "access tokens become <TOKEN>; files over {} KiB are trimmed",
+            redaction.max_file_kib
+        )
+    };
+    ui.columns(2, |cols| {
+        settings_row(&mut cols[0], palette, "Redact tokens", &limit_hint, |ui| {
+            toggle_row::render(ui, palette, "", &mut redaction.tokens, None, || {
+                changed = true
+            })
+        });
+        settings_row(
+            &mut cols[1],
+            palette,
+            "Diagnostics bundle",
+            "open a bundle someone shared, read-only",
+            |ui| {
+                if redesign_btn(
+                    ui,
+                    palette,
+                    "inspect…",
+                    BtnOpts {
+                        small: true,
+                        ..Default::default()
+                    },
+                )
+                .clicked()
+                {
+                    diagnostics_bundle_inspector::pick_and_open(orchestrator);
+                }
+            },
+        );
+    });
+    if changed {
+        orchestrator.redesign_settings.diagnostics_redaction = redaction;
+        orchestrator.redesign_settings_dirty = true;
+    }
+}
+
 fn settings_row(
     ui: &mut egui::Ui,
     palette: ThemePalette,
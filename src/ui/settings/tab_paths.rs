@@ -3,7 +3,9 @@
 
 use eframe::egui;
 
+use crate::game_discovery::{self, GameKind};
 use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
 use crate::ui::settings::state_settings::{PathStatus, PathStatusTone};
 use crate::ui::settings::validate_debounce;
 use crate::ui::settings::validate_now;
@@ -35,6 +37,7 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp) {
         "IWDEE source",
         validate_now::FIELD_IWDEE_GAME_FOLDER,
     );
+    detected_installs(ui, palette, orchestrator);
 
     ui.add_space(12.0);
     section_header(ui, palette, "WORKING FOLDERS");
@@ -61,6 +64,131 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp) {
     );
 }
 
+const GAME_FIELDS: [&str; 5] = [
+    validate_now::FIELD_BGEE_GAME_FOLDER,
+    validate_now::FIELD_BG2EE_GAME_FOLDER,
+    validate_now::FIELD_IWDEE_GAME_FOLDER,
+    validate_now::FIELD_EET_BGEE_GAME_FOLDER,
+    validate_now::FIELD_EET_BG2EE_GAME_FOLDER,
+];
+
+const fn field_for_kind(kind: GameKind) -> &'static str {
+    match kind {
+        GameKind::Bgee | GameKind::Sod => validate_now::FIELD_BGEE_GAME_FOLDER,
+        GameKind::Bg2ee => validate_now::FIELD_BG2EE_GAME_FOLDER,
+        GameKind::Iwdee => validate_now::FIELD_IWDEE_GAME_FOLDER,
+    }
+}
+
+fn detected_installs(ui: &mut egui::Ui, palette: ThemePalette, orchestrator: &mut OrchestratorApp) {
+    let scanning = orchestrator.game_discovery_rx.is_some();
+    let found = orchestrator
+        .settings_screen_state
+        .discovered_installs
+        .clone();
+    let mut use_path: Option<(&'static str, String)> = None;
+    let mut fill_empty = false;
+    let mut rescan = false;
+    let scanned = orchestrator.settings_screen_state.discovery_scanned;
+
+    ui.add_space(4.0);
+    ui.horizontal(|ui| {
+        let summary = if scanning {
+            "looking for game installs\u{2026}".to_string()
+        } else if !scanned {
+            "game installs not scanned yet".to_string()
+        } else if found.is_empty() {
+            "no game installs detected".to_string()
+        } else {
+            format!("{} game install(s) detected", found.len())
+        };
+        ui.label(
+            egui::RichText::new(summary)
+                .size(12.0)
+                .color(redesign_text_muted(palette)),
+        );
+        if !found.is_empty()
+            && redesign_btn(
+                ui,
+                palette,
+                "fill empty",
+                BtnOpts {
+                    small: true,
+                    ..Default::default()
+                },
+            )
+            .on_hover_text("Use detected installs for game folders that are still blank")
+            .clicked()
+        {
+            fill_empty = true;
+        }
+        if redesign_btn(
+            ui,
+            palette,
+            if scanned { "rescan" } else { "scan" },
+            BtnOpts {
+                small: true,
+                disabled: scanning,
+                ..Default::default()
+            },
+        )
+        .clicked()
+            && !scanning
+        {
+            rescan = true;
+        }
+    });
+    for install in &found {
+        let path = install.path.to_string_lossy().into_owned();
+        ui.horizontal(|ui| {
+            let version = install
+                .version
+                .as_deref()
+                .map_or_else(String::new, |v| format!(" v{v}"));
+            ui.label(
+                egui::RichText::new(format!(
+                    "{}{version} \u{00B7} {} \u{00B7} {path}",
+                    install.kind.label(),
+                    install.source
+                ))
+                .size(12.0)
+                .family(egui::FontFamily::Monospace)
+                .color(redesign_text_faint(palette)),
+            );
+            if redesign_btn(
+                ui,
+                palette,
+                "use",
+                BtnOpts {
+                    small: true,
+                    ..Default::default()
+                },
+            )
+            .clicked()
+            {
+                use_path = Some((field_for_kind(install.kind), path.clone()));
+            }
+        });
+    }
+
+    if let Some((field, path)) = use_path
+        && let Some(slot) = field_mut(&mut orchestrator.wizard_state.step1, field)
+    {
+        *slot = path;
+        validate_debounce::mark_dirty(orchestrator, field);
+    }
+    if fill_empty
+        && game_discovery::fill_empty_game_folders(&mut orchestrator.wizard_state.step1, &found) > 0
+    {
+        for field in GAME_FIELDS {
+            validate_debounce::mark_dirty(orchestrator, field);
+        }
+    }
+    if rescan {
+        orchestrator.start_game_discovery();
+    }
+}
+
 fn path_row_for_field(
     ui: &mut egui::Ui,
     palette: ThemePalette,
@@ -0,0 +1,410 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use eframe::egui;
+
+use crate::install_runtime::settings_profiles_driver;
+use crate::settings::layers::{self, SettingsSource};
+use crate::settings::model::Step1Settings;
+use crate::settings::profiles::{self, SettingsProfile};
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::orchestrator::widgets::clipboard;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
+use crate::ui::shared::redesign_tokens::{
+    ThemePalette, redesign_accent, redesign_error, redesign_input_bg, redesign_text_faint,
+    redesign_text_muted, redesign_text_primary,
+};
+
+enum ProfileAction {
+    Select(String),
+    Add,
+    SaveOverrides,
+    ToggleActive,
+    Export,
+    Delete,
+    Import,
+}
+
+pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp) {
+    let palette = orchestrator.theme_palette;
+    let mut action = None;
+
+    ui.columns(2, |cols| {
+        render_profiles(&mut cols[0], palette, orchestrator, &mut action);
+        render_effective(&mut cols[1], palette, orchestrator);
+    });
+
+    if let Some(action) = action {
+        apply_action(ui.ctx(), orchestrator, action);
+    }
+}
+
+fn render_profiles(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    orchestrator: &mut OrchestratorApp,
+    action: &mut Option<ProfileAction>,
+) {
+    col_header(ui, palette, "Profiles");
+    let active = orchestrator.settings_profiles.active.clone();
+    let state = &mut orchestrator.settings_screen_state;
+    if orchestrator.settings_profiles.profiles.is_empty() {
+        ui.label(
+            egui::RichText::new("No profiles yet.")
+                .size(12.0)
+                .color(redesign_text_faint(palette)),
+        );
+    }
+    for profile in &orchestrator.settings_profiles.profiles {
+        let selected = state
+            .profile_selected
+            .as_deref()
+            .is_some_and(|name| name.eq_ignore_ascii_case(&profile.name));
+        let is_active = active
+            .as_deref()
+            .is_some_and(|name| name.eq_ignore_ascii_case(&profile.name));
+        let mut text = egui::RichText::new(&profile.name).size(12.0);
+        if is_active {
+            text = text.color(redesign_accent(palette));
+        }
+        ui.horizontal(|ui| {
+            if ui.selectable_label(selected, text).clicked() {
+                *action = Some(ProfileAction::Select(profile.name.clone()));
+            }
+            let count = profile.overrides.len();
+            let mut hint = format!("{count} override{}", if count == 1 { "" } else { "s" });
+            if is_active {
+                hint.push_str(" \u{00B7} active");
+            }
+            ui.label(
+                egui::RichText::new(hint)
+                    .size(11.0)
+                    .color(redesign_text_faint(palette)),
+            );
+        });
+    }
+    ui.add_space(6.0);
+    ui.horizontal(|ui| {
+        ui.add_sized(
+            egui::vec2(180.0, 24.0),
+            egui::TextEdit::singleline(&mut state.profile_new_name)
+                .hint_text("Steam Deck")
+                .text_color(redesign_text_primary(palette))
+                .background_color(redesign_input_bg(palette))
+                .margin(egui::Margin::symmetric(8, 4)),
+        );
+        if small_btn(ui, palette, "Add profile", false) {
+            *action = Some(ProfileAction::Add);
+        }
+    });
+
+    if let Some(selected) = state.profile_selected.clone() {
+        ui.add_space(10.0);
+        col_header(ui, palette, &format!("{selected} overrides (key = value)"));
+        ui.add_sized(
+            egui::vec2(ui.available_width(), 120.0),
+            egui::TextEdit::multiline(&mut state.profile_overrides_buffer)
+                .font(egui::FontId::new(
+                    12.0,
+                    egui::FontFamily::Name("firacode_nerd".into()),
+                ))
+                .hint_text("timeout = 7200\nmods_folder = /run/media/deck/mods")
+                .text_color(redesign_text_primary(palette))
+                .background_color(redesign_input_bg(palette)),
+        );
+        ui.add_space(4.0);
+        let is_active = active
+            .as_deref()
+            .is_some_and(|name| name.eq_ignore_ascii_case(&selected));
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 6.0;
+            if small_btn(ui, palette, "Save", true) {
+                *action = Some(ProfileAction::SaveOverrides);
+            }
+            let toggle = if is_active {
+                "Deactivate"
+            } else {
+                "Make active"
+            };
+            if small_btn(ui, palette, toggle, false) {
+                *action = Some(ProfileAction::ToggleActive);
+            }
+            if small_btn(ui, palette, "Export", false) {
+                *action = Some(ProfileAction::Export);
+            }
+            if redesign_btn(
+                ui,
+                palette,
+                "Delete",
+                BtnOpts {
+                    small: true,
+                    danger: true,
+                    ..Default::default()
+                },
+            )
+            .clicked()
+            {
+                *action = Some(ProfileAction::Delete);
+            }
+        });
+    }
+
+    ui.add_space(10.0);
+    col_header(ui, palette, "Import");
+    ui.add_sized(
+        egui::vec2(ui.available_width(), 60.0),
+        egui::TextEdit::multiline(&mut state.profile_import_buffer)
+            .hint_text("paste an exported profile")
+            .text_color(redesign_text_primary(palette))
+            .background_color(redesign_input_bg(palette)),
+    );
+    ui.add_space(4.0);
+    if small_btn(ui, palette, "Import profile", false) {
+        *action = Some(ProfileAction::Import);
+    }
+    if let Some(err) = state.profile_error.as_deref() {
+        ui.add_space(4.0);
+        ui.label(
+            egui::RichText::new(err)
+                .size(12.0)
+                .color(redesign_error(palette)),
+        );
+    }
+}
+
+fn render_effective(ui: &mut egui::Ui, palette: ThemePalette, orchestrator: &mut OrchestratorApp) {
+    col_header(ui, palette, "Effective settings");
+    let Some(applied) = orchestrator.settings_layers.as_ref() else {
+        return;
+    };
+    let resolved = &applied.resolved;
+    let scope = match (&applied.modlist_id, &resolved.profile) {
+        (Some(id), Some(profile)) => format!(
+            "{} \u{00B7} profile {profile}",
+            modlist_name(orchestrator, id)
+        ),
+        (Some(id), None) => modlist_name(orchestrator, id),
+        (None, Some(profile)) => format!("profile {profile}"),
+        (None, None) => "no profile".to_string(),
+    };
+    ui.label(
+        egui::RichText::new(format!(
+            "{scope} \u{00B7} precedence: default < saved < profile < modlist < environment < command line"
+        ))
+        .size(11.0)
+        .color(redesign_text_faint(palette)),
+    );
+    for err in &resolved.errors {
+        ui.label(
+            egui::RichText::new(format!("skipped {err}"))
+                .size(11.0)
+                .color(redesign_error(palette)),
+        );
+    }
+    ui.checkbox(
+        &mut orchestrator.settings_screen_state.profile_show_all,
+        egui::RichText::new("show unchanged defaults").size(12.0),
+    );
+    ui.add_space(4.0);
+
+    let live = layers::field_texts(&Step1Settings::from(
+        orchestrator.wizard_state.step1.clone(),
+    ));
+    let show_all = orchestrator.settings_screen_state.profile_show_all;
+    egui::ScrollArea::vertical()
+        .id_salt("settings_profiles_effective")
+        .max_height(ui.available_height().max(200.0))
+        .show(ui, |ui| {
+            egui::Grid::new("settings_profiles_effective_grid")
+                .num_columns(3)
+                .spacing(egui::vec2(12.0, 4.0))
+                .striped(true)
+                .show(ui, |ui| {
+                    for value in &resolved.values {
+                        let current = live.get(&value.key).unwrap_or(&value.value);
+                        let edited = current != &value.value;
+                        if !show_all && !edited && value.source == SettingsSource::Default {
+                            continue;
+                        }
+                        ui.label(
+                            egui::RichText::new(&value.key)
+                                .size(12.0)
+                                .family(egui::FontFamily::Name("firacode_nerd".into()))
+                                .color(redesign_text_primary(palette)),
+                        );
+                        let shown = if current.is_empty() {
+                            "\u{2014}"
+                        } else {
+                            current
+                        };
+                        ui.label(
+                            egui::RichText::new(shown)
+                                .size(12.0)
+                                .family(egui::FontFamily::Name("firacode_nerd".into()))
+                                .color(redesign_text_muted(palette)),
+                        );
+                        let source = if edited {
+                            "edited this session"
+                        } else {
+                            value.source.label()
+                        };
+                        let color = if matches!(
+                            value.source,
+                            SettingsSource::Default | SettingsSource::Saved
+                        ) && !edited
+                        {
+                            redesign_text_faint(palette)
+                        } else {
+                            redesign_accent(palette)
+                        };
+                        ui.label(egui::RichText::new(source).size(12.0).color(color));
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+fn apply_action(ctx: &egui::Context, orchestrator: &mut OrchestratorApp, action: ProfileAction) {
+    orchestrator.settings_screen_state.profile_error = None;
+    let selected = orchestrator.settings_screen_state.profile_selected.clone();
+    match action {
+        ProfileAction::Select(name) => select(orchestrator, Some(name)),
+        ProfileAction::Add => {
+            let base = std::mem::take(&mut orchestrator.settings_screen_state.profile_new_name);
+            let name = orchestrator.settings_profiles.add(SettingsProfile {
+                name: base,
+                ..SettingsProfile::default()
+            });
+            settings_profiles_driver::persist(orchestrator);
+            select(orchestrator, Some(name));
+        }
+        ProfileAction::SaveOverrides => {
+            let Some(name) = selected else {
+                return;
+            };
+            let text = &orchestrator.settings_screen_state.profile_overrides_buffer;
+            let overrides = match profiles::parse_override_lines(text) {
+                Ok(overrides) => overrides,
+                Err(err) => {
+                    orchestrator.settings_screen_state.profile_error = Some(err);
+                    return;
+                }
+            };
+            let errors = layers::validate(&overrides);
+            if !errors.is_empty() {
+                orchestrator.settings_screen_state.profile_error = Some(errors.join("; "));
+                return;
+            }
+            if let Some(profile) = orchestrator.settings_profiles.find_mut(&name) {
+                profile.overrides = overrides;
+            }
+            settings_profiles_driver::persist(orchestrator);
+            settings_profiles_driver::reapply(orchestrator);
+            orchestrator
+                .notification_manager
+                .success(format!("Saved profile {name}"));
+        }
+        ProfileAction::ToggleActive => {
+            let Some(name) = selected else {
+                return;
+            };
+            let profiles = &mut orchestrator.settings_profiles;
+            profiles.active = match profiles.active.as_deref() {
+                Some(active) if active.eq_ignore_ascii_case(&name) => None,
+                _ => Some(name),
+            };
+            settings_profiles_driver::persist(orchestrator);
+            settings_profiles_driver::reapply(orchestrator);
+        }
+        ProfileAction::Export => {
+            let Some(profile) = selected
+                .as_deref()
+                .and_then(|name| orchestrator.settings_profiles.find(name))
+            else {
+                return;
+            };
+            let (json, stripped) = profiles::export_profile(profile);
+            let message = if stripped == 0 {
+                format!("Copied profile {}", profile.name)
+            } else {
+                format!(
+                    "Copied profile {} ({stripped} secret value(s) left out)",
+                    profile.name
+                )
+            };
+            clipboard::copy_with_message(ctx, json, message);
+        }
+        ProfileAction::Delete => {
+            let Some(name) = selected else {
+                return;
+            };
+            orchestrator.settings_profiles.remove(&name);
+            settings_profiles_driver::persist(orchestrator);
+            settings_profiles_driver::reapply(orchestrator);
+            select(orchestrator, None);
+        }
+        ProfileAction::Import => {
+            let text = &orchestrator.settings_screen_state.profile_import_buffer;
+            match profiles::import_profile(text) {
+                Ok(profile) => {
+                    let name = orchestrator.settings_profiles.add(profile);
+                    orchestrator
+                        .settings_screen_state
+                        .profile_import_buffer
+                        .clear();
+                    settings_profiles_driver::persist(orchestrator);
+                    orchestrator
+                        .notification_manager
+                        .success(format!("Imported profile {name}"));
+                    select(orchestrator, Some(name));
+                }
+                Err(err) => {
+                    orchestrator.settings_screen_state.profile_error =
+                        Some(format!("import failed: {err:#}"));
+                }
+            }
+        }
+    }
+}
+
+fn select(orchestrator: &mut OrchestratorApp, name: Option<String>) {
+    let buffer = name
+        .as_deref()
+        .and_then(|name| orchestrator.settings_profiles.find(name))
+        .map(|profile| profiles::override_lines(&profile.overrides))
+        .unwrap_or_default();
+    let state = &mut orchestrator.settings_screen_state;
+    state.profile_overrides_buffer = buffer;
+    state.profile_selected = name;
+}
+
+fn modlist_name(orchestrator: &OrchestratorApp, id: &str) -> String {
+    orchestrator
+        .registry
+        .find(id)
+        .map_or_else(|| id.to_string(), |entry| entry.name.clone())
+}
+
+fn small_btn(ui: &mut egui::Ui, palette: ThemePalette, label: &str, primary: bool) -> bool {
+    redesign_btn(
+        ui,
+        palette,
+        label,
+        BtnOpts {
+            small: true,
+            primary,
+            ..Default::default()
+        },
+    )
+    .clicked()
+}
+
+fn col_header(ui: &mut egui::Ui, palette: ThemePalette, label: &str) {
+    ui.label(
+        egui::RichText::new(label)
+            .size(12.0)
+            .family(egui::FontFamily::Name("poppins_medium".into()))
+            .color(redesign_text_muted(palette)),
+    );
+    ui.add_space(4.0);
+}
@@ -30,9 +30,11 @@ pub fn tick(orchestrator: &mut OrchestratorApp, now: Instant) {
 
     orchestrator.settings_screen_state.path_validation_results =
         validate_now::run_now(&orchestrator.wizard_state.step1);
-    orchestrator.wizard_state.step1_path_check = Some(
-        crate::app::state_validation::run_path_check(&orchestrator.wizard_state.step1),
-    );
+    orchestrator.wizard_state.step1_path_check =
+        Some(crate::app::state_validation::run_path_check(
+            &orchestrator.wizard_state.step1,
+            orchestrator.wizard_state.step5.resume_available,
+        ));
 
     orchestrator
         .settings_screen_state
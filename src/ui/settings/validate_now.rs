@@ -5,6 +5,7 @@ use std::path::Path;
 
 use crate::app::state::Step1State;
 use crate::app::state_validation;
+use crate::game_fingerprint;
 use crate::ui::settings::state_settings::{PathStatus, ValidationReport};
 
 pub const FIELD_BGEE_GAME_FOLDER: &str = "bgee_game_folder";
@@ -131,7 +132,12 @@ fn check_game_folder(value: &str) -> PathStatus {
             reason: "no chitin.key/lang \u{2014} not a recognizable game install".to_string(),
         };
     }
-    PathStatus::Ok { detail: None }
+    let fingerprint = game_fingerprint::fingerprint(path);
+    let detail = fingerprint
+        .findings
+        .first()
+        .map(|f| format!("not vanilla \u{2014} {}", f.detail));
+    PathStatus::Ok { detail }
 }
 
 fn check_working_folder(value: &str) -> PathStatus {
@@ -8,10 +8,10 @@ use crate::ui::shared::redesign_tokens::{
     redesign_border_strong, redesign_chrome_bg, redesign_text_muted, redesign_text_primary,
 };
 
-pub fn render(
+pub fn render<const N: usize>(
     ui: &mut egui::Ui,
     palette: ThemePalette,
-    options: [(&str, bool); 2],
+    options: [(&str, bool); N],
 ) -> Option<usize> {
     let segment_width = 90.0;
     let height = 26.0;
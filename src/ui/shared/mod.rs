@@ -13,3 +13,4 @@ pub mod redesign_fonts;
 pub mod redesign_tokens;
 pub mod redesign_visuals;
 pub mod tab_open_seam;
+pub mod weidu_step_console;
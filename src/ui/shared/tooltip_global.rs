@@ -28,6 +28,7 @@ pub const STEP1_PROMPT_CONTEXT_LOOKBACK: &str =
     "Keep this many prior output lines for prompt detection and context.";
 pub const STEP1_PREPARE_TARGET_DIRS: &str =
     "BIO prepares target directories before run (backup or clean, based on next option).";
+pub const STEP1_CLEAN_GAME_POLICY: &str = "Before install, check the source game folder for weidu.log, override files, a modified dialog.tlk and leftover installer files. Warn shows the findings; Block fails the path check.";
 pub const STEP1_BACKUP_TARGET_DIRS: &str = "If target dir has files, move it to a timestamped backup folder and recreate an empty target before copy.";
 pub const STEP1_SKIP_INSTALLED: &str = "Skip components already present in WeiDU logs.";
 pub const STEP1_CHECK_LAST_INSTALLED: &str = "Use strict last-installed validation.";
@@ -63,6 +64,10 @@ pub const STEP3_DRAG_PARENT: &str = "Drag to move parent block";
 pub const STEP3_DRAG_ROW: &str = "Drag to reorder";
 
 pub const STEP4_SAVE_WEIDU_LOG: &str = "Write weidu.log file(s) from the current install order.";
+pub const STEP4_PROMPT_DRY_RUN: &str =
+    "Walk the install order again and list every expected prompt with the answer BIO would send.";
+
+pub const STEP4_PROMPT_DRY_RUN_EXPORT: &str = "Save the prompt dry-run report as a text file.";
 
 pub const STEP5_FORCE_CANCEL: &str = "Immediate stop. May leave game/mod state unrecoverable.";
 pub const STEP5_CANCEL_INSTALL: &str = "Request cancel. Confirmation required.";
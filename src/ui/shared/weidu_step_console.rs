@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use eframe::egui;
+
+use crate::install_runtime::weidu_step_run::WeiduStepRun;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
+use crate::ui::shared::redesign_tokens::{
+    ThemePalette, redesign_error, redesign_input_bg, redesign_text_muted, redesign_text_primary,
+};
+
+const CONSOLE_MAX_H_PX: f32 = 220.0;
+const CONSOLE_MAX_CHARS: usize = 6000;
+
+fn small_btn(ui: &mut egui::Ui, palette: ThemePalette, label: &str, primary: bool) -> bool {
+    redesign_btn(
+        ui,
+        palette,
+        label,
+        BtnOpts {
+            small: true,
+            primary,
+            ..Default::default()
+        },
+    )
+    .clicked()
+}
+
+// Returns true once the run has ended and the user closes it.
+pub fn render(ui: &mut egui::Ui, palette: ThemePalette, run: &mut WeiduStepRun) -> bool {
+    let mut closed = false;
+    let status = match &run.outcome {
+        None => format!(
+            "step {}/{} \u{2014} {}",
+            (run.current + 1).min(run.steps.len()),
+            run.steps.len(),
+            run.current_label().unwrap_or_default()
+        ),
+        Some(Ok(())) => "finished".to_string(),
+        Some(Err(err)) => err.clone(),
+    };
+    let status_color = if matches!(run.outcome, Some(Err(_))) {
+        redesign_error(palette)
+    } else {
+        redesign_text_muted(palette)
+    };
+    ui.label(egui::RichText::new(status).size(12.0).color(status_color));
+    ui.add_space(6.0);
+    egui::ScrollArea::vertical()
+        .max_height(CONSOLE_MAX_H_PX)
+        .auto_shrink([false, true])
+        .stick_to_bottom(true)
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(run.console_excerpt(CONSOLE_MAX_CHARS))
+                    .monospace()
+                    .size(11.0)
+                    .color(redesign_text_primary(palette)),
+            );
+        });
+    if run.is_running() && run.awaiting_input {
+        ui.add_space(6.0);
+        ui.label(
+            egui::RichText::new("WeiDU is waiting for an answer")
+                .size(12.0)
+                .color(redesign_text_muted(palette)),
+        );
+        ui.horizontal(|ui| {
+            let response = ui.add_sized(
+                egui::vec2(ui.available_width() - 70.0, 26.0),
+                egui::TextEdit::singleline(&mut run.input_line)
+                    .text_color(redesign_text_primary(palette))
+                    .background_color(redesign_input_bg(palette))
+                    .margin(egui::Margin::symmetric(8, 4)),
+            );
+            let entered =
+                response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+            if small_btn(ui, palette, "Send", true) || entered {
+                run.send_input();
+            }
+        });
+    }
+    ui.add_space(12.0);
+    ui.allocate_ui_with_layout(
+        egui::vec2(ui.available_width(), 30.0),
+        egui::Layout::right_to_left(egui::Align::Center),
+        |ui| {
+            ui.spacing_mut().item_spacing.x = 8.0;
+            if run.is_running() {
+                if small_btn(ui, palette, "Cancel", false) {
+                    run.cancel();
+                }
+            } else if small_btn(ui, palette, "Close", true) {
+                closed = true;
+            }
+        },
+    );
+    closed
+}
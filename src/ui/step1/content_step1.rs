@@ -5,6 +5,9 @@ use eframe::egui;
 use rfd::FileDialog;
 
 use crate::app::state::Step1State;
+use crate::game_fingerprint;
+use crate::install_runtime::disk_preflight;
+use crate::ui::home::modlist_card::human_size;
 use crate::ui::layout::{
     BROWSE_BUTTON_WIDTH, PATH_FIELD_MIN_WIDTH, PATH_INPUT_HEIGHT, PATH_LABEL_WIDTH,
     PATH_ROW_INNER_GAP,
@@ -132,6 +135,37 @@ pub fn render_options_content(
     render_install_mode_combo(ui, s);
     render_option_toggles(ui, s);
     render_backup_options(ui, s);
+    render_clean_game_policy_combo(ui, s);
+}
+
+fn render_clean_game_policy_combo(ui: &mut egui::Ui, s: &mut Step1State) {
+    ui.horizontal(|ui| {
+        ui.label("Clean game check")
+            .on_hover_text(tt::STEP1_CLEAN_GAME_POLICY);
+        egui::ComboBox::from_id_salt("clean_game_policy")
+            .selected_text(clean_game_policy_label(&s.clean_game_policy))
+            .show_ui(ui, |ui| {
+                for policy in [
+                    game_fingerprint::POLICY_OFF,
+                    game_fingerprint::POLICY_WARN,
+                    game_fingerprint::POLICY_BLOCK,
+                ] {
+                    ui.selectable_value(
+                        &mut s.clean_game_policy,
+                        policy.to_string(),
+                        clean_game_policy_label(policy),
+                    );
+                }
+            });
+    });
+}
+
+fn clean_game_policy_label(policy: &str) -> &'static str {
+    match game_fingerprint::CleanGamePolicy::from_setting(policy) {
+        game_fingerprint::CleanGamePolicy::Off => "Off",
+        game_fingerprint::CleanGamePolicy::Warn => "Warn",
+        game_fingerprint::CleanGamePolicy::Block => "Block",
+    }
 }
 
 const PROMPT_HELP: &str = "Copies: // @wlb-inputs:\n\
@@ -342,6 +376,32 @@ pub fn render_install_paths_content(ui: &mut egui::Ui, s: &mut Step1State, _max_
         "EET" => render_eet_paths(ui, s),
         _ => render_bgee_paths(ui, s),
     }
+    render_disk_preflight(ui, s);
+}
+
+fn render_disk_preflight(ui: &mut egui::Ui, s: &Step1State) {
+    let Some(input) = disk_preflight::input_for_step1(s) else {
+        return;
+    };
+    let Some(preflight) = disk_preflight::preflight_cached(&input) else {
+        ui.ctx()
+            .request_repaint_after(std::time::Duration::from_millis(250));
+        return;
+    };
+    for volume in preflight.volumes.iter().filter(|volume| volume.is_short()) {
+        ui.colored_label(
+            ui.visuals().error_fg_color,
+            format!(
+                "Not enough free space on {}: needs {}, {} free.",
+                volume.mount,
+                human_size(volume.required_bytes),
+                human_size(volume.available_bytes.unwrap_or_default()),
+            ),
+        );
+    }
+    for warning in preflight.warnings() {
+        ui.label(typo::weak(format!("Note: {warning}.")));
+    }
 }
 
 pub fn render_weidu_log_mode_content(ui: &mut egui::Ui, s: &mut Step1State, _max_height: f32) {
@@ -8,7 +8,7 @@ use crate::ui::layout::SECTION_GAP;
 use crate::ui::step1::action_step1::Step1Action;
 use crate::ui::step1::frame_step1::{render_bottom, render_top};
 use crate::ui::step1::service_step1::{
-    split_path_check_lines, sync_install_mode, sync_weidu_log_mode,
+    split_path_check_lines, split_path_check_warnings, sync_install_mode, sync_weidu_log_mode,
 };
 use crate::ui::step1::state_step1::clear_path_check_if_step1_changed;
 use crate::ui::step5::service_diagnostics_support_step5::export_diagnostics;
@@ -53,10 +53,17 @@ pub fn render(
         ui.group(|ui| {
             ui.label(crate::ui::shared::typography_global::strong("Path Check"));
             if ok {
+                let (head, warnings) = split_path_check_warnings(&msg);
                 ui.label(
-                    crate::ui::shared::typography_global::plain(format!("- {msg}"))
+                    crate::ui::shared::typography_global::plain(format!("- {head}"))
                         .color(crate::ui::shared::theme_global::success_bright()),
                 );
+                for line in warnings {
+                    ui.label(
+                        crate::ui::shared::typography_global::plain(format!("- {line}"))
+                            .color(crate::ui::shared::theme_global::warning()),
+                    );
+                }
             } else {
                 for line in split_path_check_lines(&msg) {
                     ui.label(
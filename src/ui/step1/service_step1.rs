@@ -3,7 +3,7 @@
 
 use crate::app::state::Step1State;
 
-pub use crate::app::state_validation::split_path_check_lines;
+pub use crate::app::state_validation::{split_path_check_lines, split_path_check_warnings};
 
 pub fn sync_install_mode(s: &mut Step1State) {
     s.sync_install_mode_flags();
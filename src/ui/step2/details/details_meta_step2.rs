@@ -26,6 +26,7 @@ pub(crate) fn map_selected_details(data: SelectedDetailsData) -> Step2Details {
         compat_graph: data.compat_graph,
         compat_evidence: data.compat_evidence,
         compat_component_block: data.compat_component_block,
+        tp2_source: data.tp2_source,
         raw_line: data.raw_line,
         tp_file: data.tp_file,
         tp2_folder: data.tp2_folder,
@@ -96,6 +96,7 @@ pub mod details_pane_content {
         PathsGridLayout, render_component_block, render_paths_grid, render_raw_line,
     };
     use crate::ui::step2::details_selection_step2::{SelectionGridLayout, render_selection_grid};
+    use crate::ui::step2::details_tp2_browser_step2::render_tp2_source;
     use crate::ui::step2::state_step2::Step2Details;
 
     use super::Step2Action;
@@ -211,7 +212,9 @@ pub mod details_pane_content {
         };
         render_paths_grid(ui, details, action, paths_layout);
         ui.add_space(6.0);
-        render_component_block(ui, details, palette);
+        if !render_tp2_source(ui, details, action, palette) {
+            render_component_block(ui, details, palette);
+        }
         render_raw_line(ui, details, palette);
     }
 
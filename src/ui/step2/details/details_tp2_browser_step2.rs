@@ -0,0 +1,231 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::time::Duration;
+
+use eframe::egui;
+
+use crate::app::tp2_browser::{Tp2SourceFile, Tp2SourceLine, Tp2SourceState, Tp2TokenKind};
+use crate::ui::orchestrator::widgets::{ButtonIcon, clipboard, render_icon_button};
+use crate::ui::shared::redesign_tokens::{
+    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_accent,
+    redesign_accent_comment, redesign_accent_deep, redesign_accent_numbers, redesign_accent_path,
+    redesign_border_strong, redesign_conditional, redesign_conflict, redesign_input_bg,
+    redesign_prompt_text, redesign_text_faint, redesign_text_muted, redesign_text_primary,
+};
+use crate::ui::step2::action_step2::Step2Action;
+use crate::ui::step2::state_step2::Step2Details;
+
+const SOURCE_MAX_HEIGHT_PX: f32 = 360.0;
+const LINE_NUMBER_WIDTH_PX: f32 = 40.0;
+const CODE_FONT_SIZE: f32 = 12.0;
+const LOADING_POLL: Duration = Duration::from_millis(100);
+
+pub(crate) fn render_tp2_source(
+    ui: &mut egui::Ui,
+    details: &Step2Details,
+    action: &mut Option<Step2Action>,
+    palette: ThemePalette,
+) -> bool {
+    let view = match &details.tp2_source {
+        Tp2SourceState::Ready(view) => view,
+        Tp2SourceState::Loading => {
+            ui.label(
+                egui::RichText::new("Loading TP2 source…")
+                    .size(CODE_FONT_SIZE)
+                    .color(redesign_text_faint(palette)),
+            );
+            ui.ctx().request_repaint_after(LOADING_POLL);
+            return true;
+        }
+        Tp2SourceState::Unavailable => return false,
+    };
+    if view.files.is_empty() {
+        return false;
+    }
+    let id = ui.make_persistent_id((
+        "step2_tp2_source",
+        details.tp_file.as_deref().unwrap_or_default(),
+        details.component_id.as_deref().unwrap_or_default(),
+    ));
+    let file_id = id.with("file");
+    let mut selected = ui
+        .data(|data| data.get_temp::<usize>(file_id))
+        .unwrap_or(0)
+        .min(view.files.len() - 1);
+
+    egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, true)
+        .show_header(ui, |ui| {
+            ui.label(crate::ui::shared::typography_global::small_strong(
+                "TP2 Source",
+            ));
+            let spare = (ui.available_width() - 24.0).max(0.0);
+            ui.add_space(spare);
+            if render_icon_button(
+                ui,
+                palette,
+                ButtonIcon::Copy,
+                crate::ui::shared::tooltip_global::COPY,
+                true,
+            )
+            .clicked()
+            {
+                clipboard::copy(ui.ctx(), view.plain_text(selected));
+            }
+        })
+        .body_unindented(|ui| {
+            if view.files.len() > 1 {
+                render_file_chips(ui, palette, &view.files, &mut selected);
+                ui.add_space(4.0);
+            }
+            let frame = egui::Frame::default()
+                .fill(redesign_input_bg(palette))
+                .stroke(egui::Stroke::new(
+                    REDESIGN_BORDER_WIDTH_PX,
+                    redesign_border_strong(palette),
+                ))
+                .corner_radius(egui::CornerRadius::same(REDESIGN_BORDER_RADIUS_U8))
+                .inner_margin(egui::Margin::symmetric(10, 8));
+            frame.show(ui, |ui| {
+                ui.set_width(ui.available_width());
+                let file = &view.files[selected];
+                egui::ScrollArea::both()
+                    .id_salt(("step2_tp2_source_lines", selected))
+                    .max_height(SOURCE_MAX_HEIGHT_PX)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        for line in &file.lines {
+                            render_line(ui, palette, line, &mut selected, action);
+                        }
+                        if file.truncated {
+                            ui.label(
+                                egui::RichText::new("\u{2026} file truncated")
+                                    .size(CODE_FONT_SIZE)
+                                    .color(redesign_text_faint(palette)),
+                            );
+                        }
+                    });
+            });
+        });
+
+    ui.data_mut(|data| data.insert_temp(file_id, selected));
+    true
+}
+
+fn render_file_chips(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    files: &[Tp2SourceFile],
+    selected: &mut usize,
+) {
+    ui.horizontal_wrapped(|ui| {
+        for (index, file) in files.iter().enumerate() {
+            let color = if index == *selected {
+                redesign_accent_deep(palette)
+            } else {
+                redesign_text_muted(palette)
+            };
+            let text = egui::RichText::new(&file.label)
+                .size(11.0)
+                .family(egui::FontFamily::Monospace)
+                .color(color);
+            if ui
+                .selectable_label(index == *selected, text)
+                .on_hover_text(file.path.display().to_string())
+                .clicked()
+            {
+                *selected = index;
+            }
+        }
+    });
+}
+
+fn render_line(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    line: &Tp2SourceLine,
+    selected: &mut usize,
+    action: &mut Option<Step2Action>,
+) {
+    ui.horizontal_top(|ui| {
+        ui.add_sized(
+            egui::vec2(LINE_NUMBER_WIDTH_PX, CODE_FONT_SIZE + 4.0),
+            egui::Label::new(
+                egui::RichText::new(line.number.to_string())
+                    .size(CODE_FONT_SIZE)
+                    .family(egui::FontFamily::Monospace)
+                    .color(redesign_text_faint(palette)),
+            ),
+        );
+        let mut job = egui::text::LayoutJob::default();
+        for span in &line.spans {
+            job.append(
+                &span.text,
+                0.0,
+                egui::TextFormat {
+                    font_id: egui::FontId::monospace(CODE_FONT_SIZE),
+                    color: token_color(palette, span.kind),
+                    ..Default::default()
+                },
+            );
+        }
+        let label = egui::Label::new(job).extend();
+        if let Some(target) = line.include {
+            let response = ui
+                .add(label.sense(egui::Sense::click()))
+                .on_hover_cursor(egui::CursorIcon::PointingHand)
+                .on_hover_text("Open included file");
+            if response.clicked() {
+                *selected = target;
+            }
+        } else {
+            ui.add(label);
+        }
+        for reference in &line.component_refs {
+            let verb = if reference.forbid { "forbids" } else { "needs" };
+            let text = egui::RichText::new(format!(
+                "{verb} {} #{}",
+                reference.tp2, reference.component_id
+            ))
+            .size(11.0)
+            .color(redesign_accent(palette));
+            if ui
+                .link(text)
+                .on_hover_text("Jump to this component")
+                .clicked()
+            {
+                *action = Some(Step2Action::JumpToComponent {
+                    tp2: reference.tp2.clone(),
+                    component_id: reference.component_id,
+                });
+            }
+        }
+    });
+    for tra in &line.tra {
+        ui.horizontal_top(|ui| {
+            ui.add_space(LINE_NUMBER_WIDTH_PX + ui.spacing().item_spacing.x);
+            ui.add(
+                egui::Label::new(
+                    egui::RichText::new(format!("{} = {}", tra.reference, tra.text))
+                        .size(11.0)
+                        .italics()
+                        .color(redesign_text_faint(palette)),
+                )
+                .wrap(),
+            );
+        });
+    }
+}
+
+const fn token_color(palette: ThemePalette, kind: Tp2TokenKind) -> egui::Color32 {
+    match kind {
+        Tp2TokenKind::Plain => redesign_text_primary(palette),
+        Tp2TokenKind::Keyword => redesign_accent(palette),
+        Tp2TokenKind::Constraint => redesign_conflict(palette),
+        Tp2TokenKind::Prompt => redesign_prompt_text(palette),
+        Tp2TokenKind::Include => redesign_conditional(palette),
+        Tp2TokenKind::String => redesign_accent_path(palette),
+        Tp2TokenKind::TraRef => redesign_accent_numbers(palette),
+        Tp2TokenKind::Comment => redesign_accent_comment(palette),
+    }
+}
@@ -21,6 +21,8 @@ pub mod details_pane_step2;
 pub mod details_paths_step2;
 #[path = "details/details_selection_step2.rs"]
 pub mod details_selection_step2;
+#[path = "details/details_tp2_browser_step2.rs"]
+pub mod details_tp2_browser_step2;
 pub mod format_step2;
 pub mod frame_step2;
 pub mod list_pane_step2;
@@ -2,6 +2,7 @@
 // Copyright (c) 2026 Born2BSalty
 
 use crate::app::state::{Step2ModState, Step2State, WizardState};
+use crate::app::tp2_browser::Tp2SourceState;
 
 #[derive(Debug, Clone, Default)]
 pub struct Step2Details {
@@ -25,6 +26,7 @@ pub struct Step2Details {
     pub compat_graph: Option<String>,
     pub compat_evidence: Option<String>,
     pub compat_component_block: Option<String>,
+    pub tp2_source: Tp2SourceState,
     pub raw_line: Option<String>,
     pub tp_file: Option<String>,
     pub tp2_folder: Option<String>,
@@ -39,10 +39,26 @@ pub(crate) fn redo_active(state: &mut WizardState) {
     let (items, _, _, _, _, _, _, _, _, _, _, _, _, undo_stack, redo_stack) =
         crate::ui::step3::state_step3::active_list_mut(state);
     crate::app::step3_history::redo(items, undo_stack, redo_stack);
+    reconcile_active_families(state);
 }
 
 pub(crate) fn undo_active(state: &mut WizardState) {
     let (items, _, _, _, _, _, _, _, _, _, _, _, _, undo_stack, redo_stack) =
         crate::ui::step3::state_step3::active_list_mut(state);
     crate::app::step3_history::undo(items, undo_stack, redo_stack);
+    reconcile_active_families(state);
+}
+
+fn reconcile_active_families(state: &mut WizardState) {
+    if state.step3.active_game_tab == "BGEE" {
+        crate::app::step3_families::reconcile_family_checks(
+            &mut state.step2.bgee_mods,
+            &state.step3.bgee_items,
+        );
+    } else {
+        crate::app::step3_families::reconcile_family_checks(
+            &mut state.step2.bg2ee_mods,
+            &state.step3.bg2ee_items,
+        );
+    }
 }
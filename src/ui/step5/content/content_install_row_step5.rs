@@ -31,14 +31,7 @@ pub(crate) fn render_install_row(
             ctx.dev_mode,
             ctx.palette,
         );
-        render_step5_menus(
-            ui,
-            state,
-            &mut terminal,
-            ctx.dev_mode,
-            ctx.exe_fingerprint,
-            ctx.palette,
-        );
+        render_step5_menus(ui, state, &mut terminal, ctx);
         if ctx.dev_mode {
             crate::ui::step5::prompt_answers_step5::render_button(ui, state, ctx.palette);
         }
@@ -211,18 +204,16 @@ fn render_step5_menus(
     ui: &mut egui::Ui,
     state: &mut WizardState,
     terminal: &mut Option<&mut EmbeddedTerminal>,
-    dev_mode: bool,
-    exe_fingerprint: &str,
-    palette: ThemePalette,
+    ctx: Step5RenderCtx<'_>,
 ) {
-    crate::ui::step5::menus_step5::render_actions_menu(ui, state, terminal.as_deref_mut(), palette);
+    crate::ui::step5::menus_step5::render_actions_menu(ui, state, terminal.as_deref_mut(), ctx);
     crate::ui::step5::menus_step5::render_diagnostics_menu(
         ui,
         state,
         terminal.as_deref(),
-        dev_mode,
-        exe_fingerprint,
-        palette,
+        ctx.dev_mode,
+        ctx.exe_fingerprint,
+        ctx.palette,
     );
 }
 
@@ -5,6 +5,7 @@ use eframe::egui;
 
 use crate::app::state::WizardState;
 use crate::app::terminal::EmbeddedTerminal;
+use crate::settings::redesign_fields::DiagnosticsRedaction;
 use crate::ui::shared::layout_tokens_global::STEP5_SECTION_GAP;
 use crate::ui::shared::redesign_tokens::ThemePalette;
 use crate::ui::step5::action_step5::Step5Action;
@@ -21,6 +22,7 @@ pub struct Step5RenderCtx<'a> {
     pub dev_mode: bool,
     pub exe_fingerprint: &'a str,
     pub palette: ThemePalette,
+    pub redaction: DiagnosticsRedaction,
 }
 
 pub fn render(
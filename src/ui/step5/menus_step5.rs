@@ -4,22 +4,25 @@
 use eframe::egui;
 
 use crate::app::state::WizardState;
+use crate::app::step5::diagnostics_bundle::DIAGNOSTICS_BUNDLE_EXTENSION;
 use crate::app::terminal::EmbeddedTerminal;
 use crate::ui::orchestrator::widgets::{BtnOpts, clipboard, redesign_btn};
 use crate::ui::shared::redesign_tokens::ThemePalette;
+use crate::ui::step5::content_step5::Step5RenderCtx;
 use crate::ui::step5::service_diagnostics_support_step5::{
-    export_diagnostics, open_console_logs_folder, open_last_log_file, save_console_log,
+    export_diagnostics, export_diagnostics_bundle, open_console_logs_folder, open_last_log_file,
+    save_console_log,
 };
 
 pub(crate) fn render_actions_menu(
     ui: &mut egui::Ui,
     state: &mut WizardState,
     mut terminal: Option<&mut EmbeddedTerminal>,
-    palette: ThemePalette,
+    ctx: Step5RenderCtx<'_>,
 ) {
     let btn = redesign_btn(
         ui,
-        palette,
+        ctx.palette,
         "Actions",
         BtnOpts {
             small: true,
@@ -86,10 +89,49 @@ pub(crate) fn render_actions_menu(
                 let _ = open_last_log_file(&state.step1);
                 ui.memory_mut(egui::Memory::close_popup);
             }
+            if ui.button("Export Diagnostics Bundle…").clicked() {
+                ui.memory_mut(egui::Memory::close_popup);
+                save_diagnostics_bundle(state, terminal.as_deref(), ctx);
+            }
         },
     );
 }
 
+fn save_diagnostics_bundle(
+    state: &mut WizardState,
+    terminal: Option<&EmbeddedTerminal>,
+    ctx: Step5RenderCtx<'_>,
+) {
+    let Some(output) = rfd::FileDialog::new()
+        .set_file_name(format!("bio-diagnostics.{DIAGNOSTICS_BUNDLE_EXTENSION}"))
+        .add_filter("BIO diagnostics bundle", &["zip"])
+        .save_file()
+    else {
+        return;
+    };
+    match export_diagnostics_bundle(
+        state,
+        terminal,
+        ctx.dev_mode,
+        ctx.exe_fingerprint,
+        ctx.redaction,
+        &output,
+    ) {
+        Ok(report) => {
+            state.step5.last_status_text = format!(
+                "Diagnostics bundle saved: {} ({} files, {} redactions, {} truncated)",
+                report.output.display(),
+                report.files,
+                report.redactions,
+                report.truncated
+            );
+        }
+        Err(err) => {
+            state.step5.last_status_text = format!("Diagnostics bundle export failed: {err}");
+        }
+    }
+}
+
 pub(crate) fn render_diagnostics_menu(
     ui: &mut egui::Ui,
     state: &mut WizardState,
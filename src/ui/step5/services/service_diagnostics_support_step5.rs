@@ -1,10 +1,12 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Copyright (c) 2026 Born2BSalty
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::app::state::{Step1State, WizardState};
+use crate::app::step5::diagnostics_bundle::DiagnosticsBundleReport;
 use crate::app::terminal::EmbeddedTerminal;
+use crate::settings::redesign_fields::DiagnosticsRedaction;
 
 pub(crate) const fn apply_dev_defaults(state: &mut WizardState, dev_mode: bool) {
     if dev_mode {
@@ -32,6 +34,23 @@ pub(crate) fn export_diagnostics(
     crate::ui::step5::diagnostics::export_diagnostics(state, terminal, &ctx)
 }
 
+pub(crate) fn export_diagnostics_bundle(
+    state: &WizardState,
+    terminal: Option<&EmbeddedTerminal>,
+    dev_mode: bool,
+    exe_fingerprint: &str,
+    redaction: DiagnosticsRedaction,
+    output: &Path,
+) -> anyhow::Result<DiagnosticsBundleReport> {
+    let ctx = crate::ui::step5::diagnostics::DiagnosticsContext {
+        dev_mode,
+        exe_fingerprint: exe_fingerprint.to_string(),
+    };
+    crate::app::step5::diagnostics_bundle::export_diagnostics_bundle(
+        state, terminal, &ctx, redaction, output,
+    )
+}
+
 pub(crate) fn source_log_infos(
     step1: &Step1State,
 ) -> Vec<crate::ui::step5::log_files::SourceLogInfo> {
@@ -9,10 +9,14 @@ pub mod step4;
 pub mod step5;
 pub mod step_action_dispatch;
 pub mod widgets;
+pub mod workspace_apply_changes_panel;
 pub mod workspace_header;
 pub mod workspace_hint_line;
+pub mod workspace_history_panel;
+pub mod workspace_install_history_panel;
 pub mod workspace_nav_bar;
 pub mod workspace_progress_bar;
 pub mod workspace_state_loader;
 pub mod workspace_step_router;
+pub mod workspace_upstream_panel;
 pub mod workspace_view;
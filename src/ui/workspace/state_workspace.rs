@@ -5,7 +5,9 @@ use std::collections::HashSet;
 use std::time::Instant;
 
 use crate::app::state::Step2Selection;
+use crate::registry::install_history::{InstallHistory, RunFilter};
 use crate::registry::model::Game;
+use crate::registry::workspace_history::WorkspaceHistory;
 
 #[derive(Debug, Clone, Default)]
 pub struct WorkspaceStep2State {
@@ -134,9 +136,24 @@ pub struct WorkspaceViewState {
     pub save_draft_flash_until: Option<Instant>,
     pub share_paste_open: WorkspaceFlag,
     pub fork_info_open: WorkspaceFlag,
+    pub history_open: WorkspaceFlag,
+    pub history_name_temp: String,
+    pub history: Option<WorkspaceHistory>,
+    pub history_selected: Option<u64>,
+    pub history_error: Option<String>,
+    pub install_history_open: WorkspaceFlag,
+    pub install_history: Option<InstallHistory>,
+    pub install_history_query: String,
+    pub install_history_filter: RunFilter,
+    pub install_history_selected: Option<String>,
+    pub upstream_open: WorkspaceFlag,
+    pub upstream_code: String,
+    pub(crate) upstream_merge: Option<crate::app::fork_merge::ForkMerge>,
+    pub upstream_error: Option<String>,
     pub install_complete: WorkspaceFlag,
     pub loaded_workspace_id: Option<String>,
     pub step2: WorkspaceStep2State,
+    pub prompt_dry_run: Option<crate::app::prompt_dry_run::PromptDryRun>,
 }
 
 impl Default for WorkspaceViewState {
@@ -153,9 +170,24 @@ impl Default for WorkspaceViewState {
             save_draft_flash_until: None,
             share_paste_open: false,
             fork_info_open: false,
+            history_open: false,
+            history_name_temp: String::new(),
+            history: None,
+            history_selected: None,
+            history_error: None,
+            install_history_open: false,
+            install_history: None,
+            install_history_query: String::new(),
+            install_history_filter: RunFilter::All,
+            install_history_selected: None,
+            upstream_open: false,
+            upstream_code: String::new(),
+            upstream_merge: None,
+            upstream_error: None,
             install_complete: false,
             loaded_workspace_id: None,
             step2: WorkspaceStep2State::default(),
+            prompt_dry_run: None,
         }
     }
 }
@@ -9,6 +9,7 @@ use crate::app::prompt_eval_context::build_prompt_eval_context;
 use crate::app::prompt_eval_summary_step3;
 use crate::app::prompt_popup_text::format_step3_prompt_popup;
 use crate::app::state::{Step2Selection, Step3ItemState, WizardState};
+use crate::app::step3_families::{self, Step3FamilyIndex};
 use crate::app::step3_history;
 use crate::app::step3_prompt_edit::PromptActionRequest;
 use crate::parser::prompt_eval_expr::PromptEvalContext;
@@ -52,6 +53,7 @@ struct RenderCtx<'a> {
     tab_id: &'a str,
     prompt_eval: &'a PromptEvalContext,
     compat_markers: &'a HashMap<String, Step3CompatMarker>,
+    families: &'a Step3FamilyIndex,
     visible_indices: &'a [usize],
     jump_to_selected_requested: &'a mut bool,
     items: &'a mut Vec<Step3ItemState>,
@@ -75,6 +77,7 @@ struct RenderCtx<'a> {
 struct RowAccumulator {
     visible_rows: Vec<(usize, egui::Rect)>,
     uncheck_requests: Vec<(String, String)>,
+    swap_requests: Vec<(String, String, String)>,
     prompt_requests: Vec<PromptActionRequest>,
     open_prompt_popup: Option<(String, String)>,
     open_compat_popup: Option<(
@@ -90,6 +93,7 @@ impl RowAccumulator {
         Self {
             visible_rows: Vec::with_capacity(capacity),
             uncheck_requests: Vec::new(),
+            swap_requests: Vec::new(),
             prompt_requests: Vec::new(),
             open_prompt_popup: None,
             open_compat_popup: None,
@@ -184,6 +188,11 @@ fn run_row_pipeline(
     prompt_eval: &crate::parser::prompt_eval_expr::PromptEvalContext,
     initial_jump: bool,
 ) -> (Option<RowAccumulator>, bool) {
+    let families = Step3FamilyIndex::cached(if tab_id == "BGEE" {
+        &state.step2.bgee_mods
+    } else {
+        &state.step2.bg2ee_mods
+    });
     let (
         items,
         selected,
@@ -220,6 +229,7 @@ fn run_row_pipeline(
         tab_id,
         prompt_eval,
         compat_markers,
+        families: &families,
         visible_indices: &visible_indices,
         jump_to_selected_requested: &mut jump_flag,
         items,
@@ -535,6 +545,13 @@ fn render_child_row(
             let row_text = format_step3::weidu_colored_widget_text(ui, &text);
             let resp = ui.selectable_label(ctx.selected.contains(&idx), row_text);
 
+            if let Some(weidu_group) = ctx
+                .families
+                .groups(&ctx.items[idx])
+                .and_then(|groups| groups.weidu_group.as_deref())
+            {
+                render_group_pill(ui, weidu_group, ctx.palette);
+            }
             if let Some(marker) = compat_marker {
                 render_compat_pill(ui, &ctx.items[idx], marker, acc, ctx.palette);
             }
@@ -705,6 +722,26 @@ fn render_prompt_pill(
     }
 }
 
+fn render_group_pill(ui: &mut egui::Ui, weidu_group: &str, palette: ThemePalette) {
+    ui.add_space(6.0);
+    egui::Frame::default()
+        .stroke(egui::Stroke::new(
+            BORDER_THIN,
+            redesign_border_strong(palette),
+        ))
+        .corner_radius(egui::CornerRadius::same(7))
+        .inner_margin(egui::Margin::symmetric(6, 1))
+        .show(ui, |ui| {
+            ui.label(
+                egui::RichText::new(weidu_group)
+                    .color(redesign_text_faint(palette))
+                    .size(SIZE_PILL_TEXT),
+            );
+        })
+        .response
+        .on_hover_text("WeiDU GROUP");
+}
+
 fn render_parent_context_menu(drag_response: &egui::Response, ctx: &mut RenderCtx<'_>, idx: usize) {
     drag_response.context_menu(|ui| {
         if ui.button("Clone Parent (empty split target)").clicked() {
@@ -725,12 +762,27 @@ fn render_child_context_menu(
     let component_id = ctx.items[idx].component_id.clone();
     let component_label = ctx.items[idx].component_label.clone();
     let mod_name = ctx.items[idx].mod_name.clone();
+    let swap_choices = ctx.families.swap_choices(&ctx.items[idx]);
     drag_response.context_menu(|ui| {
         if ui.button("Uncheck In Step 2").clicked() {
             acc.uncheck_requests
                 .push((tp_file.clone(), component_id.clone()));
             ui.close_menu();
         }
+        if !swap_choices.is_empty() {
+            ui.menu_button("Swap Choice", |ui| {
+                for choice in &swap_choices {
+                    if ui.button(&choice.label).clicked() {
+                        acc.swap_requests.push((
+                            tp_file.clone(),
+                            component_id.clone(),
+                            choice.component_id.clone(),
+                        ));
+                        ui.close_menu();
+                    }
+                }
+            });
+        }
         if ui.button("Set @wlb-inputs...").clicked() {
             acc.prompt_requests.push(PromptActionRequest::SetWlb {
                 tp_file: tp_file.clone(),
@@ -828,10 +880,8 @@ fn update_drag_indices(ctx: &mut RenderCtx<'_>, idx: usize) {
         ctx.selected.push(idx);
         *ctx.drag_indices = block_indices;
     } else {
-        ctx.selected.clear();
-        ctx.selected.push(idx);
-        ctx.drag_indices.clear();
-        ctx.drag_indices.push(idx);
+        *ctx.drag_indices = ctx.families.family_indices(ctx.items, idx);
+        ctx.selected.clone_from(ctx.drag_indices);
     }
 }
 
@@ -924,11 +974,44 @@ fn flush_row_outcome(state: &mut WizardState, tab_id: &str, acc: &mut RowAccumul
             &acc.uncheck_requests,
         );
     }
+    if !acc.swap_requests.is_empty() {
+        apply_family_swaps(state, tab_id, &acc.swap_requests);
+    }
     if !acc.prompt_requests.is_empty() {
         service_step3::prompt_actions::apply_prompt_actions(state, &acc.prompt_requests);
     }
 }
 
+fn apply_family_swaps(
+    state: &mut WizardState,
+    tab_id: &str,
+    requests: &[(String, String, String)],
+) {
+    let (mods, items, undo_stack, redo_stack) = if tab_id.eq_ignore_ascii_case("BGEE") {
+        (
+            &mut state.step2.bgee_mods,
+            &mut state.step3.bgee_items,
+            &mut state.step3.bgee_undo_stack,
+            &mut state.step3.bgee_redo_stack,
+        )
+    } else {
+        (
+            &mut state.step2.bg2ee_mods,
+            &mut state.step3.bg2ee_items,
+            &mut state.step3.bg2ee_undo_stack,
+            &mut state.step3.bg2ee_redo_stack,
+        )
+    };
+    let snapshot = items.clone();
+    let mut swapped = false;
+    for (tp_file, from, to) in requests {
+        swapped |= step3_families::swap_family_choice(mods, items, tp_file, from, to);
+    }
+    if swapped {
+        step3_history::push_undo_snapshot(&snapshot, undo_stack, redo_stack);
+    }
+}
+
 fn paint_insert_marker_full_width(
     ui: &egui::Ui,
     items: &[Step3ItemState],
@@ -3,11 +3,12 @@
 
 use eframe::egui;
 
+use crate::app::prompt_dry_run::{self, PromptDryRun};
 use crate::app::state::WizardState;
 use crate::app::step4_action::Step4Action;
 use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
 use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
-use crate::ui::shared::redesign_tokens::{ThemePalette, redesign_text_faint};
+use crate::ui::shared::redesign_tokens::{ThemePalette, redesign_text_faint, redesign_warning};
 use crate::ui::workspace::step4::workspace_step4;
 
 pub fn render(
@@ -17,6 +18,11 @@ pub fn render(
 ) -> Option<Step4Action> {
     let mut action: Option<Step4Action> = None;
 
+    if orchestrator.workspace_view.prompt_dry_run.is_none() {
+        orchestrator.workspace_view.prompt_dry_run =
+            Some(prompt_dry_run::simulate(&orchestrator.wizard_state));
+    }
+
     let is_dual = workspace_step4::is_dual_game(&orchestrator.wizard_state);
     let save_label = if is_dual {
         "Save weidu.log's"
@@ -44,6 +50,20 @@ pub fn render(
             action = Some(Step4Action::SaveWeiduLog);
         }
 
+        if redesign_btn(ui, palette, "Dry-run prompts", BtnOpts::default())
+            .on_hover_text(crate::ui::shared::tooltip_global::STEP4_PROMPT_DRY_RUN)
+            .clicked()
+        {
+            rerun_prompt_dry_run(orchestrator);
+        }
+
+        if redesign_btn(ui, palette, "Export report", BtnOpts::default())
+            .on_hover_text(crate::ui::shared::tooltip_global::STEP4_PROMPT_DRY_RUN_EXPORT)
+            .clicked()
+        {
+            export_prompt_dry_run(orchestrator);
+        }
+
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
             ui.label(
                 egui::RichText::new(format!(
@@ -56,9 +76,80 @@ pub fn render(
         });
     });
 
+    render_prompt_dry_run(
+        ui,
+        palette,
+        orchestrator.workspace_view.prompt_dry_run.as_ref(),
+    );
+
     action
 }
 
+fn rerun_prompt_dry_run(orchestrator: &mut OrchestratorApp) {
+    let report = prompt_dry_run::simulate(&orchestrator.wizard_state);
+    let summary = report.summary();
+    if report.flagged().next().is_some() {
+        orchestrator.notification_manager.warn(summary);
+    } else {
+        orchestrator.notification_manager.success(summary);
+    }
+    orchestrator.workspace_view.prompt_dry_run = Some(report);
+}
+
+fn export_prompt_dry_run(orchestrator: &mut OrchestratorApp) {
+    let Some(report) = orchestrator.workspace_view.prompt_dry_run.as_ref() else {
+        return;
+    };
+    let Some(path) = rfd::FileDialog::new()
+        .set_file_name("bio-prompt-dry-run.txt")
+        .save_file()
+    else {
+        return;
+    };
+    match std::fs::write(&path, report.render_text()) {
+        Ok(()) => orchestrator
+            .notification_manager
+            .success(format!("Prompt dry-run saved to {}", path.display())),
+        Err(err) => orchestrator
+            .notification_manager
+            .error(format!("Couldn't export prompt dry-run: {err}")),
+    }
+}
+
+fn render_prompt_dry_run(ui: &mut egui::Ui, palette: ThemePalette, report: Option<&PromptDryRun>) {
+    let Some(report) = report else {
+        return;
+    };
+    let flagged: Vec<_> = report.flagged().collect();
+    let color = if flagged.is_empty() {
+        redesign_text_faint(palette)
+    } else {
+        redesign_warning(palette)
+    };
+    ui.add_space(6.0);
+    ui.label(
+        egui::RichText::new(report.summary())
+            .size(13.0)
+            .family(egui::FontFamily::Name("poppins_medium".into()))
+            .color(color),
+    );
+    for component in flagged {
+        ui.collapsing(
+            format!("[{}] {}", component.game_tab, component.label),
+            |ui| {
+                for prompt in component.prompts.iter().filter(|p| p.answer.is_none()) {
+                    let first = prompt.prompt.lines().next().unwrap_or_default().trim();
+                    ui.label(
+                        egui::RichText::new(first)
+                            .size(12.0)
+                            .color(redesign_text_faint(palette)),
+                    );
+                }
+            },
+        );
+    }
+}
+
 fn active_tab_counts(state: &WizardState) -> (&'static str, usize, usize) {
     let (tab_label, items) = workspace_step4::active_tab_items(state);
     let leaves: Vec<&crate::app::state::Step3ItemState> =
@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use eframe::egui;
+
+use crate::app::state::WizardState;
+use crate::install::component_policy::user_policies_path;
+use crate::ui::orchestrator::widgets::clipboard;
+use crate::ui::shared::redesign_tokens::{
+    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_error,
+    redesign_text_faint, redesign_text_primary, redesign_warning,
+};
+
+#[must_use]
+pub fn visible(state: &WizardState) -> bool {
+    !state.step5.install_running && !state.step5.failure_summary.is_empty()
+}
+
+pub fn render(ui: &mut egui::Ui, palette: ThemePalette, state: &WizardState) {
+    if !visible(state) {
+        return;
+    }
+    let failures = &state.step5.failure_summary;
+    let error = redesign_error(palette);
+
+    egui::Frame::default()
+        .stroke(egui::Stroke::new(REDESIGN_BORDER_WIDTH_PX, error))
+        .corner_radius(egui::CornerRadius::same(REDESIGN_BORDER_RADIUS_U8))
+        .inner_margin(egui::Margin::symmetric(14, 10))
+        .show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            ui.label(
+                egui::RichText::new(format!("{} component(s) failed", failures.len()))
+                    .size(13.0)
+                    .family(egui::FontFamily::Name("poppins_medium".into()))
+                    .color(error),
+            );
+            ui.add_space(6.0);
+            egui::Grid::new("step5_failure_summary")
+                .num_columns(3)
+                .striped(true)
+                .spacing(egui::vec2(16.0, 4.0))
+                .show(ui, |ui| {
+                    for failure in failures {
+                        ui.label(
+                            egui::RichText::new(&failure.label)
+                                .size(12.0)
+                                .color(redesign_text_primary(palette)),
+                        );
+                        ui.label(
+                            egui::RichText::new(failure.kind.label())
+                                .size(12.0)
+                                .color(redesign_text_faint(palette)),
+                        );
+                        let last_line = failure.error_block.lines().last().unwrap_or_default();
+                        ui.horizontal(|ui| {
+                            if ui
+                                .small_button("Copy error")
+                                .on_hover_text(&failure.error_block)
+                                .clicked()
+                            {
+                                clipboard::copy(ui.ctx(), failure.error_block.clone());
+                            }
+                            ui.label(
+                                egui::RichText::new(last_line)
+                                    .size(12.0)
+                                    .family(egui::FontFamily::Name("firacode_nerd".into()))
+                                    .color(redesign_text_faint(palette)),
+                            );
+                        });
+                        ui.end_row();
+                    }
+                });
+        });
+    ui.add_space(10.0);
+}
+
+pub fn render_policy_notice(ui: &mut egui::Ui, palette: ThemePalette) {
+    let path = user_policies_path();
+    if !path.is_file() {
+        return;
+    }
+    ui.label(
+        egui::RichText::new(format!(
+            "{} is ignored here: GUI installs run mod_installer with one global timeout and no \
+             retries. Run the `normal` or `eet` command to apply per-component policies.",
+            path.display()
+        ))
+        .size(12.0)
+        .color(redesign_warning(palette)),
+    );
+    ui.add_space(10.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::install::failure_class::{ComponentFailure, FailureKind};
+
+    #[test]
+    fn table_shows_only_after_a_run_with_failures() {
+        let mut s = WizardState::default();
+        assert!(!visible(&s));
+        s.step5.failure_summary = vec![ComponentFailure {
+            component_key: "SETUP-B.TP2#10".to_string(),
+            label: "SETUP-B.TP2 #10 Tweaks".to_string(),
+            kind: FailureKind::RequireFailed,
+            attempts: 1,
+            error_block: "NOT INSTALLED DUE TO ERRORS Tweaks".to_string(),
+        }];
+        assert!(visible(&s));
+        s.step5.install_running = true;
+        assert!(!visible(&s));
+    }
+}
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // Copyright (c) 2026 Born2BSalty
 
+pub mod failure_summary;
 pub mod page_workspace_step5;
 pub mod post_install_actions;
 pub mod share_paste_code_dialog;
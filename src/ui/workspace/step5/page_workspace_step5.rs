@@ -16,7 +16,9 @@ use crate::ui::orchestrator::orchestrator_app::{
 };
 use crate::ui::step5::action_step5::Step5Action;
 use crate::ui::workspace::step5::state_workspace_step5::PostInstallAction;
-use crate::ui::workspace::step5::{post_install_actions, share_paste_code_dialog, success_banner};
+use crate::ui::workspace::step5::{
+    failure_summary, post_install_actions, share_paste_code_dialog, success_banner,
+};
 
 pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, modlist_id: &str) {
     if orchestrator.workspace_step5.install_clicked
@@ -34,6 +36,8 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, modlist_id:
     if let Some(e) = entry.as_ref() {
         success_banner::render(ui, palette, &orchestrator.wizard_state, e);
     }
+    failure_summary::render(ui, palette, &orchestrator.wizard_state);
+    failure_summary::render_policy_notice(ui, palette);
 
     let post_install_action: Option<PostInstallAction> = entry
         .as_ref()
@@ -53,6 +57,7 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, modlist_id:
                 dev_mode: orchestrator.dev_mode,
                 exe_fingerprint: &exe_fingerprint,
                 palette,
+                redaction: orchestrator.redesign_settings.diagnostics_redaction,
             },
         );
     });
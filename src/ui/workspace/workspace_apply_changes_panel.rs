@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use eframe::egui;
+
+use crate::install::change_plan::{ChangePlan, ChangeRoute};
+use crate::install_runtime::apply_changes_driver;
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
+use crate::ui::shared::redesign_tokens::{
+    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_border_strong,
+    redesign_shell_bg, redesign_text_faint, redesign_text_muted, redesign_text_primary,
+    redesign_warning,
+};
+use crate::ui::shared::redesign_visuals::redesign_overlay_shadow;
+use crate::ui::shared::weidu_step_console;
+use crate::ui::step5::service_timefmt_step5::fmt_duration;
+
+const MAX_WIDTH_PX: f32 = 600.0;
+
+const STEP_LIST_HEIGHT_PX: f32 = 220.0;
+
+enum ApplyAction {
+    Close,
+    Start,
+}
+
+pub fn render(orchestrator: &mut OrchestratorApp, palette: ThemePalette, ctx: &egui::Context) {
+    let Some(session) = orchestrator.apply_changes.as_mut() else {
+        return;
+    };
+    let mut action = None;
+
+    let frame = egui::Frame::default()
+        .fill(redesign_shell_bg(palette))
+        .stroke(egui::Stroke::new(
+            REDESIGN_BORDER_WIDTH_PX,
+            redesign_border_strong(palette),
+        ))
+        .corner_radius(egui::CornerRadius::same(REDESIGN_BORDER_RADIUS_U8))
+        .shadow(redesign_overlay_shadow(palette))
+        .inner_margin(egui::Margin::same(18));
+
+    egui::Window::new("orchestrator_workspace_apply_changes")
+        .id(egui::Id::new("orchestrator_workspace_apply_changes"))
+        .title_bar(false)
+        .resizable(false)
+        .collapsible(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .frame(frame)
+        .show(ctx, |ui| {
+            ui.set_max_width(MAX_WIDTH_PX);
+            ui.label(
+                egui::RichText::new(format!(
+                    "Apply changes \u{00B7} {}",
+                    session.plan.route.label()
+                ))
+                .size(15.0)
+                .family(egui::FontFamily::Name("poppins_medium".into()))
+                .color(redesign_text_primary(palette)),
+            );
+            ui.add_space(4.0);
+            if let Some(note) = session.note.as_deref() {
+                ui.label(
+                    egui::RichText::new(note)
+                        .size(12.0)
+                        .color(redesign_warning(palette)),
+                );
+            }
+            ui.label(
+                egui::RichText::new(session.plan.summary())
+                    .size(13.0)
+                    .family(egui::FontFamily::Name("poppins_light".into()))
+                    .color(redesign_text_muted(palette)),
+            );
+            ui.add_space(10.0);
+
+            if let Some(run) = session.run.as_mut() {
+                if weidu_step_console::render(ui, palette, run) {
+                    action = Some(ApplyAction::Close);
+                }
+                return;
+            }
+
+            render_plan(ui, palette, &session.plan, session.estimate_secs);
+            ui.add_space(12.0);
+            ui.allocate_ui_with_layout(
+                egui::vec2(ui.available_width(), 30.0),
+                egui::Layout::right_to_left(egui::Align::Center),
+                |ui| {
+                    ui.spacing_mut().item_spacing.x = 8.0;
+                    let label = if session.plan.route == ChangeRoute::Full {
+                        "Reinstall"
+                    } else {
+                        "Apply"
+                    };
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        label,
+                        BtnOpts {
+                            small: true,
+                            primary: true,
+                            disabled: session.plan.route == ChangeRoute::UpToDate,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        action = Some(ApplyAction::Start);
+                    }
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        "Close",
+                        BtnOpts {
+                            small: true,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        action = Some(ApplyAction::Close);
+                    }
+                },
+            );
+        });
+
+    match action {
+        Some(ApplyAction::Close) => orchestrator.apply_changes = None,
+        Some(ApplyAction::Start) => apply_changes_driver::start(orchestrator),
+        None => {}
+    }
+}
+
+fn render_plan(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    plan: &ChangePlan,
+    estimate_secs: Option<u64>,
+) {
+    if plan.route == ChangeRoute::UpToDate {
+        return;
+    }
+    let runs = plan.weidu_runs();
+    let estimate = estimate_secs.map_or_else(String::new, |secs| {
+        format!(
+            " \u{00B7} about {} going by the last install",
+            fmt_duration(secs)
+        )
+    });
+    ui.label(
+        egui::RichText::new(format!("Estimated work: {runs} WeiDU run(s){estimate}"))
+            .size(13.0)
+            .family(egui::FontFamily::Name("poppins_medium".into()))
+            .color(redesign_text_primary(palette)),
+    );
+    if plan.route == ChangeRoute::Full {
+        ui.label(
+            egui::RichText::new("Reinstall opens Step 4 with the destination set to be cleared.")
+                .size(12.0)
+                .color(redesign_text_faint(palette)),
+        );
+        return;
+    }
+    ui.add_space(6.0);
+    egui::ScrollArea::vertical()
+        .max_height(STEP_LIST_HEIGHT_PX)
+        .auto_shrink([false, true])
+        .show(ui, |ui| {
+            for (index, step) in plan.steps().iter().enumerate() {
+                ui.label(
+                    egui::RichText::new(format!("{:>3}. {}", index + 1, step.label()))
+                        .size(12.0)
+                        .family(egui::FontFamily::Monospace)
+                        .color(redesign_text_muted(palette)),
+                );
+            }
+        });
+}
@@ -5,6 +5,8 @@ use std::time::{Duration, Instant};
 
 use eframe::egui;
 
+use crate::install_runtime::apply_changes_driver;
+use crate::registry::model::ModlistState;
 use crate::registry::operations_rename;
 use crate::registry::share_export::{self, ShareMeta};
 use crate::registry::store_workspace::WorkspaceStore;
@@ -17,7 +19,10 @@ use crate::ui::shared::redesign_tokens::{
     redesign_text_muted, redesign_text_primary,
 };
 use crate::ui::workspace::state_workspace::WorkspaceStep;
-use crate::ui::workspace::workspace_state_loader;
+use crate::ui::workspace::{
+    workspace_apply_changes_panel, workspace_history_panel, workspace_install_history_panel,
+    workspace_state_loader, workspace_upstream_panel,
+};
 use tracing::warn;
 
 const SAVE_FLASH_MS: u64 = 1600;
@@ -33,10 +38,76 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, ctx: &egui:
 
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
             render_save_or_share_button(ui, orchestrator, palette);
-            if orchestrator.workspace_view.fork_meta.is_some()
-                && fork_details_button(ui, palette).clicked()
+            if redesign_btn(
+                ui,
+                palette,
+                "history",
+                BtnOpts {
+                    small: true,
+                    ..Default::default()
+                },
+            )
+            .on_hover_text("Browse, compare and restore earlier revisions of this modlist")
+            .clicked()
+            {
+                orchestrator.workspace_view.history_open = true;
+            }
+            if can_apply_changes(orchestrator)
+                && redesign_btn(
+                    ui,
+                    palette,
+                    "apply changes",
+                    BtnOpts {
+                        small: true,
+                        ..Default::default()
+                    },
+                )
+                .on_hover_text(
+                    "Compare the edited order with the installed weidu.log and patch the install in place",
+                )
+                .clicked()
+            {
+                apply_changes_driver::open(orchestrator);
+            }
+            if redesign_btn(
+                ui,
+                palette,
+                "runs",
+                BtnOpts {
+                    small: true,
+                    ..Default::default()
+                },
+            )
+            .on_hover_text("Browse past install runs of this modlist and open their logs")
+            .clicked()
             {
-                orchestrator.workspace_view.fork_info_open = true;
+                orchestrator.workspace_view.install_history_open = true;
+            }
+            if orchestrator.workspace_view.fork_meta.is_some() {
+                if fork_details_button(ui, palette).clicked() {
+                    orchestrator.workspace_view.fork_info_open = true;
+                }
+                let has_baseline = workspace_upstream_panel::has_baseline(orchestrator);
+                if redesign_btn(
+                    ui,
+                    palette,
+                    "upstream",
+                    BtnOpts {
+                        small: true,
+                        disabled: !has_baseline,
+                        ..Default::default()
+                    },
+                )
+                .on_hover_text(if has_baseline {
+                    "Merge a newer share code of the modlist this fork came from"
+                } else {
+                    "This fork has no recorded parent share code to merge against"
+                })
+                .clicked()
+                    && has_baseline
+                {
+                    orchestrator.workspace_view.upstream_open = true;
+                }
             }
         });
     });
@@ -44,6 +115,20 @@ pub fn render(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, ctx: &egui:
     if orchestrator.workspace_view.fork_info_open {
         render_fork_info_popup(orchestrator, palette, ctx);
     }
+    workspace_history_panel::render(orchestrator, palette, ctx);
+    workspace_install_history_panel::render(orchestrator, palette, ctx);
+    workspace_upstream_panel::render(orchestrator, palette, ctx);
+    workspace_apply_changes_panel::render(orchestrator, palette, ctx);
+}
+
+fn can_apply_changes(orchestrator: &OrchestratorApp) -> bool {
+    matches!(
+        orchestrator.workspace_view.current_step,
+        WorkspaceStep::Step3 | WorkspaceStep::Step4
+    ) && orchestrator
+        .registry
+        .find(&orchestrator.workspace_view.modlist_id)
+        .is_some_and(|entry| entry.state == ModlistState::Installed)
 }
 
 fn render_title_row(ui: &mut egui::Ui, orchestrator: &mut OrchestratorApp, palette: ThemePalette) {
@@ -0,0 +1,416 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::time::Instant;
+
+use chrono::{Local, Utc};
+use eframe::egui;
+use tracing::warn;
+
+use crate::registry::errors::RegistryError;
+use crate::registry::store_workspace::WorkspaceStore;
+use crate::registry::workspace_history::{
+    WorkspaceHistory, WorkspaceHistoryStore, diff_workspaces, restored_state,
+};
+use crate::registry::workspace_model::ModlistWorkspaceState;
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
+use crate::ui::shared::redesign_tokens::{
+    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_accent_deep,
+    redesign_border_strong, redesign_error, redesign_input_bg, redesign_shell_bg,
+    redesign_text_faint, redesign_text_muted, redesign_text_primary,
+};
+use crate::ui::shared::redesign_visuals::redesign_overlay_shadow;
+use crate::ui::workspace::state_workspace::WorkspaceStep;
+use crate::ui::workspace::workspace_state_loader;
+
+const MAX_WIDTH_PX: f32 = 560.0;
+
+const LIST_HEIGHT_PX: f32 = 220.0;
+
+enum HistoryAction {
+    Close,
+    Select(u64),
+    SaveNamed,
+    Restore(u64),
+}
+
+pub fn render(orchestrator: &mut OrchestratorApp, palette: ThemePalette, ctx: &egui::Context) {
+    if !orchestrator.workspace_view.history_open {
+        return;
+    }
+    if orchestrator.workspace_view.history.is_none()
+        && orchestrator.workspace_view.history_error.is_none()
+    {
+        match load_history(orchestrator) {
+            Ok(history) => orchestrator.workspace_view.history = Some(history),
+            Err(err) => {
+                orchestrator.workspace_view.history_error = Some(load_error_message(&err));
+            }
+        }
+    }
+    let current = current_workspace(orchestrator);
+    let history = orchestrator
+        .workspace_view
+        .history
+        .clone()
+        .unwrap_or_default();
+    let selected = orchestrator.workspace_view.history_selected;
+    let can_restore = orchestrator.workspace_view.current_step != WorkspaceStep::Step5;
+    let mut action = None;
+
+    let frame = egui::Frame::default()
+        .fill(redesign_shell_bg(palette))
+        .stroke(egui::Stroke::new(
+            REDESIGN_BORDER_WIDTH_PX,
+            redesign_border_strong(palette),
+        ))
+        .corner_radius(egui::CornerRadius::same(REDESIGN_BORDER_RADIUS_U8))
+        .shadow(redesign_overlay_shadow(palette))
+        .inner_margin(egui::Margin::same(18));
+
+    egui::Window::new("orchestrator_workspace_history")
+        .id(egui::Id::new("orchestrator_workspace_history"))
+        .title_bar(false)
+        .resizable(false)
+        .collapsible(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .frame(frame)
+        .show(ctx, |ui| {
+            ui.set_max_width(MAX_WIDTH_PX);
+            ui.label(
+                egui::RichText::new("Revision history")
+                    .size(15.0)
+                    .family(egui::FontFamily::Name("poppins_medium".into()))
+                    .color(redesign_text_primary(palette)),
+            );
+            ui.add_space(10.0);
+
+            ui.horizontal(|ui| {
+                ui.add_sized(
+                    egui::vec2(280.0, 26.0),
+                    egui::TextEdit::singleline(&mut orchestrator.workspace_view.history_name_temp)
+                        .hint_text("name this revision")
+                        .text_color(redesign_text_primary(palette))
+                        .background_color(redesign_input_bg(palette))
+                        .margin(egui::Margin::symmetric(8, 4)),
+                );
+                let blank = orchestrator
+                    .workspace_view
+                    .history_name_temp
+                    .trim()
+                    .is_empty();
+                if redesign_btn(
+                    ui,
+                    palette,
+                    "save revision",
+                    BtnOpts {
+                        small: true,
+                        primary: true,
+                        disabled: blank,
+                        ..Default::default()
+                    },
+                )
+                .clicked()
+                    && !blank
+                {
+                    action = Some(HistoryAction::SaveNamed);
+                }
+            });
+            if let Some(err) = orchestrator.workspace_view.history_error.as_deref() {
+                ui.add_space(6.0);
+                ui.label(
+                    egui::RichText::new(err)
+                        .size(12.0)
+                        .color(redesign_error(palette)),
+                );
+            }
+            ui.add_space(10.0);
+
+            render_timeline(ui, palette, &history, selected, &mut action);
+
+            if let Some(revision) = selected.and_then(|id| history.find(id)) {
+                ui.add_space(10.0);
+                let previous = history
+                    .revisions
+                    .iter()
+                    .take_while(|r| r.id != revision.id)
+                    .last()
+                    .map(|r| &r.state);
+                let empty = ModlistWorkspaceState::default();
+                diff_block(
+                    ui,
+                    palette,
+                    "changes in this revision",
+                    &diff_workspaces(previous.unwrap_or(&empty), &revision.state).lines(),
+                );
+                if let Some(current) = current.as_ref() {
+                    diff_block(
+                        ui,
+                        palette,
+                        "restoring would change",
+                        &diff_workspaces(current, &revision.state).lines(),
+                    );
+                }
+                ui.add_space(6.0);
+                if redesign_btn(
+                    ui,
+                    palette,
+                    "restore",
+                    BtnOpts {
+                        small: true,
+                        disabled: !can_restore,
+                        ..Default::default()
+                    },
+                )
+                .on_hover_text(if can_restore {
+                    "Replace the current order, selection and prompt answers with this revision"
+                } else {
+                    "Not available while installing"
+                })
+                .clicked()
+                    && can_restore
+                {
+                    action = Some(HistoryAction::Restore(revision.id));
+                }
+            }
+
+            ui.add_space(12.0);
+            ui.allocate_ui_with_layout(
+                egui::vec2(ui.available_width(), 30.0),
+                egui::Layout::right_to_left(egui::Align::Center),
+                |ui| {
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        "Close",
+                        BtnOpts {
+                            small: true,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        action = Some(HistoryAction::Close);
+                    }
+                },
+            );
+        });
+
+    match action {
+        Some(HistoryAction::Close) => {
+            orchestrator.workspace_view.history_open = false;
+            orchestrator.workspace_view.history = None;
+            orchestrator.workspace_view.history_selected = None;
+            orchestrator.workspace_view.history_error = None;
+        }
+        Some(HistoryAction::Select(id)) => {
+            orchestrator.workspace_view.history_selected = Some(id);
+        }
+        Some(HistoryAction::SaveNamed) => save_named(orchestrator, current),
+        Some(HistoryAction::Restore(id)) => restore_revision(orchestrator, current, id),
+        None => {}
+    }
+}
+
+fn render_timeline(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    history: &WorkspaceHistory,
+    selected: Option<u64>,
+    action: &mut Option<HistoryAction>,
+) {
+    if history.revisions.is_empty() {
+        ui.label(
+            egui::RichText::new("No revisions yet. Changes are snapshotted as you edit.")
+                .size(13.0)
+                .family(egui::FontFamily::Name("poppins_light".into()))
+                .color(redesign_text_faint(palette)),
+        );
+        return;
+    }
+    egui::ScrollArea::vertical()
+        .max_height(LIST_HEIGHT_PX)
+        .auto_shrink([false, true])
+        .show(ui, |ui| {
+            for revision in history.revisions.iter().rev() {
+                let is_selected = selected == Some(revision.id);
+                let when = revision
+                    .created_at
+                    .with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M");
+                let color = if is_selected {
+                    redesign_accent_deep(palette)
+                } else if revision.auto {
+                    redesign_text_muted(palette)
+                } else {
+                    redesign_text_primary(palette)
+                };
+                let text = egui::RichText::new(format!(
+                    "#{:<4} {when}  {}  \u{00B7} {} components",
+                    revision.id,
+                    revision.title(),
+                    revision.component_count()
+                ))
+                .size(12.0)
+                .family(egui::FontFamily::Monospace)
+                .color(color);
+                if ui.selectable_label(is_selected, text).clicked() {
+                    *action = Some(HistoryAction::Select(revision.id));
+                }
+            }
+        });
+}
+
+fn diff_block(ui: &mut egui::Ui, palette: ThemePalette, heading: &str, lines: &[String]) {
+    ui.label(
+        egui::RichText::new(heading)
+            .size(12.0)
+            .family(egui::FontFamily::Name("poppins_medium".into()))
+            .color(redesign_text_muted(palette)),
+    );
+    egui::ScrollArea::vertical()
+        .id_salt(heading)
+        .max_height(120.0)
+        .auto_shrink([false, true])
+        .show(ui, |ui| {
+            for line in lines {
+                ui.label(
+                    egui::RichText::new(line)
+                        .size(12.0)
+                        .family(egui::FontFamily::Monospace)
+                        .color(redesign_text_primary(palette)),
+                );
+            }
+        });
+}
+
+fn history_store(orchestrator: &OrchestratorApp) -> WorkspaceHistoryStore {
+    let id = &orchestrator.workspace_view.modlist_id;
+    orchestrator.workspace_stores.get(id).map_or_else(
+        || WorkspaceHistoryStore::new_for_id(id),
+        |store| WorkspaceHistoryStore::beside_workspace(store.path()),
+    )
+}
+
+fn load_history(orchestrator: &OrchestratorApp) -> Result<WorkspaceHistory, RegistryError> {
+    history_store(orchestrator).load().inspect_err(|err| {
+        warn!(
+            target = "orchestrator",
+            "workspace history load failed: {err}"
+        );
+    })
+}
+
+fn load_error_message(err: &RegistryError) -> String {
+    format!("Couldn't read the revision history, so it was left untouched: {err}")
+}
+
+pub(crate) fn current_workspace(
+    orchestrator: &mut OrchestratorApp,
+) -> Option<ModlistWorkspaceState> {
+    let id = orchestrator.workspace_view.modlist_id.clone();
+    if id.is_empty() {
+        return None;
+    }
+    workspace_state_loader::sync_step3_from_step2_if_changed(&mut orchestrator.wizard_state);
+    let prior = orchestrator
+        .workspace_state
+        .get(&id)
+        .cloned()
+        .unwrap_or_default();
+    Some(workspace_state_loader::extract_workspace_state_from_wizard(
+        &orchestrator.wizard_state,
+        &prior,
+    ))
+}
+
+fn save_named(orchestrator: &mut OrchestratorApp, current: Option<ModlistWorkspaceState>) {
+    let Some(current) = current else {
+        return;
+    };
+    let name = orchestrator
+        .workspace_view
+        .history_name_temp
+        .trim()
+        .to_string();
+    let store = history_store(orchestrator);
+    let mut history = match load_history(orchestrator) {
+        Ok(history) => history,
+        Err(err) => {
+            orchestrator.workspace_view.history_error = Some(load_error_message(&err));
+            return;
+        }
+    };
+    let id = history.record_named(&name, &current, Utc::now());
+    match store.save(&history) {
+        Ok(()) => {
+            orchestrator.workspace_view.history_name_temp.clear();
+            orchestrator.workspace_view.history_selected = Some(id);
+            orchestrator.workspace_view.history = Some(history);
+            orchestrator.workspace_view.history_error = None;
+        }
+        Err(err) => {
+            warn!(
+                target = "orchestrator",
+                "saving named revision failed: {err}"
+            );
+            orchestrator
+                .notification_manager
+                .error(format!("Couldn't save revision \"{name}\": {err}"));
+        }
+    }
+}
+
+fn restore_revision(
+    orchestrator: &mut OrchestratorApp,
+    current: Option<ModlistWorkspaceState>,
+    revision_id: u64,
+) {
+    let Some(current) = current else {
+        return;
+    };
+    let store = history_store(orchestrator);
+    let mut history = match load_history(orchestrator) {
+        Ok(history) => history,
+        Err(err) => {
+            orchestrator.workspace_view.history_error = Some(load_error_message(&err));
+            return;
+        }
+    };
+    let Some(revision) = history.find(revision_id).cloned() else {
+        return;
+    };
+    history.record_before_restore(revision_id, &current, Utc::now());
+    if let Err(err) = store.save(&history) {
+        warn!(
+            target = "orchestrator",
+            "workspace history save failed: {err}"
+        );
+        orchestrator
+            .notification_manager
+            .error(format!("Couldn't restore revision #{revision_id}: {err}"));
+        return;
+    }
+
+    let id = orchestrator.workspace_view.modlist_id.clone();
+    let restored = restored_state(&current, &revision);
+    workspace_state_loader::apply_workspace_selection(
+        &restored,
+        orchestrator.workspace_view.game,
+        &mut orchestrator.wizard_state,
+    );
+    orchestrator.workspace_state.insert(id.clone(), restored);
+    orchestrator
+        .workspace_stores
+        .entry(id.clone())
+        .or_insert_with(|| WorkspaceStore::new_for_id(&id));
+    orchestrator
+        .persistence_cycle
+        .mark_workspace_dirty(&id, Instant::now());
+    orchestrator.workspace_view.history = Some(history);
+    orchestrator.workspace_view.history_error = None;
+    orchestrator
+        .notification_manager
+        .success(format!("Restored \"{}\"", revision.title()));
+}
@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use chrono::Local;
+use eframe::egui;
+use tracing::warn;
+
+use crate::app::controller::util::open_in_shell;
+use crate::registry::install_history::{
+    CONSOLE_LOG_FILE_NAME, InstallHistory, InstallHistoryStore, InstallRunRecord, RunFilter,
+    TRIAGE_FILE_NAME,
+};
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
+use crate::ui::shared::redesign_tokens::{
+    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_accent_deep,
+    redesign_border_strong, redesign_input_bg, redesign_shell_bg, redesign_text_faint,
+    redesign_text_muted, redesign_text_primary,
+};
+use crate::ui::shared::redesign_visuals::redesign_overlay_shadow;
+
+const MAX_WIDTH_PX: f32 = 600.0;
+
+const LIST_HEIGHT_PX: f32 = 240.0;
+
+enum RunsAction {
+    Close,
+    Select(String),
+    Open(String, &'static str),
+}
+
+pub fn render(orchestrator: &mut OrchestratorApp, palette: ThemePalette, ctx: &egui::Context) {
+    if !orchestrator.workspace_view.install_history_open {
+        return;
+    }
+    if orchestrator.workspace_view.install_history.is_none() {
+        orchestrator.workspace_view.install_history = Some(load_history(orchestrator));
+    }
+    let history = orchestrator
+        .workspace_view
+        .install_history
+        .clone()
+        .unwrap_or_default();
+    let selected = orchestrator.workspace_view.install_history_selected.clone();
+    let mut action = None;
+
+    let frame = egui::Frame::default()
+        .fill(redesign_shell_bg(palette))
+        .stroke(egui::Stroke::new(
+            REDESIGN_BORDER_WIDTH_PX,
+            redesign_border_strong(palette),
+        ))
+        .corner_radius(egui::CornerRadius::same(REDESIGN_BORDER_RADIUS_U8))
+        .shadow(redesign_overlay_shadow(palette))
+        .inner_margin(egui::Margin::same(18));
+
+    egui::Window::new("orchestrator_workspace_install_history")
+        .id(egui::Id::new("orchestrator_workspace_install_history"))
+        .title_bar(false)
+        .resizable(false)
+        .collapsible(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .frame(frame)
+        .show(ctx, |ui| {
+            ui.set_max_width(MAX_WIDTH_PX);
+            ui.label(
+                egui::RichText::new("Install runs")
+                    .size(15.0)
+                    .family(egui::FontFamily::Name("poppins_medium".into()))
+                    .color(redesign_text_primary(palette)),
+            );
+            ui.add_space(10.0);
+
+            ui.horizontal(|ui| {
+                ui.add_sized(
+                    egui::vec2(260.0, 26.0),
+                    egui::TextEdit::singleline(
+                        &mut orchestrator.workspace_view.install_history_query,
+                    )
+                    .hint_text("search run id, status or component")
+                    .text_color(redesign_text_primary(palette))
+                    .background_color(redesign_input_bg(palette))
+                    .margin(egui::Margin::symmetric(8, 4)),
+                );
+                for filter in RunFilter::ALL {
+                    let current = orchestrator.workspace_view.install_history_filter == filter;
+                    if ui.selectable_label(current, filter.label()).clicked() {
+                        orchestrator.workspace_view.install_history_filter = filter;
+                    }
+                }
+            });
+            ui.add_space(10.0);
+
+            let query = orchestrator.workspace_view.install_history_query.clone();
+            let filter = orchestrator.workspace_view.install_history_filter;
+            let runs = history.search(&query, filter);
+            render_run_list(
+                ui,
+                palette,
+                &history,
+                &runs,
+                selected.as_deref(),
+                &mut action,
+            );
+
+            if let Some(run) = selected
+                .as_deref()
+                .and_then(|id| history.runs.iter().find(|r| r.run_id == id))
+            {
+                ui.add_space(10.0);
+                render_run_details(ui, palette, run, &mut action);
+            }
+
+            ui.add_space(12.0);
+            ui.allocate_ui_with_layout(
+                egui::vec2(ui.available_width(), 30.0),
+                egui::Layout::right_to_left(egui::Align::Center),
+                |ui| {
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        "Close",
+                        BtnOpts {
+                            small: true,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        action = Some(RunsAction::Close);
+                    }
+                },
+            );
+        });
+
+    match action {
+        Some(RunsAction::Close) => {
+            orchestrator.workspace_view.install_history_open = false;
+            orchestrator.workspace_view.install_history = None;
+            orchestrator.workspace_view.install_history_selected = None;
+        }
+        Some(RunsAction::Select(id)) => {
+            orchestrator.workspace_view.install_history_selected = Some(id);
+        }
+        Some(RunsAction::Open(run_id, file_name)) => {
+            let path = history_store(orchestrator).run_dir(&run_id).join(file_name);
+            if let Err(err) = open_in_shell(path.to_string_lossy().as_ref()) {
+                orchestrator
+                    .notification_manager
+                    .error(format!("Couldn't open {}: {err}", path.display()));
+            }
+        }
+        None => {}
+    }
+}
+
+fn render_run_list(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    history: &InstallHistory,
+    runs: &[&InstallRunRecord],
+    selected: Option<&str>,
+    action: &mut Option<RunsAction>,
+) {
+    if runs.is_empty() {
+        let text = if history.runs.is_empty() {
+            "No install runs recorded for this modlist yet."
+        } else {
+            "No runs match the current search."
+        };
+        ui.label(
+            egui::RichText::new(text)
+                .size(13.0)
+                .family(egui::FontFamily::Name("poppins_light".into()))
+                .color(redesign_text_faint(palette)),
+        );
+        return;
+    }
+    egui::ScrollArea::vertical()
+        .max_height(LIST_HEIGHT_PX)
+        .auto_shrink([false, true])
+        .show(ui, |ui| {
+            for run in runs {
+                let is_selected = selected == Some(run.run_id.as_str());
+                let when = run.finished_at.map_or_else(
+                    || run.run_id.clone(),
+                    |t| t.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string(),
+                );
+                let color = if is_selected {
+                    redesign_accent_deep(palette)
+                } else {
+                    redesign_text_primary(palette)
+                };
+                let text = egui::RichText::new(format!(
+                    "{when}  {:<11} {}/{} ok \u{00B7} {} failed \u{00B7} {} warn \u{00B7} exit {}",
+                    run.outcome().label(),
+                    run.succeeded,
+                    run.attempted,
+                    run.failed,
+                    run.warnings,
+                    run.exit_code
+                        .map_or_else(|| "-".to_string(), |code| code.to_string())
+                ))
+                .size(12.0)
+                .family(egui::FontFamily::Monospace)
+                .color(color);
+                if ui.selectable_label(is_selected, text).clicked() {
+                    *action = Some(RunsAction::Select(run.run_id.clone()));
+                }
+            }
+        });
+}
+
+fn render_run_details(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    run: &InstallRunRecord,
+    action: &mut Option<RunsAction>,
+) {
+    let muted = |text: String| {
+        egui::RichText::new(text)
+            .size(12.0)
+            .family(egui::FontFamily::Monospace)
+            .color(redesign_text_muted(palette))
+    };
+    ui.label(muted(format!("run {} \u{00B7} {}", run.run_id, run.status)));
+    if let (Some(start), Some(end)) = (run.started_at, run.finished_at) {
+        ui.label(muted(format!(
+            "started {} \u{00B7} finished {}",
+            start.with_timezone(&Local).format("%H:%M:%S"),
+            end.with_timezone(&Local).format("%H:%M:%S")
+        )));
+    }
+    if !run.answer_sources.is_empty() {
+        let sources = run
+            .answer_sources
+            .iter()
+            .map(|(source, count)| format!("{source} {count}"))
+            .collect::<Vec<_>>()
+            .join(" \u{00B7} ");
+        ui.label(muted(format!("answers: {sources}")));
+    }
+    for component in &run.failed_components {
+        ui.label(muted(format!("failed: {component}")));
+    }
+    ui.add_space(6.0);
+    ui.horizontal(|ui| {
+        for (label, file_name) in [
+            ("open console log", CONSOLE_LOG_FILE_NAME),
+            ("open triage", TRIAGE_FILE_NAME),
+        ] {
+            if redesign_btn(
+                ui,
+                palette,
+                label,
+                BtnOpts {
+                    small: true,
+                    ..Default::default()
+                },
+            )
+            .clicked()
+            {
+                *action = Some(RunsAction::Open(run.run_id.clone(), file_name));
+            }
+        }
+    });
+}
+
+fn history_store(orchestrator: &OrchestratorApp) -> InstallHistoryStore {
+    InstallHistoryStore::new_for_id(&orchestrator.workspace_view.modlist_id)
+}
+
+fn load_history(orchestrator: &OrchestratorApp) -> InstallHistory {
+    history_store(orchestrator).load().unwrap_or_else(|err| {
+        warn!(
+            target = "orchestrator",
+            "install history load failed: {err}"
+        );
+        InstallHistory::default()
+    })
+}
@@ -27,6 +27,7 @@ pub fn render(
     palette: ThemePalette,
     current: WorkspaceStep,
     disable_prev: bool,
+    next_blocked: Option<&str>,
     left_status: Option<&str>,
 ) -> NavBarOutcome {
     let mut outcome = NavBarOutcome::default();
@@ -82,9 +83,11 @@ pub fn render(
                     GlyphSide::Trailing(ARROW_FWD),
                     "Next",
                     true,
-                    false,
+                    next_blocked.is_some(),
                 );
-                if resp.clicked() {
+                if let Some(reason) = next_blocked {
+                    resp.on_hover_text(reason);
+                } else if resp.clicked() {
                     outcome.next_clicked = true;
                 }
                 let hint = format!("next: {}", current.next().map_or("", WorkspaceStep::label));
@@ -34,10 +34,17 @@ pub fn populate_wizard_state_from_workspace(
     }
 
     reset_scanned_step2_set(wizard_state);
+    apply_workspace_selection(workspace, entry.game, wizard_state);
+}
 
+pub fn apply_workspace_selection(
+    workspace: &ModlistWorkspaceState,
+    game: Game,
+    wizard_state: &mut WizardState,
+) {
     apply_order_to_mods(&workspace.order_bgee, &mut wizard_state.step2.bgee_mods);
     apply_order_to_mods(&workspace.order_bg2ee, &mut wizard_state.step2.bg2ee_mods);
-    if entry.game == Game::IWDEE {
+    if game == Game::IWDEE {
         apply_order_to_mods(&workspace.order_iwdee, &mut wizard_state.step2.bgee_mods);
     }
     recompute_mod_checked(&mut wizard_state.step2.bgee_mods);
@@ -0,0 +1,375 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Copyright (c) 2026 Born2BSalty
+
+use std::time::Instant;
+
+use eframe::egui;
+
+use crate::app::fork_merge::{self, ForkConflictKind, ForkMerge, ForkSide};
+use crate::app::modlist_share::preview_modlist_share_code;
+use crate::registry::model::Game;
+use crate::registry::store_workspace::WorkspaceStore;
+use crate::ui::orchestrator::orchestrator_app::OrchestratorApp;
+use crate::ui::orchestrator::widgets::{BtnOpts, redesign_btn};
+use crate::ui::shared::redesign_tokens::{
+    REDESIGN_BORDER_RADIUS_U8, REDESIGN_BORDER_WIDTH_PX, ThemePalette, redesign_border_strong,
+    redesign_error, redesign_input_bg, redesign_shell_bg, redesign_text_faint, redesign_text_muted,
+    redesign_text_primary, redesign_warning,
+};
+use crate::ui::shared::redesign_visuals::redesign_overlay_shadow;
+use crate::ui::workspace::state_workspace::WorkspaceStep;
+use crate::ui::workspace::{workspace_history_panel, workspace_state_loader};
+
+const MAX_WIDTH_PX: f32 = 600.0;
+
+const CONFLICT_LIST_HEIGHT_PX: f32 = 240.0;
+
+enum UpstreamAction {
+    Close,
+    Compare,
+    Choose(usize, usize, ForkSide),
+    Apply,
+}
+
+pub fn render(orchestrator: &mut OrchestratorApp, palette: ThemePalette, ctx: &egui::Context) {
+    if !orchestrator.workspace_view.upstream_open {
+        return;
+    }
+    let has_baseline = has_baseline(orchestrator);
+    let can_apply = orchestrator.workspace_view.current_step != WorkspaceStep::Step5;
+    let mut action = None;
+
+    let frame = egui::Frame::default()
+        .fill(redesign_shell_bg(palette))
+        .stroke(egui::Stroke::new(
+            REDESIGN_BORDER_WIDTH_PX,
+            redesign_border_strong(palette),
+        ))
+        .corner_radius(egui::CornerRadius::same(REDESIGN_BORDER_RADIUS_U8))
+        .shadow(redesign_overlay_shadow(palette))
+        .inner_margin(egui::Margin::same(18));
+
+    egui::Window::new("orchestrator_workspace_upstream")
+        .id(egui::Id::new("orchestrator_workspace_upstream"))
+        .title_bar(false)
+        .resizable(false)
+        .collapsible(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+        .frame(frame)
+        .show(ctx, |ui| {
+            ui.set_max_width(MAX_WIDTH_PX);
+            ui.label(
+                egui::RichText::new("Upstream changes")
+                    .size(15.0)
+                    .family(egui::FontFamily::Name("poppins_medium".into()))
+                    .color(redesign_text_primary(palette)),
+            );
+            ui.add_space(4.0);
+            ui.label(
+                egui::RichText::new(if has_baseline {
+                    "Paste a newer share code of the parent modlist. Its changes since you forked are merged with yours; \
+                     where both sides changed the same component you pick which one wins."
+                } else {
+                    "No parent share code was recorded when this fork was created, so upstream changes \
+                     can't be told apart from yours. Merging is unavailable for this fork."
+                })
+                .size(13.0)
+                .family(egui::FontFamily::Name("poppins_light".into()))
+                .color(redesign_text_muted(palette)),
+            );
+            ui.add_space(10.0);
+
+            ui.horizontal(|ui| {
+                ui.add_sized(
+                    egui::vec2(MAX_WIDTH_PX - 110.0, 26.0),
+                    egui::TextEdit::singleline(&mut orchestrator.workspace_view.upstream_code)
+                        .hint_text("BIO-MODLIST-V1:...")
+                        .text_color(redesign_text_primary(palette))
+                        .background_color(redesign_input_bg(palette))
+                        .margin(egui::Margin::symmetric(8, 4)),
+                );
+                let blank = orchestrator.workspace_view.upstream_code.trim().is_empty();
+                if redesign_btn(
+                    ui,
+                    palette,
+                    "compare",
+                    BtnOpts {
+                        small: true,
+                        primary: true,
+                        disabled: blank || !has_baseline,
+                        ..Default::default()
+                    },
+                )
+                .clicked()
+                    && !blank
+                    && has_baseline
+                {
+                    action = Some(UpstreamAction::Compare);
+                }
+            });
+            if let Some(err) = orchestrator.workspace_view.upstream_error.as_deref() {
+                ui.add_space(6.0);
+                ui.label(
+                    egui::RichText::new(err)
+                        .size(12.0)
+                        .color(redesign_error(palette)),
+                );
+            }
+
+            if let Some(merge) = orchestrator.workspace_view.upstream_merge.as_ref() {
+                ui.add_space(10.0);
+                render_merge(ui, palette, merge, &mut action);
+            }
+
+            ui.add_space(12.0);
+            ui.allocate_ui_with_layout(
+                egui::vec2(ui.available_width(), 30.0),
+                egui::Layout::right_to_left(egui::Align::Center),
+                |ui| {
+                    ui.spacing_mut().item_spacing.x = 8.0;
+                    if orchestrator.workspace_view.upstream_merge.is_some()
+                        && redesign_btn(
+                            ui,
+                            palette,
+                            "Apply merge",
+                            BtnOpts {
+                                small: true,
+                                primary: true,
+                                disabled: !can_apply,
+                                ..Default::default()
+                            },
+                        )
+                        .on_hover_text(if can_apply {
+                            "Replace the current selection and order with the merged result"
+                        } else {
+                            "Not available while installing"
+                        })
+                        .clicked()
+                        && can_apply
+                    {
+                        action = Some(UpstreamAction::Apply);
+                    }
+                    if redesign_btn(
+                        ui,
+                        palette,
+                        "Close",
+                        BtnOpts {
+                            small: true,
+                            ..Default::default()
+                        },
+                    )
+                    .clicked()
+                    {
+                        action = Some(UpstreamAction::Close);
+                    }
+                },
+            );
+        });
+
+    match action {
+        Some(UpstreamAction::Close) => close(orchestrator),
+        Some(UpstreamAction::Compare) => compare(orchestrator),
+        Some(UpstreamAction::Choose(tab, index, side)) => {
+            if let Some(conflict) = orchestrator
+                .workspace_view
+                .upstream_merge
+                .as_mut()
+                .and_then(|merge| merge.tabs.get_mut(tab))
+                .and_then(|tab| tab.conflicts.get_mut(index))
+            {
+                conflict.choice = side;
+            }
+        }
+        Some(UpstreamAction::Apply) => apply(orchestrator),
+        None => {}
+    }
+}
+
+fn render_merge(
+    ui: &mut egui::Ui,
+    palette: ThemePalette,
+    merge: &ForkMerge,
+    action: &mut Option<UpstreamAction>,
+) {
+    ui.label(
+        egui::RichText::new(format!(
+            "{} upstream change(s) merged automatically \u{00B7} {} conflict(s)",
+            merge.adopted_count(),
+            merge.conflict_count()
+        ))
+        .size(13.0)
+        .family(egui::FontFamily::Name("poppins_medium".into()))
+        .color(redesign_text_primary(palette)),
+    );
+    if merge.conflict_count() == 0 && merge.tabs.iter().all(|t| t.order_conflicts.is_empty()) {
+        return;
+    }
+    ui.add_space(6.0);
+    egui::ScrollArea::vertical()
+        .max_height(CONFLICT_LIST_HEIGHT_PX)
+        .auto_shrink([false, true])
+        .show(ui, |ui| {
+            for (tab_index, tab) in merge.tabs.iter().enumerate() {
+                for (index, conflict) in tab.conflicts.iter().enumerate() {
+                    let kind = match conflict.kind {
+                        ForkConflictKind::Selection => "kept on one side, removed on the other",
+                        ForkConflictKind::Prompt => "prompt answers differ",
+                    };
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{} {}  \u{00B7} {kind}",
+                            tab.tab,
+                            conflict.label()
+                        ))
+                        .size(12.0)
+                        .family(egui::FontFamily::Monospace)
+                        .color(redesign_text_primary(palette)),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add_space(12.0);
+                        for (side, prefix) in
+                            [(ForkSide::Local, "mine"), (ForkSide::Upstream, "upstream")]
+                        {
+                            let text = egui::RichText::new(format!(
+                                "{prefix}: {}",
+                                conflict.describe(side)
+                            ))
+                            .size(12.0)
+                            .color(redesign_text_muted(palette));
+                            if ui.selectable_label(conflict.choice == side, text).clicked() {
+                                *action = Some(UpstreamAction::Choose(tab_index, index, side));
+                            }
+                        }
+                    });
+                    ui.add_space(4.0);
+                }
+                for forced in &tab.order_conflicts {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{} {forced}: both sides reordered around it; kept its local position",
+                            tab.tab
+                        ))
+                        .size(12.0)
+                        .color(redesign_warning(palette)),
+                    );
+                }
+            }
+        });
+    ui.label(
+        egui::RichText::new("Review Step 3 after applying; compat warnings are re-checked there.")
+            .size(12.0)
+            .color(redesign_text_faint(palette)),
+    );
+}
+
+#[must_use]
+pub fn has_baseline(orchestrator: &OrchestratorApp) -> bool {
+    orchestrator
+        .registry
+        .find(&orchestrator.workspace_view.modlist_id)
+        .is_some_and(|entry| entry.upstream_share_code.is_some())
+}
+
+fn close(orchestrator: &mut OrchestratorApp) {
+    let view = &mut orchestrator.workspace_view;
+    view.upstream_open = false;
+    view.upstream_code.clear();
+    view.upstream_merge = None;
+    view.upstream_error = None;
+}
+
+fn compare(orchestrator: &mut OrchestratorApp) {
+    let view = &mut orchestrator.workspace_view;
+    view.upstream_merge = None;
+    view.upstream_error = None;
+    let code = view.upstream_code.trim().to_string();
+    let game = view.game;
+    let id = view.modlist_id.clone();
+
+    let upstream = match preview_modlist_share_code(&code) {
+        Ok(preview) => preview,
+        Err(err) => {
+            orchestrator.workspace_view.upstream_error = Some(format!("Invalid share code: {err}"));
+            return;
+        }
+    };
+    let upstream_game = Game::from_legacy_string(&upstream.game_install);
+    if upstream_game != game {
+        orchestrator.workspace_view.upstream_error = Some(format!(
+            "This code is for {}, but the fork is {}.",
+            upstream_game.to_legacy_string(),
+            game.to_legacy_string()
+        ));
+        return;
+    }
+
+    let Some(baseline) = orchestrator
+        .registry
+        .find(&id)
+        .and_then(|entry| entry.upstream_share_code.clone())
+    else {
+        orchestrator.workspace_view.upstream_error =
+            Some("This fork has no recorded parent share code to merge against.".to_string());
+        return;
+    };
+    let base = match preview_modlist_share_code(&baseline) {
+        Ok(preview) => preview,
+        Err(err) => {
+            orchestrator.workspace_view.upstream_error = Some(format!(
+                "The recorded baseline code no longer parses: {err}"
+            ));
+            return;
+        }
+    };
+    let Some(current) = workspace_history_panel::current_workspace(orchestrator) else {
+        return;
+    };
+    orchestrator.workspace_view.upstream_merge = Some(fork_merge::merge_share_codes(
+        &base,
+        &upstream,
+        &current,
+        game == Game::IWDEE,
+    ));
+}
+
+fn apply(orchestrator: &mut OrchestratorApp) {
+    let Some(merge) = orchestrator.workspace_view.upstream_merge.clone() else {
+        return;
+    };
+    let Some(mut merged) = workspace_history_panel::current_workspace(orchestrator) else {
+        return;
+    };
+    let id = orchestrator.workspace_view.modlist_id.clone();
+    let game = orchestrator.workspace_view.game;
+    merge.apply_to(&mut merged, game == Game::IWDEE);
+    workspace_state_loader::apply_workspace_selection(
+        &merged,
+        game,
+        &mut orchestrator.wizard_state,
+    );
+    orchestrator.workspace_state.insert(id.clone(), merged);
+    orchestrator
+        .workspace_stores
+        .entry(id.clone())
+        .or_insert_with(|| WorkspaceStore::new_for_id(&id));
+    orchestrator
+        .persistence_cycle
+        .mark_workspace_dirty(&id, Instant::now());
+    let code = orchestrator.workspace_view.upstream_code.trim().to_string();
+    record_baseline(orchestrator, &id, code);
+    orchestrator.notification_manager.success(format!(
+        "Merged upstream: {} change(s) adopted, {} conflict(s) resolved",
+        merge.adopted_count(),
+        merge.conflict_count()
+    ));
+    close(orchestrator);
+}
+
+fn record_baseline(orchestrator: &mut OrchestratorApp, id: &str, code: String) {
+    if let Some(entry) = orchestrator.registry.find_mut(id) {
+        entry.upstream_share_code = Some(code);
+    }
+    orchestrator
+        .persistence_cycle
+        .mark_registry_dirty(Instant::now());
+}
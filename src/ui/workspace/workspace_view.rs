@@ -42,8 +42,15 @@ pub fn render(
         || orchestrator.wizard_state.step5.install_running
         || orchestrator.workspace_step5.install_clicked;
     let nav_status = nav_status_text(orchestrator, current);
-    let outcome =
-        workspace_nav_bar::render(ui, palette, current, disable_prev, nav_status.as_deref());
+    let next_blocked = next_blocked_reason(orchestrator, current);
+    let outcome = workspace_nav_bar::render(
+        ui,
+        palette,
+        current,
+        disable_prev,
+        next_blocked.as_deref(),
+        nav_status.as_deref(),
+    );
 
     if outcome.next_clicked {
         if let Some(next) = current.next() {
@@ -55,16 +62,36 @@ pub fn render(
             }
             orchestrator.workspace_view.completed_steps.insert(current);
             orchestrator.workspace_view.current_step = next;
+            orchestrator.workspace_view.prompt_dry_run = None;
         }
     } else if outcome.prev_clicked {
         if let Some(prev) = current.prev() {
             orchestrator.workspace_view.current_step = prev;
+            orchestrator.workspace_view.prompt_dry_run = None;
         } else {
             orchestrator.nav = NavDestination::Home;
         }
     }
 }
 
+fn next_blocked_reason(orchestrator: &OrchestratorApp, current: WorkspaceStep) -> Option<String> {
+    if current != WorkspaceStep::Step4 {
+        return None;
+    }
+    let flagged = orchestrator
+        .workspace_view
+        .prompt_dry_run
+        .as_ref()?
+        .flagged()
+        .count();
+    (flagged > 0).then(|| {
+        format!(
+            "{flagged} component(s) have unanswered prompts. Add @wlb-inputs or remembered \
+             answers, then re-run the prompt dry-run."
+        )
+    })
+}
+
 fn nav_status_text(orchestrator: &OrchestratorApp, current: WorkspaceStep) -> Option<String> {
     if current != WorkspaceStep::Step2 {
         return None;